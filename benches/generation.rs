@@ -0,0 +1,81 @@
+//! 生成流程基准：模板索引构建、大目录渲染、参数上下文合并
+//!
+//! 运行：`cargo bench --bench generation`
+
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use scaffold_gen::generators::core::{Parameters, TemplateProcessor};
+use scaffold_gen::generators::framework::gin::GinParams;
+use scaffold_gen::generators::framework::tauri::TauriParams;
+use scaffold_gen::template_engine::{TemplateEngine, get_embedded_template_files};
+use scaffold_gen::utils::console;
+
+/// 模板文件最多的几个模板集
+const LARGE_TEMPLATE_SETS: &[&str] = &["frameworks/rust/tauri", "frameworks/go/gin"];
+
+fn template_index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("template_index");
+    group.bench_function("list_embedded_files", |b| {
+        b.iter(|| get_embedded_template_files(black_box("")).unwrap())
+    });
+    group.bench_function("engine_new", |b| {
+        b.iter(|| TemplateEngine::new(PathBuf::new()).unwrap())
+    });
+    group.finish();
+}
+
+fn large_tree_rendering(c: &mut Criterion) {
+    console::set_quiet(true);
+
+    let mut group = c.benchmark_group("large_tree_rendering");
+    group.sample_size(20);
+    for template_path in LARGE_TEMPLATE_SETS {
+        let context = if template_path.ends_with("tauri") {
+            TauriParams::default().to_template_context()
+        } else {
+            GinParams::default().to_template_context()
+        };
+        group.bench_function(*template_path, |b| {
+            b.iter_batched(
+                || tempfile::tempdir().unwrap(),
+                |dir| {
+                    let processor = TemplateProcessor::new().unwrap();
+                    processor
+                        .process_embedded_template_directory(
+                            template_path,
+                            dir.path(),
+                            context.clone(),
+                        )
+                        .unwrap();
+                    dir
+                },
+                BatchSize::PerIteration,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn context_merging(c: &mut Criterion) {
+    let tauri = TauriParams::default();
+    let gin = GinParams::default().with_database("postgres".to_string());
+
+    let mut group = c.benchmark_group("context_merging");
+    group.bench_function("tauri", |b| {
+        b.iter(|| black_box(&tauri).to_template_context())
+    });
+    group.bench_function("gin_with_database", |b| {
+        b.iter(|| black_box(&gin).to_template_context())
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    template_index,
+    large_tree_rendering,
+    context_merging
+);
+criterion_main!(benches);
@@ -1,7 +1,15 @@
+use handlebars::template::{HelperTemplate, Parameter, Template, TemplateElement};
+use std::collections::BTreeSet;
 use std::env;
 use std::fs;
 use std::path::Path;
-use std::process::Command;
+use walkdir::WalkDir;
+
+/// 模板目录（相对于项目根目录）
+const TEMPLATES_DIR: &str = "templates";
+
+/// 共享片段目录（相对于模板目录）
+const PARTIALS_DIR: &str = "_partials";
 
 fn main() {
     // 重新构建条件
@@ -9,71 +17,123 @@ fn main() {
     println!("cargo:rerun-if-changed=Cargo.toml");
     println!("cargo:rerun-if-changed=templates/");
 
-    // 设置构建后钩子环境变量
-    println!("cargo:rustc-env=ENABLE_BINARY_COPY=1");
-    // 如果设置了环境变量,则在构建后复制二进制文
-    // if env::var("SCAFFOLD_COPY_BINARY").unwrap_or_default() == "1" {
-    copy_binary_to_root();
-    // }
+    // 模板在编译时嵌入二进制，语法错误或引用不存在的片段时直接让构建失败
+    validate_templates();
 }
 
-fn copy_binary_to_root() {
-    let profile = env::var("PROFILE").unwrap_or_else(|_| "debug".to_string());
-    let binary_name = get_binary_name();
-
-    // 获取项目根目录
+/// 解析所有模板和片段，检查语法以及引用的片段是否存在
+fn validate_templates() {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let project_root = Path::new(&manifest_dir);
+    let templates_root = Path::new(&manifest_dir).join(TEMPLATES_DIR);
 
-    // 构建源文件和目标文件路径
-    let source_path = project_root
-        .join("target")
-        .join(&profile)
-        .join(&binary_name);
-    let dest_path = project_root.join(&binary_name);
+    let mut partials = BTreeSet::new();
+    let mut references = Vec::new();
+    let mut errors = Vec::new();
 
-    if source_path.exists() {
-        match fs::copy(&source_path, &dest_path) {
-            Ok(_) => {
-                println!(
-                    "cargo:warning=Binary copied to project root: {}",
-                    dest_path.display()
-                );
-                // 验证复制的二进制文件
-                if verify_binary(&dest_path) {
-                    println!("cargo:warning=Binary verification successful");
-                } else {
-                    println!("cargo:warning=Binary copied but verification failed");
-                }
+    for entry in WalkDir::new(&templates_root).sort_by_file_name() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                errors.push(format!("failed to read templates directory: {e}"));
+                continue;
             }
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative_path = entry
+            .path()
+            .strip_prefix(&templates_root)
+            .unwrap()
+            .to_string_lossy()
+            .replace('\\', "/");
+        let is_partial = relative_path.starts_with(&format!("{PARTIALS_DIR}/"));
+        if is_partial {
+            let name = &relative_path[PARTIALS_DIR.len() + 1..];
+            partials.insert(name.strip_suffix(".hbs").unwrap_or(name).to_string());
+        } else if !relative_path.ends_with(".tmpl") {
+            continue;
+        }
+
+        // 路径中同样可以包含变量，如 cmd/{{project_name}}/main.go.tmpl
+        if let Err(e) = Template::compile(&relative_path) {
+            errors.push(format!(
+                "{relative_path}: invalid template path: {}",
+                e.reason()
+            ));
+        }
+
+        let content = match fs::read_to_string(entry.path()) {
+            Ok(content) => content,
             Err(e) => {
-                println!("cargo:warning=Failed to copy binary: {e}");
+                errors.push(format!("{relative_path}: {e}"));
+                continue;
+            }
+        };
+        match Template::compile(&content) {
+            Ok(template) => {
+                let mut names = BTreeSet::new();
+                collect_partials(&template, &mut names);
+                references.extend(names.into_iter().map(|name| (relative_path.clone(), name)));
             }
+            Err(e) => match e.pos() {
+                Some((line, column)) => {
+                    errors.push(format!("{relative_path}:{line}:{column}: {}", e.reason()))
+                }
+                None => errors.push(format!("{relative_path}: {}", e.reason())),
+            },
         }
-    } else {
-        println!(
-            "cargo:warning=Source binary not found: {}",
-            source_path.display()
+    }
+
+    for (template, partial) in references {
+        if !partials.contains(&partial) {
+            errors.push(format!("{template}: unknown partial '{partial}'"));
+        }
+    }
+
+    if !errors.is_empty() {
+        for error in &errors {
+            println!("cargo:warning=template error: {error}");
+        }
+        panic!(
+            "{} template error(s) in {TEMPLATES_DIR}/:\n{}",
+            errors.len(),
+            errors.join("\n")
         );
     }
 }
 
-fn get_binary_name() -> String {
-    if cfg!(target_os = "windows") {
-        "scafgen.exe".to_string()
-    } else {
-        "scafgen".to_string()
+/// 收集模板中引用的片段名
+fn collect_partials(template: &Template, names: &mut BTreeSet<String>) {
+    for element in &template.elements {
+        match element {
+            TemplateElement::Expression(helper)
+            | TemplateElement::HtmlExpression(helper)
+            | TemplateElement::HelperBlock(helper) => collect_helper_partials(helper, names),
+            TemplateElement::PartialExpression(partial)
+            | TemplateElement::PartialBlock(partial) => {
+                if let Some(name) = partial.name.as_name() {
+                    names.insert(name.to_string());
+                }
+                if let Some(inner) = &partial.template {
+                    collect_partials(inner, names);
+                }
+            }
+            _ => {}
+        }
     }
 }
 
-fn verify_binary(binary_path: &Path) -> bool {
-    if !binary_path.exists() {
-        return false;
+fn collect_helper_partials(helper: &HelperTemplate, names: &mut BTreeSet<String>) {
+    for param in helper.params.iter().chain(helper.hash.values()) {
+        if let Parameter::Subexpression(subexpression) = param
+            && let TemplateElement::Expression(inner) = subexpression.as_element()
+        {
+            collect_helper_partials(inner, names);
+        }
     }
-
-    // 尝试运行 --version 命令验证二进制文件
-    match Command::new(binary_path).arg("--version").output() {
-        Ok(output) => output.status.success(),
-        Err(_) => false,
+    for inner in [&helper.template, &helper.inverse].into_iter().flatten() {
+        collect_partials(inner, names);
     }
 }
@@ -0,0 +1,247 @@
+use anyhow::Result;
+use clap::{ArgAction, Command};
+use colored::*;
+use serde_json::{Value, json};
+use std::collections::BTreeSet;
+
+use super::new::{default_port, uses_network_settings};
+use crate::constants::{self, Framework, Language, Profile};
+use crate::generators::core::template_processor;
+use crate::template_engine;
+
+/// `scafgen catalog`：输出可生成的语言、技术栈、功能、选项及默认值
+///
+/// `--json` 输出完整的机器可读目录，供在 scafgen 之上构建 Web 界面或文档站点；
+/// 选项直接取自 `scafgen new` 的命令行定义，功能取自模板中的 `+功能` 片段目录，与实际生成保持一致
+pub struct CatalogCommand {
+    new_command: Command,
+    json: bool,
+}
+
+impl CatalogCommand {
+    /// `new_command` 为 `scafgen new` 子命令的定义
+    pub fn new(new_command: Command, json: bool) -> Self {
+        Self { new_command, json }
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        let catalog = self.catalog();
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&catalog)?);
+            return Ok(());
+        }
+
+        println!("{}", "Stacks".bold());
+        for stack in catalog["stacks"].as_array().into_iter().flatten() {
+            let port = stack["defaults"]["port"]
+                .as_u64()
+                .map(|port| format!(", port {port}"))
+                .unwrap_or_default();
+            println!(
+                "  {:<20} {}{port}",
+                stack["id"].as_str().unwrap_or_default().cyan(),
+                stack["description"].as_str().unwrap_or_default()
+            );
+            let features: Vec<&str> = stack["features"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect();
+            if !features.is_empty() {
+                println!("  {:<20} features: {}", "", features.join(", "));
+            }
+        }
+        println!();
+        println!("{}", "Profiles".bold());
+        for profile in constants::PROFILES {
+            println!("  {profile}");
+        }
+        println!();
+        println!(
+            "Run `scafgen new --help` for all options, or `scafgen catalog --json` for the full catalog."
+        );
+        Ok(())
+    }
+
+    /// 完整目录
+    pub fn catalog(&self) -> Value {
+        let stacks: Vec<Value> = constants::STACKS
+            .iter()
+            .filter_map(|stack| constants::parse_stack(stack).map(|parsed| (stack, parsed)))
+            .map(|(stack, (language, framework))| stack_entry(stack, language, framework))
+            .collect();
+        let languages: Vec<Value> = Language::all()
+            .iter()
+            .map(|language| {
+                json!({
+                    "id": language.as_lowercase(),
+                    "name": language.as_str(),
+                    "stacks": stacks
+                        .iter()
+                        .filter(|stack| stack["language"] == language.as_lowercase())
+                        .map(|stack| stack["id"].clone())
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        let profiles: Vec<Value> = constants::PROFILES
+            .iter()
+            .filter_map(|profile| Profile::parse_from_str(profile))
+            .map(|profile| json!({ "id": profile.as_str(), "features": profile.features() }))
+            .collect();
+
+        json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "languages": languages,
+            "stacks": stacks,
+            "profiles": profiles,
+            "options": self.options(),
+        })
+    }
+
+    /// `scafgen new` 的选项：名称、参数形式、说明、可选值和默认值
+    fn options(&self) -> Vec<Value> {
+        self.new_command
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set())
+            .map(|arg| {
+                let values: Vec<String> = arg
+                    .get_possible_values()
+                    .iter()
+                    .filter(|value| !value.is_hide_set())
+                    .map(|value| value.get_name().to_string())
+                    .collect();
+                let defaults: Vec<String> = arg
+                    .get_default_values()
+                    .iter()
+                    .map(|value| value.to_string_lossy().into_owned())
+                    .collect();
+                json!({
+                    "name": arg.get_id().as_str(),
+                    "flag": arg.get_long().map(|long| format!("--{long}")),
+                    "short": arg.get_short().map(|short| format!("-{short}")),
+                    "value_name": arg
+                        .get_value_names()
+                        .and_then(|names| names.first())
+                        .map(|name| name.as_str()),
+                    "description": arg.get_help().map(|help| help.to_string()),
+                    "required": arg.is_required_set(),
+                    "takes_value": arg.get_action().takes_values(),
+                    "multiple": matches!(arg.get_action(), ArgAction::Append)
+                        || arg.get_value_delimiter().is_some(),
+                    "values": values,
+                    "default": defaults.first(),
+                })
+            })
+            .collect()
+    }
+}
+
+fn stack_entry(stack: &str, language: Language, framework: Framework) -> Value {
+    let templates = template_dirs(language, framework);
+    let mut defaults = serde_json::Map::new();
+    if uses_network_settings(&language, &framework) {
+        defaults.insert("host".to_string(), json!("0.0.0.0"));
+        defaults.insert("port".to_string(), json!(default_port(&framework)));
+    }
+    json!({
+        "id": stack,
+        "language": language.as_lowercase(),
+        "framework": (framework != Framework::None).then(|| framework.as_lowercase()),
+        "name": match framework {
+            Framework::None => language.as_str(),
+            _ => framework.as_str(),
+        },
+        "description": match framework {
+            Framework::None => format!("{} project", language.as_str()),
+            _ => framework.display_name().to_string(),
+        },
+        "features": features(&templates),
+        "templates": templates,
+        "defaults": defaults,
+    })
+}
+
+/// 技术栈使用的模板组：语言模板和框架模板，不存在的目录不列出
+pub(crate) fn template_dirs(language: Language, framework: Framework) -> Vec<String> {
+    let language_dir = format!("languages/{}", language.as_lowercase());
+    let framework_dir = (framework != Framework::None).then(|| {
+        format!(
+            "frameworks/{}/{}",
+            language.as_lowercase(),
+            framework.as_lowercase()
+        )
+    });
+    std::iter::once(language_dir)
+        .chain(framework_dir)
+        .filter(|dir| {
+            template_engine::get_embedded_template_files(dir).is_ok_and(|files| !files.is_empty())
+        })
+        .collect()
+}
+
+/// 模板组中的 `+功能` 片段目录，即生成时可开关的功能
+pub(crate) fn features(templates: &[String]) -> Vec<String> {
+    let mut features = BTreeSet::new();
+    for dir in templates {
+        for file in template_engine::get_embedded_template_files(dir).unwrap_or_default() {
+            features.extend(template_processor::feature_dirs(&file).map(str::to_string));
+        }
+    }
+    features.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Arg;
+
+    #[test]
+    fn test_catalog() {
+        let new = Command::new("new")
+            .arg(Arg::new("name").required(true).help("Project name"))
+            .arg(
+                Arg::new("database")
+                    .long("database")
+                    .value_parser(["postgres", "mysql"])
+                    .default_value("postgres"),
+            );
+        let catalog = CatalogCommand::new(new, true).catalog();
+
+        let gin = catalog["stacks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|stack| stack["id"] == "go/gin")
+            .unwrap();
+        assert_eq!(
+            gin["templates"],
+            json!(["languages/go", "frameworks/go/gin"])
+        );
+        assert_eq!(gin["defaults"]["port"], json!(8080));
+        let features: Vec<&str> = gin["features"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert!(features.contains(&"docker") && features.contains(&"migrations"));
+
+        let react = &catalog["languages"][3];
+        assert_eq!(react["id"], "typescript");
+        assert!(
+            react["stacks"]
+                .as_array()
+                .unwrap()
+                .contains(&json!("ts/react"))
+        );
+
+        let options = catalog["options"].as_array().unwrap();
+        assert_eq!(options[0]["name"], "name");
+        assert_eq!(options[0]["required"], true);
+        assert_eq!(options[1]["flag"], "--database");
+        assert_eq!(options[1]["values"], json!(["postgres", "mysql"]));
+        assert_eq!(options[1]["default"], "postgres");
+    }
+}
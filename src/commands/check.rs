@@ -0,0 +1,126 @@
+use anyhow::{Result, bail};
+use colored::*;
+use std::path::{Path, PathBuf};
+
+use crate::generators::core::resolve_output_path;
+use crate::utils::feature_manifest::{self, FeatureManifest, MANIFEST_FILE};
+
+/// `scafgen check`：在项目 CI 中运行，检查固定文件（CI 流水线和 lint 配置）是否仍与生成时的模板一致
+///
+/// 固定文件的指纹在生成时写入 `.scafgen.json`，文件被修改或删除时以非零状态退出
+pub struct CheckCommand {
+    project_path: PathBuf,
+}
+
+/// 偏离模板的固定文件
+#[derive(Debug, PartialEq)]
+enum Drift {
+    Modified(String),
+    Missing(String),
+}
+
+impl CheckCommand {
+    pub fn new(project_path: PathBuf) -> Self {
+        Self { project_path }
+    }
+
+    pub fn execute(self) -> Result<()> {
+        let root = &self.project_path;
+        let manifest = FeatureManifest::load(root)?;
+        if manifest.pinned.is_empty() {
+            bail!(
+                "No pinned files recorded in {MANIFEST_FILE}; regenerate the project with scafgen {} or later to pin its CI and lint configs",
+                env!("CARGO_PKG_VERSION")
+            );
+        }
+        if manifest.version != env!("CARGO_PKG_VERSION") {
+            println!(
+                "{} project was generated by scafgen {}, running {}",
+                "Note:".yellow().bold(),
+                manifest.version,
+                env!("CARGO_PKG_VERSION")
+            );
+        }
+
+        let drifts = drifts(&manifest, root)?;
+        if drifts.is_empty() {
+            println!(
+                "{} {} pinned file(s) match the scafgen {} templates",
+                "✅".green(),
+                manifest.pinned.len(),
+                manifest.version
+            );
+            return Ok(());
+        }
+
+        println!(
+            "{}",
+            format!(
+                "Files drifted from the scafgen {} templates:",
+                manifest.version
+            )
+            .bold()
+        );
+        for drift in &drifts {
+            match drift {
+                Drift::Modified(file) => println!("  modified {file}"),
+                Drift::Missing(file) => println!("  missing  {file}"),
+            }
+        }
+        bail!(
+            "{} of {} pinned file(s) drifted from the template; restore them from the template or regenerate {MANIFEST_FILE} after an approved upgrade",
+            drifts.len(),
+            manifest.pinned.len()
+        )
+    }
+}
+
+/// 与清单中指纹不一致或已被删除的固定文件
+fn drifts(manifest: &FeatureManifest, root: &Path) -> Result<Vec<Drift>> {
+    let mut drifts = Vec::new();
+    for (file, expected) in &manifest.pinned {
+        let path = resolve_output_path(root, file)?;
+        match std::fs::read(&path) {
+            Ok(content) if feature_manifest::fingerprint(&content) == *expected => {}
+            Ok(_) => drifts.push(Drift::Modified(file.clone())),
+            Err(_) => drifts.push(Drift::Missing(file.clone())),
+        }
+    }
+    Ok(drifts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_pinned_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join(".github/workflows")).unwrap();
+        std::fs::write(root.join(".github/workflows/ci.yml"), "on: push\n").unwrap();
+        std::fs::write(root.join(".golangci.yml"), "linters: {}\n").unwrap();
+        std::fs::write(root.join("main.go"), "package main\n").unwrap();
+        let mut manifest = FeatureManifest {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            ..Default::default()
+        };
+        manifest.pin_files(root).unwrap();
+        manifest.save(root).unwrap();
+
+        CheckCommand::new(root.to_path_buf()).execute().unwrap();
+
+        // 用户代码的修改不算偏离
+        std::fs::write(root.join("main.go"), "package main\n\nfunc main() {}\n").unwrap();
+        std::fs::write(root.join(".github/workflows/ci.yml"), "on: pull_request\n").unwrap();
+        std::fs::remove_file(root.join(".golangci.yml")).unwrap();
+        assert_eq!(
+            drifts(&manifest, root).unwrap(),
+            [
+                Drift::Modified(".github/workflows/ci.yml".to_string()),
+                Drift::Missing(".golangci.yml".to_string()),
+            ]
+        );
+        assert!(CheckCommand::new(root.to_path_buf()).execute().is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use anyhow::{Result, anyhow, bail};
+use colored::*;
+use inquire::MultiSelect;
+use std::collections::BTreeSet;
+
+use super::catalog;
+use super::new::{default_port, uses_network_settings};
+use crate::constants::{self, Framework, Language};
+use crate::template_engine::TemplateManifest;
+use crate::utils::answers;
+
+/// 表格中表示"包含"和"不包含"的标记
+const YES: &str = "✓";
+const NO: &str = "-";
+
+/// `scafgen compare`：并排比较多个技术栈生成的内容，帮助在生成前做出选择
+///
+/// 功能取自模板中的 `+功能` 片段目录，主要依赖和目录结构取自模板组清单 `template.json`
+pub struct CompareCommand {
+    stacks: Vec<String>,
+}
+
+/// 一个技术栈的比较数据
+#[derive(Debug)]
+struct StackSummary {
+    id: String,
+    language: Language,
+    description: String,
+    port: Option<u16>,
+    features: Vec<String>,
+    dependencies: Vec<String>,
+    layout: Vec<String>,
+}
+
+impl CompareCommand {
+    /// `stacks` 为技术栈简写（如 `go/gin`）或框架名（如 `gin`），少于两个时交互选择
+    pub fn new(stacks: Vec<String>) -> Self {
+        Self { stacks }
+    }
+
+    pub fn execute(self) -> Result<()> {
+        let stacks = if self.stacks.len() < 2 {
+            answers::multi_select(
+                "stacks",
+                MultiSelect::new("Choose the stacks to compare:", constants::STACKS.to_vec()),
+            )?
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+        } else {
+            self.stacks
+        };
+        if stacks.len() < 2 {
+            bail!("Choose at least two stacks to compare");
+        }
+
+        let summaries = stacks
+            .iter()
+            .map(|stack| summarize(stack))
+            .collect::<Result<Vec<_>>>()?;
+        for summary in &summaries {
+            println!(
+                "{} {}",
+                format!("{}:", summary.id).cyan().bold(),
+                summary.description
+            );
+        }
+        println!();
+        print!("{}", table(&summaries));
+        println!();
+        println!("Run `scafgen new <name> --stack <stack>` to generate one of them.");
+        Ok(())
+    }
+}
+
+/// 解析技术栈并收集比较数据
+fn summarize(stack: &str) -> Result<StackSummary> {
+    let (language, framework) = resolve_stack(stack).ok_or_else(|| {
+        anyhow!(
+            "Unknown stack '{stack}', expected a framework or one of: {}",
+            constants::STACKS.join(", ")
+        )
+    })?;
+    let id = constants::STACKS
+        .iter()
+        .find(|candidate| constants::parse_stack(candidate) == Some((language, framework)))
+        .map_or_else(|| stack.to_string(), |candidate| candidate.to_string());
+
+    let templates = catalog::template_dirs(language, framework);
+    let mut description = None;
+    let mut dependencies = Vec::new();
+    let mut layout = Vec::new();
+    for template_path in &templates {
+        if let Some(manifest) = TemplateManifest::load(template_path)? {
+            description = manifest.description.or(description);
+            dependencies.extend(manifest.dependencies);
+            // 随选项变化的依赖标注对应的命令行参数
+            for (option, choices) in manifest.selectable_dependencies {
+                for (value, choice_dependencies) in choices {
+                    dependencies.extend(
+                        choice_dependencies
+                            .into_iter()
+                            .map(|dependency| format!("{dependency} (--{option} {value})")),
+                    );
+                }
+            }
+            layout.extend(manifest.layout);
+        }
+    }
+
+    Ok(StackSummary {
+        id,
+        language,
+        description: description.unwrap_or_else(|| match framework {
+            Framework::None => format!("{} project", language.as_str()),
+            _ => framework.display_name().to_string(),
+        }),
+        port: uses_network_settings(&language, &framework).then(|| default_port(&framework)),
+        features: catalog::features(&templates),
+        dependencies,
+        layout,
+    })
+}
+
+/// 技术栈简写（`go/gin`、`rust`）或单独的框架名（`gin`、`go-zero`）
+fn resolve_stack(stack: &str) -> Option<(Language, Framework)> {
+    constants::parse_stack(stack).or_else(|| {
+        let framework = Framework::parse_from_str(stack)?;
+        Some((framework.language()?, framework))
+    })
+}
+
+/// 并排比较表：每个技术栈一列，功能、依赖和目录按所有技术栈的并集逐行标记
+fn table(summaries: &[StackSummary]) -> String {
+    let mut rows: Vec<(String, Vec<String>)> = vec![
+        (
+            "Language".to_string(),
+            summaries
+                .iter()
+                .map(|summary| summary.language.as_str().to_string())
+                .collect(),
+        ),
+        (
+            "Default port".to_string(),
+            summaries
+                .iter()
+                .map(|summary| {
+                    summary
+                        .port
+                        .map_or_else(|| NO.to_string(), |port| port.to_string())
+                })
+                .collect(),
+        ),
+    ];
+    let sections: [(&str, Vec<&Vec<String>>); 3] = [
+        (
+            "Features",
+            summaries.iter().map(|summary| &summary.features).collect(),
+        ),
+        (
+            "Dependencies",
+            summaries
+                .iter()
+                .map(|summary| &summary.dependencies)
+                .collect(),
+        ),
+        (
+            "Layout",
+            summaries.iter().map(|summary| &summary.layout).collect(),
+        ),
+    ];
+    let mut headings = BTreeSet::new();
+    for (heading, items) in sections {
+        // 按首次出现的顺序合并各技术栈的条目
+        let mut union: Vec<&String> = Vec::new();
+        for item in items.iter().copied().flatten() {
+            if !union.contains(&item) {
+                union.push(item);
+            }
+        }
+        if union.is_empty() {
+            continue;
+        }
+        headings.insert(rows.len());
+        rows.push((heading.to_string(), Vec::new()));
+        for item in union {
+            let marks = items
+                .iter()
+                .map(|stack_items| if stack_items.contains(item) { YES } else { NO }.to_string())
+                .collect();
+            rows.push((format!("  {item}"), marks));
+        }
+    }
+
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or_default();
+    let column_widths: Vec<usize> = summaries
+        .iter()
+        .enumerate()
+        .map(|(index, summary)| {
+            rows.iter()
+                .filter_map(|(_, cells)| cells.get(index))
+                .map(|cell| cell.chars().count())
+                .chain([summary.id.chars().count()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut header = " ".repeat(label_width);
+    for (summary, width) in summaries.iter().zip(&column_widths) {
+        let padding = width - summary.id.chars().count();
+        header.push_str(&format!("  {}{}", summary.id.bold(), " ".repeat(padding)));
+    }
+    let mut output = format!("{}\n", header.trim_end());
+    for (index, (label, cells)) in rows.iter().enumerate() {
+        if headings.contains(&index) {
+            output.push_str(&format!("{}\n", label.bold()));
+            continue;
+        }
+        let mut line = format!("{label:<label_width$}");
+        for (cell, width) in cells.iter().zip(&column_widths) {
+            line.push_str(&format!("  {cell:<width$}"));
+        }
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_stacks() {
+        assert_eq!(
+            resolve_stack("go-zero"),
+            Some((Language::Go, Framework::GoZero))
+        );
+        assert_eq!(resolve_stack("ts/react"), resolve_stack("react"));
+        assert!(summarize("unknown").is_err());
+
+        let gin = summarize("gin").unwrap();
+        assert_eq!(gin.id, "go/gin");
+        assert_eq!(gin.port, Some(8080));
+        assert!(
+            gin.dependencies
+                .contains(&"github.com/gin-gonic/gin".to_string())
+        );
+        // 日志库的依赖只在选择对应的 --logger 时使用
+        assert!(!gin.dependencies.contains(&"go.uber.org/zap".to_string()));
+        assert!(
+            gin.dependencies
+                .contains(&"go.uber.org/zap (--logger zap)".to_string())
+        );
+        assert!(gin.layout.contains(&"routers/".to_string()));
+        let go_zero = summarize("go/go-zero").unwrap();
+        assert!(go_zero.layout.contains(&"rpc/".to_string()));
+
+        let table = table(&[gin, go_zero]);
+        let header = table.lines().next().unwrap();
+        assert!(header.contains("go/gin") && header.contains("go/go-zero"));
+        let row = |label: &str| {
+            table
+                .lines()
+                .find(|line| line.trim_start().starts_with(label))
+                .map(|line| line.split_whitespace().skip(1).collect::<Vec<_>>())
+                .unwrap()
+        };
+        assert_eq!(row("Language"), ["Go", "Go"]);
+        assert_eq!(row("github.com/gin-gonic/gin"), [YES, NO]);
+        assert_eq!(row("rpc/"), [NO, YES]);
+    }
+}
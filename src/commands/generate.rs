@@ -0,0 +1,67 @@
+use anyhow::{Result, anyhow};
+use colored::*;
+use std::path::PathBuf;
+
+use crate::constants::{self, ComponentKind, Framework};
+use crate::generators::component::{ComponentGenerator, ComponentParams};
+use crate::generators::core::{Generator, NextSteps};
+
+/// `scafgen generate <类型> <名称>`：在 scafgen 生成的项目中添加组件
+///
+/// Gin 项目可以添加处理器、模型和中间件，通过项目中的 `scafgen:` 标记注册路由组、中间件和 GORM 自动迁移；
+/// React 和 Vue3 项目可以添加组件和视图，项目安装了测试库和 Storybook 时同时生成测试和 story
+pub struct GenerateCommand {
+    kind: String,
+    name: String,
+    project_path: PathBuf,
+    framework: Option<String>,
+}
+
+impl GenerateCommand {
+    pub fn new(kind: String, name: String, project_path: PathBuf) -> Self {
+        Self {
+            kind,
+            name,
+            project_path,
+            framework: None,
+        }
+    }
+
+    /// 指定项目框架，未指定时按 go.mod 和 package.json 识别
+    pub fn with_framework(mut self, framework: Option<String>) -> Self {
+        self.framework = framework;
+        self
+    }
+
+    pub fn execute(self) -> Result<()> {
+        let kind = ComponentKind::parse_from_str(&self.kind).ok_or_else(|| {
+            anyhow!(
+                "Unsupported component type: {}. Supported types: {}",
+                self.kind,
+                constants::COMPONENT_KINDS.join(", ")
+            )
+        })?;
+        let framework = match self.framework {
+            Some(ref name) => Some(
+                Framework::parse_from_str(name)
+                    .ok_or_else(|| anyhow!("Unsupported framework: {name}"))?,
+            ),
+            None => None,
+        };
+        let params = ComponentParams::for_project(framework, kind, self.name, &self.project_path)?;
+        let generator = ComponentGenerator::new()?;
+        generator.generate(params.clone(), &self.project_path)?;
+
+        println!(
+            "{} {} {}",
+            "✅ Generated".green().bold(),
+            params.kind,
+            params.type_name()
+        );
+        let mut steps = NextSteps::new();
+        generator.next_steps(&params, &mut steps);
+        println!("Next steps:");
+        println!("{}", steps.render_text());
+        Ok(())
+    }
+}
@@ -1 +1,9 @@
+pub mod catalog;
+pub mod check;
+pub mod compare;
+pub mod generate;
 pub mod new;
+pub mod remove;
+#[cfg(feature = "serve")]
+pub mod serve;
+pub mod template;
@@ -1,11 +1,143 @@
 use anyhow::{Context, Result};
 use colored::*;
-use inquire::{Confirm, Select, Text};
-use std::path::PathBuf;
-
-use crate::constants::{Framework, Language};
-use crate::generators::{GeneratorOrchestrator, GinProjectOptions};
+use inquire::validator::Validation;
+use inquire::{Confirm, MultiSelect, Select, Text};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::catalog;
+use crate::constants::{
+    self, ApiClient, CUSTOM_LICENSE, CiProvider, Database, Editor, FailurePolicy, Feature,
+    Framework, K8sFormat, Language, Logger, NO_LICENSE, Orm, Profile, ProfileFeatures, Runtime,
+    TailwindVersion, TaskRunner, TauriVersion,
+};
+use crate::generators::core::{NextSteps, template_processor, validation};
+use crate::generators::framework::actix::parameters::DEFAULT_ACTIX_PORT;
+use crate::generators::framework::astro::parameters::DEFAULT_ASTRO_PORT;
+use crate::generators::framework::axum::parameters::DEFAULT_AXUM_PORT;
+use crate::generators::framework::chi::parameters::DEFAULT_CHI_PORT;
+use crate::generators::framework::fiber::parameters::DEFAULT_FIBER_PORT;
+use crate::generators::framework::flask::parameters::DEFAULT_FLASK_PORT;
+use crate::generators::framework::gin::openapi::OpenApiSpec;
+use crate::generators::framework::nestjs::parameters::DEFAULT_NESTJS_PORT;
+use crate::generators::framework::nextjs::parameters::DEFAULT_NEXTJS_PORT;
+use crate::generators::framework::nuxt::parameters::DEFAULT_NUXT_PORT;
+use crate::generators::framework::sveltekit::parameters::DEFAULT_SVELTEKIT_PORT;
+use crate::generators::k8s::K8sParams;
+use crate::generators::project::TaskParams;
+use crate::generators::workspace::parameters::{BACKEND_DIR, FRONTEND_DIR};
+use crate::generators::workspace::{self, ApiProxy, WorkspaceApp, WorkspaceParams};
+use crate::generators::{
+    ActixProjectOptions, AstroProjectOptions, AxumProjectOptions, ChiProjectOptions,
+    CliProjectOptions, DjangoProjectOptions, FiberProjectOptions, FlaskProjectOptions,
+    GeneratorOrchestrator, GinProjectOptions, GoZeroProjectOptions, NestJsProjectOptions,
+    NuxtProjectOptions, ProjectMetadata, SvelteKitProjectOptions,
+};
+use crate::scaffold::PostProcessor;
+use crate::status;
+use crate::template_engine;
+use crate::utils::answers;
+use crate::utils::archive::{self, ArchiveFormat};
+use crate::utils::config::UserConfig;
+use crate::utils::console;
+use crate::utils::editor;
 use crate::utils::env_checker::EnvironmentChecker;
+use crate::utils::feature_manifest;
+use crate::utils::git_identity;
+use crate::utils::hooks;
+use crate::utils::js_runtime;
+use crate::utils::overlay;
+use crate::utils::render_only;
+use crate::utils::spdx;
+use crate::utils::tool_failures::{self, ToolFailures};
+
+/// `--database` 中表示不使用数据库的取值
+const NO_DATABASE: &str = "none";
+
+/// `--ci` 中表示不生成 CI 配置的取值
+const NO_CI: &str = "none";
+
+/// 许可证选择中提供自定义许可证文本的选项
+const CUSTOM_LICENSE_OPTION: &str = "Custom (provide path)";
+
+/// `--nuxt-modules`、`--sveltekit-options` 中表示一项都不选的取值
+const NO_CHOICES: &str = "none";
+
+/// Gin 项目的 CORS 配置，未指定的部分使用生成器默认值
+struct CorsSettings {
+    origins: Option<Vec<String>>,
+    methods: Option<Vec<String>>,
+}
+
+/// 汇总确认界面中可以返回修改的答案
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SummaryItem {
+    Stack,
+    Host,
+    Port,
+    GrpcPort,
+    Services,
+    NuxtModules,
+    SvelteKitOptions,
+    Database,
+    Orm,
+    Logger,
+    Redis,
+    Grpc,
+    Drf,
+    Swagger,
+    Precommit,
+    Ci,
+    License,
+    Description,
+    Keywords,
+    Homepage,
+}
+
+impl SummaryItem {
+    fn label(&self) -> &'static str {
+        match self {
+            SummaryItem::Stack => "Language / framework",
+            SummaryItem::Host => "Host address",
+            SummaryItem::Port => "HTTP port",
+            SummaryItem::GrpcPort => "gRPC port",
+            SummaryItem::Services => "Services",
+            SummaryItem::NuxtModules => "Nuxt modules",
+            SummaryItem::SvelteKitOptions => "SvelteKit options",
+            SummaryItem::Database => "Database",
+            SummaryItem::Orm => "Data access layer",
+            SummaryItem::Logger => "Logging library",
+            SummaryItem::Redis => "Redis cache",
+            SummaryItem::Grpc => "gRPC server",
+            SummaryItem::Drf => "Django REST framework",
+            SummaryItem::Swagger => "Swagger docs",
+            SummaryItem::Precommit => "Pre-commit hooks",
+            SummaryItem::Ci => "CI provider",
+            SummaryItem::License => "License",
+            SummaryItem::Description => "Description",
+            SummaryItem::Keywords => "Keywords",
+            SummaryItem::Homepage => "Homepage",
+        }
+    }
+}
+
+/// 汇总确认界面的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SummaryAction {
+    Generate,
+    Change,
+    Cancel,
+}
+
+impl std::fmt::Display for SummaryAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SummaryAction::Generate => "Yes, generate the project",
+            SummaryAction::Change => "Change an answer",
+            SummaryAction::Cancel => "Cancel",
+        })
+    }
+}
 
 /// Project generation parameters
 struct ProjectParams {
@@ -14,11 +146,29 @@ struct ProjectParams {
     project_path: PathBuf,
     host: String,
     port: u16,
+    grpc_port: u16,
+    services: Vec<String>,
+    nuxt_modules: Vec<String>,
+    sveltekit_options: Vec<String>,
     enable_precommit: bool,
     license: String,
     enable_swagger: bool,
+    database: Option<Database>,
+    orm: Orm,
+    logger: Logger,
+    cors: Option<CorsSettings>,
+    enable_redis: bool,
+    enable_grpc: bool,
+    enable_drf: bool,
+    openapi: Option<OpenApiSpec>,
+    metadata: ProjectMetadata,
+    ci_provider: Option<CiProvider>,
+    k8s_format: Option<K8sFormat>,
+    task_runner: Option<TaskRunner>,
+    features: ProfileFeatures,
 }
 
+#[derive(Clone)]
 pub struct NewCommand {
     project_name: String,
     target_path: Option<String>,
@@ -26,10 +176,92 @@ pub struct NewCommand {
     host: Option<String>,
     port: Option<u16>,
     grpc_port: Option<u16>,
+    services: Option<Vec<String>>,
+    enable_api: Option<bool>,
+    enable_rpc: Option<bool>,
+    enable_admin: Option<bool>,
+    nuxt_modules: Option<Vec<String>>,
+    sveltekit_options: Option<Vec<String>>,
     language: Option<String>,
     enable_precommit: Option<bool>,
     license: Option<String>,
     enable_swagger: Option<bool>,
+    database: Option<String>,
+    /// `--orm`：Gin 项目访问 SQL 数据库的方式
+    orm: Option<String>,
+    /// `--logger`：Gin 项目使用的日志库
+    logger: Option<String>,
+    enable_cors: Option<bool>,
+    cors_origins: Option<Vec<String>>,
+    cors_methods: Option<Vec<String>>,
+    enable_redis: Option<bool>,
+    /// `--grpc`：Gin 项目生成 gRPC 服务器，go-zero 项目等同于 `--rpc`
+    enable_grpc: Option<bool>,
+    enable_drf: Option<bool>,
+    /// `--openapi`：Gin 项目按 OpenAPI 3 规范生成路由、处理器和模型
+    openapi: Option<PathBuf>,
+    description: Option<String>,
+    keywords: Option<Vec<String>>,
+    homepage: Option<String>,
+    /// `--author`：作者名，未指定时使用 git 的 `user.name`
+    author: Option<String>,
+    /// `--email`：作者邮箱，未指定时使用 git 的 `user.email`
+    email: Option<String>,
+    /// `--license-holder`：LICENSE 中的版权人
+    license_holder: Option<String>,
+    /// `--license-year`：LICENSE 中的版权年份或年份范围
+    license_year: Option<String>,
+    /// `--license-file`：自定义许可证文本，复制为 LICENSE
+    license_file: Option<PathBuf>,
+    profile: Option<String>,
+    failure_policy: Option<String>,
+    /// `--tailwind-version`：Vue3、React 和 Tauri 项目使用的 Tailwind CSS 主版本
+    tailwind_version: Option<String>,
+    /// `--tauri-version`：Tauri 项目的主版本
+    tauri_version: Option<String>,
+    /// `--runtime`：TypeScript 项目使用的 JavaScript 运行时
+    runtime: Option<String>,
+    /// `--api-client`：Vue3 和 React 项目生成的 API 客户端层
+    api_client: Option<String>,
+    /// `--tanstack-query`：Vue3 和 React 项目是否加入 TanStack Query 示例
+    tanstack_query: Option<bool>,
+    /// `--auth-pages`：Vue3 和 React 项目是否生成登录注册页面
+    auth_pages: Option<bool>,
+    /// `--dark-mode`：Vue3 和 React 项目是否生成主题切换
+    dark_mode: Option<bool>,
+    /// `--docker`：覆盖档位中的 Docker 开关
+    enable_docker: Option<bool>,
+    /// `--ci`：CI 平台，`none` 表示不生成 CI 配置
+    ci: Option<String>,
+    /// `--k8s`：Kubernetes 部署文件格式，后端服务才会生成
+    k8s: Option<String>,
+    /// `--compose-dev`：是否生成热重载的 docker-compose.dev.yaml
+    compose_dev: bool,
+    /// `--task-runner`：统一任务入口，`none` 表示不生成
+    task_runner: Option<String>,
+    /// `--open`：外层 Some 表示需要打开编辑器，内层为指定的编辑器
+    open: Option<Option<String>>,
+    trust: bool,
+    /// `--render-to`：只渲染模板到该目录，不执行外部工具
+    render_to: Option<String>,
+    /// `--archive`：把渲染结果打包到该文件，同样不执行外部工具
+    archive: Option<String>,
+    /// `--template-dir`：替换同路径嵌入式模板组的本地模板目录
+    template_dir: Option<PathBuf>,
+    /// `--overlay`：叠加在模板之上的组织覆盖层，本地目录或 git 仓库地址
+    overlay: Option<String>,
+    /// `--feature`：额外启用的功能片段目录（`+<功能>/`），通常来自第三方功能包
+    extra_features: Vec<String>,
+    /// 是否允许交互询问，关闭后未指定的选项使用提示中的默认值，无默认值时报错
+    interactive: bool,
+    /// `--app`：workspace 中的应用，格式为 `NAME=STACK`，为空时生成单个项目
+    apps: Vec<String>,
+    /// 是否作为 workspace 中的应用生成，git 仓库由 workspace 根目录初始化
+    in_workspace: bool,
+    /// `--fullstack`：全栈项目的后端和前端，格式为 `BACKEND+FRONTEND`
+    fullstack: Option<String>,
+    /// 全栈项目中前端转发 API 请求的后端，只在生成前端应用时设置
+    api_proxy: Option<ApiProxy>,
 }
 
 impl NewCommand {
@@ -41,10 +273,60 @@ impl NewCommand {
             host: None,
             port: None,
             grpc_port: None,
+            services: None,
+            enable_api: None,
+            enable_rpc: None,
+            enable_admin: None,
+            nuxt_modules: None,
+            sveltekit_options: None,
             language: None,
             enable_precommit: None,
             license: None,
             enable_swagger: None,
+            database: None,
+            orm: None,
+            logger: None,
+            enable_cors: None,
+            cors_origins: None,
+            cors_methods: None,
+            enable_redis: None,
+            enable_grpc: None,
+            enable_drf: None,
+            openapi: None,
+            description: None,
+            keywords: None,
+            homepage: None,
+            author: None,
+            email: None,
+            license_holder: None,
+            license_year: None,
+            license_file: None,
+            profile: None,
+            failure_policy: None,
+            tailwind_version: None,
+            tauri_version: None,
+            runtime: None,
+            api_client: None,
+            tanstack_query: None,
+            auth_pages: None,
+            dark_mode: None,
+            enable_docker: None,
+            ci: None,
+            k8s: None,
+            compose_dev: false,
+            task_runner: None,
+            open: None,
+            trust: false,
+            render_to: None,
+            archive: None,
+            template_dir: None,
+            overlay: None,
+            extra_features: Vec::new(),
+            interactive: true,
+            apps: Vec::new(),
+            in_workspace: false,
+            fullstack: None,
+            api_proxy: None,
         }
     }
 
@@ -68,11 +350,60 @@ impl NewCommand {
         self
     }
 
+    pub fn with_services(mut self, services: Option<Vec<String>>) -> Self {
+        self.services = services;
+        self
+    }
+
+    pub fn with_api(mut self, enable_api: Option<bool>) -> Self {
+        self.enable_api = enable_api;
+        self
+    }
+
+    pub fn with_rpc(mut self, enable_rpc: Option<bool>) -> Self {
+        self.enable_rpc = enable_rpc;
+        self
+    }
+
+    pub fn with_admin(mut self, enable_admin: Option<bool>) -> Self {
+        self.enable_admin = enable_admin;
+        self
+    }
+
+    pub fn with_nuxt_modules(mut self, nuxt_modules: Option<Vec<String>>) -> Self {
+        self.nuxt_modules = nuxt_modules;
+        self
+    }
+
+    pub fn with_sveltekit_options(mut self, sveltekit_options: Option<Vec<String>>) -> Self {
+        self.sveltekit_options = sveltekit_options;
+        self
+    }
+
     pub fn with_language(mut self, language: Option<String>) -> Self {
         self.language = language;
         self
     }
 
+    /// 通过 `语言/框架` 简写同时设置语言和框架
+    pub fn with_stack(mut self, stack: Option<String>) -> Self {
+        if let Some((language, framework)) = stack.as_deref().and_then(constants::parse_stack) {
+            self.language = Some(language.as_lowercase().to_string());
+            self.framework = Some(framework.as_lowercase().to_string());
+        }
+        self
+    }
+
+    pub fn with_apps(mut self, apps: Vec<String>) -> Self {
+        self.apps = apps;
+        self
+    }
+
+    pub fn with_fullstack(mut self, fullstack: Option<String>) -> Self {
+        self.fullstack = fullstack;
+        self
+    }
+
     pub fn with_precommit(mut self, enable_precommit: Option<bool>) -> Self {
         self.enable_precommit = enable_precommit;
         self
@@ -89,51 +420,804 @@ impl NewCommand {
         self
     }
 
+    pub fn with_database(mut self, database: Option<String>) -> Self {
+        self.database = database;
+        self
+    }
+
+    pub fn with_orm(mut self, orm: Option<String>) -> Self {
+        self.orm = orm;
+        self
+    }
+
+    pub fn with_logger(mut self, logger: Option<String>) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    pub fn with_cors(
+        mut self,
+        enable_cors: Option<bool>,
+        origins: Option<Vec<String>>,
+        methods: Option<Vec<String>>,
+    ) -> Self {
+        self.enable_cors = enable_cors;
+        self.cors_origins = origins;
+        self.cors_methods = methods;
+        self
+    }
+
+    pub fn with_redis(mut self, enable_redis: Option<bool>) -> Self {
+        self.enable_redis = enable_redis;
+        self
+    }
+
+    pub fn with_grpc(mut self, enable_grpc: Option<bool>) -> Self {
+        self.enable_grpc = enable_grpc;
+        self
+    }
+
+    pub fn with_drf(mut self, enable_drf: Option<bool>) -> Self {
+        self.enable_drf = enable_drf;
+        self
+    }
+
+    pub fn with_openapi(mut self, openapi: Option<PathBuf>) -> Self {
+        self.openapi = openapi;
+        self
+    }
+
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    pub fn with_keywords(mut self, keywords: Option<Vec<String>>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    pub fn with_homepage(mut self, homepage: Option<String>) -> Self {
+        self.homepage = homepage;
+        self
+    }
+
+    pub fn with_author(mut self, author: Option<String>) -> Self {
+        self.author = author;
+        self
+    }
+
+    pub fn with_email(mut self, email: Option<String>) -> Self {
+        self.email = email;
+        self
+    }
+
+    pub fn with_license_holder(mut self, license_holder: Option<String>) -> Self {
+        self.license_holder = license_holder;
+        self
+    }
+
+    pub fn with_license_year(mut self, license_year: Option<String>) -> Self {
+        self.license_year = license_year;
+        self
+    }
+
+    pub fn with_license_file(mut self, license_file: Option<PathBuf>) -> Self {
+        self.license_file = license_file;
+        self
+    }
+
+    pub fn with_profile(mut self, profile: Option<String>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn with_failure_policy(mut self, failure_policy: Option<String>) -> Self {
+        self.failure_policy = failure_policy;
+        self
+    }
+
+    pub fn with_tailwind_version(mut self, tailwind_version: Option<String>) -> Self {
+        self.tailwind_version = tailwind_version;
+        self
+    }
+
+    pub fn with_tauri_version(mut self, tauri_version: Option<String>) -> Self {
+        self.tauri_version = tauri_version;
+        self
+    }
+
+    pub fn with_runtime(mut self, runtime: Option<String>) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    pub fn with_api_client(mut self, api_client: Option<String>) -> Self {
+        self.api_client = api_client;
+        self
+    }
+
+    pub fn with_tanstack_query(mut self, tanstack_query: Option<bool>) -> Self {
+        self.tanstack_query = tanstack_query;
+        self
+    }
+
+    pub fn with_auth_pages(mut self, auth_pages: Option<bool>) -> Self {
+        self.auth_pages = auth_pages;
+        self
+    }
+
+    pub fn with_dark_mode(mut self, dark_mode: Option<bool>) -> Self {
+        self.dark_mode = dark_mode;
+        self
+    }
+
+    pub fn with_docker(mut self, enable_docker: Option<bool>) -> Self {
+        self.enable_docker = enable_docker;
+        self
+    }
+
+    pub fn with_ci(mut self, ci: Option<String>) -> Self {
+        self.ci = ci;
+        self
+    }
+
+    pub fn with_k8s(mut self, k8s: Option<String>) -> Self {
+        self.k8s = k8s;
+        self
+    }
+
+    pub fn with_compose_dev(mut self, compose_dev: bool) -> Self {
+        self.compose_dev = compose_dev;
+        self
+    }
+
+    pub fn with_task_runner(mut self, task_runner: Option<String>) -> Self {
+        self.task_runner = task_runner;
+        self
+    }
+
+    pub fn with_open(mut self, open: Option<Option<String>>) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn with_trust(mut self, trust: bool) -> Self {
+        self.trust = trust;
+        self
+    }
+
+    pub fn with_render_to(mut self, render_to: Option<String>) -> Self {
+        self.render_to = render_to;
+        self
+    }
+
+    pub fn with_archive(mut self, archive: Option<String>) -> Self {
+        self.archive = archive;
+        self
+    }
+
+    pub fn with_template_dir(mut self, template_dir: Option<PathBuf>) -> Self {
+        self.template_dir = template_dir;
+        self
+    }
+
+    pub fn with_overlay(mut self, overlay: Option<String>) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    pub fn with_extra_features(mut self, extra_features: Vec<String>) -> Self {
+        self.extra_features = extra_features;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// 非交互模式下缺少没有默认值的选项时报错
+    fn require_interactive(&self, option: &str) -> Result<()> {
+        if self.interactive {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "Missing required option '{option}' (cannot prompt in non-interactive mode)"
+            ))
+        }
+    }
+
     pub async fn execute(&self) -> Result<()> {
-        println!("Welcome to Scaffold-Gen Project Generator!");
+        let config = UserConfig::load()?;
+        status!("{}", config.welcome_banner());
+        let failures = Arc::new(ToolFailures::new(self.resolve_failure_policy(&config)?));
+        template_engine::set_local_templates_dir(self.template_dir.clone())?;
+        if let Some(ref template_dir) = self.template_dir {
+            status!("Using local templates from {}", template_dir.display());
+        }
+        // git 覆盖层克隆在临时目录中，需要保留到生成结束
+        let overlay_source = self.overlay.as_ref().or(config.template_overlay.as_ref());
+        let overlay = overlay_source
+            .map(|source| overlay::prepare(source, &config.git_hosts))
+            .transpose()?;
+        template_engine::set_overlay_dir(overlay.as_ref().map(|o| o.dir().to_path_buf()))?;
+        if let Some(source) = overlay_source {
+            status!("Using template overlay from {source}");
+        }
+        template_processor::set_extra_features(self.extra_features.clone());
+
+        // 只打包时渲染到临时目录，打包完成后随 workspace 一起删除
+        let archive = self.archive_target()?;
+        let workspace = match (&archive, &self.render_to) {
+            (Some(_), None) => Some(
+                tempfile::Builder::new()
+                    .prefix("scafgen-")
+                    .tempdir()
+                    .context("Failed to create temporary directory")?,
+            ),
+            _ => None,
+        };
+        let render_to = match workspace {
+            Some(ref workspace) => Some(workspace.path().join(&self.project_name)),
+            None => self.render_to.as_ref().map(PathBuf::from),
+        };
+        let generation = async {
+            if self.apps.is_empty() && self.fullstack.is_none() {
+                let params = self.resolve_params(render_to.as_deref()).await?;
+                let params = self.confirm_params(params, render_to.as_deref()).await?;
+                let project_path = params.project_path.clone();
+                Ok((project_path, self.generate_project(params).await?))
+            } else {
+                self.generate_workspace(render_to.as_deref()).await
+            }
+        };
+        let (project_path, mut next_steps) = self
+            .in_session(render_to.is_some(), failures.clone(), generation)
+            .await?;
+
+        let warnings = failures.take_warnings();
+        if !warnings.is_empty() {
+            println!(
+                "{}",
+                format!("Completed with {} warning(s):", warnings.len()).yellow()
+            );
+            for warning in &warnings {
+                println!("  - {}", warning.lines().next().unwrap_or(warning));
+            }
+        }
+
+        if workspace.is_some() {
+            status!("No external tools were run; the archive contains scafgen templates only.");
+        } else if render_to.is_some() {
+            status!("Rendered files written to: {}", project_path.display());
+            status!("No external tools were run; the output contains scafgen templates only.");
+        } else {
+            status!("Project created successfully!");
+            status!("Project path: {}", project_path.display());
+            status!("Next steps:");
+            status!("  cd {}", self.project_name);
+            if !next_steps.is_empty() {
+                status!("{}", next_steps.render_text());
+            }
+        }
 
+        feature_manifest::write(&project_path)?;
+
+        // 被跳过的步骤已在上方汇总，只写入 README 供后续查阅
+        for warning in &warnings {
+            next_steps.caveat(format!(
+                "Skipped during generation: {}",
+                warning.lines().next().unwrap_or(warning)
+            ));
+        }
+        next_steps
+            .write_to_readme(&project_path)
+            .context("Failed to write next steps to README.md")?;
+
+        if let Some((archive_path, format)) = archive {
+            self.write_archive(&project_path, &archive_path, format)?;
+        }
+
+        self.open_in_editor(&config, &project_path)?;
+
+        Ok(())
+    }
+
+    /// `--app` 和 `--fullstack`：生成包含多个应用的 workspace，返回根目录和下一步提示
+    ///
+    /// 每个应用由对应的框架生成器生成到 `apps/<名称>/`（全栈项目为 `backend/` 和 `frontend/`），
+    /// 根目录生成 go.work、Cargo workspace、pnpm workspace 以及共享的 Makefile 和 CI 配置；
+    /// 许可证、元数据和 CI 平台只确定一次
+    async fn generate_workspace(&self, render_to: Option<&Path>) -> Result<(PathBuf, NextSteps)> {
+        let apps = match self.fullstack {
+            Some(ref spec) => workspace::parameters::parse_fullstack(spec)?,
+            None => workspace::parameters::parse_apps(&self.apps)?,
+        };
+        let root = self.determine_project_path(render_to)?;
+
+        let (license, license_file) = self.configure_license()?;
+        let metadata = self.configure_metadata()?.with_license_file(license_file);
+        let profile = self.resolve_profile()?;
+        // 各应用共用一份 CI 配置，按第一个有 CI 模板的应用询问平台
+        let ci_provider = match apps
+            .iter()
+            .find(|app| Feature::Ci.impact(&app.language, &app.framework).is_some())
+        {
+            Some(app) => self.configure_ci(&app.language, &app.framework, profile)?,
+            None => None,
+        };
+        let runtime = self.resolve_runtime()?;
+        // 全栈项目根目录的 compose 需要前后端的镜像，默认生成 Docker 文件
+        let profile_features = profile.map(|p| p.features()).unwrap_or_default();
+        let docker = self
+            .enable_docker
+            .unwrap_or(profile_features.docker || self.fullstack.is_some());
+
+        let mut api_proxy = None;
+        let mut used_ports: Vec<u16> = Vec::new();
+        for app in &apps {
+            status!(
+                "{}",
+                format!("Generating app {} ({})", app.name, app.stack).green()
+            );
+            let app_dir = root.join(app.path());
+            let parent = app_dir.parent().unwrap_or(&root);
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            let mut member = self.workspace_member(app, &metadata, &license, parent);
+            if self.fullstack.is_some() {
+                member.enable_docker = Some(docker);
+            }
+            // 前端通过开发服务器和 nginx 的转发访问先生成的后端，API 类型依赖 API 客户端层
+            if self.fullstack.is_some() && app.name == FRONTEND_DIR {
+                member.api_proxy = api_proxy;
+                member.api_client = self.api_client.clone().or(Some("fetch".to_string()));
+            }
+            let render_path = render_to.map(|_| app_dir.clone());
+            let mut params = member.resolve_params(render_path.as_deref()).await?;
+
+            // 同一框架的应用默认端口相同，后面的应用依次顺延
+            if uses_network_settings(&params.language, &params.framework) {
+                while used_ports.contains(&params.port) {
+                    params.port += 1;
+                }
+                used_ports.push(params.port);
+                if params.framework == Framework::GoZero {
+                    while used_ports.contains(&params.grpc_port) {
+                        params.grpc_port += 1;
+                    }
+                    used_ports.push(params.grpc_port);
+                }
+            }
+            if self.fullstack.is_some() && app.name == BACKEND_DIR {
+                api_proxy = Some(ApiProxy::for_backend(params.framework, params.port));
+            }
+
+            let project_path = params.project_path.clone();
+            member
+                .generate_project(params)
+                .await?
+                .write_to_readme(&project_path)
+                .context("Failed to write next steps to README.md")?;
+        }
+
+        let orchestrator = GeneratorOrchestrator::new()?
+            .with_metadata(metadata.with_license(Some(license)))
+            .with_features(ProfileFeatures {
+                docker,
+                ci: ci_provider.is_some(),
+                ..profile_features
+            })
+            .with_ci_provider(ci_provider.unwrap_or_default())
+            .with_runtime(runtime);
+        let params =
+            WorkspaceParams::new(self.project_name.clone(), apps).with_api_proxy(api_proxy);
+        let next_steps = orchestrator.generate_workspace(params, &root)?;
+        Ok((root, next_steps))
+    }
+
+    /// workspace 中一个应用的生成命令：沿用命令行中的应用选项，已在根目录确定的元数据不再询问，
+    /// CI、pre-commit 和任务入口由根目录统一提供
+    fn workspace_member(
+        &self,
+        app: &WorkspaceApp,
+        metadata: &ProjectMetadata,
+        license: &str,
+        parent: &Path,
+    ) -> NewCommand {
+        let mut member = self.clone().with_stack(Some(app.stack.clone()));
+        member.project_name = app.name.clone();
+        member.target_path = Some(parent.to_string_lossy().into_owned());
+        member.license = Some(license.to_string());
+        member.license_file = metadata.license_file.clone();
+        if app.framework != Framework::Gin {
+            member.openapi = None;
+        }
+        member.description = Some(String::new());
+        member.keywords = Some(metadata.keywords.clone());
+        member.homepage = Some(metadata.homepage.clone().unwrap_or_default());
+        member.author = metadata.author.clone();
+        member.email = metadata.author_email.clone();
+        member.ci = Some(NO_CI.to_string());
+        member.enable_precommit = Some(false);
+        member.task_runner = Some("none".to_string());
+        member.apps = Vec::new();
+        member.fullstack = None;
+        member.in_workspace = true;
+        member
+    }
+
+    /// `--archive` 的输出路径和按扩展名确定的打包格式，在生成前检查以便尽早报错
+    fn archive_target(&self) -> Result<Option<(PathBuf, ArchiveFormat)>> {
+        let Some(ref archive) = self.archive else {
+            return Ok(None);
+        };
+        let path = PathBuf::from(archive);
+        let format = ArchiveFormat::from_path(&path).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported archive file: {archive}. Use a .zip, .tar, .tar.gz or .tgz file name"
+            )
+        })?;
+        if path.is_dir() {
+            return Err(anyhow::anyhow!("Archive path '{archive}' is a directory"));
+        }
+        Ok(Some((path, format)))
+    }
+
+    /// 将生成的项目打包，包内以项目名作为顶层目录
+    fn write_archive(
+        &self,
+        project_path: &Path,
+        archive_path: &Path,
+        format: ArchiveFormat,
+    ) -> Result<()> {
+        if let Some(parent) = archive_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        let file = std::fs::File::create(archive_path)
+            .with_context(|| format!("Failed to create {}", archive_path.display()))?;
+        archive::write_archive(
+            format,
+            project_path,
+            &self.project_name,
+            std::io::BufWriter::new(file),
+        )
+        .with_context(|| format!("Failed to write archive {}", archive_path.display()))?;
+
+        status!("Project archived to: {}", archive_path.display());
+        Ok(())
+    }
+
+    /// 按命令行参数（或交互询问）确定全部生成参数，包括项目路径
+    ///
+    /// 指定 `render_to` 时项目直接渲染到该目录
+    async fn resolve_params(&self, render_to: Option<&Path>) -> Result<ProjectParams> {
         // 交互式选择
         let language = self.select_language()?;
 
-        // 环境检查
-        self.check_environment(&language).await?;
+        // 环境检查，渲染模式下不需要任何外部工具
+        if !render_only::skip_tool("environment checks") {
+            self.check_environment(&language).await?;
+        }
 
         let framework = self.select_framework(&language)?;
 
         // 配置选项
-        let (host, port, _grpc_port) = self.configure_network_settings(&framework, &language)?;
-        let enable_precommit = self.configure_precommit()?;
-        let license = self.configure_license()?;
-        let enable_swagger = self.configure_swagger(&framework, &language).await?;
+        let (host, port, grpc_port) = self.configure_network_settings(&framework, &language)?;
+        let services = self.configure_services(&framework)?;
+        let profile = self.resolve_profile()?;
+        let nuxt_modules = self.configure_nuxt_modules(&framework, profile)?;
+        let sveltekit_options = self.configure_sveltekit_options(&framework, profile)?;
+        let enable_precommit = self.configure_precommit(&language, &framework, profile)?;
+        let (license, license_file) = self.configure_license()?;
+        let enable_swagger = self
+            .configure_swagger(&framework, &language, profile)
+            .await?;
+        let database = self.configure_database(&framework, profile)?;
+        let orm = self.configure_orm(&framework, database, profile)?;
+        let logger = self.configure_logger(&framework, profile)?;
+        let cors = self.configure_cors(&framework)?;
+        let enable_redis = self.configure_redis(&framework, profile)?;
+        let enable_grpc = self.configure_grpc(&framework, profile)?;
+        let enable_drf = self.configure_drf(&framework, profile)?;
+        let openapi = self.resolve_openapi(&framework)?;
+        let ci_provider = self.configure_ci(&language, &framework, profile)?;
+        let metadata = self.configure_metadata()?.with_license_file(license_file);
+        let k8s_format = self.resolve_k8s_format(&language, &framework)?;
+        // go-zero 的 --grpc 已在选择服务时开启 rpc 服务
+        let task_runner = self.resolve_task_runner(
+            enable_grpc || (framework == Framework::GoZero && self.enable_grpc == Some(true)),
+        )?;
+
+        // 档位决定 Docker、CI、示例测试等默认功能，--docker 和 --ci 可单独覆盖
+        // Kubernetes 工作负载需要镜像，--k8s 默认同时生成 Docker 文件
+        let profile_features = profile.map(|p| p.features()).unwrap_or_default();
+        let features = ProfileFeatures {
+            docker: self
+                .enable_docker
+                .unwrap_or(profile_features.docker || k8s_format.is_some()),
+            ci: ci_provider.is_some(),
+            docs: enable_swagger,
+            lint: enable_precommit,
+            ..profile_features
+        };
 
         // 确定项目路径
-        let project_path = self.determine_project_path()?;
+        let project_path = self.determine_project_path(render_to)?;
 
-        // 生成项目
-        let params = ProjectParams {
+        Ok(ProjectParams {
             language,
             framework,
             project_path: project_path.clone(),
             host,
             port,
+            grpc_port,
+            services,
+            nuxt_modules,
+            sveltekit_options,
             enable_precommit,
             license,
             enable_swagger,
+            database,
+            orm,
+            logger,
+            cors,
+            enable_redis,
+            enable_grpc,
+            enable_drf,
+            openapi,
+            metadata,
+            ci_provider,
+            k8s_format,
+            task_runner,
+            features,
+        })
+    }
+
+    /// 交互询问结束后展示全部选项的汇总并请求确认，可以修改其中一项后重新确认
+    ///
+    /// 非交互模式、从标准输入读取答案或全部选项都由命令行指定时不展示
+    async fn confirm_params(
+        &self,
+        mut params: ProjectParams,
+        render_to: Option<&Path>,
+    ) -> Result<ProjectParams> {
+        if !self.interactive || answers::is_piped() || !answers::has_prompted() {
+            return Ok(params);
+        }
+
+        loop {
+            let rows = summary_rows(&params);
+            println!();
+            println!("{}", "Project summary:".bold());
+            println!("  {:<24}{}", "Project name", self.project_name);
+            println!("  {:<24}{}", "Location", params.project_path.display());
+            for (item, value) in &rows {
+                println!("  {:<24}{value}", item.label());
+            }
+            let included = profile_feature_rows(&params);
+            if !included.is_empty() {
+                println!();
+                println!("{}", "Included by the feature profile (--profile):".bold());
+                for (label, hint) in &included {
+                    println!("  {label:<24}{hint}");
+                }
+            }
+            println!();
+
+            let action = Select::new(
+                "Generate the project with these settings?",
+                vec![
+                    SummaryAction::Generate,
+                    SummaryAction::Change,
+                    SummaryAction::Cancel,
+                ],
+            )
+            .prompt()
+            .context("Failed to confirm project settings")?;
+            match action {
+                SummaryAction::Generate => return Ok(params),
+                SummaryAction::Cancel => {
+                    return Err(anyhow::anyhow!("Project generation cancelled"));
+                }
+                SummaryAction::Change => {}
+            }
+
+            let options: Vec<String> = rows
+                .iter()
+                .map(|(item, value)| format!("{}: {value}", item.label()))
+                .collect();
+            let selected = Select::new("Which answer do you want to change?", options)
+                .raw_prompt()
+                .context("Failed to select the answer to change")?;
+            let item = rows[selected.index].0;
+
+            // 其余答案原样保留，只重新询问选中的一项（修改语言或框架时还会询问新框架特有的选项）
+            let command = self.with_answers(&params, item);
+            let quiet = console::is_quiet();
+            console::set_quiet(true);
+            let result = command.resolve_params(render_to).await;
+            console::set_quiet(quiet);
+            params = result?;
+        }
+    }
+
+    /// 把已确定的答案写回命令行选项，并清除要重新询问的一项
+    ///
+    /// 修改语言或框架时，端口、服务、数据库等与框架相关的答案一并清除
+    fn with_answers(&self, params: &ProjectParams, changed: SummaryItem) -> NewCommand {
+        let mut command = self.clone();
+        command.language = Some(params.language.as_lowercase().to_string());
+        command.framework = (params.framework != Framework::None)
+            .then(|| params.framework.as_lowercase().to_string());
+        command.host = Some(params.host.clone());
+        command.port = Some(params.port);
+        command.grpc_port = Some(params.grpc_port);
+        command.services = Some(params.services.clone()).filter(|s| !s.is_empty());
+        command.enable_api = None;
+        command.enable_rpc = None;
+        command.enable_admin = None;
+        command.nuxt_modules = Some(params.nuxt_modules.clone());
+        command.sveltekit_options = Some(params.sveltekit_options.clone());
+        command.database = Some(
+            params
+                .database
+                .map_or(NO_DATABASE, |database| database.as_str())
+                .to_string(),
+        );
+        command.orm = Some(params.orm.as_str().to_string());
+        command.logger = Some(params.logger.as_str().to_string());
+        command.enable_redis = Some(params.enable_redis);
+        command.enable_grpc = Some(params.enable_grpc);
+        command.enable_drf = Some(params.enable_drf);
+        command.enable_swagger = Some(params.enable_swagger);
+        command.enable_precommit = Some(params.enable_precommit);
+        command.ci = Some(
+            params
+                .ci_provider
+                .map_or(NO_CI, |provider| provider.as_str())
+                .to_string(),
+        );
+        command.license = Some(params.license.clone());
+        command.description = Some(params.metadata.description.clone().unwrap_or_default());
+        command.keywords = Some(params.metadata.keywords.clone());
+        command.homepage = Some(params.metadata.homepage.clone().unwrap_or_default());
+        command.author = params.metadata.author.clone();
+        command.email = params.metadata.author_email.clone();
+        command.license_holder = params.metadata.license_holder.clone();
+        command.license_year = params.metadata.license_year.clone();
+        command.license_file = params.metadata.license_file.clone();
+
+        match changed {
+            SummaryItem::Stack => {
+                command.language = None;
+                command.framework = None;
+                command.port = None;
+                command.grpc_port = None;
+                command.services = None;
+                command.nuxt_modules = None;
+                command.sveltekit_options = None;
+                command.database = None;
+                command.orm = None;
+                command.logger = None;
+                command.enable_redis = None;
+                command.enable_grpc = None;
+                command.enable_drf = None;
+                command.enable_swagger = None;
+            }
+            SummaryItem::Host => command.host = None,
+            SummaryItem::Port => command.port = None,
+            SummaryItem::GrpcPort => command.grpc_port = None,
+            SummaryItem::Services => command.services = None,
+            SummaryItem::NuxtModules => command.nuxt_modules = None,
+            SummaryItem::SvelteKitOptions => command.sveltekit_options = None,
+            SummaryItem::Database => command.database = None,
+            SummaryItem::Orm => command.orm = None,
+            SummaryItem::Logger => command.logger = None,
+            SummaryItem::Redis => command.enable_redis = None,
+            SummaryItem::Grpc => command.enable_grpc = None,
+            SummaryItem::Drf => command.enable_drf = None,
+            SummaryItem::Swagger => command.enable_swagger = None,
+            SummaryItem::Precommit => command.enable_precommit = None,
+            SummaryItem::Ci => command.ci = None,
+            SummaryItem::License => {
+                command.license = None;
+                command.license_file = None;
+            }
+            SummaryItem::Description => command.description = None,
+            SummaryItem::Keywords => command.keywords = None,
+            SummaryItem::Homepage => command.homepage = None,
+        }
+        command
+    }
+
+    /// 只检查选项能否解析为有效的生成参数，不生成项目
+    #[allow(dead_code)]
+    pub async fn validate(&self) -> Result<()> {
+        let render_to = self.render_to.as_ref().map(PathBuf::from);
+        self.in_session(
+            render_to.is_some(),
+            Arc::default(),
+            self.resolve_params(render_to.as_deref()),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// 生成项目并把后续步骤写入 README，返回项目路径
+    ///
+    /// 不输出汇总信息、不打开编辑器，工具失败按 lenient 策略处理（见 `scafgen serve`）
+    #[allow(dead_code)]
+    pub async fn generate(&self) -> Result<PathBuf> {
+        let render_to = self.render_to.as_ref().map(PathBuf::from);
+        let generation = async {
+            let params = self.resolve_params(render_to.as_deref()).await?;
+            let project_path = params.project_path.clone();
+            let next_steps = self.generate_project(params).await?;
+            feature_manifest::write(&project_path)?;
+            next_steps
+                .write_to_readme(&project_path)
+                .context("Failed to write next steps to README.md")?;
+            Ok(project_path)
         };
+        self.in_session(render_to.is_some(), Arc::default(), generation)
+            .await
+    }
 
-        self.generate_project(params).await?;
+    /// 在本次生成的设置下执行：渲染模式、工具失败的处理和 `--trust` 只作用于这次生成，
+    /// 不影响同一进程中的其他生成（如 `scafgen serve` 的并发请求）
+    async fn in_session<F: Future>(
+        &self,
+        render_only: bool,
+        failures: Arc<ToolFailures>,
+        future: F,
+    ) -> F::Output {
+        render_only::scope(
+            render_only,
+            hooks::scope(self.trust, tool_failures::scope(failures, future)),
+        )
+        .await
+    }
+
+    /// 按 `--open` 在编辑器中打开生成的项目，未指定编辑器时使用配置文件中的默认值或自动检测
+    ///
+    /// 项目已生成成功，编辑器不可用时只输出警告
+    fn open_in_editor(&self, config: &UserConfig, project_path: &Path) -> Result<()> {
+        let Some(ref requested) = self.open else {
+            return Ok(());
+        };
+
+        let editor = match requested {
+            Some(name) => Some(Editor::parse_from_str(name).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unsupported editor: {name}. Supported editors: {}",
+                    constants::EDITORS.join(", ")
+                )
+            })?),
+            None => config.editor.or_else(editor::detect),
+        };
 
-        println!("Project created successfully!");
-        println!("Project path: {}", project_path.display());
-        println!("Next steps:");
-        println!("  cd {}", self.project_name);
-        println!("  # Follow the README.md for further instructions");
+        let Some(editor) = editor else {
+            println!(
+                "⚠️  Warning: No supported editor found in PATH ({}); open {} manually",
+                constants::EDITORS.join(", "),
+                project_path.display()
+            );
+            return Ok(());
+        };
 
+        status!("Opening project in {editor}...");
+        if let Err(e) = editor::open(editor, project_path) {
+            println!("⚠️  Warning: {e:#}");
+        }
         Ok(())
     }
 
     async fn check_environment(&self, language: &Language) -> Result<()> {
-        println!("Checking environment...");
+        status!("Checking environment...");
 
         let env_checker = EnvironmentChecker::new();
 
@@ -143,21 +1227,52 @@ impl NewCommand {
                 "Git is not available. Please install Git first."
             ));
         }
-        println!("  Git: Available");
+        status!("  Git: Available");
 
         // 根据语言检查相应的环境
         match language {
-            Language::Go => match env_checker.check_go().await {
-                Ok(true) => println!("  Go: Available"),
-                Ok(false) => {
-                    return Err(anyhow::anyhow!(
-                        "Go is not available. Please install Go first."
-                    ));
+            Language::Go => {
+                match env_checker.check_go().await {
+                    Ok(true) => status!("  Go: Available"),
+                    Ok(false) => {
+                        return Err(anyhow::anyhow!(
+                            "Go is not available. Please install Go first."
+                        ));
+                    }
+                    Err(e) => return Err(anyhow::anyhow!("Go version check failed: {e}")),
                 }
-                Err(e) => return Err(anyhow::anyhow!("Go version check failed: {e}")),
-            },
+
+                // 如果选择了 go-zero 框架，还需要检查 goctl
+                if self.framework.as_ref().map(|f| f.to_lowercase()) == Some("go-zero".to_string())
+                {
+                    match env_checker.check_goctl().await {
+                        Ok(true) => status!("  goctl: Available"),
+                        Ok(false) => {
+                            return Err(anyhow::anyhow!(
+                                "goctl is not available. Please install goctl first:\n  go install github.com/zeromicro/go-zero/tools/goctl@latest"
+                            ));
+                        }
+                        Err(e) => return Err(anyhow::anyhow!("goctl check failed: {e}")),
+                    }
+                }
+
+                // Gin 项目的 --grpc 使用 buf 生成代码
+                if self.enable_grpc == Some(true)
+                    && self.framework.as_ref().map(|f| f.to_lowercase()) == Some("gin".to_string())
+                {
+                    match env_checker.check_buf().await {
+                        Ok(true) => status!("  buf: Available"),
+                        Ok(false) => {
+                            return Err(anyhow::anyhow!(
+                                "buf is not available, it is required by --grpc. Please install buf first:\n  go install github.com/bufbuild/buf/cmd/buf@latest"
+                            ));
+                        }
+                        Err(e) => return Err(anyhow::anyhow!("buf check failed: {e}")),
+                    }
+                }
+            }
             Language::Python => match env_checker.check_uv().await {
-                Ok(true) => println!("  uv: Available"),
+                Ok(true) => status!("  uv: Available"),
                 Ok(false) => {
                     return Err(anyhow::anyhow!(
                         "uv is not available. Please install uv first: https://docs.astral.sh/uv/"
@@ -168,7 +1283,7 @@ impl NewCommand {
             Language::Rust => {
                 // 检查 Cargo
                 match env_checker.check_cargo().await {
-                    Ok(true) => println!("  Cargo: Available"),
+                    Ok(true) => status!("  Cargo: Available"),
                     Ok(false) => {
                         return Err(anyhow::anyhow!(
                             "Cargo is not available. Please install Rust first: https://rustup.rs/"
@@ -180,7 +1295,7 @@ impl NewCommand {
                 // 如果选择了 Tauri 框架，还需要检查 pnpm
                 if self.framework.as_ref().map(|f| f.to_lowercase()) == Some("tauri".to_string()) {
                     match env_checker.check_pnpm().await {
-                        Ok(true) => println!("  pnpm: Available"),
+                        Ok(true) => status!("  pnpm: Available"),
                         Ok(false) => {
                             return Err(anyhow::anyhow!(
                                 "pnpm is not available. Please install pnpm first:\n  npm install -g pnpm\n  or visit: https://pnpm.io/installation"
@@ -191,28 +1306,71 @@ impl NewCommand {
                 }
             }
             Language::TypeScript => {
-                // 检查 Node.js
-                match env_checker.check_node().await {
-                    Ok(true) => println!("  Node.js: Available"),
-                    Ok(false) => {
-                        return Err(anyhow::anyhow!(
-                            "Node.js is not available. Please install Node.js first: https://nodejs.org/"
-                        ));
+                let runtime = self.resolve_runtime()?;
+                if runtime == Runtime::Node {
+                    // 检查 Node.js
+                    match env_checker.check_node().await {
+                        Ok(true) => status!("  Node.js: Available"),
+                        Ok(false) => {
+                            return Err(anyhow::anyhow!(
+                                "Node.js is not available. Please install Node.js first: https://nodejs.org/"
+                            ));
+                        }
+                        Err(e) => return Err(anyhow::anyhow!("Node.js check failed: {e}")),
+                    }
+
+                    // SvelteKit 的 sv 命令行要求 Node.js 18.13 及以上
+                    let framework = self
+                        .framework
+                        .as_deref()
+                        .and_then(Framework::parse_from_str);
+                    if framework == Some(Framework::SvelteKit) {
+                        env_checker
+                            .check_node_version(18, 13)
+                            .await
+                            .context("Node.js version check failed")?;
+                        status!("  Node.js version: Supported by SvelteKit");
+                    }
+                    // NestJS 11 要求 Node.js 20 及以上
+                    if framework == Some(Framework::NestJs) {
+                        env_checker
+                            .check_node_version(20, 0)
+                            .await
+                            .context("Node.js version check failed")?;
+                        status!("  Node.js version: Supported by NestJS");
+                    }
+                    // Astro 5 要求 Node.js 18.20 及以上
+                    if framework == Some(Framework::Astro) {
+                        env_checker
+                            .check_node_version(18, 20)
+                            .await
+                            .context("Node.js version check failed")?;
+                        status!("  Node.js version: Supported by Astro");
                     }
-                    Err(e) => return Err(anyhow::anyhow!("Node.js check failed: {e}")),
                 }
 
-                // 检查 pnpm
-                match env_checker.check_pnpm().await {
-                    Ok(true) => println!("  pnpm: Available"),
+                // 检查运行时的包管理器（node 使用 pnpm）
+                let package_manager = runtime.package_manager();
+                match env_checker.check_package_manager(runtime).await {
+                    Ok(true) => status!("  {package_manager}: Available"),
                     Ok(false) => {
                         return Err(anyhow::anyhow!(
-                            "pnpm is not available. Please install pnpm first:\n  npm install -g pnpm\n  or visit: https://pnpm.io/installation"
+                            "{package_manager} is not available. Please install {package_manager} first:\n  {}",
+                            js_runtime::install_hint(runtime)
                         ));
                     }
-                    Err(e) => return Err(anyhow::anyhow!("pnpm check failed: {e}")),
+                    Err(e) => return Err(anyhow::anyhow!("{package_manager} check failed: {e}")),
                 }
             }
+            Language::Zig => match env_checker.check_zig().await {
+                Ok(true) => status!("  Zig: Available"),
+                Ok(false) => {
+                    return Err(anyhow::anyhow!(
+                        "Zig is not available. Please install Zig first: https://ziglang.org/download/"
+                    ));
+                }
+                Err(e) => return Err(anyhow::anyhow!("Zig check failed: {e}")),
+            },
         }
 
         Ok(())
@@ -221,15 +1379,20 @@ impl NewCommand {
     fn select_language(&self) -> Result<Language> {
         // 如果通过命令行参数指定了语言，直接使用
         if let Some(language_str) = &self.language {
-            return match language_str.to_lowercase().as_str() {
-                "go" => Ok(Language::Go),
-                "python" => Ok(Language::Python),
-                "rust" => Ok(Language::Rust),
-                "typescript" | "ts" => Ok(Language::TypeScript),
-                _ => Err(anyhow::anyhow!(
-                    "Unsupported language: {language_str}. Supported languages: go, python, rust, typescript"
-                )),
-            };
+            return Language::parse_from_str(language_str).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unsupported language: {language_str}. Supported languages: {}",
+                    Language::supported()
+                )
+            });
+        }
+
+        // 指定了框架时，语言由框架唯一确定，无需再询问
+        if let Some(framework) = self.parse_framework_arg()?
+            && let Some(language) = framework.language()
+        {
+            status!("Programming language: {language} (inferred from framework {framework})");
+            return Ok(language);
         }
 
         let languages = vec![
@@ -237,21 +1400,36 @@ impl NewCommand {
             Language::Python,
             Language::Rust,
             Language::TypeScript,
+            Language::Zig,
         ];
 
         // 当只有一个选项时，直接返回该选项
         if languages.len() == 1 {
-            println!("Programming language: {}", languages[0]);
+            status!("Programming language: {}", languages[0]);
             return Ok(languages[0]);
         }
 
-        let selected = Select::new("Choose your programming language:", languages)
-            .prompt()
-            .context("Failed to select language")?;
+        self.require_interactive("language")?;
+        let selected = answers::select(
+            "language",
+            Select::new("Choose your programming language:", languages),
+        )
+        .context("Failed to select language")?;
 
         Ok(selected)
     }
 
+    /// 解析命令行指定的框架
+    fn parse_framework_arg(&self) -> Result<Option<Framework>> {
+        self.framework
+            .as_deref()
+            .map(|framework_str| {
+                Framework::parse_from_str(framework_str)
+                    .ok_or_else(|| unsupported_framework_error(framework_str))
+            })
+            .transpose()
+    }
+
     fn select_framework(&self, language: &Language) -> Result<Framework> {
         // 获取该语言支持的框架列表
         let frameworks = Framework::frameworks_for_language(*language);
@@ -263,11 +1441,8 @@ impl NewCommand {
 
         // 如果通过命令行参数指定了框架，验证并使用
         if let Some(framework_str) = &self.framework {
-            let framework = Framework::parse_from_str(framework_str).ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Unsupported framework: {framework_str}. Supported frameworks: gin, go-zero, tauri, vue3, react, none"
-                )
-            })?;
+            let framework = Framework::parse_from_str(framework_str)
+                .ok_or_else(|| unsupported_framework_error(framework_str))?;
 
             // 验证框架是否适用于当前语言
             if !frameworks.contains(&framework) && framework != Framework::None {
@@ -283,153 +1458,969 @@ impl NewCommand {
                 ));
             }
 
-            return Ok(framework);
-        }
+            return Ok(framework);
+        }
+
+        // 如果只有一个框架选项，直接返回
+        if frameworks.len() == 1 {
+            status!("Framework: {}", frameworks[0]);
+            return Ok(frameworks[0]);
+        }
+
+        self.require_interactive("framework")?;
+        let selected = answers::select(
+            "framework",
+            Select::new("Choose your framework:", frameworks),
+        )
+        .context("Failed to select framework")?;
+
+        Ok(selected)
+    }
+
+    fn configure_network_settings(
+        &self,
+        framework: &Framework,
+        language: &Language,
+    ) -> Result<(String, u16, u16)> {
+        if !uses_network_settings(language, framework) {
+            return Ok(("0.0.0.0".to_string(), 8080, 9000));
+        }
+
+        status!("Configuring network settings...");
+
+        let host = if let Some(ref h) = self.host {
+            validation::validate_host(h)?;
+            status!("Using provided host: {h}");
+            validation::normalize_host(h)
+        } else if !self.interactive {
+            "0.0.0.0".to_string()
+        } else {
+            status!("Prompting for host address...");
+            let host = answers::text(
+                "host",
+                Text::new("Host address:")
+                    .with_default("0.0.0.0")
+                    .with_help_message(
+                        "IPv4/IPv6 address or host name, e.g. 0.0.0.0, ::1, localhost",
+                    )
+                    .with_validator(|input: &str| {
+                        Ok(match validation::validate_host(input) {
+                            Ok(()) => Validation::Valid,
+                            Err(e) => Validation::Invalid(e.to_string().into()),
+                        })
+                    }),
+            )
+            .context("Failed to get host address")?;
+            validation::normalize_host(&host)
+        };
+
+        let port = if let Some(p) = self.port {
+            status!("Using provided port: {p}");
+            p
+        } else {
+            let default_port = default_port(framework);
+            if self.interactive {
+                status!("Prompting for HTTP port...");
+                answers::text(
+                    "port",
+                    Text::new("HTTP port:").with_default(&default_port.to_string()),
+                )
+                .context("Failed to get port")?
+                .parse::<u16>()
+                .context("Invalid port number")?
+            } else {
+                default_port
+            }
+        };
+
+        let grpc_port = if let Some(p) = self.grpc_port {
+            status!("Using provided gRPC port: {p}");
+            p
+        } else if (matches!(framework, Framework::GoZero)
+            || (matches!(framework, Framework::Gin) && self.enable_grpc == Some(true)))
+            && self.interactive
+        {
+            status!("Prompting for gRPC port...");
+            answers::text("grpc_port", Text::new("gRPC port:").with_default("9000"))
+                .context("Failed to get gRPC port")?
+                .parse::<u16>()
+                .context("Invalid gRPC port number")?
+        } else {
+            status!("Using default gRPC port: 9000");
+            9000 // 默认值，对于不需要gRPC的框架
+        };
+
+        Ok((host, port, grpc_port))
+    }
+
+    /// 选择 go-zero 项目包含的服务（api、rpc、admin），其他框架返回空列表
+    ///
+    /// `--api`/`--rpc`/`--admin` 在 `--services`（未指定时为默认的 api）基础上开启或关闭单个服务，
+    /// 命令行均未指定时交互选择
+    fn configure_services(&self, framework: &Framework) -> Result<Vec<String>> {
+        if !matches!(framework, Framework::GoZero) {
+            return Ok(Vec::new());
+        }
+
+        let toggles = [
+            ("api", self.enable_api),
+            // --grpc 在 go-zero 项目中开启 rpc 服务
+            (
+                "rpc",
+                self.enable_rpc
+                    .or(self.enable_grpc.filter(|enable| *enable)),
+            ),
+            ("admin", self.enable_admin),
+        ];
+        let has_toggles = toggles.iter().any(|(_, toggle)| toggle.is_some());
+
+        if self.services.is_some() || has_toggles {
+            let base = self
+                .services
+                .clone()
+                .unwrap_or_else(|| vec!["api".to_string()]);
+            let services: Vec<String> = constants::GO_ZERO_SERVICES
+                .iter()
+                .filter(|service| {
+                    toggles
+                        .iter()
+                        .find(|(name, _)| name == *service)
+                        .and_then(|(_, toggle)| *toggle)
+                        .unwrap_or_else(|| base.iter().any(|s| s == *service))
+                })
+                .map(|service| service.to_string())
+                .collect();
+
+            if services.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "At least one service is required. Supported services: {}",
+                    constants::GO_ZERO_SERVICES.join(", ")
+                ));
+            }
+            status!("Using provided services: {}", services.join(", "));
+            return Ok(services);
+        }
+        if !self.interactive {
+            return Ok(vec!["api".to_string()]);
+        }
+
+        let selected = answers::multi_select(
+            "services",
+            MultiSelect::new(
+                "Choose go-zero services:",
+                constants::GO_ZERO_SERVICES.to_vec(),
+            )
+            .with_default(&[0])
+            .with_validator(
+                |selected: &[inquire::list_option::ListOption<&&str>]| {
+                    Ok(if selected.is_empty() {
+                        Validation::Invalid("Select at least one service".into())
+                    } else {
+                        Validation::Valid
+                    })
+                },
+            ),
+        )
+        .context("Failed to select services")?;
+
+        Ok(selected.into_iter().map(str::to_string).collect())
+    }
+
+    /// 选择 Nuxt 项目安装的模块（tailwind、eslint、pinia），其他框架返回空列表
+    fn configure_nuxt_modules(
+        &self,
+        framework: &Framework,
+        profile: Option<Profile>,
+    ) -> Result<Vec<String>> {
+        if !matches!(framework, Framework::Nuxt) {
+            return Ok(Vec::new());
+        }
+        self.configure_choices(
+            self.nuxt_modules.as_ref(),
+            constants::NUXT_MODULES,
+            "Nuxt module",
+            "nuxt_modules",
+            profile,
+        )
+    }
+
+    /// 选择 SvelteKit 项目的选项（typescript、tailwind、playwright），其他框架返回空列表
+    fn configure_sveltekit_options(
+        &self,
+        framework: &Framework,
+        profile: Option<Profile>,
+    ) -> Result<Vec<String>> {
+        if !matches!(framework, Framework::SvelteKit) {
+            return Ok(Vec::new());
+        }
+        self.configure_choices(
+            self.sveltekit_options.as_ref(),
+            constants::SVELTEKIT_OPTIONS,
+            "SvelteKit option",
+            "sveltekit_options",
+            profile,
+        )
+    }
+
+    /// 确定多选参数（如 `--nuxt-modules`）的取值，`none` 表示一项都不选
+    ///
+    /// 指定 `--profile` 或非交互时不再询问，默认全部选中
+    fn configure_choices(
+        &self,
+        provided: Option<&Vec<String>>,
+        supported: &[&'static str],
+        kind: &str,
+        key: &str,
+        profile: Option<Profile>,
+    ) -> Result<Vec<String>> {
+        if let Some(values) = provided {
+            let mut selected = Vec::new();
+            for value in normalize_keywords(values.iter().map(String::as_str)) {
+                if value == NO_CHOICES {
+                    continue;
+                }
+                if !supported.contains(&value.as_str()) {
+                    return Err(anyhow::anyhow!(
+                        "Unsupported {kind}: {value}. Supported {kind}s: {}",
+                        supported.join(", ")
+                    ));
+                }
+                selected.push(value);
+            }
+            status!("Using provided {kind}s: {}", choices_label(&selected));
+            return Ok(selected);
+        }
+        if profile.is_some() || !self.interactive {
+            return Ok(supported.iter().map(|value| value.to_string()).collect());
+        }
+
+        let selected = answers::multi_select(
+            key,
+            MultiSelect::new(&format!("Choose {kind}s:"), supported.to_vec())
+                .with_all_selected_by_default(),
+        )
+        .with_context(|| format!("Failed to select {kind}s"))?;
+
+        Ok(selected.into_iter().map(str::to_string).collect())
+    }
+
+    /// 选择 Gin 或 Fiber 项目使用的数据库，其他框架不支持数据库集成
+    ///
+    /// 指定 `--profile` 时不再询问，默认不使用数据库
+    fn configure_database(
+        &self,
+        framework: &Framework,
+        profile: Option<Profile>,
+    ) -> Result<Option<Database>> {
+        if !matches!(framework, Framework::Gin | Framework::Fiber) {
+            return Ok(None);
+        }
+
+        let selected = if let Some(ref database) = self.database {
+            database.clone()
+        } else if profile.is_some() || !self.interactive {
+            return Ok(None);
+        } else {
+            let options: Vec<&str> = std::iter::once(NO_DATABASE)
+                .chain(constants::DATABASES.iter().copied())
+                .collect();
+            answers::select("database", Select::new("Choose a database:", options))
+                .context("Failed to select database")?
+                .to_string()
+        };
+
+        if selected == NO_DATABASE {
+            return Ok(None);
+        }
+        let database = Database::parse_from_str(&selected).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported database: {selected}. Supported databases: {}",
+                constants::DATABASES.join(", ")
+            )
+        })?;
+        status!("Using database: {database}");
+        Ok(Some(database))
+    }
+
+    /// 选择 Gin 项目访问 SQL 数据库的方式，其他情况下不使用该选项
+    ///
+    /// 指定 `--profile` 时不再询问，默认使用 GORM
+    fn configure_orm(
+        &self,
+        framework: &Framework,
+        database: Option<Database>,
+        profile: Option<Profile>,
+    ) -> Result<Orm> {
+        if *framework != Framework::Gin || !database.is_some_and(|db| db.uses_gorm()) {
+            return Ok(Orm::default());
+        }
+
+        let selected = if let Some(ref orm) = self.orm {
+            orm.clone()
+        } else if profile.is_some() || !self.interactive {
+            return Ok(Orm::default());
+        } else {
+            answers::select(
+                "orm",
+                Select::new("Choose a data access layer:", constants::ORMS.to_vec()),
+            )
+            .context("Failed to select data access layer")?
+            .to_string()
+        };
+
+        let orm = Orm::parse_from_str(&selected).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported data access layer: {selected}. Supported values: {}",
+                constants::ORMS.join(", ")
+            )
+        })?;
+        status!("Using data access layer: {orm}");
+        Ok(orm)
+    }
+
+    /// 选择 Gin 项目使用的日志库，其他框架不使用该选项
+    ///
+    /// 指定 `--profile` 时不再询问，默认使用 zap
+    fn configure_logger(&self, framework: &Framework, profile: Option<Profile>) -> Result<Logger> {
+        if *framework != Framework::Gin {
+            return Ok(Logger::default());
+        }
+
+        let selected = if let Some(ref logger) = self.logger {
+            logger.clone()
+        } else if profile.is_some() || !self.interactive {
+            return Ok(Logger::default());
+        } else {
+            answers::select(
+                "logger",
+                Select::new("Choose a logging library:", constants::LOGGERS.to_vec()),
+            )
+            .context("Failed to select logging library")?
+            .to_string()
+        };
+
+        let logger = Logger::parse_from_str(&selected).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported logging library: {selected}. Supported values: {}",
+                constants::LOGGERS.join(", ")
+            )
+        })?;
+        status!("Using logging library: {logger}");
+        Ok(logger)
+    }
+
+    /// 配置 Gin 项目的 CORS 中间件，返回 None 表示禁用
+    ///
+    /// 默认启用且不询问，来源和方法未指定时使用生成器默认值
+    fn configure_cors(&self, framework: &Framework) -> Result<Option<CorsSettings>> {
+        if !matches!(framework, Framework::Gin) {
+            return Ok(None);
+        }
+
+        if self.enable_cors == Some(false) {
+            if self.cors_origins.is_some() || self.cors_methods.is_some() {
+                return Err(anyhow::anyhow!(
+                    "--cors-origins and --cors-methods cannot be used with --cors false"
+                ));
+            }
+            status!("CORS middleware disabled");
+            return Ok(None);
+        }
+
+        let origins = match self.cors_origins {
+            Some(ref origins) => {
+                let origins = normalize_keywords(origins.iter().map(String::as_str));
+                for origin in &origins {
+                    validation::validate_cors_origin(origin)?;
+                }
+                if origins.len() > 1 && origins.iter().any(|origin| origin == "*") {
+                    return Err(anyhow::anyhow!(
+                        "CORS origin '*' cannot be combined with other origins"
+                    ));
+                }
+                status!("Using CORS origins: {}", origins.join(", "));
+                Some(origins).filter(|origins| !origins.is_empty())
+            }
+            None => None,
+        };
+
+        let methods = match self.cors_methods {
+            Some(ref methods) => {
+                let mut normalized = Vec::new();
+                for method in normalize_keywords(methods.iter().map(String::as_str)) {
+                    let method = validation::normalize_cors_method(&method)?;
+                    if !normalized.contains(&method) {
+                        normalized.push(method);
+                    }
+                }
+                status!("Using CORS methods: {}", normalized.join(", "));
+                Some(normalized).filter(|methods| !methods.is_empty())
+            }
+            None => None,
+        };
+
+        Ok(Some(CorsSettings { origins, methods }))
+    }
+
+    /// 配置 Gin 项目是否启用 Redis 缓存，其他框架不支持
+    ///
+    /// 指定 `--profile` 时不再询问，默认不启用
+    fn configure_redis(&self, framework: &Framework, profile: Option<Profile>) -> Result<bool> {
+        if !matches!(framework, Framework::Gin) {
+            return Ok(false);
+        }
+
+        if let Some(enable) = self.enable_redis {
+            status!("Using provided Redis setting: {enable}");
+            return Ok(enable);
+        }
+        if profile.is_some() || !self.interactive {
+            return Ok(false);
+        }
+
+        answers::confirm(
+            "redis",
+            Confirm::new("Enable Redis cache?").with_default(false),
+        )
+        .context("Failed to get Redis preference")
+    }
+
+    /// 配置 Gin 项目是否生成 gRPC 服务器，其他框架不支持（go-zero 使用 rpc 服务）
+    ///
+    /// 指定 `--profile` 时不再询问，默认不启用
+    fn configure_grpc(&self, framework: &Framework, profile: Option<Profile>) -> Result<bool> {
+        if !matches!(framework, Framework::Gin) {
+            return Ok(false);
+        }
+
+        if let Some(enable) = self.enable_grpc {
+            status!("Using provided gRPC setting: {enable}");
+            return Ok(enable);
+        }
+        if profile.is_some() || !self.interactive {
+            return Ok(false);
+        }
+
+        answers::confirm(
+            "grpc",
+            Confirm::new("Add a gRPC server with protobuf codegen (buf)?").with_default(false),
+        )
+        .context("Failed to get gRPC preference")
+    }
+
+    /// 读取 `--openapi` 指定的规范，只支持 Gin 项目；规范有误时在生成前报错
+    fn resolve_openapi(&self, framework: &Framework) -> Result<Option<OpenApiSpec>> {
+        let Some(ref path) = self.openapi else {
+            return Ok(None);
+        };
+        if !matches!(framework, Framework::Gin) {
+            return Err(anyhow::anyhow!(
+                "--openapi is only supported for Gin projects"
+            ));
+        }
+        let path = std::path::absolute(path)
+            .with_context(|| format!("Failed to resolve OpenAPI spec: {}", path.display()))?;
+        let spec = OpenApiSpec::load(&path)?;
+        status!(
+            "Using OpenAPI spec {} ({} operations, {} models)",
+            path.display(),
+            spec.operations.len(),
+            spec.models.len()
+        );
+        Ok(Some(spec))
+    }
+
+    /// 配置生成 CI 配置的平台，返回 None 表示不生成
+    ///
+    /// 指定 `--profile` 时不再询问，由档位决定是否生成 GitHub Actions 配置；没有 CI 模板的技术栈直接跳过
+    fn configure_ci(
+        &self,
+        language: &Language,
+        framework: &Framework,
+        profile: Option<Profile>,
+    ) -> Result<Option<CiProvider>> {
+        if Feature::Ci.impact(language, framework).is_none() {
+            return Ok(None);
+        }
+
+        let selected = if let Some(ref ci) = self.ci {
+            ci.clone()
+        } else if profile.is_some() || !self.interactive {
+            let enabled = profile.map(|p| p.features()).unwrap_or_default().ci;
+            return Ok(enabled.then(CiProvider::default));
+        } else {
+            let options: Vec<&str> = std::iter::once(NO_CI)
+                .chain(constants::CI_PROVIDERS.iter().copied())
+                .collect();
+            answers::select("ci", Select::new("Choose a CI provider:", options))
+                .context("Failed to select CI provider")?
+                .to_string()
+        };
+
+        if selected == NO_CI {
+            return Ok(None);
+        }
+        let provider = CiProvider::parse_from_str(&selected).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported CI provider: {selected}. Supported providers: {}",
+                constants::CI_PROVIDERS.join(", ")
+            )
+        })?;
+        status!("Using CI provider: {provider}");
+        Ok(Some(provider))
+    }
+
+    /// 配置 Django 项目是否集成 Django REST framework，其他框架不支持
+    ///
+    /// 指定 `--profile` 时不再询问，默认不启用
+    fn configure_drf(&self, framework: &Framework, profile: Option<Profile>) -> Result<bool> {
+        if !matches!(framework, Framework::Django) {
+            return Ok(false);
+        }
+
+        if let Some(enable) = self.enable_drf {
+            status!("Using provided Django REST framework setting: {enable}");
+            return Ok(enable);
+        }
+        if profile.is_some() {
+            return Ok(false);
+        }
+        if !self.interactive {
+            return Ok(true);
+        }
+
+        answers::confirm(
+            "drf",
+            Confirm::new("Add Django REST framework?").with_default(true),
+        )
+        .context("Failed to get Django REST framework preference")
+    }
+
+    /// 解析命令行指定的档位
+    fn resolve_profile(&self) -> Result<Option<Profile>> {
+        let Some(ref profile_str) = self.profile else {
+            return Ok(None);
+        };
+
+        let profile = Profile::parse_from_str(profile_str).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported profile: {profile_str}. Supported profiles: {}",
+                constants::PROFILES.join(", ")
+            )
+        })?;
+        status!("Using profile: {profile}");
+        Ok(Some(profile))
+    }
+
+    /// 解析非关键工具失败的处理策略：命令行参数优先，其次是配置文件，默认 lenient
+    fn resolve_failure_policy(&self, config: &UserConfig) -> Result<FailurePolicy> {
+        let Some(ref policy_str) = self.failure_policy else {
+            return Ok(config.failure_policy.unwrap_or_default());
+        };
+
+        FailurePolicy::parse_from_str(policy_str).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported failure policy: {policy_str}. Supported policies: {}",
+                constants::FAILURE_POLICIES.join(", ")
+            )
+        })
+    }
+
+    /// 解析 Tailwind CSS 主版本，未指定时使用 v4
+    fn resolve_tailwind_version(&self) -> Result<TailwindVersion> {
+        let Some(ref version_str) = self.tailwind_version else {
+            return Ok(TailwindVersion::default());
+        };
+
+        TailwindVersion::parse_from_str(version_str).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported Tailwind CSS version: {version_str}. Supported versions: {}",
+                constants::TAILWIND_VERSIONS.join(", ")
+            )
+        })
+    }
+
+    /// 解析 Tauri 主版本，未指定时使用 v2
+    fn resolve_tauri_version(&self) -> Result<TauriVersion> {
+        let Some(ref version_str) = self.tauri_version else {
+            return Ok(TauriVersion::default());
+        };
 
-        // 如果只有一个框架选项，直接返回
-        if frameworks.len() == 1 {
-            println!("Framework: {}", frameworks[0]);
-            return Ok(frameworks[0]);
-        }
+        TauriVersion::parse_from_str(version_str).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported Tauri version: {version_str}. Supported versions: {}",
+                constants::TAURI_VERSIONS.join(", ")
+            )
+        })
+    }
 
-        let selected = Select::new("Choose your framework:", frameworks)
-            .prompt()
-            .context("Failed to select framework")?;
+    /// 解析 JavaScript 运行时，未指定时使用 Node.js（pnpm）
+    fn resolve_runtime(&self) -> Result<Runtime> {
+        let Some(ref runtime_str) = self.runtime else {
+            return Ok(Runtime::default());
+        };
 
-        Ok(selected)
+        Runtime::parse_from_str(runtime_str).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported runtime: {runtime_str}. Supported runtimes: {}",
+                constants::RUNTIMES.join(", ")
+            )
+        })
     }
 
-    fn configure_network_settings(
+    /// 解析 Kubernetes 部署文件格式，前端、桌面和命令行项目跳过
+    fn resolve_k8s_format(
         &self,
-        framework: &Framework,
         language: &Language,
-    ) -> Result<(String, u16, u16)> {
-        // Rust、Python 和 TypeScript 语言不需要网络配置
-        if matches!(
-            language,
-            Language::Rust | Language::Python | Language::TypeScript
-        ) {
-            return Ok(("0.0.0.0".to_string(), 8080, 9000));
-        }
+        framework: &Framework,
+    ) -> Result<Option<K8sFormat>> {
+        let Some(ref format_str) = self.k8s else {
+            return Ok(None);
+        };
 
-        println!("Configuring network settings...");
+        let format = K8sFormat::parse_from_str(format_str).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported Kubernetes format: {format_str}. Supported formats: {}",
+                constants::K8S_FORMATS.join(", ")
+            )
+        })?;
+        if K8sParams::supports(*framework) {
+            return Ok(Some(format));
+        }
 
-        let host = if let Some(ref h) = self.host {
-            println!("Using provided host: {h}");
-            h.clone()
-        } else {
-            println!("Prompting for host address...");
-            Text::new("Host address:")
-                .with_default("0.0.0.0")
-                .prompt()
-                .context("Failed to get host address")?
+        let stack = match framework {
+            Framework::None => language.as_str(),
+            _ => framework.as_str(),
         };
+        status!("Skipping Kubernetes files: {stack} projects are not backend services");
+        Ok(None)
+    }
 
-        let port = if let Some(p) = self.port {
-            println!("Using provided port: {p}");
-            p
-        } else {
-            let default_port = match framework {
-                Framework::None => 8080,
-                Framework::Gin => 8080,
-                Framework::GoZero => 8888,
-                Framework::Tauri => 1420,
-                Framework::Vue3 => 5173,
-                Framework::React => 5173,
-            };
-            println!("Prompting for HTTP port...");
-            Text::new("HTTP port:")
-                .with_default(&default_port.to_string())
-                .prompt()
-                .context("Failed to get port")?
-                .parse::<u16>()
-                .context("Invalid port number")?
-        };
+    /// 解析统一任务入口，为 `none` 时不生成
+    ///
+    /// 未指定时只在启用 gRPC 时生成 Makefile，提供重新生成代码的 `make proto`
+    fn resolve_task_runner(&self, enable_grpc: bool) -> Result<Option<TaskRunner>> {
+        match self.task_runner.as_deref() {
+            None if enable_grpc => Ok(Some(TaskRunner::Make)),
+            None | Some("none") => Ok(None),
+            Some(runner_str) => TaskRunner::parse_from_str(runner_str)
+                .map(Some)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Unsupported task runner: {runner_str}. Supported task runners: {}, none",
+                        constants::TASK_RUNNERS.join(", ")
+                    )
+                }),
+        }
+    }
 
-        let grpc_port = if let Some(p) = self.grpc_port {
-            println!("Using provided gRPC port: {p}");
-            p
-        } else if matches!(framework, Framework::GoZero) {
-            println!("Prompting for gRPC port...");
-            Text::new("gRPC port:")
-                .with_default("9000")
-                .prompt()
-                .context("Failed to get gRPC port")?
-                .parse::<u16>()
-                .context("Invalid gRPC port number")?
-        } else {
-            println!("Using default gRPC port: 9000");
-            9000 // 默认值，对于不需要gRPC的框架
+    /// 解析 API 客户端层，只指定 `--tanstack-query` 或 `--auth-pages` 时使用 fetch 实现
+    fn resolve_api_client(&self) -> Result<Option<ApiClient>> {
+        let Some(ref client_str) = self.api_client else {
+            let needs_client =
+                self.tanstack_query.unwrap_or(false) || self.auth_pages.unwrap_or(false);
+            return Ok(needs_client.then_some(ApiClient::Fetch));
         };
 
-        Ok((host, port, grpc_port))
+        ApiClient::parse_from_str(client_str)
+            .map(Some)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unsupported API client: {client_str}. Supported clients: {}",
+                    constants::API_CLIENTS.join(", ")
+                )
+            })
     }
 
-    fn configure_precommit(&self) -> Result<bool> {
-        println!("Configuring pre-commit settings...");
+    fn configure_precommit(
+        &self,
+        language: &Language,
+        framework: &Framework,
+        profile: Option<Profile>,
+    ) -> Result<bool> {
+        status!("Configuring pre-commit settings...");
 
         if let Some(enable) = self.enable_precommit {
-            println!("Using provided pre-commit setting: {enable}");
+            status!("Using provided pre-commit setting: {enable}");
+            Ok(enable)
+        } else if let Some(profile) = profile {
+            let enable = profile.features().lint;
+            status!("Using pre-commit setting from profile {profile}: {enable}");
             Ok(enable)
+        } else if !self.interactive {
+            Ok(false)
         } else {
-            println!("Prompting for pre-commit hooks...");
-            Confirm::new("Enable pre-commit hooks?")
-                .with_default(false)
-                .prompt()
-                .context("Failed to get pre-commit preference")
+            status!("Prompting for pre-commit hooks...");
+            let hint = feature_hint(Feature::Lint, language, framework);
+            answers::confirm(
+                "precommit",
+                Confirm::new("Enable pre-commit hooks?")
+                    .with_default(false)
+                    .with_help_message(&hint),
+            )
+            .context("Failed to get pre-commit preference")
         }
     }
 
-    fn configure_license(&self) -> Result<String> {
-        println!("Configuring license...");
+    /// 返回许可证表达式，以及 `--license-file` 或选择自定义许可证时提供的许可证文本路径
+    fn configure_license(&self) -> Result<(String, Option<PathBuf>)> {
+        status!("Configuring license...");
 
+        let license_file = self
+            .license_file
+            .as_deref()
+            .map(resolve_license_file)
+            .transpose()?;
         if let Some(ref license) = self.license {
-            println!("Using provided license: {license}");
-            Ok(license.clone())
+            let license = spdx::normalize_expression(license);
+            status!("Using provided license: {license}");
+            Ok((license, license_file))
+        } else if let Some(license_file) = license_file {
+            status!("Using custom license text: {}", license_file.display());
+            Ok((CUSTOM_LICENSE.to_string(), Some(license_file)))
+        } else if !self.interactive {
+            Ok(("MIT".to_string(), None))
         } else {
-            println!("Prompting for license selection...");
-            let licenses = vec!["MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause", "None"];
-            Select::new("Select a license:", licenses)
-                .prompt()
-                .context("Failed to select license")
-                .map(|s| s.to_string())
+            status!("Prompting for license selection...");
+            // 选项为 "MIT (MIT License)" 的形式，可按标识符或名称输入过滤；
+            // 列表包含模板目录中的所有许可证，避免与实际可用的模板不一致
+            let options: Vec<String> = spdx::available_licenses()
+                .into_iter()
+                .map(|(id, name)| format!("{id} ({name})"))
+                .chain([
+                    format!("{NO_LICENSE} (no LICENSE file)"),
+                    CUSTOM_LICENSE_OPTION.to_string(),
+                ])
+                .collect();
+            let selected = answers::select(
+                "license",
+                Select::new("Select a license:", options)
+                    .with_page_size(10)
+                    .with_help_message(
+                        "Type to filter by SPDX identifier or name; other SPDX identifiers can be passed with --license",
+                    ),
+            )
+            .context("Failed to select license")?;
+            if selected != CUSTOM_LICENSE_OPTION {
+                return Ok((
+                    selected
+                        .split_once(" (")
+                        .map_or(selected.as_str(), |(id, _)| id)
+                        .to_string(),
+                    None,
+                ));
+            }
+
+            let path = answers::text(
+                "license_file",
+                Text::new("License file path:")
+                    .with_help_message("The file is copied to LICENSE; <year> and <copyright holders> placeholders are filled in")
+                    .with_validator(|input: &str| {
+                        Ok(match validation::validate_license_file(input.trim()) {
+                            Ok(()) => Validation::Valid,
+                            Err(e) => Validation::Invalid(e.to_string().into()),
+                        })
+                    }),
+            )
+            .context("Failed to get license file path")?;
+            let license_file = resolve_license_file(Path::new(path.trim()))?;
+            Ok((CUSTOM_LICENSE.to_string(), Some(license_file)))
         }
     }
 
-    async fn configure_swagger(&self, framework: &Framework, language: &Language) -> Result<bool> {
+    fn configure_metadata(&self) -> Result<ProjectMetadata> {
+        status!("Configuring project metadata...");
+
+        // 显式传入的空描述和空主页与交互时留空相同
+        let description = if let Some(ref description) = self.description {
+            let description = description.trim();
+            if !description.is_empty() {
+                status!("Using provided description: {description}");
+            }
+            Some(description.to_string()).filter(|d| !d.is_empty())
+        } else if !self.interactive {
+            None
+        } else {
+            let description = answers::text(
+                "description",
+                Text::new("Project description:")
+                    .with_help_message("Leave empty to use the default description"),
+            )
+            .context("Failed to get project description")?;
+            Some(description.trim().to_string()).filter(|d| !d.is_empty())
+        };
+
+        let keywords = if let Some(ref keywords) = self.keywords {
+            let keywords = normalize_keywords(keywords.iter().map(String::as_str));
+            for keyword in &keywords {
+                validation::validate_keyword(keyword)?;
+            }
+            status!("Using provided keywords: {}", keywords.join(", "));
+            keywords
+        } else if !self.interactive {
+            Vec::new()
+        } else {
+            let input = answers::text(
+                "keywords",
+                Text::new("Keywords/topics (comma-separated):")
+                    .with_help_message("e.g. cli, web, api")
+                    .with_validator(|input: &str| {
+                        for keyword in normalize_keywords(input.split(',')) {
+                            if let Err(e) = validation::validate_keyword(&keyword) {
+                                return Ok(Validation::Invalid(e.to_string().into()));
+                            }
+                        }
+                        Ok(Validation::Valid)
+                    }),
+            )
+            .context("Failed to get keywords")?;
+            normalize_keywords(input.split(','))
+        };
+
+        let homepage = if let Some(ref homepage) = self.homepage {
+            let homepage = homepage.trim();
+            if !homepage.is_empty() {
+                validation::validate_homepage(homepage)?;
+                status!("Using provided homepage: {homepage}");
+            }
+            Some(homepage.to_string()).filter(|h| !h.is_empty())
+        } else if !self.interactive {
+            None
+        } else {
+            let homepage = answers::text(
+                "homepage",
+                Text::new("Homepage URL:")
+                    .with_help_message("Leave empty to skip")
+                    .with_validator(|input: &str| {
+                        let input = input.trim();
+                        if input.is_empty() {
+                            return Ok(Validation::Valid);
+                        }
+                        Ok(match validation::validate_homepage(input) {
+                            Ok(()) => Validation::Valid,
+                            Err(e) => Validation::Invalid(e.to_string().into()),
+                        })
+                    }),
+            )
+            .context("Failed to get homepage")?;
+            Some(homepage.trim().to_string()).filter(|h| !h.is_empty())
+        };
+
+        let license_holder = self
+            .license_holder
+            .as_deref()
+            .map(str::trim)
+            .filter(|holder| !holder.is_empty())
+            .map(str::to_string);
+        let license_year = self
+            .license_year
+            .as_deref()
+            .map(str::trim)
+            .filter(|year| !year.is_empty())
+            .map(|year| validation::validate_license_year(year).map(|()| year.to_string()))
+            .transpose()?;
+
+        // 作者和邮箱未指定时读取 git 配置，写入 LICENSE、README 和各语言的清单文件
+        let author = self
+            .author
+            .as_deref()
+            .map(str::trim)
+            .filter(|author| !author.is_empty())
+            .map(str::to_string)
+            .or_else(git_identity::user_name);
+        let author_email = self
+            .email
+            .as_deref()
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .map(|email| validation::validate_email(email).map(|()| email.to_string()))
+            .transpose()?
+            .or_else(git_identity::user_email);
+
+        Ok(ProjectMetadata::new()
+            .with_description(description)
+            .with_keywords(keywords)
+            .with_homepage(homepage)
+            .with_license_holder(license_holder)
+            .with_license_year(license_year)
+            .with_author(author)
+            .with_author_email(author_email))
+    }
+
+    async fn configure_swagger(
+        &self,
+        framework: &Framework,
+        language: &Language,
+        profile: Option<Profile>,
+    ) -> Result<bool> {
         if let Some(enable_swagger) = self.enable_swagger {
             return Ok(enable_swagger);
         }
 
-        // 只有 Go 语言的 Gin 框架支持 Swagger
-        if !matches!(language, Language::Go) || !matches!(framework, Framework::Gin) {
+        // Go 的 Gin、Fiber 框架以及 NestJS 支持 Swagger
+        if !matches!(
+            framework,
+            Framework::Gin | Framework::Fiber | Framework::NestJs
+        ) {
             return Ok(false);
         }
 
-        // 检查swag命令是否可用
+        // Go 项目用 swag 生成文档，需要检查swag命令是否可用；NestJS 在运行时生成文档
         let env_checker = EnvironmentChecker::new();
-        let swag_available = env_checker.check_swag().await.unwrap_or(false);
+        // 渲染模式下不运行 swag，不需要检查是否已安装
+        let swag_available = !matches!(language, Language::Go)
+            || render_only::is_render_only()
+            || env_checker.check_swag().await.unwrap_or(false);
 
         if !swag_available {
-            println!(
+            status!(
                 "{}",
                 "⚠️  Swag command not found. Swagger documentation will be disabled.".yellow()
             );
-            println!(
+            status!(
                 "   To enable Swagger, install swag: go install github.com/swaggo/swag/cmd/swag@latest"
             );
             return Ok(false);
         }
 
-        let enable_swagger = Confirm::new("Enable Swagger documentation?")
-            .with_default(true)
-            .prompt()
-            .context("Failed to configure Swagger")?;
+        if let Some(profile) = profile {
+            return Ok(profile.features().docs);
+        }
+        if !self.interactive {
+            return Ok(true);
+        }
+
+        let hint = feature_hint(Feature::Docs, language, framework);
+        let enable_swagger = answers::confirm(
+            "swagger",
+            Confirm::new("Enable Swagger documentation?")
+                .with_default(true)
+                .with_help_message(&hint),
+        )
+        .context("Failed to configure Swagger")?;
 
         Ok(enable_swagger)
     }
 
-    fn determine_project_path(&self) -> Result<PathBuf> {
+    fn determine_project_path(&self, render_to: Option<&Path>) -> Result<PathBuf> {
+        // 渲染模式直接输出到指定目录，允许使用已存在的空目录
+        if let Some(render_path) = render_to {
+            let is_non_empty = render_path.is_dir()
+                && std::fs::read_dir(render_path)
+                    .with_context(|| {
+                        format!("Failed to read directory: {}", render_path.display())
+                    })?
+                    .next()
+                    .is_some();
+            if render_path.is_file() || is_non_empty {
+                return Err(anyhow::anyhow!(
+                    "Render directory '{}' already exists and is not empty",
+                    render_path.display()
+                ));
+            }
+            return Ok(render_path.to_path_buf());
+        }
+
         let base_path = if let Some(path) = &self.target_path {
             PathBuf::from(path)
         } else {
@@ -448,8 +2439,8 @@ impl NewCommand {
         Ok(project_path)
     }
 
-    async fn generate_project(&self, params: ProjectParams) -> Result<()> {
-        println!("{}", "正在生成项目...".green());
+    async fn generate_project(&self, params: ProjectParams) -> Result<NextSteps> {
+        status!("{}", "正在生成项目...".green());
 
         // 验证语言和框架组合是否有效
         let valid_frameworks = Framework::frameworks_for_language(params.language);
@@ -469,6 +2460,11 @@ impl NewCommand {
             ));
         }
 
+        let tailwind_version = self.resolve_tailwind_version()?;
+        let tauri_version = self.resolve_tauri_version()?;
+        let runtime = self.resolve_runtime()?;
+        let api_client = self.resolve_api_client()?;
+
         // 创建项目目录
         std::fs::create_dir_all(&params.project_path).with_context(|| {
             format!(
@@ -477,26 +2473,119 @@ impl NewCommand {
             )
         })?;
 
-        let mut orchestrator = GeneratorOrchestrator::new()?;
+        let orchestrator = GeneratorOrchestrator::new()?
+            .with_metadata(
+                params
+                    .metadata
+                    .clone()
+                    .with_license(Some(params.license.clone())),
+            )
+            .with_features(params.features)
+            .with_ci_provider(params.ci_provider.unwrap_or_default())
+            .with_tailwind_version(tailwind_version)
+            .with_tauri_version(tauri_version)
+            .with_runtime(runtime)
+            .with_api_client(api_client, self.tanstack_query.unwrap_or(false))
+            .with_auth_pages(self.auth_pages.unwrap_or(false))
+            .with_dark_mode(self.dark_mode.unwrap_or(false))
+            .with_compose_dev(self.compose_dev)
+            .with_git(!self.in_workspace)
+            .with_api_proxy(self.api_proxy);
 
         // 根据框架类型生成项目
-        match params.framework {
+        let mut next_steps = match params.framework {
             Framework::Gin => {
                 let options = GinProjectOptions::new()
                     .with_license(params.license.clone())
                     .with_server(params.host.clone(), params.port)
                     .with_swagger(params.enable_swagger)
+                    .with_precommit(params.enable_precommit)
+                    .with_cors(params.cors.is_some())
+                    .with_database(params.database)
+                    .with_orm(params.orm)
+                    .with_logger(params.logger)
+                    .with_redis(params.enable_redis)
+                    .with_grpc(params.enable_grpc.then_some(params.grpc_port))
+                    .with_openapi(params.openapi.clone());
+                let options = match params.cors {
+                    Some(cors) => options
+                        .with_cors_origins(cors.origins)
+                        .with_cors_methods(cors.methods),
+                    None => options,
+                };
+
+                orchestrator
+                    .generate_gin_project(self.project_name.clone(), &params.project_path, options)
+                    .await?
+            }
+            Framework::Fiber => {
+                let options = FiberProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_server(params.host.clone(), params.port)
+                    .with_swagger(params.enable_swagger)
+                    .with_precommit(params.enable_precommit)
+                    .with_database(params.database);
+
+                orchestrator
+                    .generate_fiber_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
+            }
+            Framework::Chi => {
+                let options = ChiProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_server(params.host.clone(), params.port)
                     .with_precommit(params.enable_precommit);
 
-                orchestrator.generate_gin_project(
-                    self.project_name.clone(),
-                    &params.project_path,
-                    options,
-                )?;
+                orchestrator
+                    .generate_chi_project(self.project_name.clone(), &params.project_path, options)
+                    .await?
             }
             Framework::GoZero => {
-                // TODO: 实现 GoZero 项目生成
-                return Err(anyhow::anyhow!("GoZero 项目生成尚未实现"));
+                let options = GoZeroProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_server(params.host.clone(), params.port)
+                    .with_grpc_port(params.grpc_port)
+                    .with_services(&params.services)
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_go_zero_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
+            }
+            Framework::Django => {
+                let options = DjangoProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_drf(params.enable_drf)
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_django_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
+            }
+            Framework::Flask => {
+                let options = FlaskProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_flask_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
             }
             Framework::Tauri => {
                 orchestrator
@@ -506,7 +2595,40 @@ impl NewCommand {
                         params.license.clone(),
                         params.enable_precommit,
                     )
-                    .await?;
+                    .await?
+            }
+            Framework::Axum => {
+                let options = AxumProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_server(params.host.clone(), params.port)
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_axum_project(self.project_name.clone(), &params.project_path, options)
+                    .await?
+            }
+            Framework::Actix => {
+                let options = ActixProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_server(params.host.clone(), params.port)
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_actix_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
+            }
+            Framework::Cli => {
+                let options = CliProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_cli_project(self.project_name.clone(), &params.project_path, options)
+                    .await?
             }
             Framework::Vue3 => {
                 orchestrator
@@ -516,7 +2638,7 @@ impl NewCommand {
                         params.license.clone(),
                         params.enable_precommit,
                     )
-                    .await?;
+                    .await?
             }
             Framework::React => {
                 orchestrator
@@ -526,7 +2648,69 @@ impl NewCommand {
                         params.license.clone(),
                         params.enable_precommit,
                     )
-                    .await?;
+                    .await?
+            }
+            Framework::NextJs => {
+                orchestrator
+                    .generate_nextjs_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        params.license.clone(),
+                        params.enable_precommit,
+                    )
+                    .await?
+            }
+            Framework::Nuxt => {
+                let options = NuxtProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_precommit(params.enable_precommit)
+                    .with_modules(params.nuxt_modules.clone());
+
+                orchestrator
+                    .generate_nuxt_project(self.project_name.clone(), &params.project_path, options)
+                    .await?
+            }
+            Framework::SvelteKit => {
+                let options = SvelteKitProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_precommit(params.enable_precommit)
+                    .with_options(params.sveltekit_options.clone());
+
+                orchestrator
+                    .generate_sveltekit_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
+            }
+            Framework::NestJs => {
+                let options = NestJsProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_server(params.host.clone(), params.port)
+                    .with_swagger(params.enable_swagger)
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_nestjs_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
+            }
+            Framework::Astro => {
+                let options = AstroProjectOptions::new()
+                    .with_license(params.license.clone())
+                    .with_precommit(params.enable_precommit);
+
+                orchestrator
+                    .generate_astro_project(
+                        self.project_name.clone(),
+                        &params.project_path,
+                        options,
+                    )
+                    .await?
             }
             Framework::None => {
                 // 根据语言生成纯语言项目
@@ -539,7 +2723,7 @@ impl NewCommand {
                                 params.license.clone(),
                                 params.enable_precommit,
                             )
-                            .await?;
+                            .await?
                     }
                     Language::Rust => {
                         orchestrator
@@ -549,7 +2733,17 @@ impl NewCommand {
                                 params.license.clone(),
                                 params.enable_precommit,
                             )
-                            .await?;
+                            .await?
+                    }
+                    Language::Zig => {
+                        orchestrator
+                            .generate_zig_project(
+                                self.project_name.clone(),
+                                &params.project_path,
+                                params.license.clone(),
+                                params.enable_precommit,
+                            )
+                            .await?
                     }
                     _ => {
                         return Err(anyhow::anyhow!(
@@ -564,8 +2758,367 @@ impl NewCommand {
                     }
                 }
             }
+        };
+
+        // Docker 文件与框架无关，在框架文件之后补齐
+        if params.features.docker || self.compose_dev {
+            orchestrator.generate_docker(
+                self.project_name.clone(),
+                &params.language,
+                &params.framework,
+                params.port,
+                &params.project_path,
+                &mut next_steps,
+            )?;
         }
 
-        Ok(())
+        // 不支持的框架已在解析参数时提示跳过
+        if let Some(format) = params.k8s_format
+            && let Some(k8s_params) = K8sParams::for_stack(
+                self.project_name.clone(),
+                format,
+                params.framework,
+                &params.host,
+                params.port,
+            )
+        {
+            orchestrator.generate_k8s(
+                k8s_params.with_go_zero_services(&params.services, params.grpc_port),
+                &params.project_path,
+                &mut next_steps,
+            )?;
+        }
+
+        // 任务入口的命令取决于已生成的 package.json 等文件，最后生成
+        if let Some(runner) = params.task_runner {
+            orchestrator.generate_task_runner(
+                TaskParams::new(
+                    self.project_name.clone(),
+                    runner,
+                    params.language,
+                    params.framework,
+                ),
+                &params.project_path,
+                &mut next_steps,
+            )?;
+        }
+
+        // 模板声明的钩子在所有文件生成后执行，执行前逐个确认
+        for template_path in catalog::template_dirs(params.language, params.framework) {
+            for processor in PostProcessor::from_manifest(&template_path)? {
+                processor.execute(&params.project_path)?;
+            }
+        }
+
+        Ok(next_steps)
+    }
+}
+
+/// 汇总确认界面中当前项目适用的答案及其取值
+/// 提问帮助信息中展示的功能代价（依赖、安装耗时、新增文件）
+fn feature_hint(feature: Feature, language: &Language, framework: &Framework) -> String {
+    feature
+        .impact(language, framework)
+        .map(|impact| impact.hint())
+        .unwrap_or_default()
+}
+
+/// 档位启用且不会单独询问的功能（Docker、示例测试）及其代价
+fn profile_feature_rows(params: &ProjectParams) -> Vec<(&'static str, String)> {
+    let features = params.features;
+    [
+        (Feature::Docker, features.docker),
+        (Feature::Tests, features.tests),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .filter_map(|(feature, _)| {
+        let impact = feature.impact(&params.language, &params.framework)?;
+        Some((feature.label(), impact.hint()))
+    })
+    .collect()
+}
+
+fn summary_rows(params: &ProjectParams) -> Vec<(SummaryItem, String)> {
+    let yes_no = |enabled: bool| if enabled { "yes" } else { "no" }.to_string();
+    let or_none = |value: Option<&String>, none: &str| value.cloned().unwrap_or(none.to_string());
+    let framework = params.framework;
+
+    let mut rows = vec![(
+        SummaryItem::Stack,
+        match framework {
+            Framework::None => params.language.to_string(),
+            _ => format!("{} / {}", params.language, framework.as_str()),
+        },
+    )];
+    if uses_network_settings(&params.language, &framework) {
+        rows.push((SummaryItem::Host, params.host.clone()));
+        rows.push((SummaryItem::Port, params.port.to_string()));
+    }
+    if matches!(framework, Framework::GoZero) {
+        rows.push((SummaryItem::GrpcPort, params.grpc_port.to_string()));
+        rows.push((SummaryItem::Services, params.services.join(", ")));
+    }
+    if matches!(framework, Framework::Nuxt) {
+        rows.push((
+            SummaryItem::NuxtModules,
+            choices_label(&params.nuxt_modules),
+        ));
+    }
+    if matches!(framework, Framework::SvelteKit) {
+        rows.push((
+            SummaryItem::SvelteKitOptions,
+            choices_label(&params.sveltekit_options),
+        ));
+    }
+    if matches!(framework, Framework::Gin | Framework::Fiber) {
+        rows.push((
+            SummaryItem::Database,
+            params
+                .database
+                .map_or(NO_DATABASE, |database| database.as_str())
+                .to_string(),
+        ));
+    }
+    if framework == Framework::Gin && params.database.is_some_and(|db| db.uses_gorm()) {
+        rows.push((SummaryItem::Orm, params.orm.to_string()));
+    }
+    if matches!(framework, Framework::Gin) {
+        rows.push((SummaryItem::Logger, params.logger.to_string()));
+        rows.push((SummaryItem::Redis, yes_no(params.enable_redis)));
+        rows.push((SummaryItem::Grpc, yes_no(params.enable_grpc)));
+        if params.enable_grpc {
+            rows.push((SummaryItem::GrpcPort, params.grpc_port.to_string()));
+        }
+    }
+    if matches!(framework, Framework::Django) {
+        rows.push((SummaryItem::Drf, yes_no(params.enable_drf)));
+    }
+    if matches!(
+        framework,
+        Framework::Gin | Framework::Fiber | Framework::NestJs
+    ) {
+        rows.push((SummaryItem::Swagger, yes_no(params.enable_swagger)));
+    }
+    rows.push((SummaryItem::Precommit, yes_no(params.enable_precommit)));
+    if Feature::Ci
+        .impact(&params.language, &params.framework)
+        .is_some()
+    {
+        rows.push((
+            SummaryItem::Ci,
+            params.ci_provider.map_or(NO_CI.to_string(), |provider| {
+                format!("{provider} ({})", provider.config_path())
+            }),
+        ));
+    }
+    rows.push((SummaryItem::License, params.license.clone()));
+    rows.push((
+        SummaryItem::Description,
+        or_none(params.metadata.description.as_ref(), "(default)"),
+    ));
+    rows.push((
+        SummaryItem::Keywords,
+        Some(params.metadata.keywords.join(", "))
+            .filter(|keywords| !keywords.is_empty())
+            .unwrap_or("(none)".to_string()),
+    ));
+    rows.push((
+        SummaryItem::Homepage,
+        or_none(params.metadata.homepage.as_ref(), "(none)"),
+    ));
+    rows
+}
+
+/// 各框架默认监听的 HTTP 端口（前端框架为开发服务器端口）
+pub fn default_port(framework: &Framework) -> u16 {
+    match framework {
+        Framework::None => 8080,
+        Framework::Gin => 8080,
+        Framework::Fiber => DEFAULT_FIBER_PORT,
+        Framework::Chi => DEFAULT_CHI_PORT,
+        Framework::GoZero => 8888,
+        Framework::Django => 8000,
+        Framework::Flask => DEFAULT_FLASK_PORT,
+        Framework::Tauri => 1420,
+        Framework::Axum => DEFAULT_AXUM_PORT,
+        Framework::Actix => DEFAULT_ACTIX_PORT,
+        Framework::Cli => 8080,
+        Framework::Vue3 => 5173,
+        Framework::React => 5173,
+        Framework::NextJs => DEFAULT_NEXTJS_PORT,
+        Framework::Nuxt => DEFAULT_NUXT_PORT,
+        Framework::SvelteKit => DEFAULT_SVELTEKIT_PORT,
+        Framework::NestJs => DEFAULT_NESTJS_PORT,
+        Framework::Astro => DEFAULT_ASTRO_PORT,
+    }
+}
+
+/// 是否需要配置主机和端口：Go 服务以及 Axum、Actix-web、NestJS 服务需要，
+/// 其余 Rust、Python 和 TypeScript 项目不需要
+pub fn uses_network_settings(language: &Language, framework: &Framework) -> bool {
+    matches!(language, Language::Go)
+        || matches!(
+            framework,
+            Framework::Axum | Framework::Actix | Framework::NestJs
+        )
+}
+
+/// 多选结果的展示文本，一项都没选时为 `none`
+fn choices_label(choices: &[String]) -> String {
+    if choices.is_empty() {
+        NO_CHOICES.to_string()
+    } else {
+        choices.join(", ")
+    }
+}
+
+/// 检查自定义许可证文本并转换为绝对路径，生成时的工作目录不影响读取
+fn resolve_license_file(path: &Path) -> Result<PathBuf> {
+    validation::validate_license_file(&path.to_string_lossy())?;
+    std::path::absolute(path)
+        .with_context(|| format!("Failed to resolve license file: {}", path.display()))
+}
+
+/// 清理关键字列表：去除空白、空项和重复项
+fn normalize_keywords<'a>(keywords: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for keyword in keywords.map(str::trim).filter(|k| !k.is_empty()) {
+        if !result.iter().any(|k| k == keyword) {
+            result.push(keyword.to_string());
+        }
+    }
+    result
+}
+
+/// 构造不支持框架的错误
+fn unsupported_framework_error(framework_str: &str) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Unsupported framework: {framework_str}. Supported frameworks: {}, none",
+        constants::stack_frameworks().join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_summary_and_change_answer() {
+        // 跳过对 go 等工具的环境检查
+        render_only::scope(true, async {
+            let workspace = tempfile::tempdir().unwrap();
+            let render_to = workspace.path().join("demo");
+            let command = NewCommand::new("demo".to_string(), None)
+                .with_stack(Some("go/gin".to_string()))
+                .with_port(Some(8081))
+                .with_database(Some("postgres".to_string()))
+                .with_license(Some("Apache-2.0".to_string()))
+                .with_description(Some("  ".to_string()))
+                .with_interactive(false);
+            let params = command.resolve_params(Some(&render_to)).await.unwrap();
+            assert_eq!(params.metadata.description, None);
+
+            let rows = summary_rows(&params);
+            let items: Vec<SummaryItem> = rows.iter().map(|(item, _)| *item).collect();
+            assert!(items.contains(&SummaryItem::Database));
+            assert!(!items.contains(&SummaryItem::Drf));
+            assert!(rows.contains(&(SummaryItem::Port, "8081".to_string())));
+
+            // 只清除要修改的一项，其余答案保持不变
+            let changed = command.with_answers(&params, SummaryItem::License);
+            assert_eq!(changed.license, None);
+            let reresolved = changed.resolve_params(Some(&render_to)).await.unwrap();
+            assert_eq!(reresolved.port, 8081);
+            assert_eq!(reresolved.database, Some(Database::Postgres));
+            assert_eq!(reresolved.license, "MIT");
+            assert_eq!(reresolved.ci_provider, None);
+
+            // 修改框架时清除与框架相关的答案
+            let changed = command.with_answers(&params, SummaryItem::Stack);
+            assert_eq!(changed.framework, None);
+            assert_eq!(changed.port, None);
+            assert_eq!(changed.database, None);
+            assert_eq!(changed.license.as_deref(), Some("Apache-2.0"));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_ci_provider() {
+        render_only::scope(true, async {
+            let workspace = tempfile::tempdir().unwrap();
+            let render_to = workspace.path().join("demo");
+            let command = NewCommand::new("demo".to_string(), None)
+                .with_stack(Some("rust/axum".to_string()))
+                .with_profile(Some("full".to_string()))
+                .with_interactive(false);
+
+            // 档位启用 CI 时默认生成 GitHub Actions 配置，--ci 可以换平台或关闭
+            let params = command.resolve_params(Some(&render_to)).await.unwrap();
+            assert_eq!(params.ci_provider, Some(CiProvider::Github));
+            let params = command
+                .clone()
+                .with_ci(Some("gitlab".to_string()))
+                .resolve_params(Some(&render_to))
+                .await
+                .unwrap();
+            assert_eq!(params.ci_provider, Some(CiProvider::Gitlab));
+            assert!(params.features.ci);
+            assert!(
+                summary_rows(&params)
+                    .contains(&(SummaryItem::Ci, "gitlab (.gitlab-ci.yml)".to_string()))
+            );
+            let params = command
+                .with_ci(Some(NO_CI.to_string()))
+                .resolve_params(Some(&render_to))
+                .await
+                .unwrap();
+            assert!(!params.features.ci);
+
+            // 没有 CI 模板的技术栈忽略 --ci
+            let params = NewCommand::new("demo".to_string(), None)
+                .with_stack(Some("rust/tauri".to_string()))
+                .with_ci(Some("github".to_string()))
+                .with_interactive(false)
+                .resolve_params(Some(&render_to))
+                .await
+                .unwrap();
+            assert_eq!(params.ci_provider, None);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_k8s_format() {
+        render_only::scope(true, async {
+            let workspace = tempfile::tempdir().unwrap();
+            let render_to = workspace.path().join("demo");
+
+            // Kubernetes 工作负载需要镜像，最小档位也会生成 Docker 文件
+            let params = NewCommand::new("demo".to_string(), None)
+                .with_stack(Some("go/gin".to_string()))
+                .with_profile(Some("minimal".to_string()))
+                .with_k8s(Some("chart".to_string()))
+                .with_interactive(false)
+                .resolve_params(Some(&render_to))
+                .await
+                .unwrap();
+            assert_eq!(params.k8s_format, Some(K8sFormat::Helm));
+            assert!(params.features.docker);
+
+            // 前端项目跳过
+            let params = NewCommand::new("demo".to_string(), None)
+                .with_stack(Some("ts/react".to_string()))
+                .with_profile(Some("minimal".to_string()))
+                .with_k8s(Some("manifests".to_string()))
+                .with_interactive(false)
+                .resolve_params(Some(&render_to))
+                .await
+                .unwrap();
+            assert_eq!(params.k8s_format, None);
+            assert!(!params.features.docker);
+        })
+        .await;
     }
 }
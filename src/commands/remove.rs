@@ -0,0 +1,203 @@
+use anyhow::{Context, Result, bail};
+use colored::*;
+use std::path::{Path, PathBuf};
+
+use crate::generators::core::resolve_output_path;
+use crate::utils::code_inject;
+use crate::utils::feature_manifest::{FeatureManifest, MANIFEST_FILE};
+
+/// `scafgen remove <功能>`：按项目根目录的 `.scafgen.json` 删除生成时添加的功能
+///
+/// 删除只属于该功能的文件，并去掉其他文件中 `scafgen:begin <功能>` 到 `scafgen:end <功能>` 之间的代码段
+pub struct RemoveCommand {
+    feature: String,
+    project_path: PathBuf,
+    dry_run: bool,
+}
+
+/// 待删除代码段的文件：清单中的路径、磁盘路径、删除后的内容和代码段数
+struct RegionEdit {
+    file: String,
+    path: PathBuf,
+    content: String,
+    regions: usize,
+}
+
+impl RemoveCommand {
+    pub fn new(feature: String, project_path: PathBuf) -> Self {
+        Self {
+            feature,
+            project_path,
+            dry_run: false,
+        }
+    }
+
+    /// 只列出会删除的文件和代码段，不做修改
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn execute(self) -> Result<()> {
+        let root = &self.project_path;
+        let mut manifest = FeatureManifest::load(root)?;
+        let Some(entry) = manifest.features.get(&self.feature).cloned() else {
+            let features: Vec<&str> = manifest.features.keys().map(String::as_str).collect();
+            bail!(
+                "Feature '{}' is not recorded in {MANIFEST_FILE}. Recorded features: {}",
+                self.feature,
+                features.join(", ")
+            );
+        };
+
+        // 先处理所有代码段，任一文件的标记不完整时不做任何修改
+        let mut edits = Vec::new();
+        for file in &entry.regions {
+            let path = resolve_output_path(root, file)?;
+            if !path.exists() {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let (content, regions) = code_inject::remove_regions(&content, &self.feature)
+                .with_context(|| format!("Failed to remove code from {file}"))?;
+            if regions > 0 {
+                edits.push(RegionEdit {
+                    file: file.clone(),
+                    path,
+                    content,
+                    regions,
+                });
+            }
+        }
+        let mut files = Vec::new();
+        for file in &entry.files {
+            let path = resolve_output_path(root, file)?;
+            if path.is_file() {
+                files.push((file, path));
+            }
+        }
+
+        if self.dry_run {
+            println!(
+                "{}",
+                format!("Removing '{}' would change:", self.feature).bold()
+            );
+            for (file, _) in &files {
+                println!("  delete {file}");
+            }
+            for edit in &edits {
+                println!("  edit   {} ({} block(s))", edit.file, edit.regions);
+            }
+            return Ok(());
+        }
+
+        for (file, path) in &files {
+            std::fs::remove_file(path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+            remove_empty_parents(root, path)?;
+            manifest.pinned.remove(file.as_str());
+            println!("🗑️  Removed: {file}");
+        }
+        for edit in &edits {
+            std::fs::write(&edit.path, &edit.content)
+                .with_context(|| format!("Failed to write {}", edit.path.display()))?;
+            if manifest.pinned.contains_key(&edit.file) {
+                manifest.pin(root, &edit.file)?;
+            }
+            println!("✂️  Edited: {} ({} block(s))", edit.file, edit.regions);
+        }
+
+        manifest.features.remove(&self.feature);
+        manifest.prune(root);
+        manifest.save(root)?;
+
+        println!("{} {}", "✅ Removed feature".green().bold(), self.feature);
+        if root.join("go.mod").exists() {
+            println!("Run `go mod tidy` to drop the dependencies that are no longer used.");
+        }
+        Ok(())
+    }
+}
+
+/// 删除文件后逐级删除空的父目录，项目根目录保留
+fn remove_empty_parents(root: &Path, path: &Path) -> Result<()> {
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == root || !current.starts_with(root) {
+            break;
+        }
+        let is_empty = std::fs::read_dir(current)
+            .with_context(|| format!("Failed to read {}", current.display()))?
+            .next()
+            .is_none();
+        if !is_empty {
+            break;
+        }
+        std::fs::remove_dir(current)
+            .with_context(|| format!("Failed to remove {}", current.display()))?;
+        dir = current.parent();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::feature_manifest::FeatureEntry;
+
+    #[test]
+    fn test_remove_feature() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("pkg/cache")).unwrap();
+        std::fs::write(root.join("pkg/cache/redis.go"), "package cache\n").unwrap();
+        std::fs::write(root.join("Dockerfile"), "FROM scratch\n").unwrap();
+        std::fs::write(
+            root.join("dev.toml"),
+            "[server]\nport = 8080\n\n# scafgen:begin redis\n[redis]\n# scafgen:end redis\n",
+        )
+        .unwrap();
+        let mut manifest = FeatureManifest {
+            version: "0.0.0".to_string(),
+            ..Default::default()
+        };
+        manifest.features.insert(
+            "redis".to_string(),
+            FeatureEntry {
+                files: vec!["pkg/cache/redis.go".to_string()],
+                regions: vec!["dev.toml".to_string()],
+            },
+        );
+        manifest.features.insert(
+            "docker".to_string(),
+            FeatureEntry {
+                files: vec!["Dockerfile".to_string()],
+                regions: Vec::new(),
+            },
+        );
+        manifest.save(root).unwrap();
+
+        assert!(
+            RemoveCommand::new("swagger".to_string(), root.to_path_buf())
+                .execute()
+                .is_err()
+        );
+        RemoveCommand::new("redis".to_string(), root.to_path_buf())
+            .with_dry_run(true)
+            .execute()
+            .unwrap();
+        assert!(root.join("pkg/cache/redis.go").exists());
+
+        RemoveCommand::new("redis".to_string(), root.to_path_buf())
+            .execute()
+            .unwrap();
+        assert!(!root.join("pkg").exists());
+        assert_eq!(
+            std::fs::read_to_string(root.join("dev.toml")).unwrap(),
+            "[server]\nport = 8080\n"
+        );
+        let manifest = FeatureManifest::load(root).unwrap();
+        assert_eq!(manifest.features.keys().collect::<Vec<_>>(), ["docker"]);
+    }
+}
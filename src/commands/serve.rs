@@ -0,0 +1,625 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{Semaphore, mpsc};
+use tokio::time::{Instant, timeout_at};
+
+use super::new::NewCommand;
+use crate::constants::{self, Framework};
+use crate::generators::core::validation;
+use crate::status;
+use crate::utils::archive::{self, ARCHIVE_FORMATS, ArchiveFormat};
+use crate::utils::{console, spdx};
+
+/// 请求行和请求头的最大长度
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+/// 请求体的最大长度
+const MAX_BODY_BYTES: usize = 64 * 1024;
+/// 流式响应中每个分块的大小
+const CHUNK_BYTES: usize = 64 * 1024;
+/// 同时生成的项目数，超出的请求排队等待
+const MAX_CONCURRENT_JOBS: usize = 4;
+/// 同时处理的连接数，达到上限时暂停接受新连接
+const MAX_CONNECTIONS: usize = 64;
+/// 读取完整请求（请求头和请求体）的时限
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `scafgen serve`：通过 HTTP 接口提供模板列表、选项校验和项目生成
+///
+/// 生成只渲染模板、不运行外部工具（与 `--render-to` 相同），结果以 tar/zip 流返回
+pub struct ServeCommand {
+    host: String,
+    port: u16,
+}
+
+impl ServeCommand {
+    pub fn new(host: String, port: u16) -> Self {
+        Self { host, port }
+    }
+
+    pub async fn execute(&self) -> Result<()> {
+        validation::validate_host(&self.host)?;
+        let host = validation::normalize_host(&self.host);
+        let listener = TcpListener::bind((host.as_str(), self.port))
+            .await
+            .with_context(|| format!("Failed to listen on {}", self.address()))?;
+
+        // 每个请求都渲染到临时目录，只渲染模板、不运行外部工具；
+        // 静默模式是进程级设置，服务启动时统一开启，并发请求的生成进度不会交错输出
+        let log_requests = !console::is_quiet();
+        status!("Serving scafgen API on http://{}", self.address());
+        status!("  GET  /api/templates");
+        status!("  POST /api/validate");
+        status!("  POST /api/generate");
+        console::set_quiet(true);
+
+        let jobs = Arc::new(Semaphore::new(MAX_CONCURRENT_JOBS));
+        let connections = Arc::new(Semaphore::new(MAX_CONNECTIONS));
+        loop {
+            let (permit, (stream, peer)) = tokio::select! {
+                accepted = async {
+                    let permit = connections.clone().acquire_owned().await?;
+                    anyhow::Ok((permit, listener.accept().await?))
+                } => accepted.context("Failed to accept connection")?,
+                _ = tokio::signal::ctrl_c() => break,
+            };
+            let jobs = jobs.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, &jobs, log_requests).await {
+                    eprintln!("⚠️  Warning: {peer}: {e:#}");
+                }
+                drop(permit);
+            });
+        }
+
+        if log_requests {
+            println!("Server stopped");
+        }
+        Ok(())
+    }
+
+    fn address(&self) -> String {
+        validation::format_host_port(&self.host, self.port)
+    }
+}
+
+/// 解析后的 HTTP 请求
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// 带状态码的请求错误，以 JSON 形式返回给客户端
+struct HttpError {
+    status: u16,
+    message: String,
+}
+
+impl HttpError {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// 接口的响应
+enum Response {
+    Json(u16, Value),
+    /// 生成结果，打包后以分块编码流式返回
+    Archive(GeneratedProject),
+}
+
+/// 渲染到临时目录中的项目，打包完成后删除临时目录
+struct GeneratedProject {
+    name: String,
+    format: ArchiveFormat,
+    project_path: PathBuf,
+    workspace: tempfile::TempDir,
+}
+
+/// 生成请求，字段与 `scafgen new` 的命令行参数对应
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenerateRequest {
+    name: String,
+    stack: Option<String>,
+    language: Option<String>,
+    framework: Option<String>,
+    host: Option<String>,
+    port: Option<u16>,
+    grpc_port: Option<u16>,
+    services: Option<Vec<String>>,
+    api: Option<bool>,
+    rpc: Option<bool>,
+    admin: Option<bool>,
+    nuxt_modules: Option<Vec<String>>,
+    sveltekit_options: Option<Vec<String>>,
+    profile: Option<String>,
+    precommit: Option<bool>,
+    license: Option<String>,
+    swagger: Option<bool>,
+    database: Option<String>,
+    orm: Option<String>,
+    logger: Option<String>,
+    cors: Option<bool>,
+    cors_origins: Option<Vec<String>>,
+    cors_methods: Option<Vec<String>>,
+    redis: Option<bool>,
+    grpc: Option<bool>,
+    drf: Option<bool>,
+    description: Option<String>,
+    keywords: Option<Vec<String>>,
+    homepage: Option<String>,
+    /// 作者和邮箱，服务端不读取 git 配置
+    author: Option<String>,
+    email: Option<String>,
+    /// 打包格式，默认 tar
+    format: Option<String>,
+}
+
+impl GenerateRequest {
+    fn archive_format(&self) -> Result<ArchiveFormat> {
+        let Some(ref format) = self.format else {
+            return Ok(ArchiveFormat::Tar);
+        };
+        ArchiveFormat::parse_from_str(format).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported archive format: {format}. Supported formats: {}",
+                ARCHIVE_FORMATS.join(", ")
+            )
+        })
+    }
+
+    /// 转换为非交互的生成命令，项目渲染到 `workspace/<name>`
+    ///
+    /// 未指定的选项使用交互提示中的默认值，语言和框架无法确定时报错
+    fn to_command(&self, workspace: &Path) -> Result<NewCommand> {
+        validation::validate_project_name(&self.name)?;
+        if let Some(ref stack) = self.stack
+            && constants::parse_stack(stack).is_none()
+        {
+            return Err(anyhow::anyhow!(
+                "Unsupported stack: {stack}. Supported stacks: {}",
+                constants::STACKS.join(", ")
+            ));
+        }
+        for port in [self.port, self.grpc_port].into_iter().flatten() {
+            validation::validate_port(port)?;
+        }
+        self.archive_format()?;
+
+        let render_to = workspace.join(&self.name).to_string_lossy().into_owned();
+        Ok(NewCommand::new(self.name.clone(), None)
+            .with_framework(self.framework.clone())
+            .with_host(self.host.clone())
+            .with_port(self.port)
+            .with_grpc_port(self.grpc_port)
+            .with_services(self.services.clone())
+            .with_api(self.api)
+            .with_rpc(self.rpc)
+            .with_admin(self.admin)
+            .with_nuxt_modules(self.nuxt_modules.clone())
+            .with_sveltekit_options(self.sveltekit_options.clone())
+            .with_language(self.language.clone())
+            .with_stack(self.stack.clone())
+            .with_profile(self.profile.clone())
+            .with_precommit(self.precommit)
+            .with_license(self.license.clone())
+            .with_swagger(self.swagger)
+            .with_database(self.database.clone())
+            .with_orm(self.orm.clone())
+            .with_logger(self.logger.clone())
+            .with_cors(
+                self.cors,
+                self.cors_origins.clone(),
+                self.cors_methods.clone(),
+            )
+            .with_redis(self.redis)
+            .with_grpc(self.grpc)
+            .with_drf(self.drf)
+            .with_description(self.description.clone())
+            .with_keywords(self.keywords.clone())
+            .with_homepage(self.homepage.clone())
+            .with_author(self.author.clone())
+            .with_email(self.email.clone())
+            .with_render_to(Some(render_to))
+            .with_interactive(false))
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    jobs: &Semaphore,
+    log_requests: bool,
+) -> Result<()> {
+    let (request_line, response) = match read_request(&mut stream).await {
+        Ok(request) => {
+            let request_line = format!("{} {}", request.method, request.path);
+            let response = route(&request, jobs)
+                .await
+                .unwrap_or_else(|e| Response::Json(e.status, json!({ "error": e.message })));
+            (request_line, response)
+        }
+        Err(e) => (
+            "<malformed request>".to_string(),
+            Response::Json(e.status, json!({ "error": e.message })),
+        ),
+    };
+
+    match response {
+        Response::Json(status, body) => {
+            if log_requests {
+                println!("{request_line} -> {status}");
+            }
+            write_json(&mut stream, status, &body).await
+        }
+        Response::Archive(project) => {
+            if log_requests {
+                println!(
+                    "{request_line} -> 200 ({}.{})",
+                    project.name,
+                    project.format.extension()
+                );
+            }
+            write_archive(&mut stream, project).await
+        }
+    }
+}
+
+async fn route(request: &Request, jobs: &Semaphore) -> Result<Response, HttpError> {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/api/templates") => Ok(Response::Json(200, list_templates())),
+        ("POST", "/api/validate") => {
+            let generate_request = parse_body(&request.body)?;
+            let workspace = create_workspace()?;
+            let result = match generate_request.to_command(workspace.path()) {
+                Ok(command) => command.validate().await,
+                Err(e) => Err(e),
+            };
+            Ok(Response::Json(
+                200,
+                match result {
+                    Ok(()) => json!({ "valid": true }),
+                    Err(e) => json!({ "valid": false, "error": format!("{e:#}") }),
+                },
+            ))
+        }
+        ("POST", "/api/generate") => {
+            let generate_request = parse_body(&request.body)?;
+            let workspace = create_workspace()?;
+            let command = generate_request
+                .to_command(workspace.path())
+                .map_err(|e| HttpError::new(422, format!("{e:#}")))?;
+            command
+                .validate()
+                .await
+                .map_err(|e| HttpError::new(422, format!("{e:#}")))?;
+            // 生成过程使用同步的文件和进程操作，放到阻塞线程中执行，并限制同时生成的数量
+            let _permit = jobs
+                .acquire()
+                .await
+                .map_err(|e| HttpError::new(500, format!("{e:#}")))?;
+            let runtime = tokio::runtime::Handle::current();
+            let project_path =
+                tokio::task::spawn_blocking(move || runtime.block_on(command.generate()))
+                    .await
+                    .map_err(|e| HttpError::new(500, format!("Generation task panicked: {e}")))?
+                    .map_err(|e| HttpError::new(500, format!("{e:#}")))?;
+
+            Ok(Response::Archive(GeneratedProject {
+                format: generate_request
+                    .archive_format()
+                    .map_err(|e| HttpError::new(422, format!("{e:#}")))?,
+                name: generate_request.name,
+                project_path,
+                workspace,
+            }))
+        }
+        (_, "/api/templates" | "/api/validate" | "/api/generate") => {
+            Err(HttpError::new(405, "Method not allowed"))
+        }
+        _ => Err(HttpError::new(404, "Not found")),
+    }
+}
+
+/// 可用的技术栈和选项取值
+fn list_templates() -> Value {
+    let stacks: Vec<Value> = constants::STACKS
+        .iter()
+        .filter_map(|stack| constants::parse_stack(stack).map(|parsed| (stack, parsed)))
+        .map(|(stack, (language, framework))| {
+            json!({
+                "stack": stack,
+                "language": language.as_lowercase(),
+                "framework": (framework != Framework::None).then(|| framework.as_lowercase()),
+                "name": match framework {
+                    Framework::None => language.as_str(),
+                    _ => framework.display_name(),
+                },
+            })
+        })
+        .collect();
+
+    json!({
+        "stacks": stacks,
+        "profiles": constants::PROFILES,
+        "licenses": spdx::available_licenses()
+            .into_iter()
+            .map(|(id, _)| id)
+            .chain([constants::NO_LICENSE.to_string()])
+            .collect::<Vec<_>>(),
+        "databases": constants::DATABASES,
+        "orms": constants::ORMS,
+        "loggers": constants::LOGGERS,
+        "services": constants::GO_ZERO_SERVICES,
+        "nuxt_modules": constants::NUXT_MODULES,
+        "sveltekit_options": constants::SVELTEKIT_OPTIONS,
+        "formats": ARCHIVE_FORMATS,
+    })
+}
+
+fn parse_body(body: &[u8]) -> Result<GenerateRequest, HttpError> {
+    serde_json::from_slice(body)
+        .map_err(|e| HttpError::new(400, format!("Invalid request body: {e}")))
+}
+
+fn create_workspace() -> Result<tempfile::TempDir, HttpError> {
+    tempfile::Builder::new()
+        .prefix("scafgen-serve-")
+        .tempdir()
+        .map_err(|e| HttpError::new(500, format!("Failed to create temporary directory: {e}")))
+}
+
+/// 读取请求行、请求头和按 Content-Length 指定长度的请求体，超过 `REQUEST_TIMEOUT` 未读完时报错
+async fn read_request(stream: &mut TcpStream) -> Result<Request, HttpError> {
+    let deadline = Instant::now() + REQUEST_TIMEOUT;
+    let timed_out = |_| HttpError::new(408, "Timed out reading request");
+    let mut buffer = Vec::new();
+    let head_end = loop {
+        if let Some(index) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break index;
+        }
+        if buffer.len() > MAX_HEAD_BYTES {
+            return Err(HttpError::new(431, "Request headers are too large"));
+        }
+        let mut chunk = [0u8; 4096];
+        let read = timeout_at(deadline, stream.read(&mut chunk))
+            .await
+            .map_err(timed_out)?
+            .map_err(|e| HttpError::new(400, format!("Failed to read request: {e}")))?;
+        if read == 0 {
+            return Err(HttpError::new(400, "Incomplete request"));
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = std::str::from_utf8(&buffer[..head_end])
+        .map_err(|_| HttpError::new(400, "Request headers are not valid UTF-8"))?;
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Err(HttpError::new(400, "Malformed request line"));
+    };
+    let path = target.split('?').next().unwrap_or_default().to_string();
+    let method = method.to_string();
+
+    let mut content_length = 0;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| HttpError::new(400, "Invalid Content-Length header"))?;
+        } else if name.trim().eq_ignore_ascii_case("transfer-encoding") {
+            return Err(HttpError::new(
+                411,
+                "Chunked request bodies are not supported",
+            ));
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Err(HttpError::new(413, "Request body is too large"));
+    }
+
+    let mut body = buffer.split_off(head_end + 4);
+    if body.len() < content_length {
+        let start = body.len();
+        body.resize(content_length, 0);
+        timeout_at(deadline, stream.read_exact(&mut body[start..]))
+            .await
+            .map_err(timed_out)?
+            .map_err(|e| HttpError::new(400, format!("Failed to read request body: {e}")))?;
+    }
+    body.truncate(content_length);
+
+    Ok(Request { method, path, body })
+}
+
+async fn write_json(stream: &mut TcpStream, status: u16, body: &Value) -> Result<()> {
+    let body = serde_json::to_vec(body)?;
+    let head = format!(
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        reason_phrase(status),
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// 在阻塞线程中打包生成结果，打包出的数据按分块编码边生成边写出
+///
+/// 响应头发出后打包失败时直接断开连接，客户端会收到不完整的分块响应
+async fn write_archive(stream: &mut TcpStream, project: GeneratedProject) -> Result<()> {
+    let GeneratedProject {
+        name,
+        format,
+        project_path,
+        workspace,
+    } = project;
+
+    let (sender, mut receiver) = mpsc::channel::<Vec<u8>>(4);
+    let root_name = name.clone();
+    let packing = tokio::task::spawn_blocking(move || {
+        let result =
+            archive::write_archive(format, &project_path, &root_name, ChunkWriter::new(sender));
+        drop(workspace);
+        result
+    });
+
+    let head = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Disposition: attachment; filename=\"{name}.{}\"\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n",
+        format.content_type(),
+        format.extension()
+    );
+    stream.write_all(head.as_bytes()).await?;
+
+    while let Some(chunk) = receiver.recv().await {
+        stream
+            .write_all(format!("{:x}\r\n", chunk.len()).as_bytes())
+            .await?;
+        stream.write_all(&chunk).await?;
+        stream.write_all(b"\r\n").await?;
+    }
+
+    packing
+        .await
+        .context("Archive task panicked")?
+        .context("Failed to pack generated project")?;
+    stream.write_all(b"0\r\n\r\n").await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+/// 把打包数据按固定大小分块发送给响应任务
+struct ChunkWriter {
+    sender: mpsc::Sender<Vec<u8>>,
+    buffer: Vec<u8>,
+}
+
+impl ChunkWriter {
+    fn new(sender: mpsc::Sender<Vec<u8>>) -> Self {
+        Self {
+            sender,
+            buffer: Vec::with_capacity(CHUNK_BYTES),
+        }
+    }
+
+    fn send_buffer(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_BYTES));
+        // 接收端已关闭说明客户端断开，停止打包
+        self.sender
+            .blocking_send(chunk)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "client disconnected"))
+    }
+}
+
+impl io::Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= CHUNK_BYTES {
+            self.send_buffer()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.send_buffer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_request_to_command() {
+        let workspace = tempfile::tempdir().unwrap();
+        let request: GenerateRequest = serde_json::from_value(json!({
+            "name": "demo",
+            "stack": "go/chi",
+            "format": "zip",
+        }))
+        .unwrap();
+        assert!(request.to_command(workspace.path()).is_ok());
+        assert_eq!(request.archive_format().unwrap(), ArchiveFormat::Zip);
+
+        for invalid in [
+            json!({ "name": "..", "stack": "go/chi" }),
+            json!({ "name": "demo", "stack": "go/tauri" }),
+            json!({ "name": "demo", "stack": "go/chi", "port": 80 }),
+            json!({ "name": "demo", "stack": "go/chi", "format": "rar" }),
+        ] {
+            let request: GenerateRequest = serde_json::from_value(invalid).unwrap();
+            assert!(request.to_command(workspace.path()).is_err());
+        }
+
+        // 拼写错误的字段直接拒绝，避免被静默忽略
+        assert!(
+            serde_json::from_value::<GenerateRequest>(json!({ "name": "demo", "stak": "go/chi" }))
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_without_prompting() {
+        // 请求渲染到临时目录，因此跳过对 go 等工具的环境检查
+        let workspace = tempfile::tempdir().unwrap();
+        let request: GenerateRequest =
+            serde_json::from_value(json!({ "name": "demo", "stack": "go/gin" })).unwrap();
+        let command = request.to_command(workspace.path()).unwrap();
+        assert!(command.validate().await.is_ok());
+
+        // 语言无法确定时报错而不是等待输入
+        let request: GenerateRequest = serde_json::from_value(json!({ "name": "demo" })).unwrap();
+        let command = request.to_command(workspace.path()).unwrap();
+        let error = command.validate().await.unwrap_err();
+        assert!(error.to_string().contains("language"));
+    }
+
+    #[test]
+    fn test_list_templates() {
+        let templates = list_templates();
+        let stacks = templates["stacks"].as_array().unwrap();
+        assert_eq!(stacks.len(), constants::STACKS.len());
+        assert!(stacks.iter().any(|stack| stack["stack"] == "rust/axum"
+            && stack["language"] == "rust"
+            && stack["framework"] == "axum"));
+        assert!(
+            stacks
+                .iter()
+                .any(|stack| stack["stack"] == "python" && stack["framework"].is_null())
+        );
+    }
+}
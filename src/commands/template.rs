@@ -0,0 +1,1173 @@
+use anyhow::{Context, Result};
+use colored::*;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::constants::{
+    ComponentKind, Framework, K8sFormat, Language, Runtime, TaskRunner, string_utils,
+};
+use crate::generators::component::ComponentParams;
+use crate::generators::core::{
+    BaseParams, Parameters, RenameRules, TemplateProcessor, feature_dirs, resolve_output_path,
+};
+use crate::generators::docker::{DockerParams, DockerTarget};
+use crate::generators::framework::actix::ActixParams;
+use crate::generators::framework::astro::AstroParams;
+use crate::generators::framework::axum::AxumParams;
+use crate::generators::framework::chi::ChiParams;
+use crate::generators::framework::cli::CliParams;
+use crate::generators::framework::django::DjangoParams;
+use crate::generators::framework::fiber::FiberParams;
+use crate::generators::framework::flask::FlaskParams;
+use crate::generators::framework::gin::GinParams;
+use crate::generators::framework::go_zero::parameters::GoZeroParams;
+use crate::generators::framework::nestjs::NestJsParams;
+use crate::generators::framework::nextjs::NextJsParams;
+use crate::generators::framework::nuxt::NuxtParams;
+use crate::generators::framework::react::ReactParams;
+use crate::generators::framework::sveltekit::SvelteKitParams;
+use crate::generators::framework::tauri::TauriParams;
+use crate::generators::framework::vue3::Vue3Params;
+use crate::generators::k8s::K8sParams;
+use crate::generators::language::go::GoParams;
+use crate::generators::language::python::PythonParams;
+use crate::generators::language::rust::RustParams;
+use crate::generators::language::zig::ZigParams;
+use crate::generators::project::DotfileParams;
+use crate::generators::project::ProjectParams;
+use crate::generators::project::ReadmeParams;
+use crate::generators::project::TaskParams;
+use crate::generators::workspace::{ApiProxy, WorkspaceParams};
+use crate::template_engine::{
+    self, PARTIALS_DIR, TEMPLATE_MANIFEST, TemplateEngine, TemplateUsage,
+};
+
+/// 一组共享同一生成器参数的嵌入式模板
+struct TemplateSet {
+    template_path: &'static str,
+    /// 参数结构体名称，如 `GinParams`
+    params_name: &'static str,
+    context: HashMap<String, Value>,
+}
+
+impl TemplateSet {
+    fn new<P: Parameters>(template_path: &'static str, params: P) -> Self {
+        let type_name = std::any::type_name::<P>();
+        Self {
+            template_path,
+            params_name: type_name.rsplit("::").next().unwrap_or(type_name),
+            context: params.to_template_context(),
+        }
+    }
+
+    /// 模板文件是否属于该模板组
+    fn contains(&self, template_file: &str) -> bool {
+        template_file
+            .strip_prefix(self.template_path)
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+}
+
+/// 各生成器的模板目录及其默认参数
+fn template_sets() -> Vec<TemplateSet> {
+    vec![
+        TemplateSet::new("languages/go", GoParams::default()),
+        TemplateSet::new("languages/python", PythonParams::default()),
+        TemplateSet::new("languages/rust", RustParams::default()),
+        TemplateSet::new("languages/zig", ZigParams::default()),
+        TemplateSet::new("frameworks/go/gin", GinParams::default()),
+        TemplateSet::new("frameworks/go/fiber", FiberParams::default()),
+        TemplateSet::new("frameworks/go/chi", ChiParams::default()),
+        TemplateSet::new("frameworks/go/go-zero", GoZeroParams::default()),
+        // Python 包目录名由项目名推导，生成时总是非空
+        TemplateSet::new(
+            "frameworks/python/django",
+            DjangoParams::default().with_python(PythonParams::new("example".to_string())),
+        ),
+        TemplateSet::new(
+            "frameworks/python/flask",
+            FlaskParams::default().with_python(PythonParams::new("example".to_string())),
+        ),
+        TemplateSet::new("frameworks/rust/tauri", TauriParams::default()),
+        TemplateSet::new("frameworks/rust/axum", AxumParams::default()),
+        TemplateSet::new("frameworks/rust/actix", ActixParams::default()),
+        TemplateSet::new("frameworks/rust/cli", CliParams::default()),
+        TemplateSet::new("frameworks/typescript/react", ReactParams::default()),
+        TemplateSet::new("frameworks/typescript/vue3", Vue3Params::default()),
+        TemplateSet::new("frameworks/typescript/nextjs", NextJsParams::default()),
+        TemplateSet::new("frameworks/typescript/nuxt", NuxtParams::default()),
+        TemplateSet::new(
+            "frameworks/typescript/sveltekit",
+            SvelteKitParams::default(),
+        ),
+        TemplateSet::new("frameworks/typescript/nestjs", NestJsParams::default()),
+        TemplateSet::new("frameworks/typescript/astro", AstroParams::default()),
+        TemplateSet::new(
+            "docker/go",
+            DockerParams::new(
+                "example".to_string(),
+                DockerTarget::Go,
+                Framework::Gin,
+                8080,
+            ),
+        ),
+        TemplateSet::new(
+            "docker/python",
+            DockerParams::new(
+                "example".to_string(),
+                DockerTarget::Python,
+                Framework::Django,
+                8000,
+            ),
+        ),
+        TemplateSet::new(
+            "docker/node",
+            DockerParams::new(
+                "example".to_string(),
+                DockerTarget::NodeServer,
+                Framework::NextJs,
+                3000,
+            ),
+        ),
+        TemplateSet::new(
+            "docker/static",
+            DockerParams::new(
+                "example".to_string(),
+                DockerTarget::StaticSite,
+                Framework::React,
+                5173,
+            ),
+        ),
+        TemplateSet::new(
+            "docker/rust",
+            DockerParams::new(
+                "example".to_string(),
+                DockerTarget::Rust,
+                Framework::Axum,
+                3000,
+            ),
+        ),
+        TemplateSet::new(
+            "dotfiles",
+            DotfileParams::new(
+                "example".to_string(),
+                Some(Language::Rust),
+                Framework::Tauri,
+            ),
+        ),
+        TemplateSet::new(
+            "readme",
+            ReadmeParams::new(
+                BaseParams {
+                    project_name: "example".to_string(),
+                    ..Default::default()
+                },
+                Some(Language::TypeScript),
+                Framework::Vue3,
+                Runtime::Node,
+            ),
+        ),
+        TemplateSet::new(
+            "tasks",
+            TaskParams::new(
+                "example".to_string(),
+                TaskRunner::Make,
+                Language::Go,
+                Framework::Gin,
+            ),
+        ),
+        TemplateSet::new(
+            "k8s/manifests",
+            K8sParams::for_stack(
+                "example".to_string(),
+                K8sFormat::Manifests,
+                Framework::GoZero,
+                "0.0.0.0",
+                8888,
+            )
+            .expect("Go-Zero projects have Kubernetes files")
+            .with_go_zero_services(&["api".to_string(), "rpc".to_string()], 9090),
+        ),
+        TemplateSet::new(
+            "k8s/helm",
+            K8sParams::for_stack(
+                "example".to_string(),
+                K8sFormat::Helm,
+                Framework::Django,
+                "0.0.0.0",
+                8000,
+            )
+            .expect("Django projects have Kubernetes files"),
+        ),
+        TemplateSet::new(
+            "workspace",
+            WorkspaceParams::default()
+                .with_api_proxy(Some(ApiProxy::for_backend(Framework::Gin, 8080))),
+        ),
+        TemplateSet::new("components/go/gin", ComponentParams::default()),
+        // 测试和 story 只在项目安装了对应依赖时生成，检查时全部渲染
+        TemplateSet::new(
+            "components/typescript/react",
+            ComponentParams::new(Framework::React, ComponentKind::Component, "Button".into())
+                .with_tests(true)
+                .with_storybook(Some("@storybook/react-vite".to_string())),
+        ),
+        TemplateSet::new(
+            "components/typescript/vue3",
+            ComponentParams::new(Framework::Vue3, ComponentKind::Component, "Button".into())
+                .with_tests(true)
+                .with_storybook(Some("@storybook/vue3-vite".to_string())),
+        ),
+        // 许可证生成时总会补齐作者信息
+        TemplateSet::new(
+            "licenses",
+            ProjectParams::default().with_author("Unknown".to_string()),
+        ),
+    ]
+}
+
+/// 基础参数对所有模板开放，不要求每个生成器都使用；作者等可选字段也计入其中
+fn shared_variables() -> HashMap<String, Value> {
+    BaseParams::default()
+        .with_author("Unknown".to_string())
+        .with_author_email("unknown@example.com".to_string())
+        .to_template_context()
+}
+
+/// `scafgen template check`：检查所有嵌入式模板
+///
+/// 报告解析错误、未注册的辅助函数、使用默认参数渲染时的错误（错误），
+/// 以及生成器特有参数中未被任何模板引用的变量（警告）
+pub struct TemplateCheckCommand {
+    engine: TemplateEngine,
+    errors: usize,
+    warnings: usize,
+}
+
+impl TemplateCheckCommand {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            engine: TemplateEngine::new(PathBuf::new())?,
+            errors: 0,
+            warnings: 0,
+        })
+    }
+
+    pub fn execute(mut self) -> Result<()> {
+        println!("{}", "Checking embedded templates...".cyan().bold());
+
+        let partial_usage = self.check_partials()?;
+        let shared_variables = shared_variables();
+
+        let mut checked = BTreeSet::new();
+        for set in template_sets() {
+            let files = template_engine::get_embedded_template_files(set.template_path)?;
+            let templates: Vec<String> = files
+                .into_iter()
+                .filter(|file| file.ends_with(".tmpl"))
+                .collect();
+            println!("{} ({} templates)", set.template_path, templates.len());
+            if let Err(error) = RenameRules::for_template_set(set.template_path) {
+                self.error(&format!("{error:#}"));
+            }
+
+            let mut used_variables = BTreeSet::new();
+            for template_file in &templates {
+                let usage = self.check_template(template_file, Some(&set.context));
+                used_variables.extend(usage.variables);
+                // `+<功能>` 目录由 `enable_<功能>` 开关控制，同样算作使用了该变量
+                used_variables.extend(
+                    feature_dirs(template_file)
+                        .map(|feature| format!("enable_{}", feature.replace('-', "_"))),
+                );
+                for partial in &usage.partials {
+                    if let Some(partial_usage) = partial_usage.get(partial) {
+                        used_variables.extend(partial_usage.variables.iter().cloned());
+                    }
+                }
+                checked.insert(template_file.clone());
+            }
+
+            let mut unused: Vec<&str> = set
+                .context
+                .keys()
+                .filter(|key| {
+                    !used_variables.contains(*key) && !shared_variables.contains_key(*key)
+                })
+                .map(String::as_str)
+                .collect();
+            if !unused.is_empty() {
+                unused.sort_unstable();
+                self.warn(&format!("unused variables: {}", unused.join(", ")));
+            }
+        }
+
+        // 不属于任何生成器的模板只做静态检查
+        let orphans: Vec<String> = template_engine::get_embedded_template_files("")?
+            .into_iter()
+            .filter(|file| file.ends_with(".tmpl") && !checked.contains(file))
+            .collect();
+        if !orphans.is_empty() {
+            println!("other templates ({}, not rendered)", orphans.len());
+            for template_file in &orphans {
+                self.check_template(template_file, None);
+            }
+        }
+
+        let total = checked.len() + orphans.len() + partial_usage.len();
+        println!(
+            "\nChecked {total} templates: {} error(s), {} warning(s)",
+            self.errors, self.warnings
+        );
+
+        if self.errors > 0 {
+            return Err(anyhow::anyhow!(
+                "Template check failed with {} error(s)",
+                self.errors
+            ));
+        }
+        println!("{}", "✅ All templates passed".green());
+        Ok(())
+    }
+
+    /// 检查共享片段，返回片段名到引用信息的映射
+    fn check_partials(&mut self) -> Result<HashMap<String, TemplateUsage>> {
+        let prefix = format!("{PARTIALS_DIR}/");
+        let files = template_engine::get_embedded_template_files(PARTIALS_DIR)?;
+        println!("{PARTIALS_DIR} ({} partials)", files.len());
+
+        let mut partials = HashMap::new();
+        for partial_file in files {
+            let usage = self.check_template(&partial_file, None);
+            let name = partial_file.strip_prefix(&prefix).unwrap_or(&partial_file);
+            let name = name.strip_suffix(".hbs").unwrap_or(name);
+            partials.insert(name.to_string(), usage);
+        }
+        merge_nested_partials(&mut partials);
+        Ok(partials)
+    }
+
+    /// 检查单个模板：解析、辅助函数、片段引用，以及可选的渲染
+    fn check_template(
+        &mut self,
+        template_file: &str,
+        context: Option<&HashMap<String, Value>>,
+    ) -> TemplateUsage {
+        let content = match template_engine::read_embedded_template(template_file) {
+            Ok(content) => content,
+            Err(error) => {
+                self.error(&error.to_string());
+                return TemplateUsage::default();
+            }
+        };
+
+        let mut usage = match self.engine.analyze_template(template_file, &content) {
+            Ok(usage) => usage,
+            Err(error) => {
+                self.error(&error.to_string());
+                return TemplateUsage::default();
+            }
+        };
+
+        for helper in &usage.helpers {
+            if !self.engine.is_helper_registered(helper) {
+                self.error(&format!("{template_file}: unknown helper '{helper}'"));
+            }
+        }
+        for partial in &usage.partials {
+            if !self.engine.handlebars.has_template(partial) {
+                self.error(&format!("{template_file}: unknown partial '{partial}'"));
+            }
+        }
+
+        if let Some(context) = context
+            && let Err(error) = self.render(template_file, &content, context, &mut usage)
+        {
+            self.error(&error.to_string());
+        }
+        usage
+    }
+
+    /// 使用默认参数渲染模板内容和输出路径，并记录路径中引用的变量
+    fn render(
+        &mut self,
+        template_file: &str,
+        content: &str,
+        context: &HashMap<String, Value>,
+        usage: &mut TemplateUsage,
+    ) -> Result<()> {
+        if template_file.contains("{{") {
+            let path_usage = self.engine.analyze_template(template_file, template_file)?;
+            usage.variables.extend(path_usage.variables);
+        }
+        // 输出路径不能跳出项目目录
+        let output_path = self.engine.render_path(template_file, context)?;
+        resolve_output_path(Path::new("."), &output_path)?;
+        self.engine
+            .render_named_template(template_file, content, context)?;
+        Ok(())
+    }
+
+    fn error(&mut self, message: &str) {
+        self.errors += 1;
+        // 渲染错误附带的可用变量列表在汇总输出中过长，只保留首行
+        let message = message.lines().next().unwrap_or(message);
+        println!("  {} {message}", "✗".red());
+    }
+
+    fn warn(&mut self, message: &str) {
+        self.warnings += 1;
+        println!("  {} {message}", "⚠".yellow());
+    }
+}
+
+/// `scafgen template vars <template-path>`：静态列出模板引用的变量、辅助函数和片段
+///
+/// 不渲染模板，未执行的分支同样会被统计；并与对应生成器的参数结构体交叉检查，
+/// 模板引用但参数未提供的变量为错误，参数提供但模板未引用的变量为警告
+pub struct TemplateVarsCommand {
+    engine: TemplateEngine,
+    template_path: String,
+}
+
+impl TemplateVarsCommand {
+    pub fn new(template_path: String) -> Result<Self> {
+        // 允许传入仓库中的路径，如 templates/frameworks/go/gin/
+        let template_path = template_path.trim_end_matches('/');
+        let template_path = template_path
+            .strip_prefix("templates/")
+            .unwrap_or(template_path)
+            .to_string();
+
+        Ok(Self {
+            engine: TemplateEngine::new(PathBuf::new())?,
+            template_path,
+        })
+    }
+
+    pub fn execute(self) -> Result<()> {
+        let files = self.template_files()?;
+        if files.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No embedded templates found under: {}",
+                self.template_path
+            ));
+        }
+
+        let partial_usage = self.partial_usage()?;
+        let shared_variables = shared_variables();
+        let mut undefined_total = 0;
+
+        // 按生成器参数分组输出，不属于任何生成器的模板单独列出
+        let sets = template_sets();
+        let mut groups: Vec<(Option<&TemplateSet>, Vec<&String>)> = sets
+            .iter()
+            .map(|set| {
+                let files = files.iter().filter(|file| set.contains(file)).collect();
+                (Some(set), files)
+            })
+            .collect();
+        let others = files
+            .iter()
+            .filter(|file| !sets.iter().any(|set| set.contains(file)))
+            .collect();
+        groups.push((None, others));
+
+        for (set, files) in groups {
+            if files.is_empty() {
+                continue;
+            }
+
+            match set {
+                Some(set) => println!(
+                    "{} ({} templates, parameters: {})",
+                    set.template_path.cyan().bold(),
+                    files.len(),
+                    set.params_name
+                ),
+                None => println!(
+                    "{} ({} templates)",
+                    "other templates".cyan().bold(),
+                    files.len()
+                ),
+            }
+
+            let mut group_usage = TemplateUsage::default();
+            for template_file in files {
+                let usage = self.analyze(template_file, &partial_usage)?;
+                let name = set
+                    .and_then(|set| template_file.strip_prefix(set.template_path))
+                    .map_or(template_file.as_str(), |name| name.trim_start_matches('/'));
+                println!("  {name}");
+                print_names("variables", &usage.variables);
+                print_names("helpers", &usage.helpers);
+                print_names("partials", &usage.partials);
+
+                group_usage.variables.extend(usage.variables);
+                group_usage.conditions.extend(usage.conditions);
+                group_usage.helpers.extend(usage.helpers);
+                group_usage.partials.extend(usage.partials);
+            }
+
+            let Some(set) = set else {
+                println!();
+                continue;
+            };
+
+            // 仅在条件中判断的变量（如 homepage）可以缺省，不算错误
+            let (optional, undefined): (Vec<&str>, Vec<&str>) = group_usage
+                .variables
+                .iter()
+                .filter(|variable| !set.context.contains_key(*variable))
+                .map(String::as_str)
+                .partition(|variable| group_usage.conditions.contains(*variable));
+            // 只有完整检查整个模板组时，未引用的参数才有意义
+            let mut unused: Vec<&str> = if self.template_path == set.template_path {
+                set.context
+                    .keys()
+                    .filter(|key| {
+                        !group_usage.variables.contains(*key)
+                            && !shared_variables.contains_key(*key)
+                    })
+                    .map(String::as_str)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            unused.sort_unstable();
+
+            if !undefined.is_empty() {
+                undefined_total += undefined.len();
+                println!(
+                    "  {} not provided by {}: {}",
+                    "✗".red(),
+                    set.params_name,
+                    undefined.join(", ")
+                );
+            }
+            if !optional.is_empty() {
+                println!(
+                    "  {} optional, not set by default: {}",
+                    "•".dimmed(),
+                    optional.join(", ")
+                );
+            }
+            if !unused.is_empty() {
+                println!(
+                    "  {} not referenced by any template: {}",
+                    "⚠".yellow(),
+                    unused.join(", ")
+                );
+            }
+            if undefined.is_empty() && unused.is_empty() {
+                println!("  {} in sync with {}", "✓".green(), set.params_name);
+            }
+            println!();
+        }
+
+        if undefined_total > 0 {
+            return Err(anyhow::anyhow!(
+                "{undefined_total} variable(s) referenced by templates are not provided by their parameters"
+            ));
+        }
+        Ok(())
+    }
+
+    /// 指定路径下的模板文件：单个文件或目录下所有 `.tmpl` 文件和片段
+    fn template_files(&self) -> Result<Vec<String>> {
+        if template_engine::embedded_template_exists(&self.template_path) {
+            return Ok(vec![self.template_path.clone()]);
+        }
+        if !template_engine::embedded_template_dir_exists(&self.template_path) {
+            return Ok(Vec::new());
+        }
+
+        let mut files: Vec<String> =
+            template_engine::get_embedded_template_files(&self.template_path)?
+                .into_iter()
+                .filter(|file| file.ends_with(".tmpl") || file.ends_with(".hbs"))
+                .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    /// 解析模板内容和路径，引用的片段中的变量一并计入
+    fn analyze(
+        &self,
+        template_file: &str,
+        partial_usage: &HashMap<String, TemplateUsage>,
+    ) -> Result<TemplateUsage> {
+        let content = template_engine::read_embedded_template(template_file)?;
+        let mut usage = self.engine.analyze_template(template_file, &content)?;
+        if template_file.contains("{{") {
+            let path_usage = self.engine.analyze_template(template_file, template_file)?;
+            usage.variables.extend(path_usage.variables);
+            usage.helpers.extend(path_usage.helpers);
+        }
+        for partial in &usage.partials {
+            if let Some(partial_usage) = partial_usage.get(partial) {
+                usage
+                    .variables
+                    .extend(partial_usage.variables.iter().cloned());
+            }
+        }
+        Ok(usage)
+    }
+
+    /// 共享片段名到引用信息的映射
+    fn partial_usage(&self) -> Result<HashMap<String, TemplateUsage>> {
+        let prefix = format!("{PARTIALS_DIR}/");
+        let mut partials = HashMap::new();
+        for partial_file in template_engine::get_embedded_template_files(PARTIALS_DIR)? {
+            let content = template_engine::read_embedded_template(&partial_file)?;
+            let usage = self.engine.analyze_template(&partial_file, &content)?;
+            let name = partial_file.strip_prefix(&prefix).unwrap_or(&partial_file);
+            let name = name.strip_suffix(".hbs").unwrap_or(name);
+            partials.insert(name.to_string(), usage);
+        }
+        merge_nested_partials(&mut partials);
+        Ok(partials)
+    }
+}
+
+fn print_names(label: &str, names: &BTreeSet<String>) {
+    if !names.is_empty() {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        println!("    {label:<10} {}", names.join(", "));
+    }
+}
+
+/// `scafgen template check --bench-render <N>`（隐藏）：按默认参数将每个模板组完整渲染 N 次并报告耗时
+///
+/// 每轮都重新创建模板处理器并写入临时目录，与生成项目时的渲染流程一致；
+/// 指定 `--bench-budget` 时任一模板组的中位耗时超出预算即返回错误
+pub struct TemplateBenchCommand {
+    iterations: usize,
+    budget: Option<Duration>,
+}
+
+/// 单个模板组的渲染耗时
+struct BenchResult {
+    template_path: &'static str,
+    files: usize,
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl TemplateBenchCommand {
+    pub fn new(iterations: usize, budget_ms: Option<u64>) -> Self {
+        Self {
+            iterations,
+            budget: budget_ms.map(Duration::from_millis),
+        }
+    }
+
+    pub fn execute(self) -> Result<()> {
+        if self.iterations == 0 {
+            return Err(anyhow::anyhow!(
+                "--bench-render requires at least 1 iteration"
+            ));
+        }
+
+        println!(
+            "{}",
+            format!(
+                "Benchmarking template rendering ({} iterations)...",
+                self.iterations
+            )
+            .cyan()
+            .bold()
+        );
+
+        let output = tempfile::tempdir()?;
+        let mut results = Vec::new();
+        for set in template_sets() {
+            results.push(self.bench_set(&set, output.path())?);
+        }
+
+        println!(
+            "\n{:<32} {:>6} {:>10} {:>10} {:>10}",
+            "template set", "files", "min", "median", "max"
+        );
+        for result in &results {
+            println!(
+                "{:<32} {:>6} {:>10} {:>10} {:>10}",
+                result.template_path,
+                result.files,
+                format_duration(result.min),
+                format_duration(result.median),
+                format_duration(result.max)
+            );
+        }
+        let total: Duration = results.iter().map(|result| result.median).sum();
+        println!("{:<32} {:>6} {:>10}", "total", "", format_duration(total));
+
+        let Some(budget) = self.budget else {
+            return Ok(());
+        };
+        let over_budget: Vec<String> = results
+            .iter()
+            .filter(|result| result.median > budget)
+            .map(|result| {
+                format!(
+                    "{} ({})",
+                    result.template_path,
+                    format_duration(result.median)
+                )
+            })
+            .collect();
+        if !over_budget.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Rendering exceeded the {} budget: {}",
+                format_duration(budget),
+                over_budget.join(", ")
+            ));
+        }
+        println!(
+            "{}",
+            format!("✅ All template sets within {}", format_duration(budget)).green()
+        );
+        Ok(())
+    }
+
+    fn bench_set(&self, set: &TemplateSet, output_root: &Path) -> Result<BenchResult> {
+        let output_path = output_root.join(set.template_path);
+        let files = template_engine::get_embedded_template_files(set.template_path)?.len();
+
+        let mut timings = Vec::with_capacity(self.iterations);
+        for _ in 0..self.iterations {
+            let start = Instant::now();
+            let processor = TemplateProcessor::new()?;
+            processor.process_embedded_template_directory(
+                set.template_path,
+                &output_path,
+                set.context.clone(),
+            )?;
+            timings.push(start.elapsed());
+        }
+        timings.sort_unstable();
+
+        Ok(BenchResult {
+            template_path: set.template_path,
+            files,
+            min: timings[0],
+            median: timings[timings.len() / 2],
+            max: timings[timings.len() - 1],
+        })
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// `scafgen template new`：在模板目录中生成新模板组的骨架，方便贡献新框架
+///
+/// 生成模板组清单、共享片段、示例 `.tmpl` 文件，以及用默认参数渲染示例得到的黄金测试数据
+pub struct TemplateNewCommand {
+    name: String,
+    language: Language,
+    templates_dir: PathBuf,
+}
+
+/// 骨架中的一个文件：相对路径和内容
+struct SkeletonFile {
+    path: String,
+    content: String,
+}
+
+impl TemplateNewCommand {
+    pub fn new(name: String, language: &str, templates_dir: PathBuf) -> Result<Self> {
+        let valid_name = name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && name.starts_with(|c: char| c.is_ascii_lowercase());
+        if !valid_name {
+            return Err(anyhow::anyhow!(
+                "Invalid template name '{name}': use lowercase letters, digits and '-', starting with a letter"
+            ));
+        }
+        let language = Language::parse_from_str(language).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unsupported language: {language}. Supported languages: {}",
+                Language::supported()
+            )
+        })?;
+
+        Ok(Self {
+            name,
+            language,
+            templates_dir,
+        })
+    }
+
+    /// 模板组相对于模板目录的路径，如 `frameworks/go/echo`
+    fn template_path(&self) -> String {
+        format!("frameworks/{}/{}", self.language.as_lowercase(), self.name)
+    }
+
+    /// 示例片段的名称，如 `go/echo/header`
+    fn partial_name(&self) -> String {
+        format!("{}/{}/header", self.language.as_lowercase(), self.name)
+    }
+
+    /// 黄金测试数据目录，与模板目录同级的 `tests/golden/<name>`
+    fn golden_dir(&self) -> PathBuf {
+        let root = self.templates_dir.parent().unwrap_or(Path::new(""));
+        root.join("tests").join("golden").join(&self.name)
+    }
+
+    pub fn execute(self) -> Result<()> {
+        let template_dir = self.templates_dir.join(self.template_path());
+        let partial_file = self
+            .templates_dir
+            .join(PARTIALS_DIR)
+            .join(format!("{}.hbs", self.partial_name()));
+        let golden_dir = self.golden_dir();
+        for path in [&template_dir, &partial_file, &golden_dir] {
+            if path.exists() {
+                return Err(anyhow::anyhow!("{} already exists", path.display()));
+            }
+        }
+
+        let partial = self.partial_content();
+        let templates = self.template_files();
+        let golden = self.render_golden(&partial, &templates)?;
+
+        write_file(&partial_file, &partial)?;
+        write_file(
+            &template_dir.join(TEMPLATE_MANIFEST),
+            &self.manifest_content()?,
+        )?;
+        for file in &templates {
+            write_file(
+                &resolve_output_path(&template_dir, &file.path)?,
+                &file.content,
+            )?;
+        }
+        for file in &golden {
+            write_file(
+                &resolve_output_path(&golden_dir, &file.path)?,
+                &file.content,
+            )?;
+        }
+
+        println!(
+            "{} {}",
+            "✅ Created template set".green().bold(),
+            template_dir.display()
+        );
+        println!("   partial: {}", partial_file.display());
+        println!("   golden:  {}", golden_dir.display());
+        println!();
+        println!("Next steps:");
+        println!(
+            "  1. Add the {}Params struct and generator under src/generators/framework/{}",
+            string_utils::to_pascal_case(&self.name),
+            string_utils::to_snake_case(&self.name)
+        );
+        println!(
+            "  2. Register the set in template_sets() (src/commands/template.rs) and run `scafgen template check`"
+        );
+        println!(
+            "  3. Regenerate the golden files with `--render-to` and compare them with `diff -r`"
+        );
+        Ok(())
+    }
+
+    /// 模板组清单：名称、语言、参数结构体、用到的片段和黄金测试数据位置
+    fn manifest_content(&self) -> Result<String> {
+        let manifest = serde_json::json!({
+            "name": self.name,
+            "language": self.language.as_lowercase(),
+            "description": format!("{} project template", self.name),
+            "template_path": self.template_path(),
+            "params": format!("{}Params", string_utils::to_pascal_case(&self.name)),
+            "partials": [self.partial_name()],
+            "golden": format!("tests/golden/{}", self.name),
+        });
+        Ok(serde_json::to_string_pretty(&manifest)? + "\n")
+    }
+
+    /// 示例片段：带项目名称和描述的文件头注释
+    fn partial_content(&self) -> String {
+        let comment = match self.language {
+            Language::Python => "#",
+            Language::Go | Language::Rust | Language::TypeScript | Language::Zig => "//",
+        };
+        format!("{comment} {{{{project_name}}}}: {{{{project_description}}}}\n")
+    }
+
+    /// 示例模板：README 和对应语言的入口文件
+    fn template_files(&self) -> Vec<SkeletonFile> {
+        let header = format!("{{{{> {}}}}}", self.partial_name());
+        let (path, body) = match self.language {
+            Language::Go => (
+                "main.go.tmpl",
+                "package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(\"Hello from {{project_name}}\")\n}\n",
+            ),
+            Language::Python => (
+                "main.py.tmpl",
+                "def main() -> None:\n    print(\"Hello from {{project_name}}\")\n\n\nif __name__ == \"__main__\":\n    main()\n",
+            ),
+            Language::Rust => (
+                "src/main.rs.tmpl",
+                "fn main() {\n    println!(\"Hello from {{project_name}}\");\n}\n",
+            ),
+            Language::TypeScript => (
+                "src/index.ts.tmpl",
+                "console.log(\"Hello from {{project_name}}\");\n",
+            ),
+            Language::Zig => (
+                "src/main.zig.tmpl",
+                "const std = @import(\"std\");\n\npub fn main() void {\n    std.debug.print(\"Hello from {{project_name}}\\n\", .{});\n}\n",
+            ),
+        };
+
+        vec![
+            SkeletonFile {
+                path: "README.md.tmpl".to_string(),
+                content: format!(
+                    "# {{{{project_name}}}}\n\n{{{{project_description}}}}\n\nGenerated from the `{}` template.\n",
+                    self.name
+                ),
+            },
+            SkeletonFile {
+                path: path.to_string(),
+                content: format!("{header}{body}"),
+            },
+        ]
+    }
+
+    /// 用默认参数（项目名 `demo`）渲染示例模板，作为黄金测试数据
+    fn render_golden(
+        &self,
+        partial: &str,
+        templates: &[SkeletonFile],
+    ) -> Result<Vec<SkeletonFile>> {
+        let mut engine = TemplateEngine::new(PathBuf::new())?;
+        engine.register_partial(&self.partial_name(), partial)?;
+        let context = BaseParams::new("demo".to_string()).to_template_context();
+
+        templates
+            .iter()
+            .map(|file| {
+                let name = format!("{}/{}", self.template_path(), file.path);
+                Ok(SkeletonFile {
+                    path: file
+                        .path
+                        .strip_suffix(".tmpl")
+                        .unwrap_or(&file.path)
+                        .to_string(),
+                    content: engine.render_named_template(&name, &file.content, &context)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// `scafgen template export`：把嵌入式模板组原样复制到本地目录，修改后通过 `scafgen new --template-dir` 使用
+pub struct TemplateExportCommand {
+    family: String,
+    templates_dir: PathBuf,
+}
+
+impl TemplateExportCommand {
+    pub fn new(family: String, templates_dir: PathBuf) -> Result<Self> {
+        // 与 `template vars` 一样允许传入仓库中的路径，如 templates/frameworks/go/gin/
+        let family = family.trim_end_matches('/');
+        let family = family
+            .strip_prefix("templates/")
+            .unwrap_or(family)
+            .to_string();
+        if family.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Specify a template set to export, e.g. frameworks/go/gin"
+            ));
+        }
+
+        Ok(Self {
+            family,
+            templates_dir,
+        })
+    }
+
+    pub fn execute(self) -> Result<()> {
+        let files = template_engine::list_embedded_files(&self.family)?;
+        if files.is_empty() {
+            let families: Vec<&str> = template_sets()
+                .iter()
+                .map(|set| set.template_path)
+                .chain([PARTIALS_DIR])
+                .collect();
+            return Err(anyhow::anyhow!(
+                "No embedded template set found at '{}'. Available sets: {}",
+                self.family,
+                families.join(", ")
+            ));
+        }
+
+        let export_dir = self.templates_dir.join(&self.family);
+        if export_dir.exists() {
+            return Err(anyhow::anyhow!("{} already exists", export_dir.display()));
+        }
+
+        for file in &files {
+            let contents = template_engine::get_embedded_template_bytes(file)
+                .ok_or_else(|| anyhow::anyhow!("Template content not found: {file}"))?;
+            write_file(&resolve_output_path(&self.templates_dir, file)?, contents)?;
+        }
+
+        println!(
+            "{} {} ({} files)",
+            "✅ Exported template set to".green().bold(),
+            export_dir.display(),
+            files.len()
+        );
+        println!();
+        println!("Next steps:");
+        println!("  1. Edit the templates under {}", export_dir.display());
+        println!(
+            "  2. Generate a project with them: scafgen new my-app --template-dir {}",
+            self.templates_dir.display()
+        );
+        if self.family != PARTIALS_DIR {
+            println!(
+                "Shared partials stay embedded; run `scafgen template export {PARTIALS_DIR} {}` to customize them too.",
+                self.templates_dir.display()
+            );
+        }
+        Ok(())
+    }
+}
+
+fn write_file(path: &Path, content: impl AsRef<[u8]>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// 把片段引用的其他片段中的变量并入该片段，支持多层嵌套
+fn merge_nested_partials(partials: &mut HashMap<String, TemplateUsage>) {
+    loop {
+        let mut changed = false;
+        let names: Vec<String> = partials.keys().cloned().collect();
+        for name in names {
+            let nested: BTreeSet<String> = partials[&name]
+                .partials
+                .iter()
+                .filter_map(|partial| partials.get(partial))
+                .flat_map(|usage| usage.variables.iter().cloned())
+                .collect();
+            let usage = partials.get_mut(&name).expect("partial exists");
+            let before = usage.variables.len();
+            usage.variables.extend(nested);
+            changed |= usage.variables.len() != before;
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_nested_partials() {
+        let usage = |variables: &[&str], partials: &[&str]| TemplateUsage {
+            variables: variables.iter().map(|v| v.to_string()).collect(),
+            partials: partials.iter().map(|p| p.to_string()).collect(),
+            ..Default::default()
+        };
+        let mut partials = HashMap::from([
+            (
+                "ci/nextjs".to_string(),
+                usage(&["enable_eslint"], &["ci/js-setup"]),
+            ),
+            (
+                "ci/js-setup".to_string(),
+                usage(&["runtime"], &["ci/header"]),
+            ),
+            ("ci/header".to_string(), usage(&["ci_provider"], &[])),
+        ]);
+        merge_nested_partials(&mut partials);
+        let variables = &partials["ci/nextjs"].variables;
+        assert!(
+            ["enable_eslint", "runtime", "ci_provider"]
+                .iter()
+                .all(|variable| variables.contains(*variable))
+        );
+    }
+
+    #[test]
+    fn test_template_new_skeleton() {
+        let root = tempfile::tempdir().unwrap();
+        let templates_dir = root.path().join("templates");
+        let command =
+            TemplateNewCommand::new("echo".to_string(), "go", templates_dir.clone()).unwrap();
+        command.execute().unwrap();
+
+        let set_dir = templates_dir.join("frameworks/go/echo");
+        let manifest: Value = serde_json::from_str(
+            &std::fs::read_to_string(set_dir.join(TEMPLATE_MANIFEST)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(manifest["params"], "EchoParams");
+        assert!(set_dir.join("main.go.tmpl").is_file());
+        assert!(templates_dir.join("_partials/go/echo/header.hbs").is_file());
+
+        let golden =
+            std::fs::read_to_string(root.path().join("tests/golden/echo/main.go")).unwrap();
+        assert!(golden.starts_with("// demo: A demo project generated by Scaffold-Gen\n"));
+        assert!(golden.contains("Hello from demo"));
+
+        // 已存在时拒绝覆盖
+        let command =
+            TemplateNewCommand::new("echo".to_string(), "go", templates_dir.clone()).unwrap();
+        assert!(command.execute().is_err());
+
+        assert!(TemplateNewCommand::new("Echo".to_string(), "go", templates_dir.clone()).is_err());
+        assert!(TemplateNewCommand::new("echo".to_string(), "zig", templates_dir.clone()).is_ok());
+        let error = TemplateNewCommand::new("echo".to_string(), "java", templates_dir)
+            .err()
+            .unwrap();
+        assert!(
+            error
+                .to_string()
+                .ends_with("go, python, rust, typescript, zig")
+        );
+    }
+
+    #[test]
+    fn test_template_export() {
+        let root = tempfile::tempdir().unwrap();
+        let command =
+            TemplateExportCommand::new("templates/licenses/".to_string(), root.path().into())
+                .unwrap();
+        command.execute().unwrap();
+
+        assert_eq!(
+            std::fs::read(root.path().join("licenses/MIT.tmpl")).unwrap(),
+            template_engine::get_embedded_template_bytes("licenses/MIT.tmpl")
+                .unwrap()
+                .as_ref()
+        );
+
+        // 已导出时拒绝覆盖，不存在的模板组报错
+        let command =
+            TemplateExportCommand::new("licenses".to_string(), root.path().into()).unwrap();
+        assert!(command.execute().is_err());
+        let command =
+            TemplateExportCommand::new("frameworks/go/echo".to_string(), root.path().into())
+                .unwrap();
+        assert!(command.execute().is_err());
+        assert!(TemplateExportCommand::new("/".to_string(), root.path().into()).is_err());
+    }
+}
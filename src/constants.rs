@@ -11,9 +11,30 @@ pub enum Language {
     Python,
     Rust,
     TypeScript,
+    Zig,
 }
 
 impl Language {
+    /// 所有语言，顺序即目录和提示信息中的顺序
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::Go,
+            Language::Python,
+            Language::Rust,
+            Language::TypeScript,
+            Language::Zig,
+        ]
+    }
+
+    /// 支持的语言列表，用于错误提示
+    pub fn supported() -> String {
+        Self::all()
+            .iter()
+            .map(Language::as_lowercase)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     /// 获取语言的字符串表示
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -21,6 +42,7 @@ impl Language {
             Language::Python => "Python",
             Language::Rust => "Rust",
             Language::TypeScript => "TypeScript",
+            Language::Zig => "Zig",
         }
     }
 
@@ -32,6 +54,7 @@ impl Language {
             Language::Python => "python",
             Language::Rust => "rust",
             Language::TypeScript => "typescript",
+            Language::Zig => "zig",
         }
     }
 
@@ -43,6 +66,7 @@ impl Language {
             "python" => Some(Language::Python),
             "rust" => Some(Language::Rust),
             "typescript" | "ts" => Some(Language::TypeScript),
+            "zig" => Some(Language::Zig),
             _ => None,
         }
     }
@@ -60,10 +84,22 @@ pub enum Framework {
     /// 无框架（纯语言项目）
     None,
     Gin,
+    Fiber,
+    Chi,
     GoZero,
+    Django,
+    Flask,
     Tauri,
+    Axum,
+    Actix,
+    Cli,
     Vue3,
     React,
+    NextJs,
+    Nuxt,
+    SvelteKit,
+    NestJs,
+    Astro,
 }
 
 impl Framework {
@@ -73,10 +109,22 @@ impl Framework {
         match self {
             Framework::None => "None",
             Framework::Gin => "Gin",
+            Framework::Fiber => "Fiber",
+            Framework::Chi => "Chi",
             Framework::GoZero => "go-zero",
+            Framework::Django => "Django",
+            Framework::Flask => "Flask",
             Framework::Tauri => "Tauri",
+            Framework::Axum => "Axum",
+            Framework::Actix => "Actix-web",
+            Framework::Cli => "CLI",
             Framework::Vue3 => "Vue3",
             Framework::React => "React",
+            Framework::NextJs => "Next.js",
+            Framework::Nuxt => "Nuxt",
+            Framework::SvelteKit => "SvelteKit",
+            Framework::NestJs => "NestJS",
+            Framework::Astro => "Astro",
         }
     }
 
@@ -85,10 +133,22 @@ impl Framework {
         match self {
             Framework::None => "None (Pure Language Project)",
             Framework::Gin => "Gin (Web Framework)",
+            Framework::Fiber => "Fiber (Express-style Web Framework)",
+            Framework::Chi => "Chi (Minimal net/http Router)",
             Framework::GoZero => "go-zero (Microservice Framework)",
+            Framework::Django => "Django (Batteries-included Web Framework)",
+            Framework::Flask => "Flask (Lightweight API Framework)",
             Framework::Tauri => "Tauri (Desktop App Framework)",
+            Framework::Axum => "Axum (Async Web Framework)",
+            Framework::Actix => "Actix-web (High-performance Web Framework)",
+            Framework::Cli => "CLI (clap Command-line App)",
             Framework::Vue3 => "Vue3 (Frontend Framework)",
             Framework::React => "React (Frontend Framework)",
+            Framework::NextJs => "Next.js (React Full-stack Framework)",
+            Framework::Nuxt => "Nuxt (Vue Full-stack Framework)",
+            Framework::SvelteKit => "SvelteKit (Svelte Full-stack Framework)",
+            Framework::NestJs => "NestJS (Node.js Backend Framework)",
+            Framework::Astro => "Astro (Content and Docs Static Sites)",
         }
     }
 
@@ -98,10 +158,22 @@ impl Framework {
         match self {
             Framework::None => "none",
             Framework::Gin => "gin",
+            Framework::Fiber => "fiber",
+            Framework::Chi => "chi",
             Framework::GoZero => "go-zero",
+            Framework::Django => "django",
+            Framework::Flask => "flask",
             Framework::Tauri => "tauri",
+            Framework::Axum => "axum",
+            Framework::Actix => "actix",
+            Framework::Cli => "cli",
             Framework::Vue3 => "vue3",
             Framework::React => "react",
+            Framework::NextJs => "nextjs",
+            Framework::Nuxt => "nuxt",
+            Framework::SvelteKit => "sveltekit",
+            Framework::NestJs => "nestjs",
+            Framework::Astro => "astro",
         }
     }
 
@@ -110,10 +182,22 @@ impl Framework {
         match s.to_lowercase().as_str() {
             "none" | "" => Some(Framework::None),
             "gin" => Some(Framework::Gin),
+            "fiber" => Some(Framework::Fiber),
+            "chi" => Some(Framework::Chi),
             "go-zero" => Some(Framework::GoZero),
+            "django" => Some(Framework::Django),
+            "flask" => Some(Framework::Flask),
             "tauri" => Some(Framework::Tauri),
+            "axum" => Some(Framework::Axum),
+            "actix" | "actix-web" => Some(Framework::Actix),
+            "cli" => Some(Framework::Cli),
             "vue3" | "vue" => Some(Framework::Vue3),
             "react" => Some(Framework::React),
+            "nextjs" | "next" | "next.js" => Some(Framework::NextJs),
+            "nuxt" | "nuxt3" => Some(Framework::Nuxt),
+            "sveltekit" | "svelte-kit" | "svelte" => Some(Framework::SvelteKit),
+            "nestjs" | "nest" => Some(Framework::NestJs),
+            "astro" => Some(Framework::Astro),
             _ => None,
         }
     }
@@ -124,10 +208,22 @@ impl Framework {
         match self {
             Framework::None => None,
             Framework::Gin => Some(Language::Go),
+            Framework::Fiber => Some(Language::Go),
+            Framework::Chi => Some(Language::Go),
             Framework::GoZero => Some(Language::Go),
+            Framework::Django => Some(Language::Python),
+            Framework::Flask => Some(Language::Python),
             Framework::Tauri => Some(Language::Rust),
+            Framework::Axum => Some(Language::Rust),
+            Framework::Actix => Some(Language::Rust),
+            Framework::Cli => Some(Language::Rust),
             Framework::Vue3 => Some(Language::TypeScript),
             Framework::React => Some(Language::TypeScript),
+            Framework::NextJs => Some(Language::TypeScript),
+            Framework::Nuxt => Some(Language::TypeScript),
+            Framework::SvelteKit => Some(Language::TypeScript),
+            Framework::NestJs => Some(Language::TypeScript),
+            Framework::Astro => Some(Language::TypeScript),
         }
     }
 
@@ -140,10 +236,30 @@ impl Framework {
     /// 获取指定语言支持的所有框架
     pub fn frameworks_for_language(language: Language) -> Vec<Framework> {
         match language {
-            Language::Go => vec![Framework::Gin, Framework::GoZero],
-            Language::Python => vec![], // Python 目前没有框架选项
-            Language::Rust => vec![Framework::None, Framework::Tauri],
-            Language::TypeScript => vec![Framework::Vue3, Framework::React],
+            Language::Go => vec![
+                Framework::Gin,
+                Framework::Fiber,
+                Framework::Chi,
+                Framework::GoZero,
+            ],
+            Language::Python => vec![Framework::None, Framework::Django, Framework::Flask],
+            Language::Rust => vec![
+                Framework::None,
+                Framework::Tauri,
+                Framework::Axum,
+                Framework::Actix,
+                Framework::Cli,
+            ],
+            Language::TypeScript => vec![
+                Framework::Vue3,
+                Framework::React,
+                Framework::NextJs,
+                Framework::Nuxt,
+                Framework::SvelteKit,
+                Framework::NestJs,
+                Framework::Astro,
+            ],
+            Language::Zig => vec![Framework::None],
         }
     }
 
@@ -153,10 +269,22 @@ impl Framework {
         vec![
             Framework::None,
             Framework::Gin,
+            Framework::Fiber,
+            Framework::Chi,
             Framework::GoZero,
+            Framework::Django,
+            Framework::Flask,
             Framework::Tauri,
+            Framework::Axum,
+            Framework::Actix,
+            Framework::Cli,
             Framework::Vue3,
             Framework::React,
+            Framework::NextJs,
+            Framework::Nuxt,
+            Framework::SvelteKit,
+            Framework::NestJs,
+            Framework::Astro,
         ]
     }
 }
@@ -167,6 +295,1014 @@ impl std::fmt::Display for Framework {
     }
 }
 
+/// 项目配置档位 - 控制默认启用的可选功能集合
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Profile {
+    /// 仅生成基础骨架
+    Minimal,
+    /// 常用功能（Docker、文档、测试）
+    Standard,
+    /// 启用全部可选功能
+    Full,
+}
+
+impl Profile {
+    /// 获取档位的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Minimal => "minimal",
+            Profile::Standard => "standard",
+            Profile::Full => "full",
+        }
+    }
+
+    /// 从字符串解析档位
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "minimal" => Some(Profile::Minimal),
+            "standard" => Some(Profile::Standard),
+            "full" => Some(Profile::Full),
+            _ => None,
+        }
+    }
+
+    /// 获取档位默认启用的功能
+    pub fn features(&self) -> ProfileFeatures {
+        match self {
+            Profile::Minimal => ProfileFeatures {
+                docker: false,
+                ci: false,
+                docs: false,
+                lint: false,
+                tests: false,
+            },
+            Profile::Standard => ProfileFeatures {
+                docker: true,
+                ci: false,
+                docs: true,
+                lint: false,
+                tests: true,
+            },
+            Profile::Full => ProfileFeatures {
+                docker: true,
+                ci: true,
+                docs: true,
+                lint: true,
+                tests: true,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--profile` 支持的取值
+pub const PROFILES: &[&str] = &["minimal", "standard", "full"];
+
+/// 档位对应的可选功能开关
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileFeatures {
+    /// Dockerfile / docker-compose
+    pub docker: bool,
+    /// CI 工作流
+    pub ci: bool,
+    /// API 文档（Swagger）
+    pub docs: bool,
+    /// 代码检查（pre-commit）
+    pub lint: bool,
+    /// 示例测试
+    pub tests: bool,
+}
+
+impl Default for ProfileFeatures {
+    fn default() -> Self {
+        Profile::Standard.features()
+    }
+}
+
+/// 档位中的单个可选功能
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    Docker,
+    Ci,
+    Docs,
+    Lint,
+    Tests,
+}
+
+/// 启用某个功能的代价：新增的依赖、额外的安装耗时和新增的文件
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureImpact {
+    pub dependencies: Vec<&'static str>,
+    pub install_time: Option<&'static str>,
+    pub files: Vec<&'static str>,
+}
+
+impl FeatureImpact {
+    /// 用于提问帮助信息的一行说明
+    pub fn hint(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.dependencies.is_empty() {
+            parts.push(format!("adds {}", self.dependencies.join(", ")));
+        }
+        if let Some(install_time) = self.install_time {
+            parts.push(format!("install {install_time}"));
+        }
+        if !self.files.is_empty() {
+            parts.push(format!("files: {}", self.files.join(", ")));
+        }
+        parts.join("; ")
+    }
+}
+
+impl Feature {
+    /// 功能的显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Feature::Docker => "Docker",
+            Feature::Ci => "CI",
+            Feature::Docs => "API docs (Swagger)",
+            Feature::Lint => "Lint (pre-commit)",
+            Feature::Tests => "Example tests",
+        }
+    }
+
+    /// 在指定框架下启用该功能的代价，框架不支持该功能时返回 `None`
+    pub fn impact(&self, language: &Language, framework: &Framework) -> Option<FeatureImpact> {
+        let impact = match (self, framework) {
+            (Feature::Docs, Framework::Gin) => FeatureImpact {
+                dependencies: vec!["swaggo/swag", "swaggo/gin-swagger", "swaggo/files"],
+                install_time: Some("+5-15s (swag init)"),
+                files: vec!["docs/docs.go", "docs/swagger.json", "docs/swagger.yaml"],
+            },
+            (Feature::Docs, Framework::Fiber) => FeatureImpact {
+                dependencies: vec!["swaggo/swag", "gofiber/swagger"],
+                install_time: Some("+5-15s (swag init)"),
+                files: vec!["docs/docs.go", "docs/swagger.json", "docs/swagger.yaml"],
+            },
+            (Feature::Docs, Framework::NestJs) => FeatureImpact {
+                dependencies: vec!["@nestjs/swagger"],
+                ..Default::default()
+            },
+            (Feature::Docs, _) => return None,
+            (Feature::Docker, Framework::Gin | Framework::Fiber) => FeatureImpact {
+                files: vec!["Dockerfile", "docker-compose.yml", ".dockerignore"],
+                ..Default::default()
+            },
+            (Feature::Docker, Framework::GoZero) => FeatureImpact {
+                files: vec![
+                    "deploy/docker/Dockerfile.<service>",
+                    "deploy/docker/docker-compose.yml",
+                    ".dockerignore",
+                ],
+                ..Default::default()
+            },
+            // 桌面应用和 Go、Zig 的纯语言项目没有容器化目标
+            (Feature::Docker, Framework::Tauri) => return None,
+            (Feature::Docker, Framework::None)
+                if !matches!(language, Language::Python | Language::Rust) =>
+            {
+                return None;
+            }
+            (Feature::Docker, Framework::None | Framework::Cli) => FeatureImpact {
+                files: vec!["Dockerfile", ".dockerignore"],
+                ..Default::default()
+            },
+            (Feature::Docker, Framework::Vue3 | Framework::React | Framework::Astro) => {
+                FeatureImpact {
+                    files: vec![
+                        "Dockerfile",
+                        "nginx.conf",
+                        ".dockerignore",
+                        "docker-compose.yaml",
+                    ],
+                    ..Default::default()
+                }
+            }
+            (Feature::Docker, _) => FeatureImpact {
+                files: vec!["Dockerfile", ".dockerignore", "docker-compose.yaml"],
+                ..Default::default()
+            },
+            // Go-Zero 和 Tauri 没有 CI 模板
+            (Feature::Ci, Framework::GoZero | Framework::Tauri) => return None,
+            (Feature::Ci, Framework::None) if *language == Language::Go => return None,
+            (Feature::Ci, _) => FeatureImpact {
+                files: vec![CiProvider::default().config_path()],
+                ..Default::default()
+            },
+            (Feature::Tests, Framework::Gin | Framework::Fiber) => FeatureImpact {
+                files: vec!["routers/health_test.go"],
+                ..Default::default()
+            },
+            (Feature::Tests, Framework::Chi) => FeatureImpact {
+                files: vec!["internal/server/server_test.go"],
+                ..Default::default()
+            },
+            (Feature::Tests, Framework::Axum | Framework::Actix) => FeatureImpact {
+                dependencies: vec!["tower", "http-body-util"],
+                files: vec!["tests/health.rs"],
+                ..Default::default()
+            },
+            (Feature::Tests, Framework::Cli) => FeatureImpact {
+                dependencies: vec!["assert_cmd", "predicates", "tempfile"],
+                files: vec!["tests/cli.rs"],
+                ..Default::default()
+            },
+            (Feature::Tests, Framework::Vue3) => FeatureImpact {
+                dependencies: vec!["vitest", "@vitest/coverage-v8", "jsdom", "@vue/test-utils"],
+                files: vec![
+                    "vitest.config.ts",
+                    "src/components/__tests__/HelloWorld.spec.ts",
+                ],
+                ..Default::default()
+            },
+            (Feature::Tests, Framework::React) => FeatureImpact {
+                dependencies: vec![
+                    "vitest",
+                    "@vitest/coverage-v8",
+                    "jsdom",
+                    "@testing-library/react",
+                ],
+                files: vec!["vitest.config.ts", "src/routes/__tests__/NotFound.test.tsx"],
+                ..Default::default()
+            },
+            (Feature::Tests, Framework::NestJs) => FeatureImpact {
+                dependencies: vec!["jest", "ts-jest", "supertest", "@nestjs/testing"],
+                files: vec!["src/items/items.service.spec.ts", "test/app.e2e-spec.ts"],
+                ..Default::default()
+            },
+            (Feature::Tests, _) if *language == Language::Python => FeatureImpact {
+                files: vec!["tests/"],
+                ..Default::default()
+            },
+            (Feature::Tests, _) => return None,
+            (Feature::Lint, _) => FeatureImpact {
+                dependencies: match language {
+                    Language::TypeScript if *framework == Framework::SvelteKit => {
+                        vec!["pre-commit", "svelte-check"]
+                    }
+                    Language::TypeScript if *framework == Framework::NestJs => {
+                        vec!["pre-commit", "prettier", "tsc"]
+                    }
+                    Language::TypeScript if *framework == Framework::Astro => {
+                        vec!["pre-commit", "@astrojs/check"]
+                    }
+                    Language::Go => vec!["pre-commit", "gofumpt", "golangci-lint"],
+                    Language::Python => vec!["pre-commit", "ruff"],
+                    Language::Rust => vec!["pre-commit", "rustfmt", "clippy"],
+                    Language::TypeScript => vec!["pre-commit", "eslint"],
+                    Language::Zig => vec!["pre-commit", "zig fmt"],
+                },
+                install_time: Some("+1-2 min for hook environments on the first commit"),
+                files: vec![".pre-commit-config.yaml"],
+            },
+        };
+        Some(impact)
+    }
+}
+
+/// 非关键工具（如 `go mod tidy`、`pnpm install`、swag）失败时的处理策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailurePolicy {
+    /// 任何工具失败都中止生成
+    Strict,
+    /// 记录警告并继续生成
+    #[default]
+    Lenient,
+}
+
+impl FailurePolicy {
+    /// 获取策略的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailurePolicy::Strict => "strict",
+            FailurePolicy::Lenient => "lenient",
+        }
+    }
+
+    /// 从字符串解析策略
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "strict" => Some(FailurePolicy::Strict),
+            "lenient" => Some(FailurePolicy::Lenient),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FailurePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--failure-policy` 支持的取值
+pub const FAILURE_POLICIES: &[&str] = &["strict", "lenient"];
+
+/// 前端项目使用的 Tailwind CSS 主版本
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TailwindVersion {
+    /// v3：`tailwindcss init -p` 生成的 PostCSS 插件配置
+    #[serde(rename = "3")]
+    V3,
+    /// v4：Vite 项目使用 `@tailwindcss/vite`，其余使用 `@tailwindcss/postcss`
+    #[default]
+    #[serde(rename = "4")]
+    V4,
+}
+
+impl TailwindVersion {
+    /// 获取版本的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TailwindVersion::V3 => "3",
+            TailwindVersion::V4 => "4",
+        }
+    }
+
+    /// 从字符串解析版本
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.trim_start_matches(['v', 'V']) {
+            "3" => Some(TailwindVersion::V3),
+            "4" => Some(TailwindVersion::V4),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TailwindVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--tailwind-version` 支持的取值
+pub const TAILWIND_VERSIONS: &[&str] = &["3", "4"];
+
+/// Tauri 项目的主版本，决定覆盖模板、`tauri.conf.json` 的结构和权限配置方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TauriVersion {
+    /// v1：`tauri.conf.json` 中的 allowlist 控制前端可调用的 API
+    #[serde(rename = "1")]
+    V1,
+    /// v2：插件化 API，权限由 `capabilities/` 声明
+    #[default]
+    #[serde(rename = "2")]
+    V2,
+}
+
+impl TauriVersion {
+    /// 获取版本的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TauriVersion::V1 => "1",
+            TauriVersion::V2 => "2",
+        }
+    }
+
+    /// 从字符串解析版本
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.trim_start_matches(['v', 'V']) {
+            "1" => Some(TauriVersion::V1),
+            "2" => Some(TauriVersion::V2),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TauriVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--tauri-version` 支持的取值
+pub const TAURI_VERSIONS: &[&str] = &["1", "2"];
+
+/// TypeScript 项目使用的 JavaScript 运行时，决定包管理命令、锁文件和 CI 配置
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Runtime {
+    /// Node.js，依赖由 pnpm 管理
+    #[default]
+    Node,
+    /// Bun，自带包管理器
+    Bun,
+    /// Deno 2，通过 package.json 和 npm: 说明符兼容 npm 生态
+    Deno,
+}
+
+impl Runtime {
+    /// 获取运行时的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Runtime::Node => "node",
+            Runtime::Bun => "bun",
+            Runtime::Deno => "deno",
+        }
+    }
+
+    /// 从字符串解析运行时
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "node" | "nodejs" => Some(Runtime::Node),
+            "bun" => Some(Runtime::Bun),
+            "deno" => Some(Runtime::Deno),
+            _ => None,
+        }
+    }
+
+    /// 运行时对应的包管理器可执行文件
+    pub fn package_manager(&self) -> &'static str {
+        match self {
+            Runtime::Node => "pnpm",
+            Runtime::Bun => "bun",
+            Runtime::Deno => "deno",
+        }
+    }
+
+    /// 包管理器生成的锁文件
+    pub fn lockfile(&self) -> &'static str {
+        match self {
+            Runtime::Node => "pnpm-lock.yaml",
+            Runtime::Bun => "bun.lock",
+            Runtime::Deno => "deno.lock",
+        }
+    }
+
+    /// 运行 package.json 脚本的命令，如 `pnpm dev`、`bun run dev`、`deno task dev`
+    pub fn run_command(&self, script: &str) -> String {
+        match self {
+            Runtime::Node => format!("pnpm {script}"),
+            Runtime::Bun => format!("bun run {script}"),
+            Runtime::Deno => format!("deno task {script}"),
+        }
+    }
+
+    /// 执行依赖中可执行文件的命令，如 `pnpm exec eslint .`
+    pub fn exec_command(&self, command: &str) -> String {
+        match self {
+            Runtime::Node => format!("pnpm exec {command}"),
+            Runtime::Bun => format!("bunx {command}"),
+            Runtime::Deno => format!("deno run -A npm:{command}"),
+        }
+    }
+
+    /// CI 中按锁文件安装依赖的命令
+    pub fn frozen_install_command(&self) -> &'static str {
+        match self {
+            Runtime::Node => "pnpm install --frozen-lockfile",
+            Runtime::Bun => "bun install --frozen-lockfile",
+            Runtime::Deno => "deno install --frozen",
+        }
+    }
+}
+
+impl std::fmt::Display for Runtime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--runtime` 支持的取值
+pub const RUNTIMES: &[&str] = &["node", "bun", "deno"];
+
+/// Vue3 和 React 项目生成的 API 客户端层实现
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiClient {
+    /// 基于原生 fetch 的轻量封装，不引入额外依赖
+    #[default]
+    Fetch,
+    /// axios 实例，通过拦截器处理请求头和错误
+    Axios,
+}
+
+impl ApiClient {
+    /// 获取实现的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiClient::Fetch => "fetch",
+            ApiClient::Axios => "axios",
+        }
+    }
+
+    /// 从字符串解析实现
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "fetch" => Some(ApiClient::Fetch),
+            "axios" => Some(ApiClient::Axios),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--api-client` 支持的取值
+pub const API_CLIENTS: &[&str] = &["fetch", "axios"];
+
+/// 生成的 CI 配置所针对的平台
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CiProvider {
+    /// GitHub Actions
+    #[default]
+    Github,
+    /// GitLab CI/CD
+    Gitlab,
+    /// Azure Pipelines
+    Azure,
+    /// CircleCI
+    Circleci,
+}
+
+impl CiProvider {
+    /// 获取平台的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CiProvider::Github => "github",
+            CiProvider::Gitlab => "gitlab",
+            CiProvider::Azure => "azure",
+            CiProvider::Circleci => "circleci",
+        }
+    }
+
+    /// 从字符串解析平台
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "github" | "github-actions" => Some(CiProvider::Github),
+            "gitlab" | "gitlab-ci" => Some(CiProvider::Gitlab),
+            "azure" | "azure-pipelines" => Some(CiProvider::Azure),
+            "circleci" | "circle" => Some(CiProvider::Circleci),
+            _ => None,
+        }
+    }
+
+    /// 平台读取的配置文件（相对于项目根目录）
+    pub fn config_path(&self) -> &'static str {
+        match self {
+            CiProvider::Github => ".github/workflows/ci.yml",
+            CiProvider::Gitlab => ".gitlab-ci.yml",
+            CiProvider::Azure => "azure-pipelines.yml",
+            CiProvider::Circleci => ".circleci/config.yml",
+        }
+    }
+
+    /// README 中的构建状态徽章，仓库所有者等平台信息以 `<owner>` 形式的占位符给出
+    pub fn badge(&self, project_name: &str) -> String {
+        match self {
+            CiProvider::Github => format!(
+                "[![CI](https://github.com/<owner>/{project_name}/actions/workflows/ci.yml/badge.svg)](https://github.com/<owner>/{project_name}/actions/workflows/ci.yml)"
+            ),
+            CiProvider::Gitlab => format!(
+                "[![pipeline](https://gitlab.com/<namespace>/{project_name}/badges/main/pipeline.svg)](https://gitlab.com/<namespace>/{project_name}/-/pipelines)"
+            ),
+            CiProvider::Azure => format!(
+                "[![Build Status](https://dev.azure.com/<organization>/<project>/_apis/build/status/{project_name}?branchName=main)](https://dev.azure.com/<organization>/<project>/_build/latest?definitionId=<id>&branchName=main)"
+            ),
+            CiProvider::Circleci => format!(
+                "[![CircleCI](https://dl.circleci.com/status-badge/img/gh/<owner>/{project_name}/tree/main.svg)](https://dl.circleci.com/status-badge/redirect/gh/<owner>/{project_name}/tree/main)"
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for CiProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--ci` 支持的取值
+pub const CI_PROVIDERS: &[&str] = &["github", "gitlab", "azure", "circleci"];
+
+/// `--k8s` 生成的 Kubernetes 部署文件形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum K8sFormat {
+    /// 普通的 Deployment/Service/Ingress 清单，通过 kustomize 应用
+    Manifests,
+    /// Helm chart，端口、域名和镜像写在 values.yaml 中
+    Helm,
+}
+
+impl K8sFormat {
+    /// 获取形式的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            K8sFormat::Manifests => "manifests",
+            K8sFormat::Helm => "helm",
+        }
+    }
+
+    /// 从字符串解析形式
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "manifests" | "manifest" | "kubectl" => Some(K8sFormat::Manifests),
+            "helm" | "chart" => Some(K8sFormat::Helm),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for K8sFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--k8s` 支持的取值
+pub const K8S_FORMATS: &[&str] = &["manifests", "helm"];
+
+/// `--task-runner` 生成的任务入口
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskRunner {
+    /// GNU Make 的 `Makefile`
+    Make,
+    /// just 的 `justfile`
+    Just,
+}
+
+impl TaskRunner {
+    /// 获取任务入口的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskRunner::Make => "make",
+            TaskRunner::Just => "just",
+        }
+    }
+
+    /// 从字符串解析任务入口
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "make" | "makefile" => Some(TaskRunner::Make),
+            "just" | "justfile" => Some(TaskRunner::Just),
+            _ => None,
+        }
+    }
+
+    /// 生成的文件名
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            TaskRunner::Make => "Makefile",
+            TaskRunner::Just => "justfile",
+        }
+    }
+}
+
+impl std::fmt::Display for TaskRunner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--task-runner` 支持的取值，`none` 表示不生成
+pub const TASK_RUNNERS: &[&str] = &["make", "just"];
+
+/// `scafgen generate` 在已有项目中生成的组件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentKind {
+    /// Gin 路由组及其处理器
+    Handler,
+    /// 数据模型
+    Model,
+    /// Gin 中间件
+    Middleware,
+    /// React 或 Vue3 组件
+    Component,
+    /// React 页面或 Vue3 视图，注册为路由
+    View,
+}
+
+impl ComponentKind {
+    /// 获取组件类型的字符串表示，同时也是组件模板名
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComponentKind::Handler => "handler",
+            ComponentKind::Model => "model",
+            ComponentKind::Middleware => "middleware",
+            ComponentKind::Component => "component",
+            ComponentKind::View => "view",
+        }
+    }
+
+    /// 从字符串解析组件类型
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "handler" | "router" | "route" => Some(ComponentKind::Handler),
+            "model" => Some(ComponentKind::Model),
+            "middleware" | "mw" => Some(ComponentKind::Middleware),
+            "component" => Some(ComponentKind::Component),
+            "view" | "page" => Some(ComponentKind::View),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ComponentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `scafgen generate` 支持的组件类型
+pub const COMPONENT_KINDS: &[&str] = &["handler", "model", "middleware", "component", "view"];
+
+/// Gin 和 Fiber 项目可选的数据库
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Database {
+    Postgres,
+    Mysql,
+    Sqlite,
+    Mongo,
+}
+
+impl Database {
+    /// 获取数据库的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Database::Postgres => "postgres",
+            Database::Mysql => "mysql",
+            Database::Sqlite => "sqlite",
+            Database::Mongo => "mongo",
+        }
+    }
+
+    /// 从字符串解析数据库
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "postgres" | "postgresql" => Some(Database::Postgres),
+            "mysql" => Some(Database::Mysql),
+            "sqlite" => Some(Database::Sqlite),
+            "mongo" | "mongodb" => Some(Database::Mongo),
+            _ => None,
+        }
+    }
+
+    /// 是否通过 GORM 访问（MongoDB 使用官方驱动）
+    pub fn uses_gorm(&self) -> bool {
+        !matches!(self, Database::Mongo)
+    }
+}
+
+impl std::fmt::Display for Database {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--database` 支持的取值
+pub const DATABASES: &[&str] = &["postgres", "mysql", "sqlite", "mongo"];
+
+/// Gin 项目访问 SQL 数据库的方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Orm {
+    /// GORM 模型，启动时自动迁移
+    #[default]
+    Gorm,
+    /// sqlx 执行手写 SQL，表结构由迁移维护
+    Sqlx,
+    /// ent 按 `ent/schema` 生成类型安全的客户端，表结构由迁移维护
+    Ent,
+}
+
+impl Orm {
+    /// 获取数据访问方式的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Orm::Gorm => "gorm",
+            Orm::Sqlx => "sqlx",
+            Orm::Ent => "ent",
+        }
+    }
+
+    /// 从字符串解析数据访问方式
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "gorm" => Some(Orm::Gorm),
+            "sqlx" => Some(Orm::Sqlx),
+            "ent" => Some(Orm::Ent),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Orm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--orm` 支持的取值
+pub const ORMS: &[&str] = &["gorm", "sqlx", "ent"];
+
+/// Gin 项目使用的日志库
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Logger {
+    /// zap，按级别写入 debug/info/error 三个滚动日志文件
+    #[default]
+    Zap,
+    /// zerolog，零分配的链式 JSON 日志
+    Zerolog,
+    /// 标准库 log/slog，不引入第三方日志依赖
+    Slog,
+}
+
+impl Logger {
+    /// 获取日志库的字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Logger::Zap => "zap",
+            Logger::Zerolog => "zerolog",
+            Logger::Slog => "slog",
+        }
+    }
+
+    /// 从字符串解析日志库
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "zap" => Some(Logger::Zap),
+            "zerolog" => Some(Logger::Zerolog),
+            "slog" => Some(Logger::Slog),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Logger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--logger` 支持的取值
+pub const LOGGERS: &[&str] = &["zap", "zerolog", "slog"];
+
+/// 生成完成后用于打开项目的编辑器
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Editor {
+    /// Visual Studio Code
+    Code,
+    /// IntelliJ IDEA
+    Idea,
+    /// Cursor
+    Cursor,
+}
+
+impl Editor {
+    /// 自动检测时的优先顺序
+    pub fn all() -> &'static [Editor] {
+        &[Editor::Code, Editor::Cursor, Editor::Idea]
+    }
+
+    /// 获取编辑器的字符串表示，同时也是启动命令
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Editor::Code => "code",
+            Editor::Idea => "idea",
+            Editor::Cursor => "cursor",
+        }
+    }
+
+    /// 从字符串解析编辑器
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "code" | "vscode" => Some(Editor::Code),
+            "idea" => Some(Editor::Idea),
+            "cursor" => Some(Editor::Cursor),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Editor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `--open` 支持的取值
+pub const EDITORS: &[&str] = &["code", "idea", "cursor"];
+
+/// 许可证目录：SPDX 标识符（或 `MIT OR Apache-2.0` 等组合表达式）和名称，交互选择时可输入关键字过滤
+///
+/// `templates/licenses/` 中有模板的许可证离线可用，其余许可证和目录外的 SPDX 标识符在首次使用时下载并缓存
+pub const LICENSES: &[(&str, &str)] = &[
+    ("MIT", "MIT License"),
+    ("Apache-2.0", "Apache License 2.0"),
+    (
+        "MIT OR Apache-2.0",
+        "dual MIT and Apache 2.0, the Rust convention",
+    ),
+    ("GPL-3.0", "GNU General Public License v3.0"),
+    ("GPL-2.0", "GNU General Public License v2.0"),
+    ("LGPL-3.0", "GNU Lesser General Public License v3.0"),
+    ("LGPL-2.1", "GNU Lesser General Public License v2.1"),
+    ("AGPL-3.0-only", "GNU Affero General Public License v3.0"),
+    ("MPL-2.0", "Mozilla Public License 2.0"),
+    (
+        "BSD-3-Clause",
+        "BSD 3-Clause \"New\" or \"Revised\" License",
+    ),
+    ("BSD-2-Clause", "BSD 2-Clause \"Simplified\" License"),
+    ("0BSD", "BSD Zero Clause License"),
+    ("ISC", "ISC License"),
+    ("MIT-0", "MIT No Attribution"),
+    ("Zlib", "zlib License"),
+    ("BSL-1.0", "Boost Software License 1.0"),
+    ("Unlicense", "The Unlicense"),
+    ("CC0-1.0", "Creative Commons Zero v1.0 Universal"),
+    ("EPL-2.0", "Eclipse Public License 2.0"),
+    ("EUPL-1.2", "European Union Public License 1.2"),
+    ("Artistic-2.0", "Artistic License 2.0"),
+    (
+        "CC-BY-4.0",
+        "Creative Commons Attribution 4.0 International",
+    ),
+    ("OFL-1.1", "SIL Open Font License 1.1"),
+];
+
+/// 不生成 LICENSE 文件时使用的许可证取值
+pub const NO_LICENSE: &str = "None";
+
+/// `--license-file` 提供自定义许可证文本且未指定 `--license` 时使用的 SPDX 表达式
+pub const CUSTOM_LICENSE: &str = "LicenseRef-Custom";
+
+/// `--services` 支持的取值：go-zero 项目包含的服务，同时也是生成项目中的顶层目录名
+pub const GO_ZERO_SERVICES: &[&str] = &["api", "rpc", "admin"];
+
+/// `--nuxt-modules` 支持的取值：Nuxt 项目通过 `nuxi module add` 安装的官方模块
+pub const NUXT_MODULES: &[&str] = &["tailwind", "eslint", "pinia"];
+
+/// `--sveltekit-options` 支持的取值：TypeScript 类型检查以及通过 `sv add` 安装的 Tailwind CSS 和 Playwright
+pub const SVELTEKIT_OPTIONS: &[&str] = &["typescript", "tailwind", "playwright"];
+
+/// `--stack` 简写支持的取值，格式为 `语言/框架`，省略框架表示纯语言项目
+pub const STACKS: &[&str] = &[
+    "go/gin",
+    "go/fiber",
+    "go/chi",
+    "go/go-zero",
+    "python",
+    "python/django",
+    "python/flask",
+    "rust",
+    "rust/tauri",
+    "rust/axum",
+    "rust/actix",
+    "rust/cli",
+    "ts/vue3",
+    "ts/react",
+    "ts/nextjs",
+    "ts/nuxt",
+    "ts/sveltekit",
+    "ts/nestjs",
+    "ts/astro",
+    "zig",
+];
+
+/// `STACKS` 中的框架名，按技术栈的顺序排列
+pub fn stack_frameworks() -> Vec<&'static str> {
+    STACKS
+        .iter()
+        .filter_map(|stack| stack.split_once('/'))
+        .map(|(_, framework)| framework)
+        .collect()
+}
+
+/// 解析 `--stack` 简写，返回对应的语言和框架
+pub fn parse_stack(stack: &str) -> Option<(Language, Framework)> {
+    let (language, framework) = match stack.split_once('/') {
+        Some((language, framework)) => (
+            Language::parse_from_str(language)?,
+            Framework::parse_from_str(framework)?,
+        ),
+        None => (Language::parse_from_str(stack)?, Framework::None),
+    };
+
+    let frameworks = Framework::frameworks_for_language(language);
+    if framework == Framework::None || frameworks.contains(&framework) {
+        Some((language, framework))
+    } else {
+        None
+    }
+}
+
 // 模板变量名常量 - 用于模板渲染时的变量替换
 #[allow(dead_code)]
 pub const PROJECT_NAME: &str = "project_name";
@@ -205,6 +1341,8 @@ pub mod defaults {
     pub const NODE_VERSION: &str = "20";
     /// TypeScript 默认版本
     pub const TYPESCRIPT_VERSION: &str = "5.0";
+    /// Zig 默认版本（生成的 build.zig 需要 0.14 及以上）
+    pub const ZIG_VERSION: &str = "0.14.0";
 
     // ===== 工具版本 =====
     /// uv 默认版本
@@ -251,14 +1389,17 @@ pub mod string_utils {
         result
     }
 
-    /// 将字符串转换为kebab-case (预留给未来的模板渲染功能)
-    #[allow(dead_code)]
+    /// 将字符串转换为kebab-case
     pub fn to_kebab_case(s: &str) -> String {
         let mut result = String::new();
         let chars = s.chars().peekable();
 
         for ch in chars {
-            if ch.is_uppercase() && !result.is_empty() {
+            if ch == '_' || ch == ' ' {
+                result.push('-');
+                continue;
+            }
+            if ch.is_uppercase() && !result.is_empty() && !result.ends_with('-') {
                 result.push('-');
             }
             result.push(ch.to_lowercase().next().unwrap_or(ch));
@@ -273,7 +1414,14 @@ pub mod string_utils {
         let chars = s.chars().peekable();
 
         for ch in chars {
-            if ch.is_uppercase() && !result.is_empty() {
+            // 连字符和空格同样视为单词分隔
+            if ch == '-' || ch == ' ' {
+                if !result.is_empty() && !result.ends_with('_') {
+                    result.push('_');
+                }
+                continue;
+            }
+            if ch.is_uppercase() && !result.is_empty() && !result.ends_with('_') {
                 result.push('_');
             }
             result.push(ch.to_lowercase().next().unwrap_or(ch));
@@ -281,11 +1429,282 @@ pub mod string_utils {
 
         result
     }
+
+    /// 获取英文单词的复数形式（覆盖常见规则）
+    pub fn pluralize(word: &str) -> String {
+        let lower = word.to_lowercase();
+        if word.is_empty() {
+            return String::new();
+        }
+
+        if ["s", "x", "z", "ch", "sh"]
+            .iter()
+            .any(|suffix| lower.ends_with(suffix))
+        {
+            return format!("{word}es");
+        }
+
+        if let Some(stem) = word.strip_suffix(['y', 'Y'])
+            && !stem.ends_with(['a', 'e', 'i', 'o', 'u', 'A', 'E', 'I', 'O', 'U'])
+            && !stem.is_empty()
+        {
+            return format!("{stem}ies");
+        }
+
+        format!("{word}s")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::string_utils::*;
+    use super::{Framework, Language, STACKS, parse_stack, stack_frameworks};
+
+    #[test]
+    fn test_profile_features() {
+        use super::Profile;
+
+        assert_eq!(Profile::parse_from_str("FULL"), Some(Profile::Full));
+        assert_eq!(Profile::parse_from_str("huge"), None);
+
+        let minimal = Profile::Minimal.features();
+        assert!(!minimal.docker && !minimal.ci && !minimal.docs && !minimal.lint && !minimal.tests);
+
+        let full = Profile::Full.features();
+        assert!(full.docker && full.ci && full.docs && full.lint && full.tests);
+    }
+
+    #[test]
+    fn test_feature_impact() {
+        use super::{Feature, Framework, Language};
+
+        let docs = Feature::Docs
+            .impact(&Language::Go, &Framework::Gin)
+            .unwrap();
+        assert!(docs.dependencies.contains(&"swaggo/gin-swagger"));
+        assert!(docs.hint().contains("swag init"));
+        assert!(docs.hint().contains("docs/swagger.json"));
+
+        // 框架不支持的功能没有代价说明
+        assert_eq!(Feature::Docs.impact(&Language::Go, &Framework::Chi), None);
+        assert_eq!(
+            Feature::Docker.impact(&Language::Rust, &Framework::Tauri),
+            None
+        );
+        assert_eq!(
+            Feature::Docker.impact(&Language::Zig, &Framework::None),
+            None
+        );
+        assert!(
+            Feature::Docker
+                .impact(&Language::TypeScript, &Framework::Vue3)
+                .unwrap()
+                .files
+                .contains(&"nginx.conf")
+        );
+
+        let lint = Feature::Lint
+            .impact(&Language::Python, &Framework::Flask)
+            .unwrap();
+        assert_eq!(lint.dependencies, vec!["pre-commit", "ruff"]);
+        assert_eq!(
+            Feature::Ci
+                .impact(&Language::Rust, &Framework::None)
+                .unwrap()
+                .hint(),
+            "files: .github/workflows/ci.yml"
+        );
+        assert_eq!(Feature::Ci.impact(&Language::Rust, &Framework::Tauri), None);
+    }
+
+    #[test]
+    fn test_ci_provider_parse() {
+        use super::{CI_PROVIDERS, CiProvider};
+
+        assert_eq!(
+            CiProvider::parse_from_str("GitLab"),
+            Some(CiProvider::Gitlab)
+        );
+        assert_eq!(CiProvider::parse_from_str("jenkins"), None);
+        for provider in CI_PROVIDERS {
+            assert_eq!(
+                CiProvider::parse_from_str(provider).unwrap().as_str(),
+                *provider
+            );
+        }
+        assert_eq!(CiProvider::Circleci.config_path(), ".circleci/config.yml");
+    }
+
+    #[test]
+    fn test_failure_policy_parse() {
+        use super::FailurePolicy;
+
+        assert_eq!(
+            FailurePolicy::parse_from_str("Strict"),
+            Some(FailurePolicy::Strict)
+        );
+        assert_eq!(FailurePolicy::parse_from_str("loose"), None);
+        assert_eq!(FailurePolicy::default(), FailurePolicy::Lenient);
+    }
+
+    #[test]
+    fn test_tailwind_version_parse() {
+        use super::TailwindVersion;
+
+        assert_eq!(
+            TailwindVersion::parse_from_str("v3"),
+            Some(TailwindVersion::V3)
+        );
+        assert_eq!(
+            TailwindVersion::parse_from_str("4"),
+            Some(TailwindVersion::V4)
+        );
+        assert_eq!(TailwindVersion::parse_from_str("2"), None);
+        assert_eq!(TailwindVersion::default(), TailwindVersion::V4);
+    }
+
+    #[test]
+    fn test_tauri_version_parse() {
+        use super::TauriVersion;
+
+        assert_eq!(TauriVersion::parse_from_str("v1"), Some(TauriVersion::V1));
+        assert_eq!(TauriVersion::parse_from_str("2"), Some(TauriVersion::V2));
+        assert_eq!(TauriVersion::parse_from_str("3"), None);
+        assert_eq!(TauriVersion::default(), TauriVersion::V2);
+    }
+
+    #[test]
+    fn test_runtime_commands() {
+        use super::Runtime;
+
+        assert_eq!(Runtime::parse_from_str("Bun"), Some(Runtime::Bun));
+        assert_eq!(Runtime::parse_from_str("nodejs"), Some(Runtime::Node));
+        assert_eq!(Runtime::parse_from_str("yarn"), None);
+        assert_eq!(Runtime::default(), Runtime::Node);
+
+        assert_eq!(Runtime::Node.run_command("dev"), "pnpm dev");
+        assert_eq!(Runtime::Bun.run_command("dev"), "bun run dev");
+        assert_eq!(Runtime::Deno.run_command("dev"), "deno task dev");
+        assert_eq!(Runtime::Bun.exec_command("eslint ."), "bunx eslint .");
+        assert_eq!(Runtime::Deno.lockfile(), "deno.lock");
+    }
+
+    #[test]
+    fn test_database_parse() {
+        use super::Database;
+
+        assert_eq!(
+            Database::parse_from_str("PostgreSQL"),
+            Some(Database::Postgres)
+        );
+        assert_eq!(Database::parse_from_str("mongodb"), Some(Database::Mongo));
+        assert_eq!(Database::parse_from_str("oracle"), None);
+        assert!(Database::Sqlite.uses_gorm());
+        assert!(!Database::Mongo.uses_gorm());
+    }
+
+    #[test]
+    fn test_orm_parse() {
+        use super::{ORMS, Orm};
+
+        assert_eq!(Orm::parse_from_str("Ent"), Some(Orm::Ent));
+        assert_eq!(Orm::parse_from_str("bun"), None);
+        assert!(
+            ORMS.iter()
+                .all(|orm| Orm::parse_from_str(orm).is_some_and(|parsed| parsed.as_str() == *orm))
+        );
+    }
+
+    #[test]
+    fn test_logger_parse() {
+        use super::{LOGGERS, Logger};
+
+        assert_eq!(Logger::parse_from_str("Slog"), Some(Logger::Slog));
+        assert_eq!(Logger::parse_from_str("logrus"), None);
+        assert!(LOGGERS.iter().all(|logger| {
+            Logger::parse_from_str(logger).is_some_and(|parsed| parsed.as_str() == *logger)
+        }));
+    }
+
+    #[test]
+    fn test_editor_parse() {
+        use super::Editor;
+
+        assert_eq!(Editor::parse_from_str("VSCode"), Some(Editor::Code));
+        assert_eq!(Editor::parse_from_str("idea"), Some(Editor::Idea));
+        assert_eq!(Editor::parse_from_str("vim"), None);
+        assert_eq!(Editor::Cursor.to_string(), "cursor");
+    }
+
+    #[test]
+    fn test_parse_stack() {
+        assert_eq!(parse_stack("go/gin"), Some((Language::Go, Framework::Gin)));
+        assert_eq!(
+            parse_stack("go/fiber"),
+            Some((Language::Go, Framework::Fiber))
+        );
+        assert_eq!(parse_stack("go/chi"), Some((Language::Go, Framework::Chi)));
+        assert_eq!(
+            parse_stack("python/django"),
+            Some((Language::Python, Framework::Django))
+        );
+        assert_eq!(
+            parse_stack("python/flask"),
+            Some((Language::Python, Framework::Flask))
+        );
+        assert_eq!(
+            parse_stack("rust/axum"),
+            Some((Language::Rust, Framework::Axum))
+        );
+        assert_eq!(
+            parse_stack("rust/actix"),
+            Some((Language::Rust, Framework::Actix))
+        );
+        assert_eq!(
+            parse_stack("rust/cli"),
+            Some((Language::Rust, Framework::Cli))
+        );
+        assert_eq!(
+            parse_stack("ts/react"),
+            Some((Language::TypeScript, Framework::React))
+        );
+        assert_eq!(
+            parse_stack("ts/nextjs"),
+            Some((Language::TypeScript, Framework::NextJs))
+        );
+        assert_eq!(
+            parse_stack("ts/nuxt"),
+            Some((Language::TypeScript, Framework::Nuxt))
+        );
+        assert_eq!(
+            parse_stack("ts/sveltekit"),
+            Some((Language::TypeScript, Framework::SvelteKit))
+        );
+        assert_eq!(
+            parse_stack("ts/astro"),
+            Some((Language::TypeScript, Framework::Astro))
+        );
+        assert_eq!(
+            parse_stack("ts/nestjs"),
+            Some((Language::TypeScript, Framework::NestJs))
+        );
+        assert_eq!(
+            parse_stack("python"),
+            Some((Language::Python, Framework::None))
+        );
+        assert_eq!(parse_stack("zig"), Some((Language::Zig, Framework::None)));
+        assert_eq!(parse_stack("zig/gin"), None);
+        assert_eq!(parse_stack("go/tauri"), None);
+        assert_eq!(parse_stack("java/spring"), None);
+        assert!(STACKS.iter().all(|stack| parse_stack(stack).is_some()));
+        // 帮助信息和错误提示中的框架列表覆盖所有框架
+        assert_eq!(stack_frameworks().len(), Framework::all().len() - 1);
+        assert!(
+            stack_frameworks()
+                .iter()
+                .all(|framework| Framework::parse_from_str(framework).is_some())
+        );
+    }
 
     #[test]
     fn test_to_pascal_case() {
@@ -299,6 +1718,18 @@ mod tests {
         assert_eq!(to_kebab_case("HelloWorld"), "hello-world");
         assert_eq!(to_kebab_case("TestProject"), "test-project");
         assert_eq!(to_kebab_case("single"), "single");
+        assert_eq!(to_kebab_case("my_app"), "my-app");
+        assert_eq!(to_kebab_case("my-app"), "my-app");
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("user"), "users");
+        assert_eq!(pluralize("box"), "boxes");
+        assert_eq!(pluralize("branch"), "branches");
+        assert_eq!(pluralize("category"), "categories");
+        assert_eq!(pluralize("key"), "keys");
+        assert_eq!(pluralize(""), "");
     }
 
     #[test]
@@ -306,5 +1737,7 @@ mod tests {
         assert_eq!(to_snake_case("HelloWorld"), "hello_world");
         assert_eq!(to_snake_case("TestProject"), "test_project");
         assert_eq!(to_snake_case("single"), "single");
+        assert_eq!(to_snake_case("my-service"), "my_service");
+        assert_eq!(to_snake_case("My Service"), "my_service");
     }
 }
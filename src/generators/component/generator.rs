@@ -0,0 +1,128 @@
+use anyhow::{Context, Result, bail};
+use std::path::Path;
+
+use super::parameters::ComponentParams;
+use crate::constants::Framework;
+use crate::generators::core::{
+    Generator, NextSteps, Parameters, TemplateProcessor, resolve_output_path,
+};
+use crate::template_engine::RenderContext;
+use crate::utils::code_inject::{self, CodeInjection, MARKER_PREFIX};
+use crate::utils::go_tools::GoTools;
+use crate::utils::render_only;
+
+/// 组件生成器：在已有项目中渲染单个组件模板，并在标记注释处插入注册代码
+///
+/// 与整项目生成不同，组件生成不会覆盖已有文件；缺少标记（如旧版本生成的项目）时提示手动注册
+pub struct ComponentGenerator {
+    template_processor: TemplateProcessor,
+}
+
+impl ComponentGenerator {
+    /// 创建新的组件生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            template_processor: TemplateProcessor::new()?,
+        })
+    }
+
+    /// 插入注册代码，返回修改过的文件
+    fn register(&self, params: &ComponentParams, project_path: &Path) -> Result<Vec<String>> {
+        let mut edited = Vec::new();
+        for registration in params.registrations() {
+            let path = project_path.join(&registration.file);
+            let content = std::fs::read_to_string(&path).unwrap_or_default();
+            if !code_inject::has_marker(&content, registration.marker) {
+                println!(
+                    "⚠️  No `// {MARKER_PREFIX}{}` marker in {}, add this line manually: {}",
+                    registration.marker, registration.file, registration.snippet
+                );
+                continue;
+            }
+            CodeInjection::new()
+                .with_snippet(registration.marker, &registration.snippet)
+                .apply(&path)?;
+            println!("✏️  Registered in: {}", registration.file);
+            edited.push(registration.file);
+        }
+        Ok(edited)
+    }
+}
+
+impl Default for ComponentGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create ComponentGenerator")
+    }
+}
+
+impl Generator for ComponentGenerator {
+    type Params = ComponentParams;
+
+    fn name(&self) -> &'static str {
+        "Component"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Adds a handler, model or middleware to a Gin project, or a component or view to a React or Vue3 project",
+        )
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "components"
+    }
+
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
+        params.validate()?;
+        // 先检查所有文件，任一文件已存在时不写入任何文件
+        let mut files = Vec::new();
+        for file in params.files() {
+            let path = resolve_output_path(output_path, &file.output)?;
+            if path.exists() {
+                bail!("{} already exists", file.output);
+            }
+            files.push((file, path));
+        }
+
+        let context = params.to_template_context();
+        let context = RenderContext::new(&context)?;
+        for (file, path) in &files {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            }
+            self.template_processor.render_embedded_file(
+                &file.template,
+                path,
+                &file.output,
+                &context,
+            )?;
+            println!("📝 Created: {}", file.output);
+        }
+
+        let mut edited = self.register(&params, output_path)?;
+        if params.framework != Framework::Gin || render_only::skip_tool("gofmt") {
+            return Ok(());
+        }
+        edited.extend(files.into_iter().map(|(file, _)| file.output));
+        let edited: Vec<&str> = edited.iter().map(String::as_str).collect();
+        GoTools::gofmt(output_path, &edited)
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        if params.framework == Framework::Gin {
+            steps.command(
+                "go build ./...",
+                format!(
+                    "Check that the project compiles with the new {}",
+                    params.kind
+                ),
+            );
+            return;
+        }
+        if let Some(command) = params.test_command() {
+            steps.command(command, format!("Run the new {} test", params.kind));
+        }
+        steps.command(params.runtime.run_command("dev"), "Start the dev server");
+    }
+}
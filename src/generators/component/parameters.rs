@@ -0,0 +1,590 @@
+use anyhow::{Result, bail};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::constants::{ComponentKind, Framework, Runtime, string_utils};
+use crate::generators::core::Parameters;
+
+/// 组件注册位置：在文件 `file` 的 `scafgen:<marker>` 标记处插入 `snippet`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registration {
+    pub file: String,
+    pub marker: &'static str,
+    pub snippet: String,
+}
+
+/// 组件生成的一个文件：组件模板和相对项目根目录的输出路径
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentFile {
+    pub template: String,
+    pub output: String,
+}
+
+/// 单个组件的生成参数，由 `scafgen generate` 在已有项目中使用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentParams {
+    /// 项目框架，决定组件模板目录
+    pub framework: Framework,
+    /// 组件类型
+    pub kind: ComponentKind,
+    /// 命令行中给出的组件名，如 `user`、`order_item`、`Button`
+    pub name: String,
+    /// 项目使用 GORM（模型嵌入 `gorm.Model` 并注册自动迁移）
+    pub database_gorm: bool,
+    /// 项目使用 MongoDB（模型使用 bson 标签）
+    pub database_mongo: bool,
+    /// 项目安装了组件测试库（React Testing Library 或 Vue Test Utils），同时生成测试文件
+    pub enable_tests: bool,
+    /// 项目使用的 Storybook 框架包，如 `@storybook/react-vite`，安装了 Storybook 时同时生成 story
+    pub storybook: Option<String>,
+    /// Vue3 项目使用 TypeScript（create-vue 的 `--typescript`）
+    pub typescript: bool,
+    /// 项目使用的 JavaScript 运行时，按锁文件判断
+    pub runtime: Runtime,
+}
+
+impl Default for ComponentParams {
+    fn default() -> Self {
+        Self::new(
+            Framework::Gin,
+            ComponentKind::Handler,
+            "example".to_string(),
+        )
+    }
+}
+
+impl ComponentParams {
+    pub fn new(framework: Framework, kind: ComponentKind, name: String) -> Self {
+        Self {
+            framework,
+            kind,
+            name,
+            database_gorm: false,
+            database_mongo: false,
+            enable_tests: false,
+            storybook: None,
+            typescript: true,
+            runtime: Runtime::Node,
+        }
+    }
+
+    /// 同时生成测试文件
+    pub fn with_tests(mut self, enable_tests: bool) -> Self {
+        self.enable_tests = enable_tests;
+        self
+    }
+
+    /// 使用 Storybook 框架包 `package` 生成 story
+    pub fn with_storybook(mut self, package: Option<String>) -> Self {
+        self.storybook = package;
+        self
+    }
+
+    /// 各框架支持的组件类型
+    pub fn supported_kinds(framework: Framework) -> &'static [ComponentKind] {
+        match framework {
+            Framework::Gin => &[
+                ComponentKind::Handler,
+                ComponentKind::Model,
+                ComponentKind::Middleware,
+            ],
+            Framework::React | Framework::Vue3 => &[ComponentKind::Component, ComponentKind::View],
+            _ => &[],
+        }
+    }
+
+    /// 读取 `project_path` 中的项目，按项目的框架、数据库、测试库和 Storybook 设置参数
+    ///
+    /// 未指定框架时按 go.mod 和 package.json 识别
+    pub fn for_project(
+        framework: Option<Framework>,
+        kind: ComponentKind,
+        name: String,
+        project_path: &Path,
+    ) -> Result<Self> {
+        let read =
+            |file: &str| std::fs::read_to_string(project_path.join(file)).unwrap_or_default();
+        let package_json: Value = serde_json::from_str(&read("package.json")).unwrap_or_default();
+        let dependencies: Vec<&str> = ["dependencies", "devDependencies"]
+            .iter()
+            .filter_map(|key| package_json.get(key).and_then(Value::as_object))
+            .flat_map(|deps| deps.keys().map(String::as_str))
+            .collect();
+        let has = |package: &str| dependencies.contains(&package);
+
+        let framework = match framework {
+            Some(framework) => framework,
+            None if project_path.join("go.mod").is_file() => Framework::Gin,
+            None if has("vue") && !has("nuxt") => Framework::Vue3,
+            None if has("react") && !has("next") => Framework::React,
+            None => bail!(
+                "Cannot detect the framework of {}; pass --framework (gin, react or vue3)",
+                project_path.display()
+            ),
+        };
+        let supported = Self::supported_kinds(framework);
+        if supported.is_empty() {
+            bail!(
+                "scafgen generate does not support {} projects. Supported frameworks: gin, react, vue3",
+                framework.as_str()
+            );
+        }
+        if !supported.contains(&kind) {
+            let names: Vec<&str> = supported.iter().map(ComponentKind::as_str).collect();
+            bail!(
+                "{} projects have no '{kind}' components. Supported types: {}",
+                framework.as_str(),
+                names.join(", ")
+            );
+        }
+        let params = Self::new(framework, kind, name);
+
+        if framework == Framework::Gin {
+            let go_mod = read("go.mod");
+            if go_mod.is_empty() {
+                bail!("No go.mod found in {}", project_path.display());
+            }
+            if !read("routers/enter.go").contains("RouterGroupApp")
+                || !read("routers/api.go").contains("github.com/gin-gonic/gin")
+            {
+                bail!(
+                    "{} is not a Gin project generated by scafgen (routers/enter.go or routers/api.go is missing)",
+                    project_path.display()
+                );
+            }
+            // 只渲染模板时 go.mod 中还没有依赖，同时查看数据库相关的源文件
+            let sources = [
+                go_mod,
+                read("models/user.go"),
+                read("pkg/database/database.go"),
+            ]
+            .concat();
+            return Ok(Self {
+                database_gorm: sources.contains("gorm.io/gorm"),
+                database_mongo: sources.contains("go.mongodb.org/mongo-driver"),
+                ..params
+            });
+        }
+
+        let expected = if framework == Framework::Vue3 {
+            "vue"
+        } else {
+            "react"
+        };
+        if !has(expected) {
+            bail!(
+                "{} is not a {} project (no {expected} dependency in package.json)",
+                project_path.display(),
+                framework.as_str()
+            );
+        }
+        let (test_library, storybook_packages) = match framework {
+            Framework::Vue3 => (
+                "@vue/test-utils",
+                ["@storybook/vue3-vite", "@storybook/vue3"],
+            ),
+            _ => (
+                "@testing-library/react",
+                ["@storybook/react-vite", "@storybook/react"],
+            ),
+        };
+        let runtime = [Runtime::Bun, Runtime::Deno]
+            .into_iter()
+            .find(|runtime| project_path.join(runtime.lockfile()).exists())
+            .unwrap_or(Runtime::Node);
+        Ok(Self {
+            typescript: framework == Framework::React || has("typescript"),
+            runtime,
+            ..params
+                .with_tests(has("vitest") && has(test_library))
+                .with_storybook(
+                    storybook_packages
+                        .into_iter()
+                        .find(|package| has(package))
+                        .map(str::to_string),
+                )
+        })
+    }
+
+    /// 组件模板目录
+    pub fn template_path(&self) -> &'static str {
+        match self.framework {
+            Framework::Vue3 => "components/typescript/vue3",
+            Framework::React => "components/typescript/react",
+            _ => "components/go/gin",
+        }
+    }
+
+    /// 组件的类型名：Gin 和前端组件为 PascalCase 名称，视图加上 `View`（Vue3）或 `Page`（React）后缀
+    pub fn type_name(&self) -> String {
+        let name = string_utils::to_pascal_case(&self.name);
+        match self.view_suffix() {
+            Some(suffix) if !name.ends_with(suffix) => format!("{name}{suffix}"),
+            _ => name,
+        }
+    }
+
+    fn view_suffix(&self) -> Option<&'static str> {
+        match (self.kind, self.framework) {
+            (ComponentKind::View, Framework::Vue3) => Some("View"),
+            (ComponentKind::View, _) => Some("Page"),
+            _ => None,
+        }
+    }
+
+    /// 视图的路由地址，如 `SettingsView` 为 `/settings`
+    fn view_route(&self) -> String {
+        let type_name = self.type_name();
+        let base = self
+            .view_suffix()
+            .and_then(|suffix| type_name.strip_suffix(suffix))
+            .filter(|base| !base.is_empty())
+            .unwrap_or(&type_name);
+        format!("/{}", string_utils::to_kebab_case(base))
+    }
+
+    /// 要生成的文件：组件本身，以及项目支持时的测试和 story
+    pub fn files(&self) -> Vec<ComponentFile> {
+        let template_path = self.template_path();
+        let file = |template: &str, output: String| ComponentFile {
+            template: format!("{template_path}/{template}.tmpl"),
+            output,
+        };
+        let type_name = self.type_name();
+        let script = if self.typescript { "ts" } else { "js" };
+
+        let mut files = Vec::new();
+        match (self.framework, self.kind) {
+            (Framework::Gin, kind) => {
+                let package = match kind {
+                    ComponentKind::Model => "models",
+                    ComponentKind::Middleware => "middlewares",
+                    _ => "routers",
+                };
+                let snake = string_utils::to_snake_case(&self.name);
+                files.push(file(&format!("{kind}.go"), format!("{package}/{snake}.go")));
+            }
+            (Framework::React, ComponentKind::View) => {
+                // src/pages 下的每个 .tsx 文件都会成为路由，测试放在 src/routes/__tests__ 中
+                let page = self.view_route();
+                files.push(file("view.tsx", format!("src/pages{page}.tsx")));
+                if self.enable_tests {
+                    files.push(file(
+                        "view.test.tsx",
+                        format!("src/routes/__tests__/{type_name}.test.tsx"),
+                    ));
+                }
+            }
+            (Framework::React, _) => {
+                files.push(file(
+                    "component.tsx",
+                    format!("src/components/{type_name}.tsx"),
+                ));
+                if self.enable_tests {
+                    files.push(file(
+                        "component.test.tsx",
+                        format!("src/components/__tests__/{type_name}.test.tsx"),
+                    ));
+                }
+                if self.storybook.is_some() {
+                    files.push(file(
+                        "component.stories.tsx",
+                        format!("src/components/{type_name}.stories.tsx"),
+                    ));
+                }
+            }
+            (_, kind) => {
+                let dir = if kind == ComponentKind::View {
+                    "views"
+                } else {
+                    "components"
+                };
+                files.push(file(
+                    &format!("{kind}.vue"),
+                    format!("src/{dir}/{type_name}.vue"),
+                ));
+                if self.enable_tests {
+                    files.push(file(
+                        &format!("{kind}.spec.ts"),
+                        format!("src/{dir}/__tests__/{type_name}.spec.{script}"),
+                    ));
+                }
+                if kind == ComponentKind::Component && self.storybook.is_some() {
+                    files.push(file(
+                        "component.stories.ts",
+                        format!("src/components/{type_name}.stories.{script}"),
+                    ));
+                }
+            }
+        }
+        files
+    }
+
+    /// 组件需要插入到已有代码中的注册语句；React 页面按文件系统路由，不需要注册
+    pub fn registrations(&self) -> Vec<Registration> {
+        let type_name = self.type_name();
+        let registration = |file: &str, marker, snippet| Registration {
+            file: file.to_string(),
+            marker,
+            snippet,
+        };
+        match (self.framework, self.kind) {
+            (Framework::Gin, ComponentKind::Handler) => vec![
+                registration(
+                    "routers/enter.go",
+                    "routers",
+                    format!("{type_name} {type_name}Router"),
+                ),
+                registration(
+                    "routers/api.go",
+                    "routes",
+                    format!("RouterGroupApp.{type_name}.Init{type_name}Routes(v1)"),
+                ),
+            ],
+            (Framework::Gin, ComponentKind::Model) if self.database_gorm => vec![registration(
+                "core/app.go",
+                "models",
+                format!("&models.{type_name}{{}},"),
+            )],
+            (Framework::Gin, ComponentKind::Middleware) => vec![registration(
+                "middlewares/register_mdw.go",
+                "middlewares",
+                format!("AddMiddleware({type_name}())"),
+            )],
+            (Framework::Vue3, ComponentKind::View) => {
+                let route = self.view_route();
+                let router = if self.typescript {
+                    "src/router/index.ts"
+                } else {
+                    "src/router/index.js"
+                };
+                vec![registration(
+                    router,
+                    "routes",
+                    format!(
+                        "{{\n  path: '{route}',\n  name: '{}',\n  component: () => import('../views/{type_name}.vue'),\n}},",
+                        route.trim_start_matches('/')
+                    ),
+                )]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// 运行新生成测试的命令
+    pub fn test_command(&self) -> Option<String> {
+        let test = self
+            .files()
+            .into_iter()
+            .find(|file| file.output.contains("__tests__"))?;
+        Some(
+            self.runtime
+                .exec_command(&format!("vitest run {}", test.output)),
+        )
+    }
+}
+
+impl Parameters for ComponentParams {
+    fn validate(&self) -> Result<()> {
+        let valid = self
+            .name
+            .chars()
+            .next()
+            .is_some_and(|ch| ch.is_ascii_alphabetic())
+            && self
+                .name
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-');
+        if !valid {
+            bail!(
+                "Invalid {} name '{}': use letters, digits, '_' or '-', starting with a letter",
+                self.kind,
+                self.name
+            );
+        }
+        Ok(())
+    }
+
+    fn to_template_context(&self) -> HashMap<String, Value> {
+        let mut context = HashMap::from([("component_type".to_string(), json!(self.type_name()))]);
+        match self.framework {
+            Framework::Gin => {
+                context.insert("component_name".to_string(), json!(self.name));
+                let snake = string_utils::to_snake_case(&self.name);
+                let kebab = string_utils::to_kebab_case(&self.name);
+                // 资源路由使用复数形式，如 `/orders`
+                context.insert(
+                    "component_route".to_string(),
+                    json!(format!("/{}", string_utils::pluralize(&kebab))),
+                );
+                context.insert(
+                    "component_collection".to_string(),
+                    json!(string_utils::pluralize(&snake)),
+                );
+                context.insert("database_gorm".to_string(), json!(self.database_gorm));
+                context.insert("database_mongo".to_string(), json!(self.database_mongo));
+            }
+            _ => {
+                // 视图标题由名称拆分单词得到，如 `UserProfile` 为 `User Profile`
+                let route = self.view_route();
+                let title = route
+                    .trim_start_matches('/')
+                    .split('-')
+                    .map(string_utils::to_pascal_case)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                context.insert("component_route".to_string(), json!(route));
+                context.insert("component_title".to_string(), json!(title));
+                context.insert(
+                    "storybook_package".to_string(),
+                    json!(self.storybook.as_deref().unwrap_or_default()),
+                );
+                if self.framework == Framework::Vue3 {
+                    context.insert("typescript".to_string(), json!(self.typescript));
+                }
+            }
+        }
+        context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outputs(params: &ComponentParams) -> Vec<String> {
+        params.files().into_iter().map(|file| file.output).collect()
+    }
+
+    #[test]
+    fn test_gin_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        assert!(
+            ComponentParams::for_project(
+                Some(Framework::Gin),
+                ComponentKind::Model,
+                "order".into(),
+                root
+            )
+            .is_err()
+        );
+
+        std::fs::create_dir_all(root.join("routers")).unwrap();
+        std::fs::create_dir_all(root.join("models")).unwrap();
+        std::fs::write(root.join("go.mod"), "module demo\n\ngo 1.22\n").unwrap();
+        std::fs::write(
+            root.join("routers/enter.go"),
+            "var RouterGroupApp = new(RouterGroup)\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("routers/api.go"),
+            "import \"github.com/gin-gonic/gin\"\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("models/user.go"), "import \"gorm.io/gorm\"\n").unwrap();
+
+        let params =
+            ComponentParams::for_project(None, ComponentKind::Model, "order_item".into(), root)
+                .unwrap();
+        assert!(params.database_gorm && !params.database_mongo);
+        assert_eq!(outputs(&params), ["models/order_item.go"]);
+        assert_eq!(params.registrations()[0].snippet, "&models.OrderItem{},");
+        let context = params.to_template_context();
+        assert_eq!(context["component_type"], json!("OrderItem"));
+        assert_eq!(context["component_route"], json!("/order-items"));
+        assert!(
+            ComponentParams::for_project(None, ComponentKind::View, "home".into(), root).is_err()
+        );
+
+        let handler = ComponentParams::new(Framework::Gin, ComponentKind::Handler, "user".into());
+        assert_eq!(outputs(&handler), ["routers/user.go"]);
+        assert_eq!(
+            handler.registrations()[1].snippet,
+            "RouterGroupApp.User.InitUserRoutes(v1)"
+        );
+        assert!(handler.validate().is_ok());
+        assert!(
+            ComponentParams::new(Framework::Gin, ComponentKind::Handler, "1user".into())
+                .validate()
+                .is_err()
+        );
+        assert!(
+            ComponentParams::new(Framework::Gin, ComponentKind::Handler, "user.go".into())
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_frontend_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("package.json"),
+            r#"{"dependencies": {"react": "^19"}, "devDependencies": {"vitest": "^3", "@testing-library/react": "^16", "@storybook/react-vite": "^9"}}"#,
+        )
+        .unwrap();
+
+        let button =
+            ComponentParams::for_project(None, ComponentKind::Component, "Button".into(), root)
+                .unwrap();
+        assert_eq!(button.framework, Framework::React);
+        assert_eq!(
+            outputs(&button),
+            [
+                "src/components/Button.tsx",
+                "src/components/__tests__/Button.test.tsx",
+                "src/components/Button.stories.tsx"
+            ]
+        );
+        assert_eq!(
+            button.test_command().as_deref(),
+            Some("pnpm exec vitest run src/components/__tests__/Button.test.tsx")
+        );
+
+        let page =
+            ComponentParams::for_project(None, ComponentKind::View, "user_profile".into(), root)
+                .unwrap();
+        assert_eq!(
+            outputs(&page),
+            [
+                "src/pages/user-profile.tsx",
+                "src/routes/__tests__/UserProfilePage.test.tsx"
+            ]
+        );
+        assert_eq!(
+            page.to_template_context()["component_title"],
+            json!("User Profile")
+        );
+        assert!(page.registrations().is_empty());
+        assert!(
+            ComponentParams::for_project(
+                Some(Framework::Vue3),
+                ComponentKind::View,
+                "Settings".into(),
+                root
+            )
+            .is_err()
+        );
+
+        // 没有 TypeScript 和测试库的 Vue3 项目只生成视图本身
+        std::fs::write(
+            root.join("package.json"),
+            r#"{"dependencies": {"vue": "^3.5"}}"#,
+        )
+        .unwrap();
+        let view = ComponentParams::for_project(None, ComponentKind::View, "Settings".into(), root)
+            .unwrap();
+        assert_eq!(view.type_name(), "SettingsView");
+        assert_eq!(outputs(&view), ["src/views/SettingsView.vue"]);
+        let registration = &view.registrations()[0];
+        assert_eq!(registration.file, "src/router/index.js");
+        assert_eq!(
+            registration.snippet,
+            "{\n  path: '/settings',\n  name: 'settings',\n  component: () => import('../views/SettingsView.vue'),\n},"
+        );
+    }
+}
@@ -3,8 +3,11 @@ use chrono::Datelike;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use super::parameters::Parameters;
+use crate::constants::{CI_PROVIDERS, CiProvider, defaults};
+use crate::utils::spdx;
 
 /// 基础参数结构 - 包含所有生成器共用的参数
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,7 +17,21 @@ pub struct BaseParams {
     pub project_version: String,
     pub project_description: Option<String>,
     pub author: Option<String>,
+    /// 作者邮箱，写入 Cargo.toml、package.json 和 pyproject.toml 的作者信息
+    #[serde(default)]
+    pub author_email: Option<String>,
     pub license: String,
+    /// LICENSE 中的版权人，未设置时使用作者
+    #[serde(default)]
+    pub license_holder: Option<String>,
+    /// LICENSE 中的版权年份（如 `2020-2026`），未设置时使用当前年份
+    #[serde(default)]
+    pub license_year: Option<String>,
+    /// 自定义许可证文本，设置时复制为 LICENSE 而不使用许可证模板
+    #[serde(default)]
+    pub license_file: Option<PathBuf>,
+    pub keywords: Vec<String>,
+    pub homepage: Option<String>,
 
     // Git相关
     pub enable_git: bool,
@@ -34,8 +51,17 @@ pub struct BaseParams {
     pub enable_database: bool,
     pub enable_redis: bool,
     pub enable_grpc: bool,
+    /// 按 `--openapi` 规范生成路由、处理器和模型
+    #[serde(default)]
+    pub enable_openapi: bool,
     pub enable_middleware: bool,
 
+    // 工程化功能开关（由 --profile 控制）
+    pub enable_docker: bool,
+    pub enable_ci: bool,
+    pub ci_provider: CiProvider,
+    pub enable_tests: bool,
+
     // 网络配置
     pub default_host: Option<String>,
     pub default_port: Option<u16>,
@@ -60,7 +86,13 @@ impl Default for BaseParams {
             project_version: "0.1.0".to_string(),
             project_description: None,
             author: None,
+            author_email: None,
             license: "MIT".to_string(),
+            license_holder: None,
+            license_year: None,
+            license_file: None,
+            keywords: Vec::new(),
+            homepage: None,
 
             // Git相关
             enable_git: true,
@@ -80,8 +112,15 @@ impl Default for BaseParams {
             enable_database: false,
             enable_redis: false,
             enable_grpc: false,
+            enable_openapi: false,
             enable_middleware: true,
 
+            // 工程化功能开关
+            enable_docker: true,
+            enable_ci: false,
+            ci_provider: CiProvider::default(),
+            enable_tests: true,
+
             // 网络配置
             default_host: None,
             default_port: None,
@@ -90,7 +129,7 @@ impl Default for BaseParams {
             database_type: None,
 
             // 语言特定配置
-            language_version: Some("1.21".to_string()), // Go默认版本
+            language_version: Some(defaults::GO_VERSION.to_string()), // Go默认版本
             module_name: None,
             enable_modules: true,
             enable_cgo: false,
@@ -118,6 +157,14 @@ impl Parameters for BaseParams {
             validation::validate_port(port)?;
         }
 
+        for keyword in &self.keywords {
+            validation::validate_keyword(keyword)?;
+        }
+
+        if let Some(ref homepage) = self.homepage {
+            validation::validate_homepage(homepage)?;
+        }
+
         if self.enable_database && self.database_type.is_none() {
             return Err(anyhow::anyhow!(
                 "Database type must be specified when database is enabled"
@@ -128,12 +175,28 @@ impl Parameters for BaseParams {
     }
 
     fn to_template_context(&self) -> HashMap<String, Value> {
+        use super::validation;
+
         let mut context = HashMap::new();
 
         // 项目基础信息
         context.insert("project_name".to_string(), json!(self.project_name));
         context.insert("project_version".to_string(), json!(self.project_version));
         context.insert("license".to_string(), json!(self.license));
+        // 组合许可证（如 `MIT OR Apache-2.0`）每个许可证一个 LICENSE-* 文件
+        let license_files: Vec<Value> = spdx::license_files(&self.license)
+            .into_iter()
+            .map(|(id, file)| json!({ "id": id, "file": file }))
+            .collect();
+        context.insert(
+            "compound_license".to_string(),
+            json!(license_files.len() > 1),
+        );
+        context.insert("license_files".to_string(), json!(license_files));
+        context.insert(
+            "package_json_license".to_string(),
+            json!(spdx::package_json_license(&self.license)),
+        );
 
         // 项目名称的不同格式
         context.insert(
@@ -167,14 +230,48 @@ impl Parameters for BaseParams {
             context.insert("cargo_description".to_string(), json!(default_description));
         }
 
+        // JSON 编码的描述，可直接嵌入 TOML/JSON 清单文件
+        let description = context["project_description"].clone();
+        context.insert(
+            "project_description_json".to_string(),
+            json!(description.to_string()),
+        );
+
+        // 关键字与主页
+        context.insert("keywords".to_string(), json!(self.keywords));
+        context.insert(
+            "keywords_json".to_string(),
+            json!(json!(self.keywords).to_string()),
+        );
+        if let Some(ref homepage) = self.homepage {
+            context.insert("homepage".to_string(), json!(homepage));
+        }
+
         // 作者信息
         if let Some(ref author) = self.author {
             context.insert("author".to_string(), json!(author));
+            // Cargo.toml 和 package.json 中 `Name <email>` 形式的作者，JSON 字符串可直接作为 TOML 字符串使用
+            context.insert(
+                "author_json".to_string(),
+                json!(json!(self.author_contact()).to_string()),
+            );
+        }
+        if let Some(ref email) = self.author_email {
+            context.insert("author_email".to_string(), json!(email));
         }
 
         // 当前年份
         let current_year = chrono::Utc::now().year();
         context.insert("year".to_string(), json!(current_year));
+        // LICENSE 中的版权年份，`{{year}}` 会被同名辅助函数解析，因此单独命名
+        context.insert(
+            "copyright_year".to_string(),
+            json!(
+                self.license_year
+                    .clone()
+                    .unwrap_or_else(|| current_year.to_string())
+            ),
+        );
 
         // Git相关
         context.insert("enable_git".to_string(), json!(self.enable_git));
@@ -182,6 +279,7 @@ impl Parameters for BaseParams {
 
         // 服务器配置
         if let Some(ref host) = self.host {
+            let host = validation::normalize_host(host);
             context.insert("host".to_string(), json!(host));
             context.insert("default_host".to_string(), json!(host));
         }
@@ -189,6 +287,13 @@ impl Parameters for BaseParams {
             context.insert("port".to_string(), json!(port));
             context.insert("default_port".to_string(), json!(port));
         }
+        // host:port 组合（IPv6 地址会带方括号，如 [::1]:8080）
+        if let (Some(host), Some(port)) = (&self.host, self.port) {
+            context.insert(
+                "host_port".to_string(),
+                json!(validation::format_host_port(host, port)),
+            );
+        }
 
         // 通用功能开关
         context.insert("enable_swagger".to_string(), json!(self.enable_swagger));
@@ -202,6 +307,21 @@ impl Parameters for BaseParams {
         context.insert("enable_jwt".to_string(), json!(self.enable_jwt));
         context.insert("enable_database".to_string(), json!(self.enable_database));
         context.insert("enable_redis".to_string(), json!(self.enable_redis));
+        context.insert("enable_grpc".to_string(), json!(self.enable_grpc));
+        context.insert("enable_openapi".to_string(), json!(self.enable_openapi));
+
+        // 工程化功能开关
+        context.insert("enable_docker".to_string(), json!(self.enable_docker));
+        context.insert("enable_ci".to_string(), json!(self.enable_ci));
+        // 每个平台一个开关，对应模板中的 +ci-<平台>/ 片段目录
+        context.insert("ci_provider".to_string(), json!(self.ci_provider.as_str()));
+        for provider in CI_PROVIDERS {
+            context.insert(
+                format!("enable_ci_{provider}"),
+                json!(self.ci_provider.as_str() == *provider),
+            );
+        }
+        context.insert("enable_tests".to_string(), json!(self.enable_tests));
 
         // 数据库配置
         if let Some(ref db_type) = self.database_type {
@@ -247,9 +367,38 @@ impl BaseParams {
         self
     }
 
-    /// 设置许可证
+    /// 设置作者邮箱
+    pub fn with_author_email(mut self, email: String) -> Self {
+        self.author_email = Some(email);
+        self
+    }
+
+    /// `Name <email>` 形式的作者信息，没有邮箱时只有名字
+    pub fn author_contact(&self) -> Option<String> {
+        let author = self.author.as_ref()?;
+        Some(match self.author_email {
+            Some(ref email) => format!("{author} <{email}>"),
+            None => author.clone(),
+        })
+    }
+
+    /// 设置许可证，可以是 `MIT OR Apache-2.0` 等组合表达式
     pub fn with_license(mut self, license: String) -> Self {
-        self.license = license;
+        self.license = spdx::normalize_expression(&license);
+        self
+    }
+
+    /// 设置关键字
+    #[allow(dead_code)]
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// 设置项目主页
+    #[allow(dead_code)]
+    pub fn with_homepage(mut self, homepage: String) -> Self {
+        self.homepage = Some(homepage);
         self
     }
 
@@ -288,6 +437,8 @@ impl BaseParams {
             "jwt" => self.enable_jwt = true,
             "database" => self.enable_database = true,
             "redis" => self.enable_redis = true,
+            "grpc" => self.enable_grpc = true,
+            "openapi" => self.enable_openapi = true,
             "git" => self.enable_git = true,
             "precommit" => self.enable_precommit = true,
             "modules" => self.enable_modules = true,
@@ -310,6 +461,8 @@ impl BaseParams {
             "jwt" => self.enable_jwt = false,
             "database" => self.enable_database = false,
             "redis" => self.enable_redis = false,
+            "grpc" => self.enable_grpc = false,
+            "openapi" => self.enable_openapi = false,
             "git" => self.enable_git = false,
             "precommit" => self.enable_precommit = false,
             "modules" => self.enable_modules = false,
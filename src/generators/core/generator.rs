@@ -3,8 +3,10 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::path::Path;
 
+use super::next_steps::NextSteps;
 use super::parameters::Parameters;
 use super::template_processor::TemplateProcessor;
+use crate::status;
 
 /// 核心生成器trait，定义所有生成器的基础接口
 pub trait Generator {
@@ -24,17 +26,17 @@ pub trait Generator {
     fn get_template_path(&self) -> &'static str;
 
     /// 生成代码 - 默认实现使用嵌入式模板渲染
-    fn generate(&mut self, params: Self::Params, output_path: &Path) -> Result<()> {
-        let mut template_processor = TemplateProcessor::new()?;
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
+        let template_processor = TemplateProcessor::new()?;
         let template_path = self.get_template_path();
         let context = params.to_template_context();
 
-        println!("Generating {} structure", self.name());
+        status!("Generating {} structure", self.name());
 
         // 检查嵌入式模板目录是否存在
         if crate::template_engine::embedded_template_dir_exists(template_path) {
             self.render_embedded_templates(
-                &mut template_processor,
+                &template_processor,
                 template_path,
                 output_path,
                 context,
@@ -48,14 +50,14 @@ pub trait Generator {
             ));
         }
 
-        println!("{} structure generated", self.name());
+        status!("{} structure generated", self.name());
         Ok(())
     }
 
     /// 渲染嵌入式模板 - 可以被子类重写以实现自定义逻辑
     fn render_embedded_templates(
-        &mut self,
-        template_processor: &mut TemplateProcessor,
+        &self,
+        template_processor: &TemplateProcessor,
         template_path: &str,
         output_path: &Path,
         context: HashMap<String, Value>,
@@ -67,26 +69,31 @@ pub trait Generator {
 
     /// 后处理逻辑，在生成完成后执行
     #[allow(dead_code)]
-    fn post_process(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn post_process(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 默认实现为空
         Ok(())
     }
+
+    /// 补充生成完成后的后续步骤（命令、访问地址、注意事项）
+    fn next_steps(&self, _params: &Self::Params, _steps: &mut NextSteps) {
+        // 默认不提供后续步骤
+    }
 }
 
 /// 项目级别生成器trait
 pub trait ProjectGenerator: Generator {
     /// 生成许可证文件
-    fn generate_license(&mut self, params: &Self::Params, output_path: &Path) -> Result<()>;
+    fn generate_license(&self, params: &Self::Params, output_path: &Path) -> Result<()>;
 
     /// 初始化Git仓库
-    fn init_git_repository(&mut self, output_path: &Path) -> Result<()>;
+    fn init_git_repository(&self, output_path: &Path) -> Result<()>;
 
     /// 生成README文件 (预留给未来的文档生成功能)
     #[allow(dead_code)]
-    fn generate_readme(&mut self, params: &Self::Params, output_path: &Path) -> Result<()>;
+    fn generate_readme(&self, params: &Self::Params, output_path: &Path) -> Result<()>;
 
     /// 安装 pre-commit hooks
-    fn install_precommit(&mut self, output_path: &Path) -> Result<()>;
+    fn install_precommit(&self, output_path: &Path) -> Result<()>;
 }
 
 /// 语言级别生成器trait (预留给未来的多语言支持扩展)
@@ -97,12 +104,11 @@ pub trait LanguageGenerator: Generator {
 
     /// 设置语言环境 (预留给未来的环境配置功能)
     #[allow(dead_code)]
-    fn setup_environment(&mut self, params: &Self::Params, output_path: &Path) -> Result<()>;
+    fn setup_environment(&self, params: &Self::Params, output_path: &Path) -> Result<()>;
 
     /// 生成语言配置文件 (预留给未来的语言特定配置生成)
     #[allow(dead_code)]
-    fn generate_language_config(&mut self, params: &Self::Params, output_path: &Path)
-    -> Result<()>;
+    fn generate_language_config(&self, params: &Self::Params, output_path: &Path) -> Result<()>;
 }
 
 /// 框架级别生成器trait
@@ -116,14 +122,14 @@ pub trait FrameworkGenerator: Generator {
     fn language(&self) -> &'static str;
 
     /// 生成基础结构
-    fn generate_basic_structure(&mut self, params: &Self::Params, output_path: &Path)
-    -> Result<()>;
+    #[allow(dead_code)]
+    fn generate_basic_structure(&self, params: &Self::Params, output_path: &Path) -> Result<()>;
 
     /// 生成配置文件
     #[allow(dead_code)]
-    fn generate_config(&mut self, params: &Self::Params, output_path: &Path) -> Result<()>;
+    fn generate_config(&self, params: &Self::Params, output_path: &Path) -> Result<()>;
 
     /// 生成中间件
     #[allow(dead_code)]
-    fn generate_middleware(&mut self, params: &Self::Params, output_path: &Path) -> Result<()>;
+    fn generate_middleware(&self, params: &Self::Params, output_path: &Path) -> Result<()>;
 }
@@ -1,9 +1,11 @@
 pub mod base_parameters;
 pub mod generator;
+pub mod next_steps;
 pub mod parameters;
 pub mod template_processor;
 
 pub use base_parameters::*;
 pub use generator::*;
+pub use next_steps::*;
 pub use parameters::*;
 pub use template_processor::*;
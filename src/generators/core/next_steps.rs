@@ -0,0 +1,229 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::validation;
+
+/// README 中后续步骤章节的标题
+const README_SECTION: &str = "## Next Steps";
+
+/// 生成完成后的后续步骤：常用命令、访问地址和注意事项
+///
+/// 由各生成器通过 [`Generator::next_steps`](super::Generator::next_steps) 补充，
+/// 生成结束后输出到终端并写入项目 README
+#[derive(Debug, Clone, Default)]
+pub struct NextSteps {
+    commands: Vec<(String, String)>,
+    urls: Vec<(String, String)>,
+    caveats: Vec<String>,
+}
+
+impl NextSteps {
+    /// 创建空的后续步骤
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加一条命令及其说明
+    pub fn command(&mut self, command: impl Into<String>, description: impl Into<String>) {
+        self.commands.push((command.into(), description.into()));
+    }
+
+    /// 添加一个访问地址
+    pub fn url(&mut self, label: impl Into<String>, url: impl Into<String>) {
+        self.urls.push((label.into(), url.into()));
+    }
+
+    /// 添加一条注意事项（如被跳过的步骤）
+    pub fn caveat(&mut self, caveat: impl Into<String>) {
+        self.caveats.push(caveat.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty() && self.urls.is_empty() && self.caveats.is_empty()
+    }
+
+    /// 命令列表，说明按最长命令对齐
+    fn command_lines(&self) -> Vec<String> {
+        let width = self
+            .commands
+            .iter()
+            .map(|(command, _)| command.len())
+            .max()
+            .unwrap_or(0);
+
+        self.commands
+            .iter()
+            .map(|(command, description)| {
+                if description.is_empty() {
+                    command.clone()
+                } else {
+                    format!("{command:width$}  # {description}")
+                }
+            })
+            .collect()
+    }
+
+    /// 渲染为终端输出，每行带两个空格缩进
+    pub fn render_text(&self) -> String {
+        let mut lines: Vec<String> = self
+            .command_lines()
+            .into_iter()
+            .map(|line| format!("  {line}"))
+            .collect();
+
+        if !self.urls.is_empty() {
+            lines.push("URLs:".to_string());
+            for (label, url) in &self.urls {
+                lines.push(format!("  {label}: {url}"));
+            }
+        }
+
+        if !self.caveats.is_empty() {
+            lines.push("Notes:".to_string());
+            for caveat in &self.caveats {
+                lines.push(format!("  - {caveat}"));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// 渲染为 README 章节
+    pub fn render_markdown(&self) -> String {
+        let mut output = format!("{README_SECTION}\n\n");
+
+        if !self.commands.is_empty() {
+            output.push_str("```bash\n");
+            for line in self.command_lines() {
+                output.push_str(&line);
+                output.push('\n');
+            }
+            output.push_str("```\n\n");
+        }
+
+        for (label, url) in &self.urls {
+            output.push_str(&format!("- {label}: <{url}>\n"));
+        }
+        if !self.urls.is_empty() {
+            output.push('\n');
+        }
+
+        if !self.caveats.is_empty() {
+            output.push_str("Notes:\n\n");
+            for caveat in &self.caveats {
+                output.push_str(&format!("- {caveat}\n"));
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// 将后续步骤写入项目 README：放在 License 章节之前，已存在的同名章节会被替换
+    pub fn write_to_readme(&self, project_path: &Path) -> Result<()> {
+        let readme_path = project_path.join("README.md");
+        if self.is_empty() || !readme_path.exists() {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&readme_path)
+            .with_context(|| format!("Failed to read {}", readme_path.display()))?;
+        std::fs::write(
+            &readme_path,
+            insert_section(&content, &self.render_markdown()),
+        )
+        .with_context(|| format!("Failed to write {}", readme_path.display()))?;
+
+        Ok(())
+    }
+}
+
+/// 本机访问地址，监听所有地址（0.0.0.0 / ::）时使用 localhost
+pub fn local_url(host: &str, port: u16, path: &str) -> String {
+    let host = match validation::normalize_host(host).as_str() {
+        "0.0.0.0" | "::" => "localhost".to_string(),
+        host => host.to_string(),
+    };
+    format!("http://{}{path}", validation::format_host_port(&host, port))
+}
+
+/// 在 Markdown 文档中插入章节：替换已有的同名章节，否则插入到 License 章节之前或文档末尾
+fn insert_section(content: &str, section: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    // 移除已有的后续步骤章节
+    if let Some(start) = lines.iter().position(|line| line.trim() == README_SECTION) {
+        let end = lines[start + 1..]
+            .iter()
+            .position(|line| line.starts_with("## "))
+            .map_or(lines.len(), |offset| start + 1 + offset);
+        lines.drain(start..end);
+    }
+
+    let insert_at = lines
+        .iter()
+        .position(|line| line.trim() == "## License")
+        .unwrap_or(lines.len());
+
+    let before = lines[..insert_at].join("\n");
+    let after = lines[insert_at..].join("\n");
+
+    let mut output = before.trim_end().to_string();
+    if !output.is_empty() {
+        output.push_str("\n\n");
+    }
+    if after.is_empty() {
+        output.push_str(section.trim_end());
+    } else {
+        output.push_str(section);
+        output.push_str(&after);
+    }
+    output.push('\n');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_text() {
+        let mut steps = NextSteps::new();
+        steps.command("cd demo", "");
+        steps.command("go run main.go", "Start the server");
+        steps.url("Health check", "http://localhost:8080/health");
+        steps.caveat("swag was skipped");
+
+        assert_eq!(
+            steps.render_text(),
+            "  cd demo\n  go run main.go  # Start the server\nURLs:\n  Health check: http://localhost:8080/health\nNotes:\n  - swag was skipped"
+        );
+    }
+
+    #[test]
+    fn test_local_url() {
+        assert_eq!(
+            local_url("0.0.0.0", 8080, "/health"),
+            "http://localhost:8080/health"
+        );
+        assert_eq!(local_url("::1", 8888, "/"), "http://[::1]:8888/");
+    }
+
+    #[test]
+    fn test_insert_section() {
+        let section = "## Next Steps\n\n```bash\ncargo run\n```\n\n";
+
+        let content = "# demo\n\nIntro\n\n## License\n\nMIT\n";
+        let expected =
+            "# demo\n\nIntro\n\n## Next Steps\n\n```bash\ncargo run\n```\n\n## License\n\nMIT\n";
+        assert_eq!(insert_section(content, section), expected);
+
+        // 再次写入时替换已有章节
+        assert_eq!(insert_section(expected, section), expected);
+
+        assert_eq!(
+            insert_section("# demo\n", section),
+            "# demo\n\n## Next Steps\n\n```bash\ncargo run\n```\n"
+        );
+    }
+}
@@ -33,6 +33,7 @@ pub trait ParameterBuilder<T: Parameters> {
 /// 通用参数验证辅助函数
 pub mod validation {
     use anyhow::{Result, anyhow};
+    use std::net::IpAddr;
 
     /// 验证项目名称
     #[allow(dead_code)]
@@ -66,12 +67,250 @@ pub mod validation {
         Ok(())
     }
 
-    /// 验证主机地址
-    #[allow(dead_code)]
+    /// 验证主机地址（IPv4/IPv6 地址或主机名，IPv6 可带方括号）
     pub fn validate_host(host: &str) -> Result<()> {
-        if host.is_empty() {
+        if host.trim().is_empty() {
             return Err(anyhow!("Host cannot be empty"));
         }
+
+        let bare = normalize_host(host);
+        if bare.parse::<IpAddr>().is_ok() {
+            return Ok(());
+        }
+
+        // 方括号只允许包裹 IPv6 地址
+        if host.contains('[') || host.contains(']') {
+            return Err(anyhow!("Invalid IPv6 address: {host}"));
+        }
+
+        // 形如 1.2.3.4 但无法解析的输入视为错误的 IPv4 地址
+        if bare.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return Err(anyhow!("Invalid IPv4 address: {host}"));
+        }
+
+        let hostname = bare.strip_suffix('.').unwrap_or(&bare);
+        if hostname.len() > 253 {
+            return Err(anyhow!("Host name is too long (max 253 characters)"));
+        }
+
+        for label in hostname.split('.') {
+            if label.is_empty() || label.len() > 63 {
+                return Err(anyhow!(
+                    "Invalid host name: {host} (each label must be 1-63 characters)"
+                ));
+            }
+            if label.starts_with('-') || label.ends_with('-') {
+                return Err(anyhow!(
+                    "Invalid host name: {host} (labels cannot start or end with '-')"
+                ));
+            }
+            if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return Err(anyhow!(
+                    "Invalid host name: {host} (only letters, digits, '-' and '.' are allowed)"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 验证项目主页地址
+    pub fn validate_homepage(homepage: &str) -> Result<()> {
+        if !(homepage.starts_with("http://") || homepage.starts_with("https://")) {
+            return Err(anyhow!("Homepage must start with http:// or https://"));
+        }
+        if homepage
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '<' | '>'))
+        {
+            return Err(anyhow!("Homepage contains invalid characters"));
+        }
+        Ok(())
+    }
+
+    /// 验证作者邮箱：`name@domain` 形式，不含空白和尖括号
+    pub fn validate_email(email: &str) -> Result<()> {
+        let valid = match email.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty() && !domain.is_empty() && !domain.contains('@')
+            }
+            None => false,
+        };
+        if !valid
+            || email
+                .chars()
+                .any(|c| c.is_whitespace() || matches!(c, '"' | '<' | '>' | ','))
+        {
+            return Err(anyhow!("Invalid email address: {email}"));
+        }
         Ok(())
     }
+
+    /// 验证许可证年份：单个年份或 `2020-2026` 形式的年份范围
+    pub fn validate_license_year(year: &str) -> Result<()> {
+        let is_year = |part: &str| part.len() == 4 && part.chars().all(|c| c.is_ascii_digit());
+        let valid = match year.split_once('-') {
+            Some((start, end)) => is_year(start) && is_year(end) && start < end,
+            None => is_year(year),
+        };
+        if !valid {
+            return Err(anyhow!(
+                "License year must be a year or a range such as 2020-2026: {year}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// 验证自定义许可证文本的路径：必须是已存在的文件
+    pub fn validate_license_file(path: &str) -> Result<()> {
+        if !std::path::Path::new(path).is_file() {
+            return Err(anyhow!("License file not found: {path}"));
+        }
+        Ok(())
+    }
+
+    /// 验证关键字
+    pub fn validate_keyword(keyword: &str) -> Result<()> {
+        if keyword.is_empty() || keyword.len() > 50 {
+            return Err(anyhow!("Keyword must be 1-50 characters: {keyword}"));
+        }
+        if !keyword
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '+'))
+        {
+            return Err(anyhow!(
+                "Keyword can only contain alphanumeric characters, '-', '_' and '+': {keyword}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// 验证 CORS 允许的来源：`*` 或不带路径的 `http(s)://host[:port]`
+    pub fn validate_cors_origin(origin: &str) -> Result<()> {
+        if origin == "*" {
+            return Ok(());
+        }
+
+        let authority = origin
+            .strip_prefix("http://")
+            .or_else(|| origin.strip_prefix("https://"))
+            .ok_or_else(|| {
+                anyhow!("CORS origin must be '*' or start with http:// or https://: {origin}")
+            })?;
+        if authority.is_empty()
+            || !authority
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':' | '[' | ']'))
+        {
+            return Err(anyhow!(
+                "CORS origin must be a scheme and host without a path: {origin}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// 标准化 CORS 允许的 HTTP 方法（转为大写），不支持的方法返回错误
+    pub fn normalize_cors_method(method: &str) -> Result<String> {
+        const METHODS: &[&str] = &["GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"];
+
+        let method = method.trim().to_ascii_uppercase();
+        if !METHODS.contains(&method.as_str()) {
+            return Err(anyhow!(
+                "Unsupported CORS method: {method}. Supported methods: {}",
+                METHODS.join(", ")
+            ));
+        }
+        Ok(method)
+    }
+
+    /// 标准化主机地址：去除首尾空白以及 IPv6 地址外层的方括号
+    pub fn normalize_host(host: &str) -> String {
+        let host = host.trim();
+        host.strip_prefix('[')
+            .and_then(|h| h.strip_suffix(']'))
+            .unwrap_or(host)
+            .to_string()
+    }
+
+    /// 将主机和端口格式化为 `host:port`，IPv6 地址会使用 `[::1]:port` 形式
+    pub fn format_host_port(host: &str, port: u16) -> String {
+        let bare = normalize_host(host);
+        match bare.parse::<IpAddr>() {
+            Ok(IpAddr::V6(_)) => format!("[{bare}]:{port}"),
+            _ => format!("{bare}:{port}"),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_validate_host() {
+            assert!(validate_host("0.0.0.0").is_ok());
+            assert!(validate_host("127.0.0.1").is_ok());
+            assert!(validate_host("::1").is_ok());
+            assert!(validate_host("[::1]").is_ok());
+            assert!(validate_host("localhost").is_ok());
+            assert!(validate_host("api.example.com").is_ok());
+
+            assert!(validate_host("").is_err());
+            assert!(validate_host("256.1.1.1").is_err());
+            assert!(validate_host("[localhost]").is_err());
+            assert!(validate_host("bad_host").is_err());
+            assert!(validate_host("-bad.example.com").is_err());
+            assert!(validate_host("a..b").is_err());
+            assert!(validate_host("http://localhost").is_err());
+        }
+
+        #[test]
+        fn test_validate_cors_origin() {
+            assert!(validate_cors_origin("*").is_ok());
+            assert!(validate_cors_origin("https://example.com").is_ok());
+            assert!(validate_cors_origin("http://localhost:5173").is_ok());
+
+            assert!(validate_cors_origin("example.com").is_err());
+            assert!(validate_cors_origin("https://").is_err());
+            assert!(validate_cors_origin("https://example.com/app").is_err());
+            assert!(validate_cors_origin("https://exa\"mple.com").is_err());
+
+            assert_eq!(normalize_cors_method(" patch ").unwrap(), "PATCH");
+            assert!(normalize_cors_method("CONNECT").is_err());
+        }
+
+        #[test]
+        fn test_validate_license_year() {
+            assert!(validate_license_year("2026").is_ok());
+            assert!(validate_license_year("2020-2026").is_ok());
+
+            assert!(validate_license_year("26").is_err());
+            assert!(validate_license_year("2026-2020").is_err());
+            assert!(validate_license_year("2020-").is_err());
+        }
+
+        #[test]
+        fn test_validate_license_file() {
+            assert!(validate_license_file("Cargo.toml").is_ok());
+
+            assert!(validate_license_file("src").is_err());
+            assert!(validate_license_file("missing-LICENSE.txt").is_err());
+        }
+
+        #[test]
+        fn test_validate_email() {
+            assert!(validate_email("dev@example.com").is_ok());
+
+            assert!(validate_email("dev").is_err());
+            assert!(validate_email("@example.com").is_err());
+            assert!(validate_email("Dev <dev@example.com>").is_err());
+        }
+
+        #[test]
+        fn test_format_host_port() {
+            assert_eq!(format_host_port("0.0.0.0", 8080), "0.0.0.0:8080");
+            assert_eq!(format_host_port("localhost", 80), "localhost:80");
+            assert_eq!(format_host_port("::1", 8080), "[::1]:8080");
+            assert_eq!(format_host_port("[::1]", 8080), "[::1]:8080");
+        }
+    }
 }
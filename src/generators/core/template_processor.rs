@@ -1,39 +1,68 @@
 use anyhow::{Context, Result};
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
 
 use crate::scaffold::{ParameterScope, Scaffold};
-use crate::template_engine::TemplateEngine;
+use crate::template_engine::{RenderContext, TEMPLATE_MANIFEST, TemplateEngine, TemplateManifest};
+use crate::utils::feature_manifest;
 
 /// 模板处理器 - 封装模板处理的核心逻辑
 pub struct TemplateProcessor {
-    template_engine: TemplateEngine,
+    template_engine: Arc<TemplateEngine>,
+    feature: Option<&'static str>,
 }
 
 impl TemplateProcessor {
-    /// 创建新的模板处理器
+    /// 创建新的模板处理器，所有处理器共用同一个模板引擎
     pub fn new() -> Result<Self> {
-        let templates_root = crate::template_engine::get_templates_dir()?;
-        let template_engine = TemplateEngine::new(templates_root)?;
+        Ok(Self {
+            template_engine: TemplateEngine::shared()?,
+            feature: None,
+        })
+    }
 
-        Ok(Self { template_engine })
+    /// 把写入的所有文件记为功能 `feature` 的文件，用于整体属于一个功能的生成器（如 Docker、Kubernetes）
+    pub fn with_feature(mut self, feature: &'static str) -> Self {
+        self.feature = Some(feature);
+        self
     }
 
     /// 处理嵌入式模板目录
     pub fn process_embedded_template_directory(
-        &mut self,
+        &self,
         template_path: &str,
         output_path: &Path,
         context: HashMap<String, Value>,
     ) -> Result<()> {
-        use std::fs;
+        self.process_embedded_template_directory_filtered(
+            template_path,
+            output_path,
+            context,
+            |_| true,
+        )
+    }
 
+    /// 处理嵌入式模板目录，只渲染 `include` 返回 true 的文件（参数为相对模板目录的路径）
+    pub fn process_embedded_template_directory_filtered(
+        &self,
+        template_path: &str,
+        output_path: &Path,
+        context: HashMap<String, Value>,
+        include: impl Fn(&str) -> bool,
+    ) -> Result<()> {
         // 获取嵌入式模板文件列表
         let template_files = crate::template_engine::get_embedded_template_files(template_path)
             .with_context(|| {
                 format!("Failed to get embedded template files for: {template_path}")
             })?;
+        // 所有文件共用同一份渲染上下文和改名规则
+        let render_context = RenderContext::new(&context)?;
+        let renames = RenameRules::for_template_set(template_path)?;
 
         for template_file in template_files {
             // 获取相对于模板路径的文件路径
@@ -41,14 +70,19 @@ impl TemplateProcessor {
                 .strip_prefix(&format!("{template_path}/"))
                 .unwrap_or(&template_file);
 
-            // 去除 .tmpl 后缀
-            let output_relative_path = if let Some(stripped) = relative_path.strip_suffix(".tmpl") {
-                stripped // 移除 ".tmpl"
-            } else {
-                relative_path
-            };
+            // 跳过模板组清单、被功能开关禁用或被调用方排除的文件
+            if relative_path == TEMPLATE_MANIFEST
+                || is_feature_disabled(relative_path, &context)
+                || !include(relative_path)
+            {
+                continue;
+            }
+
+            // 去除 .tmpl 后缀、按规则改名并渲染路径中的变量
+            let output_relative_path =
+                self.render_output_path(relative_path, &renames, &context)?;
 
-            let output_file_path = output_path.join(output_relative_path);
+            let output_file_path = resolve_output_path(output_path, &output_relative_path)?;
 
             // 确保输出目录存在
             if let Some(parent) = output_file_path.parent() {
@@ -56,50 +90,87 @@ impl TemplateProcessor {
                     .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
             }
 
-            // 判断是否为模板文件
+            // 模板文件渲染后写入，其他文件原样复制
             if template_file.ends_with(".tmpl") {
-                // 获取模板内容
-                if let Some(template_content) =
-                    crate::template_engine::get_embedded_template_content(&template_file)
-                {
-                    // 渲染模板
-                    let rendered_content = self
-                        .template_engine
-                        .render_template_content(&template_content, context.clone())
-                        .with_context(|| {
-                            format!("Failed to render embedded template: {template_file}")
-                        })?;
-
-                    // 写入文件
-                    fs::write(&output_file_path, rendered_content).with_context(|| {
-                        format!(
-                            "Failed to write rendered file: {}",
-                            output_file_path.display()
-                        )
-                    })?;
-                } else {
-                    return Err(anyhow::anyhow!(
-                        "Template content not found: {template_file}"
-                    ));
-                }
+                self.render_embedded_file(
+                    &template_file,
+                    &output_file_path,
+                    &output_relative_path,
+                    &render_context,
+                )?;
             } else {
-                // 直接复制非模板文件
-                if let Some(file_content) =
-                    crate::template_engine::get_embedded_template_content(&template_file)
-                {
-                    fs::write(&output_file_path, file_content).with_context(|| {
-                        format!("Failed to write file: {}", output_file_path.display())
-                    })?;
-                } else {
-                    return Err(anyhow::anyhow!("File content not found: {template_file}"));
-                }
+                copy_embedded_file(&template_file, &output_file_path, &output_relative_path)?;
+            }
+            if let Some(feature) = self.feature {
+                feature_manifest::record_file(feature, &output_file_path);
             }
         }
 
         Ok(())
     }
 
+    /// 渲染单个嵌入式模板文件并写入输出路径
+    pub fn render_embedded_file(
+        &self,
+        template_file: &str,
+        output_file_path: &Path,
+        output_relative_path: &str,
+        context: &RenderContext,
+    ) -> Result<()> {
+        let rendered_content = self.render_embedded_content(template_file, context)?;
+
+        fs::write(output_file_path, &rendered_content).with_context(|| {
+            format!(
+                "Failed to write rendered file: {}",
+                output_file_path.display()
+            )
+        })?;
+        apply_file_mode(
+            output_file_path,
+            output_relative_path,
+            rendered_content.as_bytes(),
+        )?;
+        feature_manifest::record_template_file(
+            template_file,
+            output_file_path,
+            rendered_content.as_bytes(),
+        );
+        Ok(())
+    }
+
+    /// 渲染单个嵌入式模板文件，返回渲染结果而不写入文件
+    pub fn render_embedded_content(
+        &self,
+        template_file: &str,
+        context: &RenderContext,
+    ) -> Result<String> {
+        let template_bytes = crate::template_engine::get_embedded_template_bytes(template_file)
+            .ok_or_else(|| anyhow::anyhow!("Template content not found: {template_file}"))?;
+        self.template_engine.render_with_context(
+            template_file,
+            &String::from_utf8_lossy(&template_bytes),
+            context,
+        )
+    }
+
+    /// 计算模板文件的输出路径：去除功能片段目录和 `.tmpl` 后缀，按改名规则改写后渲染路径中的变量
+    pub fn render_output_path(
+        &self,
+        relative_path: &str,
+        renames: &RenameRules,
+        context: &HashMap<String, Value>,
+    ) -> Result<String> {
+        let output_relative_path = strip_feature_dirs(relative_path);
+        let output_relative_path = output_relative_path
+            .strip_suffix(".tmpl")
+            .unwrap_or(&output_relative_path);
+
+        self.template_engine
+            .render_path(&renames.apply(output_relative_path), context)
+    }
+
     /// 处理单个模板目录
+    #[allow(dead_code)]
     pub fn process_template_directory(
         &self,
         template_path: &Path,
@@ -124,7 +195,7 @@ impl TemplateProcessor {
 
     /// 处理单个模板文件
     pub fn process_template_file(
-        &mut self,
+        &self,
         template_file: &Path,
         output_file: &Path,
         context: HashMap<String, Value>,
@@ -153,14 +224,16 @@ impl TemplateProcessor {
         Ok(templates_root.join(relative_path))
     }
 
-    /// 渲染模板内容
-    pub fn render_template_content(
-        &mut self,
+    /// 渲染指定名称的模板内容，出错时报告模板路径、行号和可用变量
+    #[allow(dead_code)]
+    pub fn render_named_template(
+        &self,
+        name: &str,
         template_content: &str,
-        context: HashMap<String, Value>,
+        context: &HashMap<String, Value>,
     ) -> Result<String> {
         self.template_engine
-            .render_template_content(template_content, context)
+            .render_named_template(name, template_content, context)
     }
 
     /// 检查模板是否存在（强制使用嵌入式模板）
@@ -169,8 +242,390 @@ impl TemplateProcessor {
     }
 }
 
+/// 功能片段目录的前缀，如 `+docker/Dockerfile.tmpl` 只在启用 docker 时生成为 `Dockerfile`
+pub const FEATURE_DIR_PREFIX: char = '+';
+
+/// 通过 `--feature` 启用的功能，用于上下文中没有对应开关的第三方功能包
+static EXTRA_FEATURES: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// 设置通过 `--feature` 启用的功能
+pub fn set_extra_features(features: Vec<String>) {
+    *EXTRA_FEATURES.write().unwrap_or_else(|e| e.into_inner()) = features;
+}
+
+/// 模板路径中功能片段目录的功能名（去掉 `+` 前缀），文件名本身不计入
+pub fn feature_dirs(relative_path: &str) -> impl Iterator<Item = &str> {
+    let dir = relative_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+    dir.split('/')
+        .filter_map(|segment| segment.strip_prefix(FEATURE_DIR_PREFIX))
+}
+
+/// 功能是否启用
+///
+/// 内置功能由上下文中的 `enable_<功能>` 开关控制（`-` 换成 `_`，如 `+docker` 对应 `enable_docker`），
+/// 上下文中没有该开关时只在通过 `--feature` 启用后生成
+pub fn is_feature_enabled(feature: &str, context: &HashMap<String, Value>) -> bool {
+    let flag = format!("enable_{}", feature.replace('-', "_"));
+    match context.get(&flag).and_then(Value::as_bool) {
+        Some(enabled) => enabled,
+        None => EXTRA_FEATURES
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .any(|extra| extra == feature),
+    }
+}
+
+/// 检查模板文件是否被功能开关禁用：所在的任一功能片段目录（如 --profile minimal 下的 `+docker/`、`+ci/`）未启用
+pub fn is_feature_disabled(relative_path: &str, context: &HashMap<String, Value>) -> bool {
+    feature_dirs(relative_path).any(|feature| !is_feature_enabled(feature, context))
+}
+
+/// 去掉路径中的功能片段目录，片段中的文件合并到模板组的对应位置
+pub fn strip_feature_dirs(relative_path: &str) -> String {
+    let (dir, file_name) = relative_path
+        .rsplit_once('/')
+        .unwrap_or(("", relative_path));
+    dir.split('/')
+        .filter(|segment| !segment.is_empty() && !segment.starts_with(FEATURE_DIR_PREFIX))
+        .chain(std::iter::once(file_name))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// 内置的点文件别名：npm 等生态发布或提交模板源时会丢弃、忽略这些文件，模板中改用 `_` 前缀保存
+const DEFAULT_RENAMES: &[(&str, &str)] = &[
+    ("_gitignore", ".gitignore"),
+    ("_gitattributes", ".gitattributes"),
+    ("_npmrc", ".npmrc"),
+    ("_npmignore", ".npmignore"),
+    ("_dockerignore", ".dockerignore"),
+    ("_helmignore", ".helmignore"),
+    ("_editorconfig", ".editorconfig"),
+];
+
+/// 输出路径的改名规则：模板组清单 `renames` 中的规则优先，其次是内置的点文件别名
+///
+/// 规则按路径片段（文件名或目录名，已去除 `.tmpl` 后缀）匹配，
+/// 键中可以包含一个 `*`，匹配到的部分替换目标中的 `*`，如 `"dot-*": ".*"`
+#[derive(Debug, Clone)]
+pub struct RenameRules {
+    rules: Vec<(String, String)>,
+}
+
+impl Default for RenameRules {
+    fn default() -> Self {
+        Self {
+            rules: DEFAULT_RENAMES
+                .iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl RenameRules {
+    /// 加载模板组的改名规则，模板组没有清单或清单中没有 `renames` 时只使用内置别名
+    pub fn for_template_set(template_path: &str) -> Result<Self> {
+        match TemplateManifest::load(template_path)?.and_then(|manifest| manifest.renames) {
+            Some(renames) => Self::from_manifest(&renames)
+                .with_context(|| format!("Invalid renames in {template_path}/{TEMPLATE_MANIFEST}")),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 解析清单中的 `renames` 对象（模板中的名称 → 输出名称）
+    fn from_manifest(renames: &Value) -> Result<Self> {
+        let renames = renames
+            .as_object()
+            .ok_or_else(|| anyhow::anyhow!("'renames' must map template names to output names"))?;
+
+        let mut rules = Vec::with_capacity(renames.len() + DEFAULT_RENAMES.len());
+        for (from, to) in renames {
+            let to = to
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("rename target for '{from}' must be a string"))?;
+            let wildcards = from.matches('*').count();
+            if from.is_empty() || to.is_empty() || from.contains('/') || to.contains('/') {
+                return Err(anyhow::anyhow!(
+                    "'{from}' -> '{to}': names must be non-empty and must not contain '/'"
+                ));
+            }
+            if wildcards > 1 || (wildcards == 0 && to.contains('*')) {
+                return Err(anyhow::anyhow!(
+                    "'{from}' -> '{to}': only one '*' is allowed, and the target may use it only when the name does"
+                ));
+            }
+            rules.push((from.clone(), to.to_string()));
+        }
+        rules.extend(Self::default().rules);
+        Ok(Self { rules })
+    }
+
+    /// 按第一条匹配的规则改写单个路径片段，没有匹配的规则时原样返回
+    fn rename_segment<'a>(&self, segment: &'a str) -> Cow<'a, str> {
+        for (from, to) in &self.rules {
+            match from.split_once('*') {
+                None if from == segment => return Cow::Owned(to.clone()),
+                Some((prefix, suffix)) => {
+                    let stem = segment
+                        .strip_prefix(prefix)
+                        .and_then(|rest| rest.strip_suffix(suffix));
+                    if let Some(stem) = stem.filter(|stem| !stem.is_empty()) {
+                        return Cow::Owned(to.replacen('*', stem, 1));
+                    }
+                }
+                None => {}
+            }
+        }
+        Cow::Borrowed(segment)
+    }
+
+    /// 改写路径中的每个片段
+    pub fn apply(&self, relative_path: &str) -> String {
+        relative_path
+            .split('/')
+            .map(|segment| self.rename_segment(segment))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// 将模板输出的相对路径拼接到输出目录
+///
+/// 拒绝绝对路径和包含 `..` 的路径，防止模板（尤其是第三方模板）写出到目标目录之外
+pub fn resolve_output_path(output_root: &Path, relative_path: &str) -> Result<PathBuf> {
+    let path = Path::new(relative_path);
+    // `\` 在 Windows 上同样是路径分隔符，统一按分隔符检查
+    let escapes = path.has_root()
+        || path
+            .components()
+            .any(|component| matches!(component, Component::Prefix(_)))
+        || relative_path
+            .split(['/', '\\'])
+            .any(|segment| segment == "..");
+
+    if relative_path.is_empty() || escapes {
+        return Err(anyhow::anyhow!(
+            "Template output path '{relative_path}' escapes the target directory {}",
+            output_root.display()
+        ));
+    }
+
+    Ok(output_root.join(path))
+}
+
+/// 需要可执行权限的生成文件的权限位
+#[cfg(unix)]
+const EXECUTABLE_MODE: u32 = 0o755;
+
+/// 存放 git hooks 的目录名，其中无扩展名的文件视为 hook 脚本
+const HOOK_DIRS: &[&str] = &["hooks", ".githooks", ".husky"];
+
+/// 判断生成的文件是否需要可执行权限
+///
+/// `.sh` 脚本、hooks 目录下无扩展名的文件，以及以 shebang（`#!`）开头的文件需要可执行权限
+pub fn is_executable_file(relative_path: &str, content: &[u8]) -> bool {
+    let path = Path::new(relative_path);
+    let in_hook_dir = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|dir| dir.to_str())
+        .is_some_and(|dir| HOOK_DIRS.contains(&dir));
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("sh") => true,
+        None if in_hook_dir => true,
+        _ => content.starts_with(b"#!"),
+    }
+}
+
+/// 为需要的生成文件设置可执行权限，非 Unix 平台上不做处理
+pub fn apply_file_mode(path: &Path, relative_path: &str, content: &[u8]) -> Result<()> {
+    if !is_executable_file(relative_path, content) {
+        return Ok(());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(EXECUTABLE_MODE))
+            .with_context(|| format!("Failed to set file mode: {}", path.display()))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+
+    Ok(())
+}
+
+/// 将嵌入式文件原样复制到输出路径
+///
+/// 直接从二进制中的数据流式写入磁盘，不转换为 String，二进制资源（图标等）也能保持原样
+pub fn copy_embedded_file(
+    template_file: &str,
+    output_file_path: &Path,
+    output_relative_path: &str,
+) -> Result<()> {
+    let contents = crate::template_engine::get_embedded_template_bytes(template_file)
+        .ok_or_else(|| anyhow::anyhow!("File content not found: {template_file}"))?;
+
+    let mut file = fs::File::create(output_file_path)
+        .with_context(|| format!("Failed to write file: {}", output_file_path.display()))?;
+    io::copy(&mut &contents[..], &mut file)
+        .with_context(|| format!("Failed to write file: {}", output_file_path.display()))?;
+    apply_file_mode(output_file_path, output_relative_path, &contents)?;
+    feature_manifest::record_template_file(template_file, output_file_path, &contents);
+    Ok(())
+}
+
 impl Default for TemplateProcessor {
     fn default() -> Self {
         Self::new().expect("Failed to create TemplateProcessor")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_is_feature_disabled() {
+        let mut context = HashMap::new();
+        context.insert("enable_docker".to_string(), json!(false));
+        context.insert("enable_ci".to_string(), json!(true));
+
+        assert!(is_feature_disabled("+docker/Dockerfile.tmpl", &context));
+        assert!(is_feature_disabled(
+            "+docker/deploy/docker-compose.yml.tmpl",
+            &context
+        ));
+        assert!(!is_feature_disabled(
+            "+ci/.github/workflows/ci.yml.tmpl",
+            &context
+        ));
+        // 嵌套的片段目录需要全部启用
+        assert!(is_feature_disabled("+ci/+docker/docker.yml.tmpl", &context));
+        // 文件名不再决定是否生成
+        assert!(!is_feature_disabled("Dockerfile.tmpl", &context));
+        assert!(!is_feature_disabled("main.go.tmpl", &context));
+
+        // 上下文中没有开关的功能只在通过 --feature 启用后生成
+        assert!(is_feature_disabled(
+            "+open-telemetry/internal/otel/otel.go.tmpl",
+            &context
+        ));
+        set_extra_features(vec!["open-telemetry".to_string()]);
+        assert!(!is_feature_disabled(
+            "+open-telemetry/internal/otel/otel.go.tmpl",
+            &context
+        ));
+        set_extra_features(Vec::new());
+    }
+
+    #[test]
+    fn test_strip_feature_dirs() {
+        assert_eq!(
+            strip_feature_dirs("+docker/Dockerfile.tmpl"),
+            "Dockerfile.tmpl"
+        );
+        assert_eq!(
+            strip_feature_dirs("+tests/src/items/+ci/items.spec.ts.tmpl"),
+            "src/items/items.spec.ts.tmpl"
+        );
+        assert_eq!(strip_feature_dirs("src/main.rs.tmpl"), "src/main.rs.tmpl");
+        assert_eq!(strip_feature_dirs("+notes.md"), "+notes.md");
+    }
+
+    #[test]
+    fn test_rename_rules() {
+        let defaults = RenameRules::default();
+        assert_eq!(defaults.apply("_gitignore"), ".gitignore");
+        assert_eq!(defaults.apply("web/_npmrc"), "web/.npmrc");
+        // 只有内置别名会被改写，Python 包和其他下划线文件保持原样
+        assert_eq!(defaults.apply("app/__init__.py"), "app/__init__.py");
+        assert_eq!(defaults.apply("pages/_app.tsx"), "pages/_app.tsx");
+
+        let rules = RenameRules::from_manifest(&serde_json::json!({
+            "dot-*": ".*",
+            "*.env-example": "*.env.example",
+            "_github": ".github",
+            "_gitignore": "gitignore.txt",
+        }))
+        .unwrap();
+        assert_eq!(rules.apply("dot-prettierrc"), ".prettierrc");
+        assert_eq!(rules.apply("dot-"), "dot-");
+        assert_eq!(
+            rules.apply("config/local.env-example"),
+            "config/local.env.example"
+        );
+        assert_eq!(
+            rules.apply("_github/workflows/ci.yml"),
+            ".github/workflows/ci.yml"
+        );
+        // 清单中的规则优先于内置别名
+        assert_eq!(rules.apply("_gitignore"), "gitignore.txt");
+        assert_eq!(rules.apply("_npmrc"), ".npmrc");
+
+        for invalid in [
+            json!(["_gitignore"]),
+            json!({"_gitignore": 1}),
+            json!({"*a*": "b"}),
+            json!({"_gitignore": ".*"}),
+            json!({"docs/_gitignore": ".gitignore"}),
+        ] {
+            assert!(RenameRules::from_manifest(&invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_output_path() {
+        let root = Path::new("/tmp/demo");
+        assert_eq!(
+            resolve_output_path(root, "cmd/demo/main.go").unwrap(),
+            root.join("cmd/demo/main.go")
+        );
+        assert_eq!(
+            resolve_output_path(root, "./config/..dev.toml").unwrap(),
+            root.join("./config/..dev.toml")
+        );
+
+        for path in [
+            "",
+            "../outside.txt",
+            "config/../../outside.txt",
+            "/etc/passwd",
+            "..\\outside.txt",
+        ] {
+            assert!(resolve_output_path(root, path).is_err(), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_copy_embedded_file_preserves_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("LICENSE");
+
+        copy_embedded_file("licenses/MIT.tmpl", &output, "LICENSE").unwrap();
+
+        let expected = crate::template_engine::get_embedded_template_bytes("licenses/MIT.tmpl");
+        assert_eq!(
+            Some(std::fs::read(&output).unwrap().as_slice()),
+            expected.as_deref()
+        );
+    }
+
+    #[test]
+    fn test_is_executable_file() {
+        assert!(is_executable_file("scripts/generate-api.sh", b""));
+        assert!(is_executable_file(".githooks/pre-commit", b""));
+        assert!(is_executable_file("tools/run", b"#!/usr/bin/env bash\n"));
+        // React hooks 目录下的源码文件不是可执行脚本
+        assert!(!is_executable_file(
+            "src/hooks/useDarkMode.ts",
+            b"export {}"
+        ));
+        assert!(!is_executable_file("main.go", b"package main"));
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{DockerParams, DockerTarget};
+use crate::constants::Framework;
+use crate::generators::core::{Generator, NextSteps, Parameters, TemplateProcessor, local_url};
+use crate::status;
+
+/// 框架模板已提供 Dockerfile 时存在的路径，如 go-zero 的 `deploy/docker`
+const DOCKERFILE_PATHS: &[&str] = &["Dockerfile", "deploy/docker"];
+/// 框架模板已提供 compose 文件时存在的路径
+const COMPOSE_PATHS: &[&str] = &[
+    "docker-compose.yml",
+    "docker-compose.yaml",
+    "compose.yaml",
+    "compose.yml",
+    "deploy/docker",
+];
+/// 开发环境的 compose 文件，已存在时保持不变
+const COMPOSE_DEV_PATH: &str = "docker-compose.dev.yaml";
+
+/// 跨语言的 Docker 文件生成器：多阶段 Dockerfile、`.dockerignore`、`docker-compose.yaml`
+/// 以及热重载的 `docker-compose.dev.yaml`
+///
+/// 框架模板中已有的 Docker 文件优先，只补齐缺少的部分
+pub struct DockerGenerator {
+    template_processor: TemplateProcessor,
+}
+
+impl DockerGenerator {
+    /// 创建新的 Docker 生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            template_processor: TemplateProcessor::new()?.with_feature("docker"),
+        })
+    }
+}
+
+impl Default for DockerGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create DockerGenerator")
+    }
+}
+
+impl Generator for DockerGenerator {
+    type Params = DockerParams;
+
+    fn name(&self) -> &'static str {
+        "Docker"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates a multi-stage Dockerfile, .dockerignore and docker-compose.yaml")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "docker"
+    }
+
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
+        status!("Generating {} files", self.name());
+
+        let exists = |paths: &[&str]| paths.iter().any(|path| output_path.join(path).exists());
+        let has_dockerfile = exists(DOCKERFILE_PATHS);
+        // 没有端口的命令行程序不需要 compose 服务
+        let skip_compose = exists(COMPOSE_PATHS) || params.container_port.is_none();
+        let has_dockerignore = output_path.join(".dockerignore").exists();
+        let has_compose_dev = output_path.join(COMPOSE_DEV_PATH).exists();
+        let production = params.production;
+
+        self.template_processor
+            .process_embedded_template_directory_filtered(
+                params.target.template_path(),
+                output_path,
+                params.to_template_context(),
+                |relative_path| match relative_path {
+                    "Dockerfile.tmpl" | "nginx.conf.tmpl" => production && !has_dockerfile,
+                    "docker-compose.yaml.tmpl" => production && !skip_compose,
+                    "_dockerignore.tmpl" => production && !has_dockerignore,
+                    "+compose_dev/docker-compose.dev.yaml.tmpl" => !has_compose_dev,
+                    _ => production,
+                },
+            )
+            .context("Failed to generate Docker files")?;
+
+        status!("{} files generated", self.name());
+        Ok(())
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        if params.generates_compose_dev()
+            && let Some(port) = params.dev_port
+        {
+            steps.command(
+                format!("docker compose -f {COMPOSE_DEV_PATH} up"),
+                "Run the development stack with hot reload",
+            );
+            steps.url("Dev server", local_url("localhost", port, "/"));
+        }
+        if !params.production {
+            return;
+        }
+        match params.container_port {
+            Some(port) => {
+                steps.command("docker compose up --build", "Build and run the container");
+                steps.url("Container", local_url("localhost", port, "/"));
+            }
+            None => steps.command(
+                format!("docker build -t {} .", params.base.project_name),
+                "Build the container image",
+            ),
+        }
+        if params.target == DockerTarget::NodeServer && params.framework == Framework::SvelteKit {
+            steps.caveat(
+                "The Docker image runs `node build`; switch svelte.config.js to @sveltejs/adapter-node first",
+            );
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::constants::{Framework, Language, Runtime, defaults};
+use crate::generators::core::{BaseParams, InheritableParams};
+
+/// Node.js 镜像版本
+const DEFAULT_NODE_VERSION: &str = "20";
+/// Python Web 框架在容器内由 gunicorn 监听的端口
+const PYTHON_SERVER_PORT: u16 = 8000;
+/// Next.js、Nuxt、SvelteKit 的 Node 服务端口
+const NODE_SERVER_PORT: u16 = 3000;
+/// 静态站点由非 root 的 nginx 监听的端口
+pub const STATIC_SITE_PORT: u16 = 8080;
+/// Vite 开发服务器的默认端口
+const VITE_DEV_PORT: u16 = 5173;
+/// Astro 开发服务器的默认端口
+const ASTRO_DEV_PORT: u16 = 4321;
+/// 开发环境中 Go 项目热重载使用的 air 版本
+const AIR_VERSION: &str = "v1.61.7";
+
+/// Docker 镜像的构建方式，由语言和框架决定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DockerTarget {
+    /// Go 静态二进制，运行在 distroless 镜像中
+    Go,
+    /// uv 安装依赖的 Python 虚拟环境
+    Python,
+    /// 构建后由 Node（或 Bun、Deno）运行的服务端
+    NodeServer,
+    /// 构建出的静态文件由 nginx 提供
+    StaticSite,
+    /// cargo release 构建的二进制
+    Rust,
+}
+
+impl DockerTarget {
+    /// 项目对应的构建方式，桌面应用和 Zig 项目不生成 Docker 文件时返回 `None`
+    pub fn for_stack(language: &Language, framework: &Framework) -> Option<Self> {
+        match framework {
+            Framework::Gin | Framework::Fiber | Framework::Chi | Framework::GoZero => {
+                Some(DockerTarget::Go)
+            }
+            Framework::Django | Framework::Flask => Some(DockerTarget::Python),
+            Framework::Axum | Framework::Actix | Framework::Cli => Some(DockerTarget::Rust),
+            Framework::NextJs | Framework::Nuxt | Framework::SvelteKit | Framework::NestJs => {
+                Some(DockerTarget::NodeServer)
+            }
+            Framework::Vue3 | Framework::React | Framework::Astro => Some(DockerTarget::StaticSite),
+            Framework::Tauri => None,
+            Framework::None => match language {
+                Language::Python => Some(DockerTarget::Python),
+                Language::Rust => Some(DockerTarget::Rust),
+                Language::Go | Language::TypeScript | Language::Zig => None,
+            },
+        }
+    }
+
+    /// 构建方式的模板目录
+    pub fn template_path(&self) -> &'static str {
+        match self {
+            DockerTarget::Go => "docker/go",
+            DockerTarget::Python => "docker/python",
+            DockerTarget::NodeServer => "docker/node",
+            DockerTarget::StaticSite => "docker/static",
+            DockerTarget::Rust => "docker/rust",
+        }
+    }
+}
+
+/// Docker 文件生成参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 构建方式
+    pub target: DockerTarget,
+    /// 项目框架，决定启动命令和构建产物
+    pub framework: Framework,
+    /// 容器对外暴露的端口，命令行工具为 `None`
+    pub container_port: Option<u16>,
+    /// 项目中是否有运行时读取的 `config/` 目录
+    pub config_dir: bool,
+    /// JavaScript 运行时，决定构建镜像和包管理命令
+    pub runtime: Runtime,
+    /// 是否生成 Dockerfile、`.dockerignore` 和 `docker-compose.yaml`
+    pub production: bool,
+    /// 是否生成热重载的 `docker-compose.dev.yaml`
+    pub compose_dev: bool,
+    /// 开发服务器在容器内监听的端口，不支持开发环境的项目为 `None`
+    pub dev_port: Option<u16>,
+    /// 静态站点镜像中 nginx 转发 `/api` 和 `/health` 的后端地址，只用于全栈项目的前端
+    pub api_proxy: Option<String>,
+}
+
+impl Default for DockerParams {
+    fn default() -> Self {
+        Self::new(String::new(), DockerTarget::Go, Framework::Gin, 8080)
+    }
+}
+
+impl InheritableParams for DockerParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            target: DockerTarget::Go,
+            framework: Framework::Gin,
+            container_port: None,
+            config_dir: false,
+            runtime: Runtime::default(),
+            production: true,
+            compose_dev: false,
+            dev_port: None,
+            api_proxy: None,
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        let mut context = HashMap::from([
+            ("container_port".to_string(), json!(self.container_port)),
+            (
+                "enable_compose_dev".to_string(),
+                json!(self.generates_compose_dev()),
+            ),
+            ("dev_port".to_string(), json!(self.dev_port)),
+            (
+                "dev_command".to_string(),
+                json!(exec_form(&self.dev_command())),
+            ),
+            ("dev_environment".to_string(), json!(self.dev_environment())),
+        ]);
+        match self.target {
+            DockerTarget::Go | DockerTarget::Rust => {
+                context.insert("config_dir".to_string(), json!(self.config_dir));
+            }
+            DockerTarget::Python => {
+                let package = self
+                    .base
+                    .project_name
+                    .to_lowercase()
+                    .replace(['-', ' '], "_");
+                let (command, environment, server_package): (
+                    Vec<String>,
+                    Vec<String>,
+                    Option<&str>,
+                ) = match self.framework {
+                    Framework::Django => (
+                        vec![
+                            "gunicorn".to_string(),
+                            format!("{package}.wsgi:application"),
+                            "--bind".to_string(),
+                            format!("0.0.0.0:{PYTHON_SERVER_PORT}"),
+                        ],
+                        vec![
+                            "DJANGO_SECRET_KEY=change-me".to_string(),
+                            "DJANGO_ALLOWED_HOSTS=localhost,127.0.0.1".to_string(),
+                            // compose 直接通过 HTTP 访问，不跳转 HTTPS
+                            "DJANGO_SECURE_SSL_REDIRECT=false".to_string(),
+                        ],
+                        Some("gunicorn"),
+                    ),
+                    Framework::Flask => (
+                        vec!["gunicorn".to_string(), "wsgi:app".to_string()],
+                        vec![
+                            "ENV=prod".to_string(),
+                            "SECRET_KEY=change-me".to_string(),
+                            format!("PORT={PYTHON_SERVER_PORT}"),
+                        ],
+                        None,
+                    ),
+                    _ => (
+                        vec!["python".to_string(), "main.py".to_string()],
+                        Vec::new(),
+                        None,
+                    ),
+                };
+                context.insert("docker_command".to_string(), json!(exec_form(&command)));
+                context.insert("docker_environment".to_string(), json!(environment));
+                context.insert("server_package".to_string(), json!(server_package));
+            }
+            DockerTarget::NodeServer | DockerTarget::StaticSite => {
+                context.insert("runtime".to_string(), json!(self.runtime.as_str()));
+                context.insert("node_version".to_string(), json!(DEFAULT_NODE_VERSION));
+                context.insert("lockfile".to_string(), json!(self.runtime.lockfile()));
+                let app = match self.framework {
+                    Framework::NextJs => "nextjs",
+                    Framework::Nuxt => "nuxt",
+                    Framework::SvelteKit => "sveltekit",
+                    Framework::Astro => "astro",
+                    Framework::NestJs => "nestjs",
+                    _ => "spa",
+                };
+                context.insert("docker_app".to_string(), json!(app));
+                if self.target == DockerTarget::StaticSite {
+                    context.insert("api_proxy".to_string(), json!(self.api_proxy));
+                }
+            }
+        }
+        context
+    }
+}
+
+impl DockerParams {
+    /// 为指定项目创建 Docker 参数，`port` 为命令行指定的服务端口
+    pub fn new(
+        project_name: String,
+        target: DockerTarget,
+        framework: Framework,
+        port: u16,
+    ) -> Self {
+        // 镜像只需项目名和语言版本，不带 module_name 等源码层面的参数
+        let base = BaseParams {
+            project_name,
+            language_version: match target {
+                DockerTarget::Go => Some(defaults::GO_VERSION.to_string()),
+                DockerTarget::Python => Some(defaults::PYTHON_VERSION.to_string()),
+                _ => None,
+            },
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            target,
+            framework,
+            container_port: container_port(framework, port),
+            config_dir: false,
+            runtime: Runtime::default(),
+            production: true,
+            compose_dev: false,
+            dev_port: dev_port(framework, port),
+            api_proxy: None,
+        }
+    }
+
+    /// 设置是否生成生产镜像文件和开发环境的 compose 文件
+    pub fn with_outputs(mut self, production: bool, compose_dev: bool) -> Self {
+        self.production = production;
+        self.compose_dev = compose_dev;
+        self
+    }
+
+    /// 是否会生成 `docker-compose.dev.yaml`，go-zero 等没有单一开发服务器的项目除外
+    pub fn generates_compose_dev(&self) -> bool {
+        self.compose_dev && self.dev_port.is_some()
+    }
+
+    /// 开发容器的启动命令：安装依赖后以热重载方式运行，并监听所有网卡
+    fn dev_command(&self) -> Vec<String> {
+        let Some(port) = self.dev_port else {
+            return Vec::new();
+        };
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect();
+        match self.target {
+            DockerTarget::Go => args(&[
+                "go",
+                "run",
+                &format!("github.com/air-verse/air@{AIR_VERSION}"),
+                "--build.cmd",
+                "go build -o ./tmp/server .",
+                "--build.bin",
+                "./tmp/server",
+            ]),
+            DockerTarget::Python => match self.framework {
+                Framework::Django => args(&[
+                    "uv",
+                    "run",
+                    "python",
+                    "manage.py",
+                    "runserver",
+                    &format!("0.0.0.0:{port}"),
+                ]),
+                _ => args(&[
+                    "uv",
+                    "run",
+                    "flask",
+                    "--app",
+                    "wsgi",
+                    "run",
+                    "--debug",
+                    "--host",
+                    "0.0.0.0",
+                    "--port",
+                    &port.to_string(),
+                ]),
+            },
+            // cargo home 挂载为卷，watchexec 只在首次启动时安装
+            DockerTarget::Rust => args(&[
+                "sh",
+                "-c",
+                "command -v watchexec >/dev/null || cargo install --locked watchexec-cli; \
+                 exec watchexec --restart --exts rs,toml -- cargo run",
+            ]),
+            DockerTarget::NodeServer | DockerTarget::StaticSite => {
+                let dev = match self.framework {
+                    Framework::NestJs => self.runtime.run_command("start:dev"),
+                    Framework::NextJs => {
+                        format!("{} -H 0.0.0.0 -p {port}", self.runtime.run_command("dev"))
+                    }
+                    _ => format!(
+                        "{} --host 0.0.0.0 --port {port}",
+                        self.runtime.run_command("dev")
+                    ),
+                };
+                let corepack = match self.runtime {
+                    Runtime::Node => "corepack enable && ",
+                    Runtime::Bun | Runtime::Deno => "",
+                };
+                args(&[
+                    "sh",
+                    "-c",
+                    &format!(
+                        "{corepack}{} install && exec {dev}",
+                        self.runtime.package_manager()
+                    ),
+                ])
+            }
+        }
+    }
+
+    /// 开发容器的环境变量：让服务监听所有网卡，并允许从宿主机 shell 覆盖前端的 API 地址
+    fn dev_environment(&self) -> Vec<String> {
+        let Some(port) = self.dev_port else {
+            return Vec::new();
+        };
+        match self.target {
+            DockerTarget::Go => vec![
+                // go.mod 要求更高版本时自动下载工具链
+                "GOTOOLCHAIN=auto".to_string(),
+                "GO_ENV=dev".to_string(),
+                "SERVER_HOST=0.0.0.0".to_string(),
+            ],
+            DockerTarget::Python => {
+                let mut environment = vec!["UV_LINK_MODE=copy".to_string()];
+                if self.framework == Framework::Flask {
+                    environment.push("ENV=dev".to_string());
+                }
+                environment
+            }
+            DockerTarget::Rust => vec![
+                "APP_ENV=dev".to_string(),
+                "APP__SERVER__HOST=0.0.0.0".to_string(),
+            ],
+            DockerTarget::NodeServer | DockerTarget::StaticSite => {
+                let mut environment = vec!["NODE_ENV=development".to_string()];
+                if self.runtime == Runtime::Node {
+                    environment.push("COREPACK_ENABLE_DOWNLOAD_PROMPT=0".to_string());
+                }
+                match self.framework {
+                    Framework::NestJs => {
+                        environment.push("HOST=0.0.0.0".to_string());
+                        environment.push(format!("PORT={port}"));
+                    }
+                    Framework::Vue3 | Framework::React => {
+                        environment
+                            .push("VITE_API_BASE_URL=${VITE_API_BASE_URL:-/api}".to_string());
+                    }
+                    _ => {}
+                }
+                environment
+            }
+        }
+    }
+
+    /// 设置静态站点镜像中 nginx 转发 API 请求的后端地址，如 `http://backend:8080`
+    pub fn with_api_proxy(mut self, api_proxy: Option<String>) -> Self {
+        self.api_proxy = api_proxy;
+        self
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// 根据已生成的项目文件补充语言版本和 `config/` 目录
+    pub fn detect_from(mut self, output_path: &Path) -> Self {
+        self.config_dir = output_path.join("config").is_dir();
+        let detected = match self.target {
+            DockerTarget::Go => std::fs::read_to_string(output_path.join("go.mod"))
+                .ok()
+                .and_then(|go_mod| go_directive(&go_mod)),
+            DockerTarget::Python => std::fs::read_to_string(output_path.join(".python-version"))
+                .ok()
+                .and_then(|version| python_minor_version(&version)),
+            _ => None,
+        };
+        if detected.is_some() {
+            self.base.language_version = detected;
+        }
+        self
+    }
+}
+
+/// 镜像中服务监听的端口，`port` 为命令行指定的服务端口，命令行工具返回 `None`
+///
+/// Python 框架由 gunicorn、静态站点由 nginx 在固定端口上提供服务
+pub fn container_port(framework: Framework, port: u16) -> Option<u16> {
+    match framework {
+        Framework::Django | Framework::Flask => Some(PYTHON_SERVER_PORT),
+        Framework::NextJs | Framework::Nuxt | Framework::SvelteKit => Some(NODE_SERVER_PORT),
+        Framework::Vue3 | Framework::React | Framework::Astro => Some(STATIC_SITE_PORT),
+        Framework::Gin
+        | Framework::Fiber
+        | Framework::Chi
+        | Framework::GoZero
+        | Framework::Axum
+        | Framework::Actix
+        | Framework::NestJs => Some(port),
+        Framework::None | Framework::Cli | Framework::Tauri => None,
+    }
+}
+
+/// `docker-compose.dev.yaml` 中开发服务器监听的端口，`port` 为命令行指定的服务端口
+///
+/// go-zero 的 API 和 RPC 服务、命令行工具和桌面应用没有单一的开发服务器，返回 `None`
+pub fn dev_port(framework: Framework, port: u16) -> Option<u16> {
+    match framework {
+        Framework::Django | Framework::Flask => Some(PYTHON_SERVER_PORT),
+        Framework::NextJs | Framework::Nuxt => Some(NODE_SERVER_PORT),
+        Framework::Vue3 | Framework::React | Framework::SvelteKit => Some(VITE_DEV_PORT),
+        Framework::Astro => Some(ASTRO_DEV_PORT),
+        Framework::Gin
+        | Framework::Fiber
+        | Framework::Chi
+        | Framework::Axum
+        | Framework::Actix
+        | Framework::NestJs => Some(port),
+        Framework::GoZero | Framework::None | Framework::Cli | Framework::Tauri => None,
+    }
+}
+
+/// Dockerfile 和 compose 共用的 exec 形式命令，如 `["gunicorn", "wsgi:app"]`
+fn exec_form(command: &[String]) -> String {
+    let command = command
+        .iter()
+        .map(|arg| json!(arg).to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("[{command}]")
+}
+
+/// go.mod 中 `go` 指令声明的版本
+fn go_directive(go_mod: &str) -> Option<String> {
+    go_mod
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("go "))
+        .map(|version| version.trim().to_string())
+        .filter(|version| !version.is_empty())
+}
+
+/// 镜像标签只使用 Python 的主次版本号，如 `3.12.4` 取 `3.12`
+fn python_minor_version(version: &str) -> Option<String> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next().filter(|part| !part.is_empty())?;
+    let minor = parts.next()?;
+    Some(format!("{major}.{minor}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_docker_target_for_stack() {
+        assert_eq!(
+            DockerTarget::for_stack(&Language::Go, &Framework::Chi),
+            Some(DockerTarget::Go)
+        );
+        assert_eq!(
+            DockerTarget::for_stack(&Language::Python, &Framework::None),
+            Some(DockerTarget::Python)
+        );
+        assert_eq!(
+            DockerTarget::for_stack(&Language::TypeScript, &Framework::Vue3),
+            Some(DockerTarget::StaticSite)
+        );
+        assert_eq!(
+            DockerTarget::for_stack(&Language::Rust, &Framework::Tauri),
+            None
+        );
+        assert_eq!(
+            DockerTarget::for_stack(&Language::Zig, &Framework::None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_detected_versions() {
+        assert_eq!(
+            go_directive("module demo\n\ngo 1.22.3\n\nrequire ()\n"),
+            Some("1.22.3".to_string())
+        );
+        assert_eq!(go_directive("module demo\n"), None);
+        assert_eq!(python_minor_version("3.12.4\n"), Some("3.12".to_string()));
+        assert_eq!(python_minor_version("3.13"), Some("3.13".to_string()));
+        assert_eq!(python_minor_version(""), None);
+    }
+
+    #[test]
+    fn test_python_command() {
+        let params = DockerParams::new(
+            "my-site".to_string(),
+            DockerTarget::Python,
+            Framework::Django,
+            8080,
+        );
+        let context = params.extended_template_context();
+        assert_eq!(
+            context["docker_command"],
+            json!(r#"["gunicorn", "my_site.wsgi:application", "--bind", "0.0.0.0:8000"]"#)
+        );
+        assert_eq!(context["container_port"], json!(8000));
+        assert_eq!(context["server_package"], json!("gunicorn"));
+    }
+
+    #[test]
+    fn test_compose_dev() {
+        let params = DockerParams::new(
+            "web".to_string(),
+            DockerTarget::StaticSite,
+            Framework::React,
+            8080,
+        )
+        .with_runtime(Runtime::Bun)
+        .with_outputs(false, true);
+        let context = params.extended_template_context();
+        assert_eq!(context["enable_compose_dev"], json!(true));
+        assert_eq!(context["dev_port"], json!(5173));
+        assert_eq!(
+            context["dev_command"],
+            json!(r#"["sh", "-c", "bun install && exec bun run dev --host 0.0.0.0 --port 5173"]"#)
+        );
+
+        let go_zero =
+            DockerParams::new("svc".to_string(), DockerTarget::Go, Framework::GoZero, 8888)
+                .with_outputs(true, true);
+        assert!(!go_zero.generates_compose_dev());
+        assert_eq!(
+            go_zero.extended_template_context()["dev_command"],
+            json!("[]")
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::language::rust::RustParams;
+use crate::generators::project::ProjectParams;
+
+/// Actix-web 默认监听端口
+pub const DEFAULT_ACTIX_PORT: u16 = 8080;
+
+/// Actix-web框架参数
+///
+/// 服务地址写入 `config/config.dev.toml` 和代码中的默认配置，运行时可由环境变量覆盖
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActixParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Rust语言参数
+    pub rust: RustParams,
+}
+
+impl Default for ActixParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            default_host: Some("127.0.0.1".to_string()),
+            default_port: Some(DEFAULT_ACTIX_PORT),
+            enable_swagger: false,
+            enable_middleware: true,
+            enable_logging: true,
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            project: ProjectParams::default(),
+            rust: RustParams::default(),
+        }
+    }
+}
+
+impl InheritableParams for ActixParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            rust: RustParams::default(),
+        }
+    }
+}
+
+impl ActixParams {
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        // 设置Actix-web特定的默认值
+        base.default_host = Some("127.0.0.1".to_string());
+        base.default_port = Some(DEFAULT_ACTIX_PORT);
+        base.enable_swagger = false;
+        base.enable_middleware = true;
+        base.enable_logging = true;
+
+        Self {
+            base,
+            project: ProjectParams::from_project_name(project_name.clone()),
+            rust: RustParams::new(project_name),
+        }
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.base.host = Some(host);
+        self.base.port = Some(port);
+        self
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置Rust参数
+    pub fn with_rust(mut self, rust: RustParams) -> Self {
+        self.rust = rust;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+}
@@ -0,0 +1,174 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{ASTRO_INTEGRATIONS, AstroParams, DEFAULT_ASTRO_PORT};
+use crate::constants::{Framework, Language, Runtime};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps,
+};
+use crate::status;
+use crate::utils::{js_runtime, tool_failures};
+
+/// Astro框架级别生成器实现
+#[derive(Debug)]
+pub struct AstroGenerator {}
+
+impl AstroGenerator {
+    /// 创建新的Astro生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+
+    /// 使用 create-astro 非交互地创建最小 Astro 项目，依赖在创建时一并安装
+    pub fn create_astro_project(
+        project_name: &str,
+        output_path: &Path,
+        runtime: Runtime,
+    ) -> Result<()> {
+        status!("🚀 Creating Astro project with create-astro...");
+
+        // 获取父目录
+        let parent_dir = output_path.parent().unwrap_or_else(|| Path::new("."));
+
+        // create-astro 按调用它的包管理器安装依赖
+        let output = js_runtime::create(runtime, "astro@latest")
+            .args([
+                project_name,
+                "--template",
+                "minimal",
+                "--install",
+                // git 仓库由项目级生成器初始化
+                "--no-git",
+                "--skip-houston",
+                "--yes",
+            ])
+            .current_dir(parent_dir)
+            .output()
+            .context("Failed to execute create-astro")?;
+
+        if output.status.success() {
+            status!("✅ Astro project created successfully");
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            Err(anyhow::anyhow!(
+                "Failed to create Astro project:\nstdout: {stdout}\nstderr: {stderr}"
+            ))
+        }
+    }
+
+    /// 通过 astro add 安装 Tailwind CSS 和 MDX 集成，启用 pre-commit 时再安装 astro check 的依赖
+    pub fn install_integrations(output_path: &Path, params: &AstroParams) -> Result<()> {
+        status!(
+            "📦 Adding Astro integrations: {}...",
+            ASTRO_INTEGRATIONS.join(", ")
+        );
+
+        let output = js_runtime::run(params.runtime, "astro")
+            .arg("add")
+            .args(ASTRO_INTEGRATIONS)
+            .arg("--yes")
+            .current_dir(output_path)
+            .output()
+            .context("Failed to execute astro add")?;
+
+        if output.status.success() {
+            status!("✅ Integrations added successfully");
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!(
+                "Failed to add Astro integrations: {}",
+                stderr.trim()
+            ))?;
+        }
+
+        if params.base.enable_precommit {
+            status!("📦 Installing @astrojs/check for the pre-commit hook...");
+            let output = js_runtime::add(params.runtime, true, &["@astrojs/check", "typescript"])
+                .current_dir(output_path)
+                .output()
+                .context("Failed to install @astrojs/check")?;
+
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tool_failures::report(format!(
+                    "Failed to install @astrojs/check: {}",
+                    stderr.trim()
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for AstroGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create AstroGenerator")
+    }
+}
+
+impl Generator for AstroGenerator {
+    type Params = AstroParams;
+
+    fn name(&self) -> &'static str {
+        "Astro"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates Astro static site for content and docs with Tailwind CSS and MDX")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/typescript/astro"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let runtime = params.runtime;
+        steps.command(runtime.run_command("dev"), "Start development server");
+        steps.command(
+            runtime.run_command("build"),
+            "Build the static site into dist/",
+        );
+        if params.base.enable_precommit {
+            steps.command(
+                runtime.run_command("astro check"),
+                "Type-check .astro and .mdx files",
+            );
+        }
+        steps.url(
+            "Dev server",
+            format!("http://localhost:{DEFAULT_ASTRO_PORT}"),
+        );
+        steps.url(
+            "Example docs page",
+            format!("http://localhost:{DEFAULT_ASTRO_PORT}/docs"),
+        );
+    }
+}
+
+impl FrameworkGeneratorTrait for AstroGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Astro.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::TypeScript.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构由 create-astro 生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // astro.config.mjs 由 create-astro 生成，集成由 astro add 注册
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 静态站点不需要中间件
+        Ok(())
+    }
+}
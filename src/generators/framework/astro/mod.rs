@@ -0,0 +1,5 @@
+mod generator;
+pub mod parameters;
+
+pub use generator::AstroGenerator;
+pub use parameters::AstroParams;
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::Runtime;
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::project::ProjectParams;
+
+/// Astro 开发服务器的默认端口
+pub const DEFAULT_ASTRO_PORT: u16 = 4321;
+
+/// 通过 `astro add` 安装的集成：Tailwind CSS 和 MDX
+pub const ASTRO_INTEGRATIONS: &[&str] = &["tailwind", "mdx"];
+
+/// Astro框架参数 - 继承自BaseParams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AstroParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Node.js 版本
+    pub node_version: String,
+    /// JavaScript 运行时，决定包管理命令和 CI 配置
+    pub runtime: Runtime,
+    /// 包管理器，由运行时决定 (pnpm、bun、deno)
+    pub package_manager: String,
+}
+
+impl Default for AstroParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            default_host: Some("localhost".to_string()),
+            default_port: Some(DEFAULT_ASTRO_PORT),
+            ..Default::default()
+        };
+
+        Self::from_base(base)
+    }
+}
+
+impl InheritableParams for AstroParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            node_version: "20".to_string(),
+            runtime: Runtime::default(),
+            package_manager: "pnpm".to_string(),
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        // 项目结构由 create-astro 生成，模板只有布局、示例文档页、CI 和 pre-commit 配置
+        HashMap::from([
+            ("node_version".to_string(), json!(self.node_version)),
+            ("runtime".to_string(), json!(self.runtime)),
+        ])
+    }
+}
+
+impl AstroParams {
+    /// 创建新的Astro参数
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        base.default_host = Some("localhost".to_string());
+        base.default_port = Some(DEFAULT_ASTRO_PORT);
+
+        Self {
+            project: ProjectParams::from_project_name(project_name),
+            ..Self::from_base(base)
+        }
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self.package_manager = runtime.package_manager().to_string();
+        self
+    }
+
+    /// 设置 Node.js 版本
+    #[allow(dead_code)]
+    pub fn with_node_version(mut self, version: String) -> Self {
+        self.node_version = version;
+        self
+    }
+}
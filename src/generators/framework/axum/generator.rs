@@ -0,0 +1,79 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::parameters::{AxumParams, DEFAULT_AXUM_PORT};
+use crate::constants::{Framework, Language};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, local_url,
+};
+
+/// Axum框架级别生成器实现
+///
+/// 模板完全覆盖项目结构（包括 Cargo.toml 中的依赖），依赖的拉取和编译由 RustGenerator 完成
+#[derive(Debug)]
+pub struct AxumGenerator {}
+
+impl AxumGenerator {
+    /// 创建新的Axum生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+}
+
+impl Default for AxumGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create AxumGenerator")
+    }
+}
+
+impl Generator for AxumGenerator {
+    type Params = AxumParams;
+
+    fn name(&self) -> &'static str {
+        "Axum"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates an axum HTTP service with a tower middleware stack")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/rust/axum"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let host = params.base.host.as_deref().unwrap_or("localhost");
+        let port = params.base.port.unwrap_or(DEFAULT_AXUM_PORT);
+
+        steps.command("cargo run", "Start the server");
+        if params.base.enable_tests {
+            steps.command("cargo test", "Run the tests");
+        }
+        steps.url("Health check", local_url(host, port, "/health"));
+    }
+}
+
+impl FrameworkGeneratorTrait for AxumGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Axum.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::Rust.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构完全由模板生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 配置文件通过模板生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // tower 中间件栈通过模板生成
+        Ok(())
+    }
+}
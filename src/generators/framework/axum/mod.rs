@@ -0,0 +1,6 @@
+pub mod generator;
+pub mod parameters;
+
+// 明确导出具体类型，避免通配符导入
+pub use generator::AxumGenerator;
+pub use parameters::AxumParams;
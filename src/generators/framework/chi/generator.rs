@@ -0,0 +1,79 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::parameters::{ChiParams, DEFAULT_CHI_PORT};
+use crate::constants::{Framework, Language};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, local_url,
+};
+
+/// Chi框架级别生成器实现
+///
+/// 模板完全覆盖项目结构，使用默认的嵌入式模板渲染（功能开关由模板处理器统一处理）
+#[derive(Debug)]
+pub struct ChiGenerator {}
+
+impl ChiGenerator {
+    /// 创建新的Chi生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+}
+
+impl Default for ChiGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create ChiGenerator")
+    }
+}
+
+impl Generator for ChiGenerator {
+    type Params = ChiParams;
+
+    fn name(&self) -> &'static str {
+        "Chi"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates a minimal net/http service with the chi router")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/go/chi"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let host = params.base.host.as_deref().unwrap_or("localhost");
+        let port = params.base.port.unwrap_or(DEFAULT_CHI_PORT);
+
+        steps.command("go run main.go", "Start the server");
+        if params.base.enable_tests {
+            steps.command("go test ./...", "Run the tests");
+        }
+        steps.url("Health check", local_url(host, port, "/health"));
+    }
+}
+
+impl FrameworkGeneratorTrait for ChiGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Chi.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::Go.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构完全由模板生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 配置从环境变量读取，无需生成配置文件
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 中间件通过模板生成
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use std::path::Path;
+
+use super::parameters::CliParams;
+use crate::constants::{Framework, Language};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps,
+};
+
+/// CLI应用框架级别生成器实现
+///
+/// 模板完全覆盖项目结构（包括 Cargo.toml 中的依赖），依赖的拉取和编译由 RustGenerator 完成
+#[derive(Debug)]
+pub struct CliGenerator {}
+
+impl CliGenerator {
+    /// 创建新的CLI应用生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+}
+
+impl Default for CliGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create CliGenerator")
+    }
+}
+
+impl Generator for CliGenerator {
+    type Params = CliParams;
+
+    fn name(&self) -> &'static str {
+        "CLI"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates a clap command-line application with config loading and tracing")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/rust/cli"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        steps.command("cargo run -- --help", "List the available subcommands");
+        steps.command("cargo run -- greet", "Run the example subcommand");
+        if params.base.enable_tests {
+            steps.command("cargo test", "Run the tests");
+        }
+    }
+}
+
+impl FrameworkGeneratorTrait for CliGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Cli.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::Rust.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构完全由模板生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 配置示例文件通过模板生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 命令行应用没有中间件
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::language::rust::RustParams;
+use crate::generators::project::ProjectParams;
+
+/// CLI应用参数
+///
+/// 命令行应用不监听端口，环境变量前缀和配置文件路径由模板根据项目名生成
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CliParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Rust语言参数
+    pub rust: RustParams,
+}
+
+impl Default for CliParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            enable_swagger: false,
+            enable_middleware: false,
+            enable_logging: true,
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            project: ProjectParams::default(),
+            rust: RustParams::default(),
+        }
+    }
+}
+
+impl InheritableParams for CliParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            rust: RustParams::default(),
+        }
+    }
+}
+
+impl CliParams {
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        // 设置CLI应用特定的默认值
+        base.enable_swagger = false;
+        base.enable_middleware = false;
+        base.enable_logging = true;
+
+        Self {
+            base,
+            project: ProjectParams::from_project_name(project_name.clone()),
+            rust: RustParams::new(project_name),
+        }
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置Rust参数
+    pub fn with_rust(mut self, rust: RustParams) -> Self {
+        self.rust = rust;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+}
@@ -0,0 +1,231 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+use super::parameters::DjangoParams;
+use crate::constants::{Framework, Language};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, Parameters,
+    TemplateProcessor, local_url,
+};
+use crate::generators::language::python::uv;
+use crate::status;
+use crate::utils::manifest::{self, ManifestMetadata};
+use crate::utils::{render_only, tool_failures};
+
+/// Django 开发服务器默认端口
+const DJANGO_DEV_PORT: u16 = 8000;
+
+/// DRF api 模块的模板目录，未启用 DRF 时不渲染
+const API_TEMPLATE_DIR: &str = "{{package_name}}/api/";
+
+/// Django框架级别生成器实现
+///
+/// 先用 uv 创建项目并执行 `django-admin startproject`，再用模板覆盖为拆分后的配置结构
+#[derive(Debug)]
+pub struct DjangoGenerator {}
+
+impl DjangoGenerator {
+    /// 创建新的Django生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+
+    /// 执行 `django-admin startproject`，返回生成的密钥
+    ///
+    /// 执行前确认项目环境中可以运行 django-admin，失败时按失败策略处理并返回 None
+    fn start_project(&self, params: &DjangoParams, output_path: &Path) -> Result<Option<String>> {
+        if render_only::skip_tool("django-admin startproject") {
+            return Ok(None);
+        }
+
+        let version = Command::new("uv")
+            .args(["run", "django-admin", "--version"])
+            .env_remove("VIRTUAL_ENV")
+            .current_dir(output_path)
+            .output();
+        match version {
+            Ok(output) if output.status.success() => {
+                status!(
+                    "Django {} available",
+                    String::from_utf8_lossy(&output.stdout).trim()
+                );
+            }
+            _ => {
+                tool_failures::report(
+                    "django-admin is not available in the project environment, skipping startproject",
+                )?;
+                return Ok(None);
+            }
+        }
+
+        status!("Creating Django project with django-admin startproject...");
+
+        let package_name = params.package_name();
+        let output = Command::new("uv")
+            .args(["run", "django-admin", "startproject", &package_name, "."])
+            .env_remove("VIRTUAL_ENV")
+            .current_dir(output_path)
+            .output()
+            .context("Failed to execute django-admin startproject")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!(
+                "django-admin startproject failed: {}",
+                stderr.trim()
+            ))?;
+            return Ok(None);
+        }
+
+        // 单文件配置由 settings 包取代，取出其中的密钥后删除
+        let settings_path = output_path.join(&package_name).join("settings.py");
+        let settings = std::fs::read_to_string(&settings_path)
+            .with_context(|| format!("Failed to read {}", settings_path.display()))?;
+        std::fs::remove_file(&settings_path)
+            .with_context(|| format!("Failed to remove {}", settings_path.display()))?;
+
+        status!("Django project created");
+        Ok(read_secret_key(&settings))
+    }
+}
+
+/// 从 startproject 生成的 settings.py 中读取 `SECRET_KEY`
+fn read_secret_key(settings: &str) -> Option<String> {
+    settings.lines().find_map(|line| {
+        let value = line
+            .trim()
+            .strip_prefix("SECRET_KEY")?
+            .trim_start()
+            .strip_prefix('=')?
+            .trim();
+        let quote = value.chars().next().filter(|c| matches!(c, '\'' | '"'))?;
+        value[1..].strip_suffix(quote).map(str::to_string)
+    })
+}
+
+impl Default for DjangoGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create DjangoGenerator")
+    }
+}
+
+impl Generator for DjangoGenerator {
+    type Params = DjangoParams;
+
+    fn name(&self) -> &'static str {
+        "Django"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates a Django project with split settings managed by uv")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/python/django"
+    }
+
+    fn generate(&self, mut params: Self::Params, output_path: &Path) -> Result<()> {
+        params.validate()?;
+
+        status!("Generating {} structure", self.name());
+
+        // 1. 使用 uv 创建项目并添加依赖
+        uv::init_bare_project(&params.base.project_name, output_path)?;
+        manifest::update_pyproject_metadata(
+            output_path,
+            &ManifestMetadata::from_base(&params.base),
+        )
+        .context("Failed to update pyproject.toml metadata")?;
+        let mut dependencies = vec!["django", "python-dotenv"];
+        if params.enable_drf {
+            dependencies.push("djangorestframework");
+        }
+        uv::add_dependencies(output_path, &dependencies)?;
+
+        // 2. startproject 生成项目骨架和密钥
+        if let Some(secret_key) = self.start_project(&params, output_path)? {
+            params.secret_key = Some(secret_key);
+        }
+
+        // 3. 模板覆盖入口文件并生成拆分后的配置，未启用 DRF 时跳过 api 模块
+        let enable_drf = params.enable_drf;
+        let template_processor = TemplateProcessor::new()?;
+        template_processor.process_embedded_template_directory_filtered(
+            self.get_template_path(),
+            output_path,
+            params.to_template_context(),
+            |relative_path| enable_drf || !relative_path.starts_with(API_TEMPLATE_DIR),
+        )?;
+
+        status!("{} structure generated", self.name());
+        Ok(())
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        steps.command(
+            "uv run python manage.py migrate",
+            "Apply database migrations",
+        );
+        steps.command(
+            "uv run python manage.py runserver",
+            "Start the development server",
+        );
+        if params.base.enable_tests {
+            steps.command("uv run python manage.py test", "Run the tests");
+        }
+        steps.url(
+            "Health check",
+            local_url("127.0.0.1", DJANGO_DEV_PORT, "/health/"),
+        );
+        if params.enable_drf {
+            steps.url(
+                "API",
+                local_url("127.0.0.1", DJANGO_DEV_PORT, "/api/v1/ping/"),
+            );
+        }
+    }
+}
+
+impl FrameworkGeneratorTrait for DjangoGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Django.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::Python.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目骨架由 django-admin startproject 生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 拆分后的配置通过模板生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // Django 中间件在 settings/base.py 中配置
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_secret_key() {
+        let settings = "from pathlib import Path\n\n\
+            # SECURITY WARNING: keep the secret key used in production secret!\n\
+            SECRET_KEY = 'django-insecure-a#b$c(d)=e'\n\n\
+            DEBUG = True\n";
+        assert_eq!(
+            read_secret_key(settings).as_deref(),
+            Some("django-insecure-a#b$c(d)=e")
+        );
+        assert_eq!(read_secret_key("DEBUG = True\n"), None);
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::language::python::PythonParams;
+use crate::generators::project::ProjectParams;
+
+/// Django框架参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DjangoParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Python语言参数
+    pub python: PythonParams,
+    /// 是否集成 Django REST framework
+    pub enable_drf: bool,
+    /// `django-admin startproject` 生成的密钥，写入 .env；未运行 startproject 时为 None
+    pub secret_key: Option<String>,
+}
+
+impl Default for DjangoParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            enable_swagger: false,
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            project: ProjectParams::default(),
+            python: PythonParams::default(),
+            enable_drf: false,
+            secret_key: None,
+        }
+    }
+}
+
+impl InheritableParams for DjangoParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        // 复用 Python 的包名、版本和工具版本，保证与纯 Python 项目一致
+        let mut context = self.python.extended_template_context();
+        context.insert("enable_drf".to_string(), serde_json::json!(self.enable_drf));
+        context.insert(
+            "django_secret_key".to_string(),
+            serde_json::json!(self.secret_key),
+        );
+        context
+    }
+}
+
+impl DjangoParams {
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        base.enable_swagger = false;
+
+        Self {
+            base,
+            project: ProjectParams::from_project_name(project_name.clone()),
+            python: PythonParams::new(project_name),
+            enable_drf: false,
+            secret_key: None,
+        }
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置Python参数
+    pub fn with_python(mut self, python: PythonParams) -> Self {
+        self.python = python;
+        self
+    }
+
+    /// 设置是否集成 Django REST framework
+    pub fn with_drf(mut self, enable_drf: bool) -> Self {
+        self.enable_drf = enable_drf;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+
+    /// Python 包名，即 startproject 生成的配置包目录
+    pub fn package_name(&self) -> String {
+        self.python.package_name()
+    }
+}
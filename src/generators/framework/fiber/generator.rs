@@ -0,0 +1,221 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::parameters::{DEFAULT_FIBER_PORT, FiberParams};
+use crate::constants::{Database, Framework, Language};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, RenameRules,
+    TemplateProcessor, copy_embedded_file, is_feature_disabled, local_url, resolve_output_path,
+};
+use crate::status;
+use crate::template_engine::{RenderContext, TEMPLATE_MANIFEST};
+use crate::utils::go_tools::GoTools;
+use crate::utils::render_only;
+
+/// Fiber框架级别生成器实现
+#[derive(Debug)]
+pub struct FiberGenerator {}
+
+impl FiberGenerator {
+    /// 创建新的Fiber生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+}
+
+impl Default for FiberGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create FiberGenerator")
+    }
+}
+
+impl Generator for FiberGenerator {
+    type Params = FiberParams;
+
+    fn name(&self) -> &'static str {
+        "Fiber"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates Fiber web framework specific files and structure")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/go/fiber"
+    }
+
+    /// 渲染嵌入式模板 - 重写以实现Fiber特定的逻辑
+    fn render_embedded_templates(
+        &self,
+        template_processor: &TemplateProcessor,
+        template_path: &str,
+        output_path: &Path,
+        context: HashMap<String, Value>,
+        params: &Self::Params,
+    ) -> Result<()> {
+        use std::fs;
+
+        // 获取嵌入式模板文件列表
+        let template_files = crate::template_engine::get_embedded_template_files(template_path)
+            .with_context(|| {
+                format!("Failed to get embedded template files for: {template_path}")
+            })?;
+        // 所有文件共用同一份渲染上下文
+        let render_context = RenderContext::new(&context)?;
+        let renames = RenameRules::for_template_set(template_path)?;
+
+        for template_file in template_files {
+            // 获取相对于模板路径的文件路径
+            let relative_path = template_file
+                .strip_prefix(&format!("{template_path}/"))
+                .unwrap_or(&template_file);
+
+            // 模板组清单不输出到项目中
+            if relative_path == TEMPLATE_MANIFEST {
+                continue;
+            }
+
+            let file_name = std::path::Path::new(relative_path)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("");
+
+            // 检查是否应该跳过swagger相关文件
+            if self.should_skip_swagger_file(file_name, params) {
+                continue;
+            }
+
+            // 检查是否应该跳过数据库相关文件
+            if params.database().is_none() && is_database_file(relative_path) {
+                continue;
+            }
+
+            // 检查是否被功能开关禁用
+            if is_feature_disabled(relative_path, &context) {
+                continue;
+            }
+
+            // 去除 .tmpl 后缀并渲染路径中的变量
+            let output_relative_path =
+                template_processor.render_output_path(relative_path, &renames, &context)?;
+
+            let output_file_path = resolve_output_path(output_path, &output_relative_path)?;
+
+            // 确保输出目录存在
+            if let Some(parent) = output_file_path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            }
+
+            // 判断是否为模板文件
+            if template_file.ends_with(".tmpl") {
+                template_processor.render_embedded_file(
+                    &template_file,
+                    &output_file_path,
+                    &output_relative_path,
+                    &render_context,
+                )?;
+                status!("📝 Rendered: {relative_path} -> {output_relative_path}");
+            } else {
+                // 直接复制非模板文件
+                copy_embedded_file(&template_file, &output_file_path, &output_relative_path)?;
+                status!("📋 Copied: {relative_path} -> {output_relative_path}");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let host = params.base.host.as_deref().unwrap_or("localhost");
+        let port = params.base.port.unwrap_or(DEFAULT_FIBER_PORT);
+
+        // SQLite 之外的数据库需要单独启动
+        if let Some(database) = params.database().filter(|db| *db != Database::Sqlite) {
+            if params.base.enable_docker {
+                steps.command(
+                    format!("docker-compose up -d {database}"),
+                    "Start the database",
+                );
+            } else {
+                steps.caveat(format!(
+                    "Start {database} and update the [database] section in config/dev.toml before running the server"
+                ));
+            }
+        }
+        steps.command("go run main.go", "Start the server");
+        if params.base.enable_tests {
+            steps.command("go test ./...", "Run the tests");
+        }
+        steps.url("Health check", local_url(host, port, "/health"));
+        if params.enable_swagger() {
+            steps.url("Swagger UI", local_url(host, port, "/swagger/index.html"));
+        }
+    }
+}
+
+impl FiberGenerator {
+    /// 后处理逻辑 - 处理 Swagger 文档生成
+    pub fn post_process(&self, params: &FiberParams, output_path: &Path) -> Result<()> {
+        if render_only::skip_tool("swag code generation") {
+            return Ok(());
+        }
+
+        if params.enable_swagger() {
+            GoTools::swag_init(output_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FiberGenerator {
+    /// 检查是否应该跳过swagger相关文件
+    fn should_skip_swagger_file(&self, file_name: &str, params: &FiberParams) -> bool {
+        if !params.enable_swagger() {
+            // 如果禁用swagger，跳过所有swagger相关文件
+            file_name.contains("swagger")
+                || file_name.starts_with("docs.go")
+                || file_name.ends_with("swagger.json.tmpl")
+                || file_name.ends_with("swagger.yaml.tmpl")
+        } else {
+            false
+        }
+    }
+}
+
+/// 仅在启用数据库时生成的模板：连接初始化、示例模型和仓储
+const DATABASE_TEMPLATES: &[&str] = &["pkg/database/", "repository/", "models/user.go.tmpl"];
+
+fn is_database_file(relative_path: &str) -> bool {
+    DATABASE_TEMPLATES
+        .iter()
+        .any(|prefix| relative_path.starts_with(prefix))
+}
+
+impl FrameworkGeneratorTrait for FiberGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Fiber.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::Go.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 不再需要自定义结构生成，完全依赖模板
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 配置文件通过模板生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 中间件通过模板生成
+        Ok(())
+    }
+}
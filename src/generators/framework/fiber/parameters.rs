@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::constants::Database;
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::language::go::{GoParams, database_template_context};
+use crate::generators::project::ProjectParams;
+
+/// Fiber 默认监听端口，与 Fiber 官方示例保持一致
+pub const DEFAULT_FIBER_PORT: u16 = 3000;
+
+/// Fiber框架参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiberParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Go语言参数
+    pub go: GoParams,
+}
+
+impl Default for FiberParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            default_host: Some("127.0.0.1".to_string()),
+            default_port: Some(DEFAULT_FIBER_PORT),
+            enable_swagger: true,
+            enable_middleware: true,
+            enable_logging: true,
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            project: ProjectParams::default(),
+            go: GoParams::default(),
+        }
+    }
+}
+
+impl InheritableParams for FiberParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            go: GoParams::default(),
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        database_template_context(self.database(), &self.base.project_name)
+    }
+}
+
+impl FiberParams {
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        // 设置Fiber特定的默认值
+        base.default_host = Some("127.0.0.1".to_string());
+        base.default_port = Some(DEFAULT_FIBER_PORT);
+        base.enable_swagger = true;
+        base.enable_middleware = true;
+        base.enable_logging = true;
+
+        Self {
+            base,
+            project: ProjectParams::from_project_name(project_name.clone()),
+            go: GoParams::from_project_name(project_name),
+        }
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.base.host = Some(host);
+        self.base.port = Some(port);
+        self
+    }
+
+    /// 设置是否启用Swagger
+    pub fn with_swagger(mut self, enable_swagger: bool) -> Self {
+        self.base.enable_swagger = enable_swagger;
+        self
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置Go参数
+    pub fn with_go(mut self, go: GoParams) -> Self {
+        self.go = go;
+        self
+    }
+
+    /// 设置数据库类型
+    pub fn with_database(mut self, db_type: String) -> Self {
+        self.base.database_type = Some(db_type);
+        self.base.enable_database = true;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+
+    pub fn enable_swagger(&self) -> bool {
+        self.base.enable_swagger
+    }
+
+    #[allow(dead_code)]
+    pub fn enable_precommit(&self) -> bool {
+        self.base.enable_precommit
+    }
+
+    /// 启用的数据库，未启用时返回 None
+    pub fn database(&self) -> Option<Database> {
+        self.base
+            .database_type
+            .as_deref()
+            .filter(|_| self.base.enable_database)
+            .and_then(Database::parse_from_str)
+    }
+}
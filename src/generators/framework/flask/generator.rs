@@ -0,0 +1,124 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{DEFAULT_FLASK_PORT, FlaskParams};
+use crate::constants::{Framework, Language};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, Parameters,
+    TemplateProcessor, local_url,
+};
+use crate::generators::language::python::uv;
+use crate::status;
+use crate::utils::manifest::{self, ManifestMetadata};
+
+/// Flask 项目依赖
+const FLASK_DEPENDENCIES: &[&str] = &["flask", "gunicorn", "python-dotenv"];
+
+/// Flask框架级别生成器实现
+///
+/// 用 uv 创建空项目后由模板生成应用工厂、蓝图和配置类
+#[derive(Debug)]
+pub struct FlaskGenerator {}
+
+impl FlaskGenerator {
+    /// 创建新的Flask生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+}
+
+impl Default for FlaskGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create FlaskGenerator")
+    }
+}
+
+impl Generator for FlaskGenerator {
+    type Params = FlaskParams;
+
+    fn name(&self) -> &'static str {
+        "Flask"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates a lightweight Flask API with an app factory and blueprints")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/python/flask"
+    }
+
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
+        params.validate()?;
+
+        status!("Generating {} structure", self.name());
+
+        // 1. 使用 uv 创建项目
+        uv::init_bare_project(&params.base.project_name, output_path)?;
+        manifest::update_pyproject_metadata(
+            output_path,
+            &ManifestMetadata::from_base(&params.base),
+        )
+        .context("Failed to update pyproject.toml metadata")?;
+
+        // 2. 模板生成应用代码
+        let template_processor = TemplateProcessor::new()?;
+        template_processor.process_embedded_template_directory(
+            self.get_template_path(),
+            output_path,
+            params.to_template_context(),
+        )?;
+
+        // 3. 添加依赖
+        uv::add_dependencies(output_path, FLASK_DEPENDENCIES)?;
+
+        status!("{} structure generated", self.name());
+        Ok(())
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        steps.command(
+            "uv run flask --app wsgi run --debug",
+            "Start the development server",
+        );
+        if params.base.enable_tests {
+            steps.command(
+                "uv run python -m unittest discover -s tests -t .",
+                "Run the tests",
+            );
+        }
+        steps.command(
+            "ENV=prod SECRET_KEY=... uv run gunicorn wsgi:app",
+            "Serve with gunicorn",
+        );
+        steps.url(
+            "Health check",
+            local_url("127.0.0.1", DEFAULT_FLASK_PORT, "/health"),
+        );
+    }
+}
+
+impl FrameworkGeneratorTrait for FlaskGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Flask.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::Python.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构完全由模板生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 配置类通过模板生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // Flask 没有独立的中间件层，错误处理在应用工厂中注册
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::language::python::PythonParams;
+use crate::generators::project::ProjectParams;
+
+/// `flask run` 开发服务器默认端口
+pub const DEFAULT_FLASK_PORT: u16 = 5000;
+
+/// Flask框架参数
+///
+/// Flask 项目定位为轻量 API，服务地址由环境变量配置，不提供 Swagger 和数据库集成
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlaskParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Python语言参数
+    pub python: PythonParams,
+}
+
+impl Default for FlaskParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            enable_swagger: false,
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            project: ProjectParams::default(),
+            python: PythonParams::default(),
+        }
+    }
+}
+
+impl InheritableParams for FlaskParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        // 复用 Python 的包名、版本和工具版本，保证与纯 Python 项目一致
+        self.python.extended_template_context()
+    }
+}
+
+impl FlaskParams {
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        base.enable_swagger = false;
+
+        Self {
+            base,
+            project: ProjectParams::from_project_name(project_name.clone()),
+            python: PythonParams::new(project_name),
+        }
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置Python参数
+    pub fn with_python(mut self, python: PythonParams) -> Self {
+        self.python = python;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+}
@@ -4,11 +4,16 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use super::parameters::GinParams;
-use crate::constants::{Framework, Language};
+use crate::constants::{Database, Framework, Language, Logger, Orm};
 use crate::generators::core::{
-    FrameworkGenerator as FrameworkGeneratorTrait, Generator, TemplateProcessor,
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, RenameRules,
+    TemplateProcessor, copy_embedded_file, is_feature_disabled, local_url, resolve_output_path,
 };
+use crate::status;
+use crate::template_engine::{RenderContext, TEMPLATE_MANIFEST};
+use crate::utils::feature_manifest;
 use crate::utils::go_tools::GoTools;
+use crate::utils::render_only;
 
 /// Gin框架级别生成器实现
 #[derive(Debug)]
@@ -44,8 +49,8 @@ impl Generator for GinGenerator {
 
     /// 渲染嵌入式模板 - 重写以实现Gin特定的逻辑
     fn render_embedded_templates(
-        &mut self,
-        template_processor: &mut TemplateProcessor,
+        &self,
+        template_processor: &TemplateProcessor,
         template_path: &str,
         output_path: &Path,
         context: HashMap<String, Value>,
@@ -58,6 +63,9 @@ impl Generator for GinGenerator {
             .with_context(|| {
                 format!("Failed to get embedded template files for: {template_path}")
             })?;
+        // 所有文件共用同一份渲染上下文
+        let render_context = RenderContext::new(&context)?;
+        let renames = RenameRules::for_template_set(template_path)?;
 
         for template_file in template_files {
             // 获取相对于模板路径的文件路径
@@ -65,6 +73,11 @@ impl Generator for GinGenerator {
                 .strip_prefix(&format!("{template_path}/"))
                 .unwrap_or(&template_file);
 
+            // 模板组清单不输出到项目中
+            if relative_path == TEMPLATE_MANIFEST {
+                continue;
+            }
+
             let file_name = std::path::Path::new(relative_path)
                 .file_name()
                 .and_then(|n| n.to_str())
@@ -75,19 +88,36 @@ impl Generator for GinGenerator {
                 continue;
             }
 
-            // 检查是否应该跳过pre-commit相关文件
-            if self.should_skip_precommit_file(file_name, params) {
+            // 检查是否应该跳过数据库相关文件
+            if params.database().is_none() && is_database_file(relative_path) {
                 continue;
             }
 
-            // 去除 .tmpl 后缀
-            let output_relative_path = if let Some(stripped) = relative_path.strip_suffix(".tmpl") {
-                stripped // 移除 ".tmpl"
-            } else {
-                relative_path
-            };
+            // 检查是否应该跳过其他数据访问方式的文件
+            if is_other_orm_file(relative_path, params.orm()) {
+                continue;
+            }
+
+            // 检查是否应该跳过其他日志库的文件
+            if is_other_logger_file(relative_path, params.logger) {
+                continue;
+            }
 
-            let output_file_path = output_path.join(output_relative_path);
+            // 检查是否应该跳过Redis相关文件
+            if !params.enable_redis() && is_redis_file(relative_path) {
+                continue;
+            }
+
+            // 检查是否被功能开关禁用
+            if is_feature_disabled(relative_path, &context) {
+                continue;
+            }
+
+            // 去除 .tmpl 后缀并渲染路径中的变量
+            let output_relative_path =
+                template_processor.render_output_path(relative_path, &renames, &context)?;
+
+            let output_file_path = resolve_output_path(output_path, &output_relative_path)?;
 
             // 确保输出目录存在
             if let Some(parent) = output_file_path.parent() {
@@ -97,126 +127,136 @@ impl Generator for GinGenerator {
 
             // 判断是否为模板文件
             if template_file.ends_with(".tmpl") {
-                // 获取模板内容
-                if let Some(template_content) =
-                    crate::template_engine::get_embedded_template_content(&template_file)
-                {
-                    // 渲染模板
-                    let rendered_content = template_processor
-                        .render_template_content(&template_content, context.clone())
-                        .with_context(|| {
-                            format!("Failed to render embedded template: {template_file}")
-                        })?;
-
-                    // 写入文件
-                    fs::write(&output_file_path, rendered_content).with_context(|| {
-                        format!(
-                            "Failed to write rendered file: {}",
-                            output_file_path.display()
-                        )
-                    })?;
-
-                    println!("📝 Rendered: {relative_path} -> {output_relative_path}");
-                } else {
-                    return Err(anyhow::anyhow!(
-                        "Template content not found: {template_file}"
-                    ));
-                }
+                template_processor.render_embedded_file(
+                    &template_file,
+                    &output_file_path,
+                    &output_relative_path,
+                    &render_context,
+                )?;
+                status!("📝 Rendered: {relative_path} -> {output_relative_path}");
             } else {
                 // 直接复制非模板文件
-                if let Some(file_content) =
-                    crate::template_engine::get_embedded_template_content(&template_file)
-                {
-                    fs::write(&output_file_path, file_content).with_context(|| {
-                        format!("Failed to write file: {}", output_file_path.display())
-                    })?;
-
-                    println!("📋 Copied: {relative_path} -> {output_relative_path}");
-                } else {
-                    return Err(anyhow::anyhow!("File content not found: {template_file}"));
-                }
+                copy_embedded_file(&template_file, &output_file_path, &output_relative_path)?;
+                status!("📋 Copied: {relative_path} -> {output_relative_path}");
+            }
+            if is_redis_file(relative_path) {
+                feature_manifest::record_file("redis", &output_file_path);
             }
         }
 
         Ok(())
     }
-}
 
-impl GinGenerator {
-    /// 后处理逻辑 - 处理 Swagger 文档生成
-    pub fn post_process(&self, params: &GinParams, output_path: &Path) -> Result<()> {
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let host = params.base.host.as_deref().unwrap_or("localhost");
+        let port = params.base.port.unwrap_or(8080);
+
+        // 需要单独启动的依赖服务：数据库（SQLite 除外）和 Redis
+        let database = params.database().filter(|db| *db != Database::Sqlite);
+        let services: Vec<&str> = database
+            .map(|db| db.as_str())
+            .into_iter()
+            .chain(params.enable_redis().then_some("redis"))
+            .collect();
+        if !services.is_empty() {
+            if params.base.enable_docker {
+                let description = match (database.is_some(), params.enable_redis()) {
+                    (true, true) => "Start the database and Redis",
+                    (true, false) => "Start the database",
+                    _ => "Start Redis",
+                };
+                steps.command(
+                    format!("docker-compose up -d {}", services.join(" ")),
+                    description,
+                );
+            } else {
+                if let Some(database) = database {
+                    steps.caveat(format!(
+                        "Start {database} and update the [database] section in config/dev.toml before running the server"
+                    ));
+                }
+                if params.enable_redis() {
+                    steps.caveat(
+                        "Start Redis and update the [redis] section in config/dev.toml before running the server",
+                    );
+                }
+            }
+        }
+        // sqlx 和 ent 不自动迁移，启动前先用迁移创建表
+        if matches!(params.orm(), Some(Orm::Sqlx | Orm::Ent)) {
+            steps.command("./scripts/migrate.sh up", "Create the database tables");
+        }
+        steps.command("go run main.go", "Start the server");
+        if params.base.enable_tests {
+            steps.command("go test ./...", "Run the tests");
+        }
+        steps.url("Health check", local_url(host, port, "/health"));
         if params.enable_swagger() {
-            println!("Checking for swag command...");
+            steps.url("Swagger UI", local_url(host, port, "/swagger/index.html"));
+        }
+        if params.enable_grpc() {
+            steps.command(
+                format!(
+                    "grpcurl -plaintext -d '{{\"name\": \"gopher\"}}' localhost:{} greeter.v1.GreeterService/SayHello",
+                    params.grpc_port
+                ),
+                "Call the sample gRPC service",
+            );
+        }
+    }
+}
 
-            // 使用同步方式检查 swag 命令
-            let has_swag = match std::process::Command::new("swag").arg("--version").output() {
-                Ok(output) => output.status.success(),
-                Err(_) => false,
-            };
+impl GinGenerator {
+    /// 启用 gRPC 时用 buf 生成 gen/ 中的代码，需要在 go mod tidy 之前运行，
+    /// 否则 grpcserver 引用的包不存在
+    pub fn generate_grpc_code(&self, params: &GinParams, output_path: &Path) -> Result<()> {
+        if !params.enable_grpc() || render_only::skip_tool("buf code generation") {
+            return Ok(());
+        }
+        GoTools::buf_generate(output_path)
+    }
 
-            if !has_swag {
-                println!(
-                    "Warning: 'swag' command not found. Please install swag to generate Swagger documentation:"
-                );
-                println!("   go install github.com/swaggo/swag/cmd/swag@latest");
-                return Ok(());
-            }
+    /// `--orm ent` 时按 ent/schema 生成 ent 客户端，需要在 go mod init 之后、go mod tidy 之前运行
+    pub fn generate_ent_code(&self, params: &GinParams, output_path: &Path) -> Result<()> {
+        if params.orm() != Some(Orm::Ent) || render_only::skip_tool("ent code generation") {
+            return Ok(());
+        }
+        GoTools::ent_generate(output_path)
+    }
 
-            // 执行 swag init 命令
-            let output = std::process::Command::new("swag")
-                .arg("init")
-                .arg("-g")
-                .arg("main.go")
-                .current_dir(output_path)
-                .output()
-                .context("Failed to execute swag init command")?;
-
-            if output.status.success() {
-                println!("Swagger documentation generated successfully");
-
-                // 升级 swag 版本以确保兼容性
-                println!("Upgrading swag to latest version...");
-                let upgrade_output = std::process::Command::new("go")
-                    .arg("get")
-                    .arg("-u")
-                    .arg("github.com/swaggo/swag")
-                    .current_dir(output_path)
-                    .output()
-                    .context("Failed to execute go get -u github.com/swaggo/swag command")?;
-
-                if upgrade_output.status.success() {
-                    println!("Swag upgraded successfully");
-                } else {
-                    let stderr = String::from_utf8_lossy(&upgrade_output.stderr);
-                    println!("Warning: Failed to upgrade swag: {stderr}");
-                }
+    /// 按 `--openapi` 生成代码后复制规范文件到 api/，并格式化生成的路由和模型
+    pub fn generate_openapi_code(&self, params: &GinParams, output_path: &Path) -> Result<()> {
+        let Some(ref openapi) = params.openapi else {
+            return Ok(());
+        };
+        let spec_file = output_path.join(openapi.output_file());
+        if let Some(parent) = spec_file.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        std::fs::copy(&openapi.source, &spec_file)
+            .with_context(|| format!("Failed to copy {}", openapi.source.display()))?;
+        feature_manifest::record_file("openapi", &spec_file);
+        status!(
+            "Generated {} routes from {}",
+            openapi.operations.len(),
+            openapi.output_file()
+        );
+
+        if render_only::skip_tool("gofmt") {
+            return Ok(());
+        }
+        GoTools::gofmt(output_path, &["routers", "models/openapi"])
+    }
 
-                // 向 swagger.json 文件末尾添加空白行以符合格式规范
-                let swagger_json_path = output_path.join("docs").join("swagger.json");
-                if swagger_json_path.exists()
-                    && let Ok(mut content) = std::fs::read_to_string(&swagger_json_path)
-                {
-                    // 检查文件是否以换行符结尾（支持不同平台的换行符）
-                    let needs_newline = !content.ends_with('\n') && !content.ends_with("\r\n");
-
-                    if needs_newline {
-                        content.push('\n');
-
-                        if let Err(e) = std::fs::write(&swagger_json_path, content) {
-                            println!("Warning: Failed to add newline to swagger.json: {e}");
-                        } else {
-                            println!("Added newline to swagger.json for proper formatting");
-                        }
-                    }
-                }
+    /// 后处理逻辑 - 处理 Swagger 文档生成
+    pub fn post_process(&self, params: &GinParams, output_path: &Path) -> Result<()> {
+        if render_only::skip_tool("swag code generation") {
+            return Ok(());
+        }
 
-                // 生成 Swagger 文档后，重新运行 go mod tidy 来整理新增的依赖
-                GoTools::mod_tidy(output_path)
-                    .context("Failed to run go mod tidy after Swagger generation")?;
-            } else {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                println!("Failed to generate Swagger documentation: {stderr}");
-            }
+        if params.enable_swagger() {
+            GoTools::swag_init(output_path)?;
         }
 
         Ok(())
@@ -236,18 +276,50 @@ impl GinGenerator {
             false
         }
     }
+}
 
-    /// 检查是否应该跳过pre-commit相关文件
-    fn should_skip_precommit_file(&self, file_name: &str, params: &GinParams) -> bool {
-        if !params.enable_precommit() {
-            // 如果禁用pre-commit，跳过所有pre-commit相关文件
-            file_name == ".pre-commit-config.yaml.tmpl" || file_name == ".pre-commit-config.yaml"
-        } else {
-            false
-        }
+/// 仅在启用数据库时生成的模板：连接初始化、示例模型和仓储
+const DATABASE_TEMPLATES: &[&str] = &["pkg/database/", "repository/", "models/user.go.tmpl"];
+
+fn is_database_file(relative_path: &str) -> bool {
+    DATABASE_TEMPLATES
+        .iter()
+        .any(|prefix| relative_path.starts_with(prefix))
+}
+
+/// ent 的实体定义和代码生成入口，只在 `--orm ent` 时生成
+const ENT_TEMPLATES: &str = "ent/";
+
+/// ent 项目使用 ent 生成的实体类型，不生成示例模型；其他项目不生成 ent 目录
+fn is_other_orm_file(relative_path: &str, orm: Option<Orm>) -> bool {
+    if orm == Some(Orm::Ent) {
+        relative_path == "models/user.go.tmpl"
+    } else {
+        relative_path.starts_with(ENT_TEMPLATES)
     }
 }
 
+/// 只属于部分日志库的模板：zap 使用自己的配置和构造函数，zerolog 和 slog 共用 `[log]` 配置
+fn is_other_logger_file(relative_path: &str, logger: Logger) -> bool {
+    let loggers: &[Logger] = match relative_path {
+        "pkg/logging/zap.go.tmpl" | "pkg/logging/logger.go.tmpl" => &[Logger::Zap],
+        "pkg/logging/config.go.tmpl" => &[Logger::Zerolog, Logger::Slog],
+        "pkg/logging/zerolog.go.tmpl" => &[Logger::Zerolog],
+        "pkg/logging/slog.go.tmpl" => &[Logger::Slog],
+        _ => return false,
+    };
+    !loggers.contains(&logger)
+}
+
+/// 仅在启用 Redis 时生成的模板：客户端初始化和缓存中间件
+const REDIS_TEMPLATES: &[&str] = &["pkg/cache/", "middlewares/cache.go.tmpl"];
+
+fn is_redis_file(relative_path: &str) -> bool {
+    REDIS_TEMPLATES
+        .iter()
+        .any(|prefix| relative_path.starts_with(prefix))
+}
+
 impl FrameworkGeneratorTrait for GinGenerator {
     fn framework(&self) -> &'static str {
         Framework::Gin.as_str()
@@ -257,21 +329,17 @@ impl FrameworkGeneratorTrait for GinGenerator {
         Language::Go.as_str()
     }
 
-    fn generate_basic_structure(
-        &mut self,
-        _params: &Self::Params,
-        _output_path: &Path,
-    ) -> Result<()> {
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 不再需要自定义结构生成，完全依赖模板
         Ok(())
     }
 
-    fn generate_config(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 配置文件通过模板生成
         Ok(())
     }
 
-    fn generate_middleware(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 中间件通过模板生成
         Ok(())
     }
@@ -1,4 +1,5 @@
 pub mod generator;
+pub mod openapi;
 pub mod parameters;
 
 // 明确导出具体类型，避免通配符导入
@@ -0,0 +1,820 @@
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::constants::string_utils;
+use crate::status;
+
+/// 生成的模型所在的 Go 包名，路由中以 `openapi.Pet` 的形式引用
+const MODELS_PACKAGE: &str = "openapi";
+
+/// 路径项中按顺序处理的 HTTP 方法
+const METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch"];
+
+/// 模板已经注册的路由，规范中的同名路由会导致 Gin 启动时 panic，跳过并提示
+const RESERVED_ROUTES: &[(&str, &str)] = &[
+    ("GET", "/health"),
+    ("GET", "/metrics"),
+    ("GET", "/api/v1/ping"),
+];
+
+/// 不能用作变量名的 Go 关键字和处理器中已使用的标识符
+const RESERVED_IDENTS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+    "c",
+    "o",
+    "req",
+    "err",
+    "http",
+    "gin",
+    "openapi",
+];
+
+/// 从 OpenAPI 3 规范解析出的路由和模型，作为 Gin 模板的渲染上下文
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpenApiSpec {
+    /// 规范文件的路径，生成时复制到项目的 `api/` 目录
+    pub source: PathBuf,
+    pub operations: Vec<Operation>,
+    pub models: Vec<Model>,
+}
+
+/// 一个接口：Gin 路由注册和处理器桩代码
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Operation {
+    /// 大写的 HTTP 方法，对应 `rg.GET` 等注册函数
+    pub method: String,
+    /// Gin 风格的路径，如 `/pets/:petId`
+    pub path: String,
+    /// 规范中的路径，如 `/pets/{petId}`，用于 swag 注释
+    pub spec_path: String,
+    pub handler: String,
+    pub summary: String,
+    pub tag: Option<String>,
+    pub path_params: Vec<Param>,
+    pub query_params: Vec<Param>,
+    /// 请求体绑定的 Go 类型，如 `openapi.NewPet`
+    pub request_type: Option<String>,
+    /// 成功时的状态码
+    pub status: u16,
+    /// 成功响应的 Go 类型，没有响应体时为空
+    pub response_type: Option<String>,
+    /// swag `@Success` 注释中的类型，如 `{array} openapi.Pet`
+    pub swag_response: Option<String>,
+    /// 丢弃桩代码中尚未使用的参数，如 `_, _ = petId, req`，避免编译错误
+    pub discard: Option<String>,
+}
+
+/// 路径或查询参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Param {
+    /// 规范中的参数名
+    pub name: String,
+    /// 注册路由时使用的参数名，同一位置的参数名需要一致
+    pub route_name: String,
+    /// 处理器中的变量名
+    pub var: String,
+    /// swag `@Param` 注释中的类型
+    pub swag_type: String,
+    pub required: bool,
+    pub description: String,
+}
+
+/// `components.schemas` 或内联请求/响应体对应的 Go 类型
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Model {
+    pub name: String,
+    pub description: String,
+    /// `struct`、`enum`（字符串枚举）或 `alias`（数组、基本类型等）
+    pub kind: String,
+    /// `allOf` 中引用的其他模型，作为嵌入字段
+    pub embeds: Vec<String>,
+    pub fields: Vec<Field>,
+    /// 枚举和别名的底层类型
+    pub underlying: String,
+    pub values: Vec<EnumValue>,
+}
+
+/// 结构体字段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Field {
+    pub name: String,
+    pub go_type: String,
+    pub tag: String,
+    pub description: String,
+}
+
+/// 字符串枚举的取值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnumValue {
+    pub name: String,
+    pub value: String,
+}
+
+impl OpenApiSpec {
+    /// 读取并解析 YAML 或 JSON 格式的 OpenAPI 3 规范
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read OpenAPI spec: {}", path.display()))?;
+        let doc: Value = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse OpenAPI spec: {}", path.display()))?;
+        let mut spec = Self::parse(&doc)
+            .with_context(|| format!("Invalid OpenAPI spec: {}", path.display()))?;
+        spec.source = path.to_path_buf();
+        Ok(spec)
+    }
+
+    /// 从已解析的规范文档生成路由和模型
+    pub fn parse(doc: &Value) -> Result<Self> {
+        match doc.get("openapi").and_then(Value::as_str) {
+            Some(version) if version.starts_with("3.") => {}
+            Some(version) => bail!("Unsupported OpenAPI version {version}; expected 3.x"),
+            None if doc.get("swagger").is_some() => {
+                bail!("Swagger 2.0 specs are not supported; convert the spec to OpenAPI 3 first")
+            }
+            None => bail!("Missing the `openapi` version field"),
+        }
+
+        let mut parser = Parser {
+            doc,
+            models: Vec::new(),
+            handlers: vec!["InitOpenApiRoutes".to_string()],
+            route_params: HashMap::new(),
+        };
+        if let Some(schemas) = doc
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(Value::as_mapping)
+        {
+            for (name, schema) in schemas {
+                let name = type_name(name.as_str().unwrap_or_default());
+                parser.add_model(name, schema);
+            }
+        }
+
+        let mut operations = Vec::new();
+        if let Some(paths) = doc.get("paths").and_then(Value::as_mapping) {
+            for (path, item) in paths {
+                let Some(path) = path.as_str() else { continue };
+                let shared = item.get("parameters").cloned();
+                for method in METHODS {
+                    let Some(operation) = item.get(*method) else {
+                        continue;
+                    };
+                    let method = method.to_uppercase();
+                    if RESERVED_ROUTES.contains(&(method.as_str(), path)) {
+                        status!(
+                            "Skipping {method} {path} from the OpenAPI spec: the template already serves it"
+                        );
+                        continue;
+                    }
+                    operations.push(parser.operation(&method, path, operation, shared.as_ref())?);
+                }
+            }
+        }
+
+        Ok(Self {
+            source: PathBuf::new(),
+            operations,
+            models: parser.models,
+        })
+    }
+
+    /// 是否有接口引用生成的模型，决定路由文件是否导入模型包
+    pub fn uses_models(&self) -> bool {
+        let prefix = format!("{MODELS_PACKAGE}.");
+        self.operations.iter().any(|operation| {
+            [&operation.request_type, &operation.response_type]
+                .into_iter()
+                .flatten()
+                .any(|ty| ty.contains(&prefix))
+        })
+    }
+
+    /// 复制到项目中的规范文件路径，保留原扩展名
+    pub fn output_file(&self) -> String {
+        let extension = self
+            .source
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("yaml");
+        format!("api/openapi.{extension}")
+    }
+}
+
+struct Parser<'a> {
+    doc: &'a Value,
+    models: Vec<Model>,
+    /// 已使用的处理器名
+    handlers: Vec<String>,
+    /// 路径中参数的位置（参数之前的部分）到第一次出现的参数名，Gin 要求同一位置的参数名一致
+    route_params: HashMap<String, String>,
+}
+
+impl Parser<'_> {
+    /// 解析 `$ref` 指向的文档节点，只支持当前文档中的 `#/...` 引用
+    fn resolve<'v>(&'v self, value: &'v Value) -> &'v Value {
+        let Some(reference) = value.get("$ref").and_then(Value::as_str) else {
+            return value;
+        };
+        reference
+            .strip_prefix("#/")
+            .and_then(|pointer| {
+                pointer
+                    .split('/')
+                    .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+                    .try_fold(self.doc, |node, segment| node.get(segment.as_str()))
+            })
+            .unwrap_or(value)
+    }
+
+    fn operation(
+        &mut self,
+        method: &str,
+        path: &str,
+        operation: &Value,
+        shared: Option<&Value>,
+    ) -> Result<Operation> {
+        let handler = operation
+            .get("operationId")
+            .and_then(Value::as_str)
+            .map(type_name)
+            .unwrap_or_else(|| type_name(&format!("{} {path}", method.to_lowercase())));
+        let handler = unique_name(&handler, &self.handlers);
+        self.handlers.push(handler.clone());
+
+        let summary = ["summary", "description"]
+            .into_iter()
+            .find_map(|key| operation.get(key).and_then(Value::as_str))
+            .map(single_line)
+            .unwrap_or_else(|| format!("{method} {path}"));
+        let tag = operation
+            .get("tags")
+            .and_then(Value::as_sequence)
+            .and_then(|tags| tags.first())
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        // 路径项和接口中的参数，接口中的同名参数优先
+        let mut parameters: Vec<Value> = Vec::new();
+        for list in [operation.get("parameters"), shared].into_iter().flatten() {
+            for parameter in list.as_sequence().into_iter().flatten() {
+                let parameter = self.resolve(parameter).clone();
+                let key = |p: &Value| (p.get("name").cloned(), p.get("in").cloned());
+                if !parameters.iter().any(|p| key(p) == key(&parameter)) {
+                    parameters.push(parameter);
+                }
+            }
+        }
+        let mut vars: Vec<String> = Vec::new();
+        let mut param = |parameter: &Value| {
+            let name = parameter
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let var = unique_name(&var_name(&name), &vars);
+            vars.push(var.clone());
+            let swag_type = parameter
+                .get("schema")
+                .and_then(|schema| schema.get("type"))
+                .and_then(Value::as_str)
+                .filter(|ty| matches!(*ty, "string" | "integer" | "number" | "boolean"))
+                .unwrap_or("string")
+                .to_string();
+            Param {
+                route_name: name.clone(),
+                var,
+                swag_type,
+                required: parameter
+                    .get("required")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+                description: parameter
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(|description| single_line(description).replace('"', "'"))
+                    .unwrap_or_else(|| name.clone()),
+                name,
+            }
+        };
+        let location = |parameter: &&Value, location: &str| {
+            parameter.get("in").and_then(Value::as_str) == Some(location)
+        };
+        let mut path_params: Vec<Param> = parameters
+            .iter()
+            .filter(|p| location(p, "path"))
+            .map(&mut param)
+            .collect();
+        let query_params: Vec<Param> = parameters
+            .iter()
+            .filter(|p| location(p, "query"))
+            .map(&mut param)
+            .collect();
+        let route = self.route(path, &mut path_params);
+
+        let request_type = operation
+            .get("requestBody")
+            .map(|body| self.resolve(body).clone())
+            .and_then(|body| json_schema(&body))
+            .map(|schema| self.go_type(&schema, MODELS_PACKAGE, &format!("{handler}Request")));
+
+        // 第一个 2xx 响应作为成功响应
+        let responses = operation.get("responses").and_then(Value::as_mapping);
+        let success = responses
+            .into_iter()
+            .flatten()
+            .filter_map(|(code, response)| {
+                let code = match code {
+                    Value::Number(n) => n.as_u64()?,
+                    Value::String(s) => s.parse().ok()?,
+                    _ => return None,
+                };
+                (200..300)
+                    .contains(&code)
+                    .then(|| (code as u16, self.resolve(response).clone()))
+            })
+            .min_by_key(|(code, _)| *code);
+        let (status, response_type) = match success {
+            Some((code, response)) => (
+                code,
+                json_schema(&response).map(|schema| {
+                    self.go_type(&schema, MODELS_PACKAGE, &format!("{handler}Response"))
+                }),
+            ),
+            None => (200, None),
+        };
+        let swag_response = response_type
+            .as_deref()
+            .map(|ty| match ty.strip_prefix("[]") {
+                Some(item) => format!("{{array}} {item}"),
+                None if ty.starts_with(&format!("{MODELS_PACKAGE}.")) || ty.starts_with("map[") => {
+                    format!("{{object}} {ty}")
+                }
+                None => format!("{{{}}} {ty}", swag_primitive(ty)),
+            });
+
+        let unused: Vec<&str> = path_params
+            .iter()
+            .chain(&query_params)
+            .map(|param| param.var.as_str())
+            .chain(request_type.as_ref().map(|_| "req"))
+            .collect();
+        let discard = (!unused.is_empty()).then(|| {
+            format!(
+                "{} = {}",
+                vec!["_"; unused.len()].join(", "),
+                unused.join(", ")
+            )
+        });
+
+        Ok(Operation {
+            method: method.to_string(),
+            path: route,
+            spec_path: path.to_string(),
+            handler,
+            summary,
+            tag,
+            path_params,
+            query_params,
+            request_type,
+            status,
+            response_type,
+            swag_response,
+            discard,
+        })
+    }
+
+    /// 将 `/pets/{petId}` 转换为 Gin 的 `/pets/:petId`，同一位置已注册过其他参数名时沿用该名称
+    fn route(&mut self, path: &str, params: &mut [Param]) -> String {
+        let mut route = String::new();
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            route.push('/');
+            match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(name) => {
+                    let route_name = self
+                        .route_params
+                        .entry(route.clone())
+                        .or_insert_with(|| name.to_string())
+                        .clone();
+                    if let Some(param) = params.iter_mut().find(|p| p.name == name) {
+                        param.route_name = route_name.clone();
+                    }
+                    route.push(':');
+                    route.push_str(&route_name);
+                }
+                None => route.push_str(segment),
+            }
+        }
+        if route.is_empty() {
+            route.push('/');
+        }
+        route
+    }
+
+    /// 模式对应的 Go 类型；内联的对象模式生成名为 `hint` 的模型，`prefix` 为引用模型时的包名
+    fn go_type(&mut self, schema: &Value, prefix: &str, hint: &str) -> String {
+        let qualify = |name: &str| match prefix {
+            "" => name.to_string(),
+            _ => format!("{prefix}.{name}"),
+        };
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return qualify(&type_name(
+                reference.rsplit('/').next().unwrap_or(reference),
+            ));
+        }
+        match schema.get("type").and_then(Value::as_str) {
+            Some("array") => {
+                let items = schema.get("items").cloned().unwrap_or(Value::Null);
+                format!("[]{}", self.go_type(&items, prefix, &format!("{hint}Item")))
+            }
+            Some("string") => "string".to_string(),
+            Some("integer") => match schema.get("format").and_then(Value::as_str) {
+                Some("int32") => "int32".to_string(),
+                _ => "int64".to_string(),
+            },
+            Some("number") => match schema.get("format").and_then(Value::as_str) {
+                Some("float") => "float32".to_string(),
+                _ => "float64".to_string(),
+            },
+            Some("boolean") => "bool".to_string(),
+            _ if schema.get("properties").is_some() || schema.get("allOf").is_some() => {
+                let name = unique_name(hint, &self.model_names());
+                self.add_model(name.clone(), schema);
+                qualify(&name)
+            }
+            _ => match schema.get("additionalProperties") {
+                Some(Value::Mapping(values)) if !values.is_empty() => {
+                    let values = Value::Mapping(values.clone());
+                    format!("map[string]{}", self.go_type(&values, prefix, hint))
+                }
+                _ if schema.get("type").and_then(Value::as_str) == Some("object") => {
+                    "map[string]any".to_string()
+                }
+                _ => "any".to_string(),
+            },
+        }
+    }
+
+    fn model_names(&self) -> Vec<String> {
+        self.models.iter().map(|model| model.name.clone()).collect()
+    }
+
+    /// 添加一个模型：对象为结构体，字符串枚举为带常量的类型，其余为类型别名
+    fn add_model(&mut self, name: String, schema: &Value) {
+        let description = schema
+            .get("description")
+            .and_then(Value::as_str)
+            .map(single_line)
+            .unwrap_or_default();
+        let index = self.models.len();
+        self.models.push(Model {
+            name: name.clone(),
+            description,
+            kind: "alias".to_string(),
+            embeds: Vec::new(),
+            fields: Vec::new(),
+            underlying: String::new(),
+            values: Vec::new(),
+        });
+
+        if schema.get("properties").is_some() || schema.get("allOf").is_some() {
+            let mut embeds = Vec::new();
+            let mut fields = Vec::new();
+            let parts: Vec<Value> = match schema.get("allOf").and_then(Value::as_sequence) {
+                Some(parts) => parts.clone(),
+                None => vec![schema.clone()],
+            };
+            for part in &parts {
+                if let Some(reference) = part.get("$ref").and_then(Value::as_str) {
+                    embeds.push(type_name(reference.rsplit('/').next().unwrap_or(reference)));
+                    continue;
+                }
+                fields.extend(self.fields(&name, part));
+            }
+            let model = &mut self.models[index];
+            model.kind = "struct".to_string();
+            model.embeds = embeds;
+            model.fields = fields;
+            return;
+        }
+
+        let values: Vec<EnumValue> = schema
+            .get("enum")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(|value| EnumValue {
+                name: format!("{name}{}", type_name(value)),
+                value: value.replace('\\', "\\\\").replace('"', "\\\""),
+            })
+            .collect();
+        let underlying = self.go_type(schema, "", &format!("{name}Item"));
+        let model = &mut self.models[index];
+        if underlying == "string" && !values.is_empty() {
+            model.kind = "enum".to_string();
+            model.values = values;
+        }
+        model.underlying = underlying;
+    }
+
+    /// 对象模式的字段：必填字段添加 `binding:"required"`，零值合法的数字和布尔字段除外
+    fn fields(&mut self, model: &str, schema: &Value) -> Vec<Field> {
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let mut fields: Vec<Field> = Vec::new();
+        let properties = schema.get("properties").and_then(Value::as_mapping);
+        for (name, property) in properties.into_iter().flatten() {
+            let Some(name) = name.as_str() else { continue };
+            let field_name = unique_name(
+                &type_name(name),
+                &fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>(),
+            );
+            let go_type = self.go_type(property, "", &format!("{model}{field_name}"));
+            let tag = if !required.contains(&name) {
+                format!("json:\"{name},omitempty\"")
+            } else if go_type == "bool"
+                || go_type.starts_with("int")
+                || go_type.starts_with("float")
+            {
+                format!("json:\"{name}\"")
+            } else {
+                format!("json:\"{name}\" binding:\"required\"")
+            };
+            fields.push(Field {
+                name: field_name,
+                go_type,
+                tag,
+                description: property
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(single_line)
+                    .unwrap_or_default(),
+            });
+        }
+        fields
+    }
+}
+
+/// 请求体或响应中 JSON 内容的模式
+fn json_schema(body: &Value) -> Option<Value> {
+    let content = body.get("content")?.as_mapping()?;
+    content
+        .iter()
+        .find(|(media, _)| media.as_str().is_some_and(|media| media.contains("json")))
+        .and_then(|(_, media)| media.get("schema"))
+        .cloned()
+}
+
+/// swag 注释中基本类型的名称
+fn swag_primitive(go_type: &str) -> &'static str {
+    match go_type {
+        "bool" => "boolean",
+        ty if ty.starts_with("int") => "integer",
+        ty if ty.starts_with("float") => "number",
+        _ => "string",
+    }
+}
+
+/// 规范中的名称转换为导出的 Go 标识符，如 `list-pets` 为 `ListPets`
+fn type_name(name: &str) -> String {
+    let words: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let name = string_utils::to_pascal_case(&words);
+    match name.chars().next() {
+        Some(c) if c.is_ascii_digit() => format!("N{name}"),
+        Some(_) => name,
+        None => "Empty".to_string(),
+    }
+}
+
+/// 参数名转换为处理器中的变量名，避开关键字和已使用的标识符
+fn var_name(name: &str) -> String {
+    let name = type_name(name);
+    let mut chars = name.chars();
+    let var = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    };
+    if RESERVED_IDENTS.contains(&var.as_str()) {
+        format!("{var}Param")
+    } else {
+        var
+    }
+}
+
+/// 与已有名称重复时追加序号
+fn unique_name(name: &str, taken: &[String]) -> String {
+    let mut candidate = name.to_string();
+    let mut index = 2;
+    while taken.contains(&candidate) {
+        candidate = format!("{name}{index}");
+        index += 1;
+    }
+    candidate
+}
+
+/// 多行描述合并为一行，用于 Go 注释
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      tags: [pets]
+      parameters:
+        - name: limit
+          in: query
+          schema: { type: integer }
+      responses:
+        "200":
+          content:
+            application/json:
+              schema:
+                type: array
+                items: { $ref: "#/components/schemas/Pet" }
+    post:
+      summary: Create a pet
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              required: [name]
+              properties:
+                name: { type: string }
+                age: { type: integer, format: int32 }
+      responses:
+        "201":
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Pet" }
+  /pets/{petId}:
+    parameters:
+      - $ref: "#/components/parameters/PetId"
+    delete:
+      operationId: delete_pet
+      responses:
+        "204": { description: deleted }
+  /pets/{id}/photos:
+    get:
+      parameters:
+        - { name: id, in: path, required: true, schema: { type: string } }
+        - { name: type, in: query, schema: { type: string } }
+      responses:
+        "200": { description: ok }
+  /health:
+    get:
+      responses:
+        "200": { description: ok }
+components:
+  parameters:
+    PetId:
+      name: petId
+      in: path
+      required: true
+      schema: { type: string }
+  schemas:
+    Pet:
+      required: [id, status]
+      properties:
+        id: { type: integer }
+        status: { $ref: "#/components/schemas/PetStatus" }
+        tags: { type: array, items: { type: string } }
+    PetStatus:
+      type: string
+      enum: [available, sold-out]
+"##;
+
+    #[test]
+    fn test_parse_spec() {
+        let doc: Value = serde_yaml::from_str(SPEC).unwrap();
+        let spec = OpenApiSpec::parse(&doc).unwrap();
+
+        // 与模板中健康检查重复的路由被跳过
+        let routes: Vec<(&str, &str, &str)> = spec
+            .operations
+            .iter()
+            .map(|o| (o.method.as_str(), o.path.as_str(), o.handler.as_str()))
+            .collect();
+        assert_eq!(
+            routes,
+            [
+                ("GET", "/pets", "ListPets"),
+                ("POST", "/pets", "PostPets"),
+                ("DELETE", "/pets/:petId", "DeletePet"),
+                ("GET", "/pets/:petId/photos", "GetPetsIdPhotos"),
+            ]
+        );
+
+        let list = &spec.operations[0];
+        assert_eq!(list.query_params[0].var, "limit");
+        assert_eq!(list.query_params[0].swag_type, "integer");
+        assert_eq!(list.response_type.as_deref(), Some("[]openapi.Pet"));
+        assert_eq!(list.swag_response.as_deref(), Some("{array} openapi.Pet"));
+
+        let create = &spec.operations[1];
+        assert_eq!(create.summary, "Create a pet");
+        assert_eq!(
+            create.request_type.as_deref(),
+            Some("openapi.PostPetsRequest")
+        );
+        assert_eq!(create.status, 201);
+        assert_eq!(
+            create.swag_response.as_deref(),
+            Some("{object} openapi.Pet")
+        );
+
+        let delete = &spec.operations[2];
+        assert_eq!(delete.path_params[0].name, "petId");
+        assert_eq!(delete.status, 204);
+        assert_eq!(delete.response_type, None);
+        assert_eq!(delete.discard.as_deref(), Some("_ = petId"));
+        assert_eq!(create.discard.as_deref(), Some("_ = req"));
+
+        // 同一位置的路径参数沿用先注册的名称，关键字参数名改为其他变量名
+        let photos = &spec.operations[3];
+        assert_eq!(photos.spec_path, "/pets/{id}/photos");
+        assert_eq!(photos.path_params[0].route_name, "petId");
+        assert_eq!(photos.query_params[0].var, "typeParam");
+
+        let model = |name: &str| spec.models.iter().find(|m| m.name == name).unwrap();
+        let pet = model("Pet");
+        assert_eq!(pet.kind, "struct");
+        let fields: Vec<(&str, &str, &str)> = pet
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.go_type.as_str(), f.tag.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("Id", "int64", "json:\"id\""),
+                (
+                    "Status",
+                    "PetStatus",
+                    "json:\"status\" binding:\"required\""
+                ),
+                ("Tags", "[]string", "json:\"tags,omitempty\""),
+            ]
+        );
+        let status = model("PetStatus");
+        assert_eq!(status.kind, "enum");
+        assert_eq!(status.values[1].name, "PetStatusSoldOut");
+        assert_eq!(model("PostPetsRequest").fields[1].go_type, "int32");
+        assert!(spec.uses_models());
+    }
+
+    #[test]
+    fn test_unsupported_specs() {
+        let doc: Value = serde_yaml::from_str("swagger: '2.0'\npaths: {}").unwrap();
+        assert!(OpenApiSpec::parse(&doc).is_err());
+        let doc: Value = serde_yaml::from_str("info: {}").unwrap();
+        assert!(OpenApiSpec::parse(&doc).is_err());
+    }
+}
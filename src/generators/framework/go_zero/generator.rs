@@ -1,21 +1,29 @@
 use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::Path;
+use std::process::Command;
 
 use super::parameters::GoZeroParams;
-use crate::constants::{Framework, Language};
+use crate::constants::{Framework, GO_ZERO_SERVICES, Language, string_utils};
 use crate::generators::core::{
-    FrameworkGenerator as FrameworkGeneratorTrait, Generator, Parameters, TemplateProcessor,
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, TemplateProcessor,
+    local_url, validation,
 };
+use crate::status;
+use crate::utils::go_tools::GoTools;
+use crate::utils::{render_only, tool_failures};
 
-pub struct GoZeroGenerator {
-    template_processor: TemplateProcessor,
-}
+/// Go-Zero框架级别生成器实现
+///
+/// 模板只提供 .api / .proto 服务定义和少量业务代码，其余代码由 goctl 在后处理阶段生成
+#[derive(Debug)]
+pub struct GoZeroGenerator {}
 
 impl GoZeroGenerator {
+    /// 创建新的Go-Zero生成器
     pub fn new() -> Result<Self> {
-        Ok(Self {
-            template_processor: TemplateProcessor::new()?,
-        })
+        Ok(Self {})
     }
 }
 
@@ -37,96 +45,281 @@ impl Generator for GoZeroGenerator {
     }
 
     fn get_template_path(&self) -> &'static str {
-        "frameworks/go/go_zero"
+        "frameworks/go/go-zero"
+    }
+
+    /// 渲染嵌入式模板 - 跳过未启用服务的模板
+    fn render_embedded_templates(
+        &self,
+        template_processor: &TemplateProcessor,
+        template_path: &str,
+        output_path: &Path,
+        context: HashMap<String, Value>,
+        params: &Self::Params,
+    ) -> Result<()> {
+        template_processor.process_embedded_template_directory_filtered(
+            template_path,
+            output_path,
+            context,
+            |relative_path| service_for(relative_path).is_none_or(|s| is_enabled(params, s)),
+        )
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let host = params.base.host.as_deref().unwrap_or("0.0.0.0");
+        let port = params.base.port.unwrap_or(8888);
+
+        if params.enable_api() {
+            steps.command("cd api && go run .", "Start the API service");
+            steps.url("API health check", local_url(host, port, "/health"));
+        }
+        if params.enable_rpc() {
+            steps.command("cd rpc && go run .", "Start the RPC service");
+            steps.caveat(
+                "The rpc service is not registered in etcd; add an Etcd section to rpc/etc/*.yaml to enable service discovery",
+            );
+        }
+        if params.enable_admin() {
+            steps.command("cd admin && go run .", "Start the admin service");
+            steps.url(
+                "Admin health check",
+                local_url(host, params.admin_port(), "/admin/health"),
+            );
+        }
     }
+}
 
-    fn generate(&mut self, params: Self::Params, output_path: &Path) -> Result<()> {
-        params.validate()?;
+impl GoZeroGenerator {
+    /// 后处理逻辑 - 使用 goctl 生成各服务代码并写入服务配置
+    pub fn post_process(&self, params: &GoZeroParams, output_path: &Path) -> Result<()> {
+        if render_only::skip_tool("goctl code generation") {
+            return Ok(());
+        }
 
-        println!("Generating Go-Zero microservice framework structure");
+        for service in GO_ZERO_SERVICES.iter().filter(|s| is_enabled(params, s)) {
+            status!("Generating {service} service with goctl...");
 
-        // 处理Go-Zero模板目录
-        let template_dir = "frameworks/go/go_zero";
-        if self.template_processor.template_exists(template_dir) {
-            let context = params.to_template_context();
+            let output = Command::new("goctl")
+                .args(goctl_args(service, params))
+                .current_dir(output_path)
+                .output()
+                .context("Failed to execute goctl command")?;
+
+            if output.status.success() {
+                let etc_dir = output_path.join(service).join("etc");
+                update_service_config(&etc_dir, &service_config(service, params))?;
+                status!("{service} service generated successfully");
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tool_failures::report(format!(
+                    "goctl failed to generate the {service} service: {}\n   Fix the issue and run ./scripts/gen-{service}.sh",
+                    stderr.trim()
+                ))?;
+            }
+        }
 
-            let template_path = std::path::Path::new(template_dir);
-            self.template_processor
-                .process_template_directory(template_path, output_path, context)
-                .context("Failed to process Go-Zero templates")?;
-        } else {
-            // 如果模板不存在，生成基础结构
-            self.generate_basic_structure(&params, output_path)?;
+        // goctl 生成的代码引入了 go-zero 依赖，需要重新整理
+        if let Err(e) = GoTools::mod_tidy(output_path) {
+            tool_failures::report(format!(
+                "Failed to run go mod tidy after goctl generation: {e}"
+            ))?;
         }
 
-        println!("Go-Zero microservice framework structure generated");
         Ok(())
     }
 }
 
-impl FrameworkGeneratorTrait for GoZeroGenerator {
-    fn framework(&self) -> &'static str {
-        Framework::GoZero.as_str()
+/// 模板文件所属的服务：服务目录下的文件，以及 `Dockerfile.<服务>`、`gen-<服务>.sh`
+fn service_for(relative_path: &str) -> Option<&'static str> {
+    let file_name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+    let file_name = file_name.strip_suffix(".tmpl").unwrap_or(file_name);
+
+    GO_ZERO_SERVICES.iter().copied().find(|service| {
+        relative_path.starts_with(&format!("{service}/"))
+            || file_name == format!("Dockerfile.{service}")
+            || file_name == format!("gen-{service}.sh")
+    })
+}
+
+fn is_enabled(params: &GoZeroParams, service: &str) -> bool {
+    match service {
+        "api" => params.enable_api(),
+        "rpc" => params.enable_rpc(),
+        "admin" => params.enable_admin(),
+        _ => false,
     }
+}
 
-    fn language(&self) -> &'static str {
-        Language::Go.as_str()
+/// goctl 命令参数，与生成项目中 scripts/gen-<服务>.sh 保持一致
+fn goctl_args(service: &str, params: &GoZeroParams) -> Vec<String> {
+    let project_name = &params.base.project_name;
+    let args = match service {
+        "rpc" => vec![
+            "rpc".to_string(),
+            "protoc".to_string(),
+            format!("rpc/{}.proto", string_utils::to_snake_case(project_name)),
+            "--go_out=rpc".to_string(),
+            "--go-grpc_out=rpc".to_string(),
+            "--zrpc_out=rpc".to_string(),
+        ],
+        "admin" => vec![
+            "api".to_string(),
+            "go".to_string(),
+            "-api".to_string(),
+            "admin/admin.api".to_string(),
+            "-dir".to_string(),
+            "admin".to_string(),
+        ],
+        _ => vec![
+            "api".to_string(),
+            "go".to_string(),
+            "-api".to_string(),
+            format!("api/{project_name}.api"),
+            "-dir".to_string(),
+            "api".to_string(),
+        ],
+    };
+
+    args.into_iter()
+        .chain(std::iter::once("--style=goZero".to_string()))
+        .collect()
+}
+
+/// 服务配置中需要覆盖的顶层键；值为 None 表示删除该键及其下属内容
+fn service_config(service: &str, params: &GoZeroParams) -> Vec<(&'static str, Option<String>)> {
+    let host = params.base.host.as_deref().unwrap_or("0.0.0.0");
+    let quote = |value: String| serde_json::to_string(&value).unwrap_or(value);
+
+    match service {
+        // 不依赖 etcd 直接监听，需要服务注册时由用户自行添加 Etcd 配置
+        "rpc" => vec![
+            (
+                "ListenOn",
+                Some(quote(validation::format_host_port(host, params.grpc_port))),
+            ),
+            ("Etcd", None),
+        ],
+        "admin" => vec![
+            ("Host", Some(quote(validation::normalize_host(host)))),
+            ("Port", Some(params.admin_port().to_string())),
+        ],
+        _ => vec![
+            ("Host", Some(quote(validation::normalize_host(host)))),
+            ("Port", Some(params.base.port.unwrap_or(8888).to_string())),
+        ],
     }
+}
 
-    fn generate_basic_structure(
-        &mut self,
-        params: &Self::Params,
-        output_path: &Path,
-    ) -> Result<()> {
-        // 创建基础目录结构
-        let dirs = ["api", "rpc", "admin", "common", "model"];
+/// 改写 goctl 生成的 etc/*.yaml 服务配置
+fn update_service_config(etc_dir: &Path, values: &[(&str, Option<String>)]) -> Result<()> {
+    if !etc_dir.is_dir() {
+        return Ok(());
+    }
 
-        for dir in &dirs {
-            let dir_path = output_path.join(dir);
-            std::fs::create_dir_all(&dir_path)
-                .with_context(|| format!("Failed to create directory: {}", dir_path.display()))?;
+    for entry in std::fs::read_dir(etc_dir)
+        .with_context(|| format!("Failed to read directory: {}", etc_dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("yaml") {
+            continue;
         }
 
-        // 根据参数决定生成哪些服务
-        if params.enable_api() {
-            self.generate_api_service(params, output_path)?;
-        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        std::fs::write(&path, rewrite_config(&content, values))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
 
-        if params.enable_rpc() {
-            self.generate_rpc_service(params, output_path)?;
+    Ok(())
+}
+
+/// 按行替换 YAML 顶层键的值，goctl 生成的配置结构简单，无需完整解析
+fn rewrite_config(content: &str, values: &[(&str, Option<String>)]) -> String {
+    let mut lines = Vec::new();
+    let mut skipping = false;
+
+    for line in content.lines() {
+        let is_top_level = !line.is_empty() && !line.starts_with([' ', '\t', '-']);
+        if is_top_level {
+            let key = line.split(':').next().unwrap_or_default().trim();
+            skipping = false;
+            if let Some((_, value)) = values.iter().find(|(k, _)| *k == key) {
+                match value {
+                    Some(value) => lines.push(format!("{key}: {value}")),
+                    None => skipping = true,
+                }
+                continue;
+            }
         }
 
-        if params.enable_admin() {
-            self.generate_admin_service(params, output_path)?;
+        if !skipping {
+            lines.push(line.to_string());
         }
+    }
 
-        Ok(())
+    let mut output = lines.join("\n");
+    output.push('\n');
+    output
+}
+
+impl FrameworkGeneratorTrait for GoZeroGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::GoZero.as_str()
     }
 
-    fn generate_config(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
-        // Go-Zero 配置生成逻辑
-        Ok(())
+    fn language(&self) -> &'static str {
+        Language::Go.as_str()
     }
 
-    fn generate_middleware(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
-        // Go-Zero 中间件生成逻辑
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 服务定义通过模板生成，其余结构由 goctl 生成
         Ok(())
     }
-}
 
-impl GoZeroGenerator {
-    fn generate_api_service(&self, _params: &GoZeroParams, _output_path: &Path) -> Result<()> {
-        // 生成API服务相关文件
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 服务配置由 goctl 生成，在后处理阶段写入主机和端口
         Ok(())
     }
 
-    fn generate_rpc_service(&self, _params: &GoZeroParams, _output_path: &Path) -> Result<()> {
-        // 生成RPC服务相关文件
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 中间件由 goctl 根据 .api 定义生成
         Ok(())
     }
+}
 
-    fn generate_admin_service(&self, _params: &GoZeroParams, _output_path: &Path) -> Result<()> {
-        // 生成管理后台相关文件
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_for() {
+        assert_eq!(service_for("api/{{project_name}}.api.tmpl"), Some("api"));
+        assert_eq!(
+            service_for("admin/internal/logic/healthLogic.go.tmpl"),
+            Some("admin")
+        );
+        assert_eq!(
+            service_for("+docker/deploy/docker/Dockerfile.rpc.tmpl"),
+            Some("rpc")
+        );
+        assert_eq!(service_for("scripts/gen-admin.sh.tmpl"), Some("admin"));
+        assert_eq!(
+            service_for("+docker/deploy/docker/docker-compose.yml.tmpl"),
+            None
+        );
+        assert_eq!(service_for("common/response/response.go.tmpl"), None);
+    }
+
+    #[test]
+    fn test_rewrite_config() {
+        let content = "Name: demo.rpc\nListenOn: 0.0.0.0:8080\nEtcd:\n  Hosts:\n  - 127.0.0.1:2379\n  Key: demo.rpc\n";
+        let values = [
+            ("ListenOn", Some("\"127.0.0.1:9000\"".to_string())),
+            ("Etcd", None),
+        ];
+        assert_eq!(
+            rewrite_config(content, &values),
+            "Name: demo.rpc\nListenOn: \"127.0.0.1:9000\"\n"
+        );
     }
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
 
-use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::core::{BaseParams, InheritableParams, validation};
 use crate::generators::language::go::GoParams;
 use crate::generators::project::ProjectParams;
 
@@ -17,11 +19,18 @@ pub struct GoZeroParams {
     pub enable_api: bool,
     pub enable_rpc: bool,
     pub enable_admin: bool,
+    /// RPC 服务监听端口
+    pub grpc_port: u16,
 }
 
+/// RPC 服务默认端口
+pub const DEFAULT_GRPC_PORT: u16 = 9000;
+
 impl Default for GoZeroParams {
     fn default() -> Self {
         let base = BaseParams {
+            host: Some("127.0.0.1".to_string()),
+            port: Some(8888),
             default_host: Some("127.0.0.1".to_string()),
             default_port: Some(8888),
             enable_swagger: true,
@@ -38,6 +47,7 @@ impl Default for GoZeroParams {
             enable_api: true,
             enable_rpc: false,
             enable_admin: false,
+            grpc_port: DEFAULT_GRPC_PORT,
         }
     }
 }
@@ -59,10 +69,44 @@ impl InheritableParams for GoZeroParams {
             enable_api: true,
             enable_rpc: false,
             enable_admin: false,
+            grpc_port: DEFAULT_GRPC_PORT,
         }
     }
 
-    // Go-Zero参数有额外的project和go参数
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        let mut context = HashMap::new();
+
+        context.insert("enable_api".to_string(), json!(self.enable_api));
+        context.insert("enable_rpc".to_string(), json!(self.enable_rpc));
+        context.insert("enable_admin".to_string(), json!(self.enable_admin));
+
+        // 模块路径和 Go 版本来自 Go 语言参数
+        let module_name = self
+            .go
+            .base
+            .module_name
+            .clone()
+            .unwrap_or_else(|| GoParams::infer_module_name(&self.base.project_name));
+        context.insert("module_name".to_string(), json!(module_name));
+        if let Some(ref version) = self.go.base.language_version {
+            context.insert("go_version".to_string(), json!(version));
+        }
+
+        // RPC 服务与 API 服务共用主机地址，管理后台使用 API 端口 + 1
+        let host = self.base.host.as_deref().unwrap_or("0.0.0.0");
+        context.insert("grpc_port".to_string(), json!(self.grpc_port));
+        context.insert(
+            "grpc_listen_on".to_string(),
+            json!(validation::format_host_port(host, self.grpc_port)),
+        );
+        context.insert("admin_port".to_string(), json!(self.admin_port()));
+        context.insert(
+            "admin_host_port".to_string(),
+            json!(validation::format_host_port(host, self.admin_port())),
+        );
+
+        context
+    }
 }
 
 impl GoZeroParams {
@@ -73,10 +117,10 @@ impl GoZeroParams {
     }
 
     /// 从项目名称创建
-    #[allow(dead_code)]
     pub fn from_project_name(project_name: String) -> Self {
         let mut base = BaseParams::new(project_name.clone());
         // 设置Go-Zero特定的默认值
+        base.port = Some(8888);
         base.default_host = Some("127.0.0.1".to_string());
         base.default_port = Some(8888);
         base.enable_swagger = true;
@@ -92,20 +136,34 @@ impl GoZeroParams {
             enable_api: true,
             enable_rpc: false,
             enable_admin: false,
+            grpc_port: DEFAULT_GRPC_PORT,
         }
     }
 
     /// 设置主机地址
     #[allow(dead_code)]
     pub fn with_host(mut self, host: String) -> Self {
-        self.base.default_host = Some(host);
+        self.base.host = Some(host);
         self
     }
 
     /// 设置端口
     #[allow(dead_code)]
     pub fn with_port(mut self, port: u16) -> Self {
-        self.base.default_port = Some(port);
+        self.base.port = Some(port);
+        self
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.base.host = Some(host);
+        self.base.port = Some(port);
+        self
+    }
+
+    /// 设置 RPC 服务端口
+    pub fn with_grpc_port(mut self, grpc_port: u16) -> Self {
+        self.grpc_port = grpc_port;
         self
     }
 
@@ -145,33 +203,30 @@ impl GoZeroParams {
     }
 
     /// 设置是否启用Admin服务
-    #[allow(dead_code)]
     pub fn with_admin(mut self, enable_admin: bool) -> Self {
         self.enable_admin = enable_admin;
         self
     }
 
-    #[allow(dead_code)]
+    /// 设置是否启用API服务
     pub fn with_api(mut self, enable_api: bool) -> Self {
         self.enable_api = enable_api;
         self
     }
 
-    #[allow(dead_code)]
+    /// 设置是否启用RPC服务
     pub fn with_rpc(mut self, enable_rpc: bool) -> Self {
         self.enable_rpc = enable_rpc;
         self
     }
 
     /// 设置项目参数
-    #[allow(dead_code)]
     pub fn with_project(mut self, project: ProjectParams) -> Self {
         self.project = project;
         self
     }
 
     /// 设置Go参数
-    #[allow(dead_code)]
     pub fn with_go(mut self, go: GoParams) -> Self {
         self.go = go;
         self
@@ -224,4 +279,9 @@ impl GoZeroParams {
     pub fn enable_rpc(&self) -> bool {
         self.enable_rpc
     }
+
+    /// 管理后台服务端口（API 端口 + 1）
+    pub fn admin_port(&self) -> u16 {
+        self.base.port.unwrap_or(8888).saturating_add(1)
+    }
 }
@@ -1,6 +1,18 @@
+pub mod actix;
+pub mod astro;
+pub mod axum;
+pub mod chi;
+pub mod cli;
+pub mod django;
+pub mod fiber;
+pub mod flask;
 pub mod gin;
 pub mod go_zero;
+pub mod nestjs;
+pub mod nextjs;
+pub mod nuxt;
 pub mod react;
+pub mod sveltekit;
 pub mod tauri;
 pub mod vue3;
 
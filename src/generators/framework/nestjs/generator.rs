@@ -0,0 +1,109 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{DEFAULT_NESTJS_PORT, NestJsParams};
+use crate::constants::{Framework, Language, Runtime};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, local_url,
+};
+use crate::status;
+use crate::utils::{js_runtime, tool_failures};
+
+/// NestJS框架级别生成器实现
+///
+/// 不依赖 nest CLI，模板完全覆盖项目结构（包括 package.json 中的依赖），之后由所选运行时的包管理器安装依赖
+#[derive(Debug)]
+pub struct NestJsGenerator {}
+
+impl NestJsGenerator {
+    /// 创建新的NestJS生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+
+    /// 使用运行时的包管理器安装 package.json 中的依赖
+    pub fn install_dependencies(output_path: &Path, runtime: Runtime) -> Result<()> {
+        let package_manager = runtime.package_manager();
+        status!("📦 Installing dependencies with {package_manager}...");
+
+        let output = js_runtime::install(runtime)
+            .current_dir(output_path)
+            .output()
+            .with_context(|| format!("Failed to execute {package_manager} install"))?;
+
+        if output.status.success() {
+            status!("✅ Dependencies installed successfully");
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // lenient 策略下不返回错误，让用户手动安装
+            tool_failures::report(format!("Failed to install dependencies: {}", stderr.trim()))
+        }
+    }
+}
+
+impl Default for NestJsGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create NestJsGenerator")
+    }
+}
+
+impl Generator for NestJsGenerator {
+    type Params = NestJsParams;
+
+    fn name(&self) -> &'static str {
+        "NestJS"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates a NestJS REST API with modules, validated DTOs and Jest tests")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/typescript/nestjs"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let runtime = params.runtime;
+        let host = params.base.host.as_deref().unwrap_or("localhost");
+        let port = params.base.port.unwrap_or(DEFAULT_NESTJS_PORT);
+
+        steps.command(
+            runtime.run_command("start:dev"),
+            "Start the server in watch mode",
+        );
+        if params.base.enable_tests {
+            steps.command(runtime.run_command("test"), "Run the unit tests");
+            steps.command(runtime.run_command("test:e2e"), "Run the end-to-end tests");
+        }
+        steps.url("Health check", local_url(host, port, "/health"));
+        if params.base.enable_swagger {
+            steps.url("Swagger UI", local_url(host, port, "/docs"));
+        }
+    }
+}
+
+impl FrameworkGeneratorTrait for NestJsGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::NestJs.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::TypeScript.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构完全由模板生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 配置文件通过模板生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 全局 ValidationPipe 在 src/main.ts 模板中注册
+        Ok(())
+    }
+}
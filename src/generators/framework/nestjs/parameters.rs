@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::Runtime;
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::project::ProjectParams;
+
+/// NestJS 默认监听端口
+pub const DEFAULT_NESTJS_PORT: u16 = 3000;
+
+/// NestJS框架参数
+///
+/// 服务地址作为 `src/main.ts` 中的默认值，运行时可由 `HOST`、`PORT` 环境变量覆盖
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NestJsParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Node.js 版本
+    pub node_version: String,
+    /// JavaScript 运行时，决定包管理命令和 CI 配置
+    pub runtime: Runtime,
+}
+
+impl Default for NestJsParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            default_host: Some("0.0.0.0".to_string()),
+            default_port: Some(DEFAULT_NESTJS_PORT),
+            enable_swagger: true,
+            ..Default::default()
+        };
+
+        Self::from_base(base)
+    }
+}
+
+impl InheritableParams for NestJsParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            node_version: "20".to_string(),
+            runtime: Runtime::default(),
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        HashMap::from([
+            ("node_version".to_string(), json!(self.node_version)),
+            ("runtime".to_string(), json!(self.runtime)),
+            (
+                "package_manager".to_string(),
+                json!(self.runtime.package_manager()),
+            ),
+            ("lockfile".to_string(), json!(self.runtime.lockfile())),
+        ])
+    }
+}
+
+impl NestJsParams {
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        // 设置NestJS特定的默认值
+        base.default_host = Some("0.0.0.0".to_string());
+        base.default_port = Some(DEFAULT_NESTJS_PORT);
+        base.enable_swagger = true;
+
+        Self {
+            project: ProjectParams::from_project_name(project_name),
+            ..Self::from_base(base)
+        }
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.base.host = Some(host);
+        self.base.port = Some(port);
+        self
+    }
+
+    /// 设置是否生成 Swagger 文档
+    pub fn with_swagger(mut self, enable_swagger: bool) -> Self {
+        self.base.enable_swagger = enable_swagger;
+        self
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// 设置 Node.js 版本
+    #[allow(dead_code)]
+    pub fn with_node_version(mut self, version: String) -> Self {
+        self.node_version = version;
+        self
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{DEFAULT_NEXTJS_PORT, NextJsParams};
+use crate::constants::{Framework, Language, Runtime};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps,
+};
+use crate::status;
+use crate::utils::{js_runtime, tool_failures};
+
+/// Next.js框架级别生成器实现
+#[derive(Debug)]
+pub struct NextJsGenerator {}
+
+impl NextJsGenerator {
+    /// 创建新的Next.js生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+
+    /// 使用 create-next-app 非交互地创建项目（TypeScript、App Router、Tailwind CSS），
+    /// 依赖在创建时一并安装；deno 不受 create-next-app 支持，创建后单独执行 `deno install`
+    pub fn create_nextjs_project(
+        project_name: &str,
+        output_path: &Path,
+        params: &NextJsParams,
+    ) -> Result<()> {
+        status!("🚀 Creating Next.js project with create-next-app...");
+
+        // 获取父目录
+        let parent_dir = output_path.parent().unwrap_or_else(|| Path::new("."));
+
+        let output = js_runtime::create(params.runtime, "next-app@latest")
+            .arg(project_name)
+            .args(params.create_args())
+            .current_dir(parent_dir)
+            .output()
+            .context("Failed to execute create-next-app")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            return Err(anyhow::anyhow!(
+                "Failed to create Next.js project:\nstdout: {stdout}\nstderr: {stderr}"
+            ));
+        }
+
+        if params.runtime == Runtime::Deno {
+            let output = js_runtime::install(params.runtime)
+                .current_dir(output_path)
+                .output()
+                .context("Failed to execute deno install")?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tool_failures::report(format!(
+                    "Failed to install dependencies: {}",
+                    stderr.trim()
+                ))?;
+            }
+        }
+
+        status!("✅ Next.js project created successfully");
+        Ok(())
+    }
+}
+
+impl Default for NextJsGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create NextJsGenerator")
+    }
+}
+
+impl Generator for NextJsGenerator {
+    type Params = NextJsParams;
+
+    fn name(&self) -> &'static str {
+        "Next.js"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates Next.js application with TypeScript, App Router and Tailwind CSS")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/typescript/nextjs"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let runtime = params.runtime;
+        steps.command(runtime.run_command("dev"), "Start development server");
+        steps.command(runtime.run_command("build"), "Build for production");
+        if params.enable_eslint {
+            steps.command(runtime.run_command("lint"), "Run ESLint");
+        }
+        steps.url(
+            "Dev server",
+            format!("http://localhost:{DEFAULT_NEXTJS_PORT}"),
+        );
+    }
+}
+
+impl FrameworkGeneratorTrait for NextJsGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::NextJs.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::TypeScript.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构由 create-next-app 生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 配置文件由 create-next-app 生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // Next.js 不需要中间件
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+mod generator;
+pub mod parameters;
+
+pub use generator::NextJsGenerator;
+pub use parameters::NextJsParams;
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::Runtime;
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::project::ProjectParams;
+
+/// Next.js 开发服务器的默认端口
+pub const DEFAULT_NEXTJS_PORT: u16 = 3000;
+
+/// Next.js框架参数 - 继承自BaseParams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NextJsParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Node.js 版本
+    pub node_version: String,
+    /// JavaScript 运行时，决定包管理命令和 CI 配置
+    pub runtime: Runtime,
+    /// 是否启用 Tailwind CSS
+    pub enable_tailwind: bool,
+    /// 是否使用 App Router
+    pub enable_app_router: bool,
+    /// 是否把源码放在 src 目录下
+    pub enable_src_dir: bool,
+    /// 模块导入别名
+    pub import_alias: String,
+    /// 是否启用 ESLint
+    pub enable_eslint: bool,
+    /// 包管理器，由运行时决定 (pnpm、bun、deno)
+    pub package_manager: String,
+}
+
+impl Default for NextJsParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            default_host: Some("localhost".to_string()),
+            default_port: Some(DEFAULT_NEXTJS_PORT),
+            ..Default::default()
+        };
+
+        Self::from_base(base)
+    }
+}
+
+impl InheritableParams for NextJsParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            node_version: "20".to_string(),
+            runtime: Runtime::default(),
+            enable_tailwind: true,
+            enable_app_router: true,
+            enable_src_dir: true,
+            import_alias: "@/*".to_string(),
+            enable_eslint: true,
+            package_manager: "pnpm".to_string(),
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        // 项目结构由 create-next-app 生成，模板只有 CI 和 pre-commit 配置用到这些参数
+        HashMap::from([
+            ("node_version".to_string(), json!(self.node_version)),
+            ("runtime".to_string(), json!(self.runtime)),
+            ("enable_eslint".to_string(), json!(self.enable_eslint)),
+        ])
+    }
+}
+
+impl NextJsParams {
+    /// 创建新的Next.js参数
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        base.default_host = Some("localhost".to_string());
+        base.default_port = Some(DEFAULT_NEXTJS_PORT);
+
+        Self {
+            project: ProjectParams::from_project_name(project_name),
+            ..Self::from_base(base)
+        }
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self.package_manager = runtime.package_manager().to_string();
+        self
+    }
+
+    /// 获取是否启用pre-commit
+    #[allow(dead_code)]
+    pub fn enable_precommit(&self) -> bool {
+        self.base.enable_precommit
+    }
+
+    /// 设置是否启用 Tailwind CSS
+    #[allow(dead_code)]
+    pub fn with_tailwind(mut self, enable: bool) -> Self {
+        self.enable_tailwind = enable;
+        self
+    }
+
+    /// 设置 Node.js 版本
+    #[allow(dead_code)]
+    pub fn with_node_version(mut self, version: String) -> Self {
+        self.node_version = version;
+        self
+    }
+
+    /// `create-next-app` 的非交互参数，未启用的选项显式关闭以免工具再次询问
+    pub fn create_args(&self) -> Vec<String> {
+        let toggle = |enabled: bool, name: &str| {
+            if enabled {
+                format!("--{name}")
+            } else {
+                format!("--no-{name}")
+            }
+        };
+
+        // create-next-app 不支持 deno，跳过安装，由生成器随后执行 `deno install`
+        let install = match self.runtime {
+            Runtime::Deno => "--skip-install".to_string(),
+            _ => format!("--use-{}", self.package_manager),
+        };
+
+        vec![
+            "--ts".to_string(),
+            toggle(self.enable_app_router, "app"),
+            toggle(self.enable_tailwind, "tailwind"),
+            toggle(self.enable_eslint, "eslint"),
+            toggle(self.enable_src_dir, "src-dir"),
+            "--import-alias".to_string(),
+            self.import_alias.clone(),
+            install,
+            // git 仓库由项目级生成器初始化
+            "--disable-git".to_string(),
+            "--yes".to_string(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_args() {
+        let args = NextJsParams::default().create_args();
+        for expected in [
+            "--ts",
+            "--app",
+            "--tailwind",
+            "--src-dir",
+            "--use-pnpm",
+            "--yes",
+        ] {
+            assert!(args.iter().any(|arg| arg == expected), "missing {expected}");
+        }
+
+        let args = NextJsParams::default().with_tailwind(false).create_args();
+        assert!(args.iter().any(|arg| arg == "--no-tailwind"));
+
+        let args = NextJsParams::default()
+            .with_runtime(Runtime::Bun)
+            .create_args();
+        assert!(args.iter().any(|arg| arg == "--use-bun"));
+
+        let args = NextJsParams::default()
+            .with_runtime(Runtime::Deno)
+            .create_args();
+        assert!(args.iter().any(|arg| arg == "--skip-install"));
+    }
+}
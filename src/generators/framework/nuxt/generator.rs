@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{DEFAULT_NUXT_PORT, NuxtParams};
+use crate::constants::{Framework, Language, Runtime};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps,
+};
+use crate::status;
+use crate::utils::{js_runtime, tool_failures};
+
+/// Nuxt框架级别生成器实现
+#[derive(Debug)]
+pub struct NuxtGenerator {}
+
+impl NuxtGenerator {
+    /// 创建新的Nuxt生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+
+    /// 使用 nuxi init 非交互地创建 Nuxt 项目，依赖在创建时一并安装
+    ///
+    /// 官方模块不在这里选择，由 `install_modules` 逐个安装
+    pub fn create_nuxt_project(
+        project_name: &str,
+        output_path: &Path,
+        runtime: Runtime,
+    ) -> Result<()> {
+        status!("🚀 Creating Nuxt project with nuxi...");
+
+        // 获取父目录
+        let parent_dir = output_path.parent().unwrap_or_else(|| Path::new("."));
+
+        let output = js_runtime::dlx(runtime, "nuxi@latest")
+            .args([
+                "init",
+                project_name,
+                "--template",
+                "minimal",
+                "--packageManager",
+                runtime.package_manager(),
+                // git 仓库由项目级生成器初始化
+                "--gitInit",
+                "false",
+                "--no-modules",
+            ])
+            .current_dir(parent_dir)
+            .output()
+            .context("Failed to execute nuxi init")?;
+
+        if output.status.success() {
+            status!("✅ Nuxt project created successfully");
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            Err(anyhow::anyhow!(
+                "Failed to create Nuxt project:\nstdout: {stdout}\nstderr: {stderr}"
+            ))
+        }
+    }
+
+    /// 通过 nuxi module add 安装选中的模块（Tailwind CSS、ESLint、Pinia）
+    pub fn install_modules(output_path: &Path, params: &NuxtParams) -> Result<()> {
+        for module in params.module_names() {
+            status!("📦 Installing Nuxt module {module}...");
+
+            let output = js_runtime::dlx(params.runtime, "nuxi@latest")
+                .args(["module", "add", module])
+                .current_dir(output_path)
+                .output()
+                .with_context(|| format!("Failed to install Nuxt module {module}"))?;
+
+            if output.status.success() {
+                status!("✅ {module} installed successfully");
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tool_failures::report(format!(
+                    "Failed to install Nuxt module {module}: {}",
+                    stderr.trim()
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for NuxtGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create NuxtGenerator")
+    }
+}
+
+impl Generator for NuxtGenerator {
+    type Params = NuxtParams;
+
+    fn name(&self) -> &'static str {
+        "Nuxt"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates Nuxt 3 server-rendered Vue application with TypeScript")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/typescript/nuxt"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let runtime = params.runtime;
+        steps.command(runtime.run_command("dev"), "Start development server");
+        steps.command(runtime.run_command("build"), "Build for production");
+        if params.enable_eslint {
+            steps.command(runtime.exec_command("eslint ."), "Run ESLint");
+        }
+        steps.url(
+            "Dev server",
+            format!("http://localhost:{DEFAULT_NUXT_PORT}"),
+        );
+    }
+}
+
+impl FrameworkGeneratorTrait for NuxtGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::Nuxt.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::TypeScript.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构由 nuxi init 生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // nuxt.config.ts 由 nuxi 生成，模块由 nuxi module add 注册
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 服务端中间件按需在 server/middleware 中添加
+        Ok(())
+    }
+}
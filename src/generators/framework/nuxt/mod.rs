@@ -0,0 +1,5 @@
+mod generator;
+pub mod parameters;
+
+pub use generator::NuxtGenerator;
+pub use parameters::NuxtParams;
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::Runtime;
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::project::ProjectParams;
+
+/// Nuxt 开发服务器的默认端口
+pub const DEFAULT_NUXT_PORT: u16 = 3000;
+
+/// Nuxt框架参数 - 继承自BaseParams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NuxtParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Node.js 版本
+    pub node_version: String,
+    /// JavaScript 运行时，决定包管理命令和 CI 配置
+    pub runtime: Runtime,
+    /// 是否启用 Tailwind CSS (@nuxtjs/tailwindcss)
+    pub enable_tailwind: bool,
+    /// 是否启用 ESLint (@nuxt/eslint)
+    pub enable_eslint: bool,
+    /// 是否启用 Pinia 状态管理 (@pinia/nuxt)
+    pub enable_pinia: bool,
+    /// 包管理器，由运行时决定 (pnpm、bun、deno)
+    pub package_manager: String,
+}
+
+impl Default for NuxtParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            default_host: Some("localhost".to_string()),
+            default_port: Some(DEFAULT_NUXT_PORT),
+            ..Default::default()
+        };
+
+        Self::from_base(base)
+    }
+}
+
+impl InheritableParams for NuxtParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            node_version: "20".to_string(),
+            runtime: Runtime::default(),
+            enable_tailwind: true,
+            enable_eslint: true,
+            enable_pinia: true,
+            package_manager: "pnpm".to_string(),
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        // 项目结构由 nuxi init 生成，模板只有 CI 和 pre-commit 配置用到这些参数
+        HashMap::from([
+            ("node_version".to_string(), json!(self.node_version)),
+            ("runtime".to_string(), json!(self.runtime)),
+            ("enable_eslint".to_string(), json!(self.enable_eslint)),
+        ])
+    }
+}
+
+impl NuxtParams {
+    /// 创建新的Nuxt参数
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        base.default_host = Some("localhost".to_string());
+        base.default_port = Some(DEFAULT_NUXT_PORT);
+
+        Self {
+            project: ProjectParams::from_project_name(project_name),
+            ..Self::from_base(base)
+        }
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self.package_manager = runtime.package_manager().to_string();
+        self
+    }
+
+    /// 按 `--nuxt-modules` 的取值（tailwind、eslint、pinia）设置要安装的模块
+    pub fn with_modules(mut self, modules: &[String]) -> Self {
+        let enabled = |name: &str| modules.iter().any(|module| module == name);
+        self.enable_tailwind = enabled("tailwind");
+        self.enable_eslint = enabled("eslint");
+        self.enable_pinia = enabled("pinia");
+        self
+    }
+
+    /// 设置 Node.js 版本
+    #[allow(dead_code)]
+    pub fn with_node_version(mut self, version: String) -> Self {
+        self.node_version = version;
+        self
+    }
+
+    /// 需要通过 `nuxi module add` 安装的模块名
+    pub fn module_names(&self) -> Vec<&'static str> {
+        [
+            (self.enable_tailwind, "tailwindcss"),
+            (self.enable_eslint, "eslint"),
+            (self.enable_pinia, "pinia"),
+        ]
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, name)| name)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_names() {
+        assert_eq!(
+            NuxtParams::default().module_names(),
+            ["tailwindcss", "eslint", "pinia"]
+        );
+
+        let params = NuxtParams::default().with_modules(&["pinia".to_string()]);
+        assert_eq!(params.module_names(), ["pinia"]);
+        assert!(!params.enable_eslint);
+
+        assert!(
+            NuxtParams::default()
+                .with_modules(&[])
+                .module_names()
+                .is_empty()
+        );
+    }
+}
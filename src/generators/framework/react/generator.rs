@@ -1,10 +1,17 @@
 use anyhow::{Context, Result};
 use std::path::Path;
-use std::process::Command;
 
 use super::parameters::ReactParams;
-use crate::constants::{Framework, Language};
-use crate::generators::core::{FrameworkGenerator as FrameworkGeneratorTrait, Generator};
+use crate::constants::{Framework, Language, Runtime, TailwindVersion};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps,
+};
+use crate::status;
+use crate::utils::json_patch::PackageJsonPatch;
+use crate::utils::{api_client, js_runtime, tailwind, tool_failures, vitest};
+
+/// Vite React 模板生成、被路由骨架取代的文件
+const VITE_STARTER_FILES: &[&str] = &["src/App.tsx", "src/App.css", "src/index.css"];
 
 /// React框架级别生成器实现
 #[derive(Debug)]
@@ -16,36 +23,25 @@ impl ReactGenerator {
         Ok(Self {})
     }
 
-    /// 检查 pnpm 是否已安装
-    pub fn check_pnpm() -> Result<bool> {
-        match Command::new("pnpm").arg("--version").output() {
-            Ok(output) => Ok(output.status.success()),
-            Err(_) => Ok(false),
-        }
-    }
-
-    /// 使用 pnpm create vite 创建 React 项目
-    pub fn create_react_project(project_name: &str, output_path: &Path) -> Result<()> {
-        println!("🚀 Creating React project with Vite...");
+    /// 使用 create-vite 创建 React 项目
+    pub fn create_react_project(
+        project_name: &str,
+        output_path: &Path,
+        runtime: Runtime,
+    ) -> Result<()> {
+        status!("🚀 Creating React project with Vite...");
 
         // 获取父目录
         let parent_dir = output_path.parent().unwrap_or_else(|| Path::new("."));
 
-        // 使用 pnpm create vite 创建项目
-        let output = Command::new("pnpm")
-            .args([
-                "create",
-                "vite@latest",
-                project_name,
-                "--template",
-                "react-ts",
-            ])
+        let output = js_runtime::create(runtime, "vite@latest")
+            .args([project_name, "--template", "react-ts"])
             .current_dir(parent_dir)
             .output()
-            .context("Failed to execute pnpm create vite")?;
+            .context("Failed to execute create-vite")?;
 
         if output.status.success() {
-            println!("✅ React project created successfully");
+            status!("✅ React project created successfully");
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -56,125 +52,63 @@ impl ReactGenerator {
         }
     }
 
-    /// 安装 Tailwind CSS
-    pub fn install_tailwind(output_path: &Path) -> Result<()> {
-        println!("📦 Installing Tailwind CSS...");
-
-        // 安装 Tailwind CSS 依赖
-        let output = Command::new("pnpm")
-            .args([
-                "add",
-                "-D",
-                "tailwindcss",
-                "postcss",
-                "autoprefixer",
-                "@tailwindcss/forms",
-                "@tailwindcss/typography",
-            ])
-            .current_dir(output_path)
-            .output()
-            .context("Failed to install Tailwind CSS")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to install Tailwind CSS: {stderr}");
-        }
-
-        // 初始化 Tailwind CSS
-        let output = Command::new("pnpm")
-            .args(["exec", "tailwindcss", "init", "-p"])
-            .current_dir(output_path)
-            .output()
-            .context("Failed to initialize Tailwind CSS")?;
-
-        if output.status.success() {
-            println!("✅ Tailwind CSS installed successfully");
+    /// 写入 package.json 的依赖：Tailwind CSS、`src/utils/cn.ts` 用到的 clsx 和 tailwind-merge、
+    /// React Router、状态管理库、API 客户端层，以及启用测试时的 Vitest
+    pub fn package_patch(params: &ReactParams, enable_tests: bool) -> PackageJsonPatch {
+        // tailwind-merge 3.x 只支持 Tailwind CSS v4
+        let tailwind_merge = match params.tailwind_version {
+            TailwindVersion::V3 => "tailwind-merge@^2",
+            TailwindVersion::V4 => "tailwind-merge@^3",
+        };
+        let patch = PackageJsonPatch::new()
+            .with_dev_dependencies(tailwind::packages(params.tailwind_version))
+            .with_dependencies(&["clsx@^2", tailwind_merge, "react-router-dom@^7"])
+            .with_dependencies(params.state_packages())
+            .with_dependencies(&params.api_packages());
+        if enable_tests {
+            vitest::add_to(patch, Framework::React)
         } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to initialize Tailwind CSS: {stderr}");
+            patch
         }
-
-        Ok(())
     }
 
-    /// 安装 React Router
-    pub fn install_router(output_path: &Path) -> Result<()> {
-        println!("📦 Installing React Router...");
-
-        let output = Command::new("pnpm")
-            .args(["add", "react-router-dom"])
-            .current_dir(output_path)
-            .output()
-            .context("Failed to install React Router")?;
-
-        if output.status.success() {
-            println!("✅ React Router installed successfully");
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to install React Router: {stderr}");
-        }
-
-        Ok(())
+    /// 依赖安装完成后按所选主版本配置 Tailwind CSS
+    pub fn configure_tailwind(
+        output_path: &Path,
+        version: TailwindVersion,
+        runtime: Runtime,
+    ) -> Result<()> {
+        tailwind::configure_for_vite(output_path, version, runtime)
     }
 
-    /// 安装状态管理库
-    pub fn install_state_management(output_path: &Path, state_management: &str) -> Result<()> {
-        println!("📦 Installing {state_management}...");
-
-        let packages = match state_management {
-            "zustand" => vec!["zustand"],
-            "redux" => vec!["@reduxjs/toolkit", "react-redux"],
-            "jotai" => vec!["jotai"],
-            _ => vec!["zustand"], // 默认使用 zustand
-        };
-
-        let mut args = vec!["add"];
-        args.extend(packages.iter().copied());
-
-        let output = Command::new("pnpm")
-            .args(&args)
-            .current_dir(output_path)
-            .output()
-            .context("Failed to install state management library")?;
-
-        if output.status.success() {
-            println!("✅ {state_management} installed successfully");
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to install {state_management}: {stderr}");
+    /// 删除 Vite 模板自带的示例组件和样式，入口改由路由骨架接管
+    pub fn remove_vite_starter(output_path: &Path) -> Result<()> {
+        for file in VITE_STARTER_FILES {
+            let path = output_path.join(file);
+            if path.exists() {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+            }
         }
-
         Ok(())
     }
 
     /// 安装前端依赖
-    pub fn install_dependencies(output_path: &Path) -> Result<()> {
-        println!("📦 Installing frontend dependencies...");
+    pub fn install_dependencies(output_path: &Path, runtime: Runtime) -> Result<()> {
+        status!("📦 Installing frontend dependencies...");
 
-        let output = Command::new("pnpm")
-            .arg("install")
+        let output = js_runtime::install(runtime)
             .current_dir(output_path)
             .output()
-            .context("Failed to execute pnpm install")?;
+            .with_context(|| format!("Failed to execute {} install", runtime.package_manager()))?;
 
         if output.status.success() {
-            println!("✅ Dependencies installed successfully");
+            status!("✅ Dependencies installed successfully");
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to install dependencies: {stderr}");
-            // 不返回错误，让用户手动安装
-            Ok(())
-        }
-    }
-
-    /// 检查是否应该跳过pre-commit相关文件
-    #[allow(dead_code)]
-    fn should_skip_precommit_file(&self, file_name: &str, params: &ReactParams) -> bool {
-        if !params.enable_precommit() {
-            file_name == ".pre-commit-config.yaml.tmpl" || file_name == ".pre-commit-config.yaml"
-        } else {
-            false
+            // lenient 策略下不返回错误，让用户手动安装
+            tool_failures::report(format!("Failed to install dependencies: {}", stderr.trim()))
         }
     }
 }
@@ -199,6 +133,26 @@ impl Generator for ReactGenerator {
     fn get_template_path(&self) -> &'static str {
         "frameworks/typescript/react"
     }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let runtime = params.runtime;
+        steps.command(runtime.run_command("dev"), "Start development server");
+        steps.command(runtime.run_command("build"), "Build for production");
+        if params.base.enable_tests {
+            steps.command(
+                runtime.run_command("test:coverage"),
+                "Run unit tests with coverage",
+            );
+        }
+        steps.url("Dev server", "http://localhost:5173");
+        if params.api_layer().is_some() {
+            steps.caveat(api_client::BASE_URL_CAVEAT);
+        }
+        if params.enable_auth_pages {
+            steps.url("Sign in", "http://localhost:5173/login");
+            steps.caveat(api_client::AUTH_ENDPOINTS_CAVEAT);
+        }
+    }
 }
 
 impl FrameworkGeneratorTrait for ReactGenerator {
@@ -210,21 +164,17 @@ impl FrameworkGeneratorTrait for ReactGenerator {
         Language::TypeScript.as_str()
     }
 
-    fn generate_basic_structure(
-        &mut self,
-        _params: &Self::Params,
-        _output_path: &Path,
-    ) -> Result<()> {
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 不再需要自定义结构生成，完全依赖模板
         Ok(())
     }
 
-    fn generate_config(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 配置文件通过模板生成
         Ok(())
     }
 
-    fn generate_middleware(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // React 不需要中间件
         Ok(())
     }
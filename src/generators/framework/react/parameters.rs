@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
 
+use crate::constants::{ApiClient, Runtime, TailwindVersion};
 use crate::generators::core::{BaseParams, InheritableParams};
 use crate::generators::project::ProjectParams;
+use crate::utils::{api_client, vitest};
+
+/// 支持的状态管理方案，其他取值按 zustand 处理
+pub const REACT_STATE_LIBRARIES: &[&str] = &["zustand", "redux", "jotai"];
 
 /// React框架参数 - 继承自BaseParams
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,10 +19,14 @@ pub struct ReactParams {
     pub project: ProjectParams,
     /// Node.js 版本
     pub node_version: String,
+    /// JavaScript 运行时，决定包管理命令和 CI 配置
+    pub runtime: Runtime,
     /// 是否启用 TypeScript (强制启用)
     pub enable_typescript: bool,
     /// 是否启用 Tailwind CSS
     pub enable_tailwind: bool,
+    /// Tailwind CSS 主版本
+    pub tailwind_version: TailwindVersion,
     /// 是否启用 React Router
     pub enable_router: bool,
     /// 状态管理方案 (zustand, redux, jotai)
@@ -24,8 +35,16 @@ pub struct ReactParams {
     pub enable_eslint: bool,
     /// 是否启用 Prettier
     pub enable_prettier: bool,
-    /// 包管理器 (pnpm)
+    /// 包管理器，由运行时决定 (pnpm、bun、deno)
     pub package_manager: String,
+    /// API 客户端层实现，None 表示不生成 `src/api`
+    pub api_client: Option<ApiClient>,
+    /// 是否加入 TanStack Query 和示例查询 hook
+    pub enable_tanstack_query: bool,
+    /// 是否生成登录注册页面、认证状态和路由守卫
+    pub enable_auth_pages: bool,
+    /// 是否生成浅色/深色/跟随系统的主题切换
+    pub enable_dark_mode: bool,
 }
 
 impl Default for ReactParams {
@@ -40,13 +59,19 @@ impl Default for ReactParams {
             base,
             project: ProjectParams::default(),
             node_version: "20".to_string(),
+            runtime: Runtime::default(),
             enable_typescript: true, // 强制启用 TypeScript
             enable_tailwind: true,
+            tailwind_version: TailwindVersion::default(),
             enable_router: true,
             state_management: "zustand".to_string(),
             enable_eslint: true,
             enable_prettier: true,
             package_manager: "pnpm".to_string(),
+            api_client: None,
+            enable_tanstack_query: false,
+            enable_auth_pages: false,
+            enable_dark_mode: false,
         }
     }
 }
@@ -65,14 +90,48 @@ impl InheritableParams for ReactParams {
             base,
             project: ProjectParams::default(),
             node_version: "20".to_string(),
+            runtime: Runtime::default(),
             enable_typescript: true,
             enable_tailwind: true,
+            tailwind_version: TailwindVersion::default(),
             enable_router: true,
             state_management: "zustand".to_string(),
             enable_eslint: true,
             enable_prettier: true,
             package_manager: "pnpm".to_string(),
+            api_client: None,
+            enable_tanstack_query: false,
+            enable_auth_pages: false,
+            enable_dark_mode: false,
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        // 示例 store 和计数组件按状态管理方案从 `+zustand`、`+redux`、`+jotai` 目录中选择
+        let state_library = self.state_library();
+        let mut context = HashMap::from([
+            (
+                "tailwind_v4".to_string(),
+                json!(self.tailwind_version == TailwindVersion::V4),
+            ),
+            ("state_management".to_string(), json!(state_library)),
+            ("enable_dark_mode".to_string(), json!(self.enable_dark_mode)),
+            ("node_version".to_string(), json!(self.node_version)),
+            ("runtime".to_string(), json!(self.runtime)),
+        ]);
+        context.extend(api_client::template_context(
+            self.api_layer(),
+            self.enable_tanstack_query,
+            self.enable_auth_pages,
+        ));
+        context.extend(vitest::template_context(self.base.enable_tests));
+        for library in REACT_STATE_LIBRARIES {
+            context.insert(
+                format!("enable_{library}"),
+                json!(*library == state_library),
+            );
         }
+        context
     }
 }
 
@@ -93,16 +152,28 @@ impl ReactParams {
             base,
             project: ProjectParams::from_project_name(project_name),
             node_version: "20".to_string(),
+            runtime: Runtime::default(),
             enable_typescript: true,
             enable_tailwind: true,
+            tailwind_version: TailwindVersion::default(),
             enable_router: true,
             state_management: "zustand".to_string(),
             enable_eslint: true,
             enable_prettier: true,
             package_manager: "pnpm".to_string(),
+            api_client: None,
+            enable_tanstack_query: false,
+            enable_auth_pages: false,
+            enable_dark_mode: false,
         }
     }
 
+    /// 设置 Tailwind CSS 主版本
+    pub fn with_tailwind_version(mut self, version: TailwindVersion) -> Self {
+        self.tailwind_version = version;
+        self
+    }
+
     /// 设置项目参数
     pub fn with_project(mut self, project: ProjectParams) -> Self {
         self.project = project;
@@ -115,7 +186,15 @@ impl ReactParams {
         self
     }
 
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self.package_manager = runtime.package_manager().to_string();
+        self
+    }
+
     /// 获取是否启用pre-commit
+    #[allow(dead_code)]
     pub fn enable_precommit(&self) -> bool {
         self.base.enable_precommit
     }
@@ -141,6 +220,66 @@ impl ReactParams {
         self
     }
 
+    /// 实际使用的状态管理方案，未知取值回退到 zustand
+    pub fn state_library(&self) -> &'static str {
+        REACT_STATE_LIBRARIES
+            .iter()
+            .copied()
+            .find(|library| *library == self.state_management)
+            .unwrap_or("zustand")
+    }
+
+    /// 状态管理方案需要安装的依赖，包括 jotai 的 devtools 组件
+    pub fn state_packages(&self) -> &'static [&'static str] {
+        match self.state_library() {
+            "redux" => &["@reduxjs/toolkit@^2", "react-redux@^9"],
+            "jotai" => &["jotai@^2", "jotai-devtools@^0.12"],
+            _ => &["zustand@^5"],
+        }
+    }
+
+    /// 设置 API 客户端层实现
+    pub fn with_api_client(mut self, api_client: Option<ApiClient>) -> Self {
+        self.api_client = api_client;
+        self
+    }
+
+    /// 设置是否加入 TanStack Query
+    pub fn with_tanstack_query(mut self, enable: bool) -> Self {
+        self.enable_tanstack_query = enable;
+        self
+    }
+
+    /// 设置是否生成登录注册页面
+    pub fn with_auth_pages(mut self, enable: bool) -> Self {
+        self.enable_auth_pages = enable;
+        self
+    }
+
+    /// 设置是否生成主题切换
+    pub fn with_dark_mode(mut self, enable: bool) -> Self {
+        self.enable_dark_mode = enable;
+        self
+    }
+
+    /// 实际生成的 API 客户端层，启用 TanStack Query 或登录注册页面时至少生成 fetch 实现
+    pub fn api_layer(&self) -> Option<ApiClient> {
+        self.api_client
+            .or((self.enable_tanstack_query || self.enable_auth_pages).then_some(ApiClient::Fetch))
+    }
+
+    /// API 客户端层需要安装的依赖
+    pub fn api_packages(&self) -> Vec<&'static str> {
+        let mut packages = Vec::new();
+        if self.api_layer() == Some(ApiClient::Axios) {
+            packages.push("axios@^1");
+        }
+        if self.enable_tanstack_query {
+            packages.push("@tanstack/react-query@^5");
+        }
+        packages
+    }
+
     /// 设置 Node.js 版本
     #[allow(dead_code)]
     pub fn with_node_version(mut self, version: String) -> Self {
@@ -148,3 +287,52 @@ impl ReactParams {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_library_context() {
+        let params = ReactParams::default().with_state_management("redux".to_string());
+        let context = params.extended_template_context();
+        assert_eq!(context["state_management"], json!("redux"));
+        assert_eq!(context["enable_redux"], json!(true));
+        assert_eq!(context["enable_zustand"], json!(false));
+        assert_eq!(
+            params.state_packages(),
+            ["@reduxjs/toolkit@^2", "react-redux@^9"]
+        );
+
+        let params = ReactParams::default().with_state_management("mobx".to_string());
+        assert_eq!(params.state_library(), "zustand");
+    }
+
+    #[test]
+    fn test_api_layer_context() {
+        let context = ReactParams::default().extended_template_context();
+        assert_eq!(context["enable_api_client"], json!(false));
+        assert_eq!(context["enable_tanstack_query"], json!(false));
+
+        // 只启用 TanStack Query 时使用 fetch 实现
+        let params = ReactParams::default().with_tanstack_query(true);
+        let context = params.extended_template_context();
+        assert_eq!(context["enable_api_client"], json!(true));
+        assert_eq!(context["api_client"], json!("fetch"));
+        assert_eq!(params.api_packages(), ["@tanstack/react-query@^5"]);
+
+        let params = ReactParams::default().with_api_client(Some(ApiClient::Axios));
+        assert_eq!(
+            params.extended_template_context()["api_client"],
+            json!("axios")
+        );
+        assert_eq!(params.api_packages(), ["axios@^1"]);
+
+        // 登录注册页面复用 API 客户端层
+        let context = ReactParams::default()
+            .with_auth_pages(true)
+            .extended_template_context();
+        assert_eq!(context["enable_auth_pages"], json!(true));
+        assert_eq!(context["api_client"], json!("fetch"));
+    }
+}
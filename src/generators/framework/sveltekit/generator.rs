@@ -0,0 +1,144 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{DEFAULT_SVELTEKIT_PORT, SvelteKitParams};
+use crate::constants::{Framework, Language};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps,
+};
+use crate::status;
+use crate::utils::{js_runtime, tool_failures};
+
+/// SvelteKit框架级别生成器实现
+#[derive(Debug)]
+pub struct SvelteKitGenerator {}
+
+impl SvelteKitGenerator {
+    /// 创建新的SvelteKit生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+
+    /// 使用 sv create 非交互地创建 SvelteKit 项目，依赖在创建时一并安装
+    ///
+    /// `pnpm create svelte`（create-svelte）已被官方的 `sv` 命令行取代
+    pub fn create_sveltekit_project(
+        project_name: &str,
+        output_path: &Path,
+        params: &SvelteKitParams,
+    ) -> Result<()> {
+        status!("🚀 Creating SvelteKit project with sv...");
+
+        // 获取父目录
+        let parent_dir = output_path.parent().unwrap_or_else(|| Path::new("."));
+
+        let output = js_runtime::dlx(params.runtime, "sv")
+            .args(params.create_args(project_name))
+            .current_dir(parent_dir)
+            .output()
+            .context("Failed to execute sv create")?;
+
+        if output.status.success() {
+            status!("✅ SvelteKit project created successfully");
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            Err(anyhow::anyhow!(
+                "Failed to create SvelteKit project:\nstdout: {stdout}\nstderr: {stderr}"
+            ))
+        }
+    }
+
+    /// 通过 sv add 安装选中的附加组件（Tailwind CSS、Playwright）
+    pub fn install_add_ons(output_path: &Path, params: &SvelteKitParams) -> Result<()> {
+        let Some(args) = params.add_on_args() else {
+            return Ok(());
+        };
+        status!("📦 Installing SvelteKit add-ons...");
+
+        let output = js_runtime::dlx(params.runtime, "sv")
+            .args(&args)
+            .current_dir(output_path)
+            .output()
+            .context("Failed to execute sv add")?;
+
+        if output.status.success() {
+            status!("✅ Add-ons installed successfully");
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!(
+                "Failed to install SvelteKit add-ons: {}",
+                stderr.trim()
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for SvelteKitGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create SvelteKitGenerator")
+    }
+}
+
+impl Generator for SvelteKitGenerator {
+    type Params = SvelteKitParams;
+
+    fn name(&self) -> &'static str {
+        "SvelteKit"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates SvelteKit full-stack Svelte application")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "frameworks/typescript/sveltekit"
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let runtime = params.runtime;
+        steps.command(runtime.run_command("dev"), "Start development server");
+        steps.command(runtime.run_command("build"), "Build for production");
+        if params.enable_typescript {
+            steps.command(runtime.run_command("check"), "Type-check with svelte-check");
+        }
+        if params.enable_playwright {
+            steps.command(
+                runtime.run_command("test:e2e"),
+                "Run Playwright end-to-end tests",
+            );
+        }
+        steps.url(
+            "Dev server",
+            format!("http://localhost:{DEFAULT_SVELTEKIT_PORT}"),
+        );
+    }
+}
+
+impl FrameworkGeneratorTrait for SvelteKitGenerator {
+    fn framework(&self) -> &'static str {
+        Framework::SvelteKit.as_str()
+    }
+
+    fn language(&self) -> &'static str {
+        Language::TypeScript.as_str()
+    }
+
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 项目结构由 sv create 生成
+        Ok(())
+    }
+
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // svelte.config.js 和 vite.config 由 sv 生成
+        Ok(())
+    }
+
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 服务端钩子按需在 src/hooks.server 中添加
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+// 明确导出各框架生成器和参数类型
+mod generator;
+pub mod parameters;
+
+pub use generator::SvelteKitGenerator;
+pub use parameters::SvelteKitParams;
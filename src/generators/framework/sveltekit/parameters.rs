@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::Runtime;
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::project::ProjectParams;
+
+/// SvelteKit（Vite）开发服务器的默认端口
+pub const DEFAULT_SVELTEKIT_PORT: u16 = 5173;
+
+/// SvelteKit框架参数 - 继承自BaseParams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SvelteKitParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目级别参数
+    pub project: ProjectParams,
+    /// Node.js 版本
+    pub node_version: String,
+    /// JavaScript 运行时，决定包管理命令和 CI 配置
+    pub runtime: Runtime,
+    /// 是否使用 TypeScript 类型检查（否则使用 JSDoc）
+    pub enable_typescript: bool,
+    /// 是否启用 Tailwind CSS
+    pub enable_tailwind: bool,
+    /// 是否启用 Playwright 端到端测试
+    pub enable_playwright: bool,
+    /// 包管理器，由运行时决定 (pnpm、bun、deno)
+    pub package_manager: String,
+}
+
+impl Default for SvelteKitParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            default_host: Some("localhost".to_string()),
+            default_port: Some(DEFAULT_SVELTEKIT_PORT),
+            ..Default::default()
+        };
+
+        Self::from_base(base)
+    }
+}
+
+impl InheritableParams for SvelteKitParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            project: ProjectParams::default(),
+            node_version: "20".to_string(),
+            runtime: Runtime::default(),
+            enable_typescript: true,
+            enable_tailwind: true,
+            enable_playwright: true,
+            package_manager: "pnpm".to_string(),
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        // 项目结构由 sv create 生成，模板只有 CI 和 pre-commit 配置用到这些参数
+        HashMap::from([
+            ("node_version".to_string(), json!(self.node_version)),
+            ("runtime".to_string(), json!(self.runtime)),
+            (
+                "enable_typescript".to_string(),
+                json!(self.enable_typescript),
+            ),
+            (
+                "enable_playwright".to_string(),
+                json!(self.enable_playwright),
+            ),
+        ])
+    }
+}
+
+impl SvelteKitParams {
+    /// 创建新的SvelteKit参数
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从项目名称创建
+    pub fn from_project_name(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name.clone());
+        base.default_host = Some("localhost".to_string());
+        base.default_port = Some(DEFAULT_SVELTEKIT_PORT);
+
+        Self {
+            project: ProjectParams::from_project_name(project_name),
+            ..Self::from_base(base)
+        }
+    }
+
+    /// 设置项目参数
+    pub fn with_project(mut self, project: ProjectParams) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self.package_manager = runtime.package_manager().to_string();
+        self
+    }
+
+    /// 按 `--sveltekit-options` 的取值（typescript、tailwind、playwright）设置选项
+    pub fn with_options(mut self, options: &[String]) -> Self {
+        let enabled = |name: &str| options.iter().any(|option| option == name);
+        self.enable_typescript = enabled("typescript");
+        self.enable_tailwind = enabled("tailwind");
+        self.enable_playwright = enabled("playwright");
+        self
+    }
+
+    /// 设置 Node.js 版本
+    #[allow(dead_code)]
+    pub fn with_node_version(mut self, version: String) -> Self {
+        self.node_version = version;
+        self
+    }
+
+    /// `sv create` 的非交互参数，附加组件由 `add_on_args` 单独安装
+    pub fn create_args(&self, project_name: &str) -> Vec<String> {
+        let mut args = vec![
+            "create".to_string(),
+            project_name.to_string(),
+            "--template".to_string(),
+            "minimal".to_string(),
+        ];
+        if self.enable_typescript {
+            args.extend(["--types".to_string(), "ts".to_string()]);
+        } else {
+            args.push("--no-types".to_string());
+        }
+        args.extend([
+            "--no-add-ons".to_string(),
+            "--install".to_string(),
+            self.package_manager.clone(),
+        ]);
+        args
+    }
+
+    /// `sv add` 的参数，未选择 Tailwind CSS 和 Playwright 时返回 `None`
+    pub fn add_on_args(&self) -> Option<Vec<String>> {
+        let mut args = vec!["add".to_string()];
+        if self.enable_tailwind {
+            // 不安装 typography、forms 插件，避免 sv 询问
+            args.push("tailwindcss=plugins:none".to_string());
+        }
+        if self.enable_playwright {
+            args.push("playwright".to_string());
+        }
+        if args.len() == 1 {
+            return None;
+        }
+        args.extend([
+            // git 仓库由项目级生成器在之后初始化
+            "--no-git-check".to_string(),
+            "--install".to_string(),
+            self.package_manager.clone(),
+        ]);
+        Some(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sv_args() {
+        let params = SvelteKitParams::default();
+        let args = params.create_args("demo");
+        assert_eq!(args[..2], ["create", "demo"]);
+        for expected in ["--types", "ts", "--no-add-ons", "pnpm"] {
+            assert!(args.iter().any(|arg| arg == expected), "missing {expected}");
+        }
+        let add_ons = params.add_on_args().unwrap();
+        assert!(add_ons.contains(&"playwright".to_string()));
+        assert!(add_ons.contains(&"tailwindcss=plugins:none".to_string()));
+
+        let params = SvelteKitParams::default().with_options(&[]);
+        assert!(
+            params
+                .create_args("demo")
+                .contains(&"--no-types".to_string())
+        );
+        assert_eq!(params.add_on_args(), None);
+    }
+}
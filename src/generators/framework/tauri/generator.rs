@@ -1,14 +1,65 @@
 use anyhow::{Context, Result};
+use inquire::Confirm;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::Path;
 use std::process::Command;
 
 use super::parameters::TauriParams;
-use crate::constants::{Framework, Language};
+use crate::constants::{Framework, Language, TauriVersion};
 use crate::generators::core::{
-    FrameworkGenerator as FrameworkGeneratorTrait, Generator, TemplateProcessor,
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, RenameRules,
+    TemplateProcessor, copy_embedded_file, is_feature_disabled, resolve_output_path,
 };
+use crate::status;
+use crate::template_engine::{RenderContext, TEMPLATE_MANIFEST};
+use crate::utils::{answers, manifest, tool_failures, tool_install};
+
+/// 支持的 create-tauri-app 版本，4.x 生成 Tauri 2 项目；Tauri 1 项目由覆盖模板替换 Rust 端文件
+pub const CREATE_TAURI_APP_VERSION: &str = "^4.0.0";
+
+/// create-tauri-app 生成的、Tauri 1 项目中不存在的文件
+const V2_ONLY_PATHS: [&str; 2] = ["src-tauri/src/lib.rs", "src-tauri/capabilities"];
+
+/// 清单文件的结构化合并函数，参数为已有内容和覆盖模板的渲染结果
+type ManifestMerge = fn(&str, &str) -> Result<String>;
+
+/// create-tauri-app 已生成的 package.json 和 Cargo.toml 按结构合并，保留上游的依赖版本和字段
+///
+/// create-tauri-app 只生成 Tauri 2 项目，Tauri 1 项目的清单仍整体覆盖
+fn manifest_merge(relative_path: &str, tauri_version: TauriVersion) -> Option<ManifestMerge> {
+    if tauri_version == TauriVersion::V1 {
+        return None;
+    }
+    match Path::new(relative_path).file_name()?.to_str()? {
+        "package.json" => Some(manifest::merge_package_json),
+        "Cargo.toml" => Some(manifest::merge_cargo_toml),
+        _ => None,
+    }
+}
+
+/// 从 `cargo install --list` 的输出中读取指定工具的版本，如 `create-tauri-app v4.6.2:`
+fn parse_installed_version(list: &str, name: &str) -> Option<String> {
+    list.lines().find_map(|line| {
+        let version = line.strip_prefix(name)?.trim().strip_prefix('v')?;
+        Some(version.trim_end_matches(':').to_string())
+    })
+}
+
+/// 版本是否满足 [`CREATE_TAURI_APP_VERSION`]：主版本相同且不低于最低版本
+pub fn is_supported_create_tauri_app(version: &str) -> bool {
+    let parse = |version: &str| -> Option<Vec<u64>> {
+        version
+            .trim_start_matches('^')
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect()
+    };
+    match (parse(version), parse(CREATE_TAURI_APP_VERSION)) {
+        (Some(version), Some(minimum)) => version[0] == minimum[0] && version >= minimum,
+        _ => false,
+    }
+}
 
 /// Tauri框架级别生成器实现
 #[derive(Debug)]
@@ -20,19 +71,20 @@ impl TauriGenerator {
         Ok(Self {})
     }
 
-    /// 检查 create-tauri-app 是否已安装
-    pub fn check_create_tauri_app() -> Result<bool> {
+    /// 读取已安装的 create-tauri-app 版本，未安装时返回 `None`
+    pub fn installed_create_tauri_app_version() -> Result<Option<String>> {
         let output = Command::new("cargo")
             .args(["install", "--list"])
             .output()
             .context("Failed to execute cargo install --list")?;
 
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            Ok(stdout.contains("create-tauri-app"))
-        } else {
-            Ok(false)
+        if !output.status.success() {
+            return Ok(None);
         }
+        Ok(parse_installed_version(
+            &String::from_utf8_lossy(&output.stdout),
+            "create-tauri-app",
+        ))
     }
 
     /// 检查 pnpm 是否已安装
@@ -43,16 +95,15 @@ impl TauriGenerator {
         }
     }
 
-    /// 安装 create-tauri-app
+    /// 安装或升级 create-tauri-app 到 [`CREATE_TAURI_APP_VERSION`]，有 cargo-binstall 时使用预编译包以免从源码编译
     pub fn install_create_tauri_app() -> Result<()> {
-        println!("📦 Installing create-tauri-app...");
-        let output = Command::new("cargo")
-            .args(["install", "create-tauri-app"])
+        status!("📦 Installing create-tauri-app {CREATE_TAURI_APP_VERSION}...");
+        let output = tool_install::cargo_install("create-tauri-app", CREATE_TAURI_APP_VERSION)
             .output()
             .context("Failed to install create-tauri-app")?;
 
         if output.status.success() {
-            println!("✅ create-tauri-app installed successfully");
+            status!("✅ create-tauri-app installed successfully");
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -62,9 +113,25 @@ impl TauriGenerator {
         }
     }
 
+    /// 已安装的 create-tauri-app 不满足版本要求时询问是否升级，拒绝升级则返回错误
+    pub fn upgrade_create_tauri_app(installed: &str) -> Result<()> {
+        let message = format!(
+            "create-tauri-app {installed} does not generate Tauri 2 projects. Install {CREATE_TAURI_APP_VERSION} instead?"
+        );
+        if answers::confirm(
+            "upgrade_create_tauri_app",
+            Confirm::new(&message).with_default(true),
+        )? {
+            return Self::install_create_tauri_app();
+        }
+        Err(anyhow::anyhow!(
+            "create-tauri-app {installed} is not supported, install a supported version with:\n  cargo install create-tauri-app --version {CREATE_TAURI_APP_VERSION}"
+        ))
+    }
+
     /// 使用 create-tauri-app 创建项目
     pub fn create_tauri_project(project_name: &str, output_path: &Path) -> Result<()> {
-        println!("🚀 Creating Tauri project with create-tauri-app...");
+        status!("🚀 Creating Tauri project with create-tauri-app...");
 
         // 获取父目录
         let parent_dir = output_path.parent().unwrap_or_else(|| Path::new("."));
@@ -86,7 +153,7 @@ impl TauriGenerator {
             .context("Failed to execute cargo create-tauri-app")?;
 
         if output.status.success() {
-            println!("✅ Tauri project created successfully");
+            status!("✅ Tauri project created successfully");
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -99,7 +166,7 @@ impl TauriGenerator {
 
     /// 安装前端依赖
     pub fn install_dependencies(output_path: &Path) -> Result<()> {
-        println!("📦 Installing frontend dependencies...");
+        status!("📦 Installing frontend dependencies...");
 
         let output = Command::new("pnpm")
             .arg("install")
@@ -108,22 +175,12 @@ impl TauriGenerator {
             .context("Failed to execute pnpm install")?;
 
         if output.status.success() {
-            println!("✅ Dependencies installed successfully");
+            status!("✅ Dependencies installed successfully");
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to install dependencies: {stderr}");
-            // 不返回错误，让用户手动安装
-            Ok(())
-        }
-    }
-
-    /// 检查是否应该跳过pre-commit相关文件
-    fn should_skip_precommit_file(&self, file_name: &str, params: &TauriParams) -> bool {
-        if !params.enable_precommit() {
-            file_name == ".pre-commit-config.yaml.tmpl" || file_name == ".pre-commit-config.yaml"
-        } else {
-            false
+            // lenient 策略下不返回错误，让用户手动安装
+            tool_failures::report(format!("Failed to install dependencies: {}", stderr.trim()))
         }
     }
 
@@ -165,8 +222,8 @@ impl Generator for TauriGenerator {
 
     /// 渲染嵌入式模板 - 重写以实现Tauri特定的逻辑
     fn render_embedded_templates(
-        &mut self,
-        template_processor: &mut TemplateProcessor,
+        &self,
+        template_processor: &TemplateProcessor,
         template_path: &str,
         output_path: &Path,
         context: HashMap<String, Value>,
@@ -174,11 +231,29 @@ impl Generator for TauriGenerator {
     ) -> Result<()> {
         use std::fs;
 
+        // Tauri 1 没有 lib.rs 入口和 capabilities，删除 create-tauri-app 生成的 v2 文件
+        if params.tauri_version == TauriVersion::V1 {
+            for v2_only in V2_ONLY_PATHS {
+                let path = output_path.join(v2_only);
+                if path.is_dir() {
+                    fs::remove_dir_all(&path)
+                } else if path.exists() {
+                    fs::remove_file(&path)
+                } else {
+                    continue;
+                }
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+            }
+        }
+
         // 获取嵌入式模板文件列表
         let template_files = crate::template_engine::get_embedded_template_files(template_path)
             .with_context(|| {
                 format!("Failed to get embedded template files for: {template_path}")
             })?;
+        // 所有文件共用同一份渲染上下文
+        let render_context = RenderContext::new(&context)?;
+        let renames = RenameRules::for_template_set(template_path)?;
 
         for template_file in template_files {
             // 获取相对于模板路径的文件路径
@@ -186,13 +261,8 @@ impl Generator for TauriGenerator {
                 .strip_prefix(&format!("{template_path}/"))
                 .unwrap_or(&template_file);
 
-            let file_name = std::path::Path::new(relative_path)
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("");
-
-            // 检查是否应该跳过pre-commit相关文件
-            if self.should_skip_precommit_file(file_name, params) {
+            // 模板组清单不输出到项目中
+            if relative_path == TEMPLATE_MANIFEST {
                 continue;
             }
 
@@ -201,14 +271,16 @@ impl Generator for TauriGenerator {
                 continue;
             }
 
-            // 去除 .tmpl 后缀
-            let output_relative_path = if let Some(stripped) = relative_path.strip_suffix(".tmpl") {
-                stripped
-            } else {
-                relative_path
-            };
+            // 检查是否被功能开关禁用
+            if is_feature_disabled(relative_path, &context) {
+                continue;
+            }
+
+            // 去除 .tmpl 后缀并渲染路径中的变量
+            let output_relative_path =
+                template_processor.render_output_path(relative_path, &renames, &context)?;
 
-            let output_file_path = output_path.join(output_relative_path);
+            let output_file_path = resolve_output_path(output_path, &output_relative_path)?;
 
             // 确保输出目录存在
             if let Some(parent) = output_file_path.parent() {
@@ -217,61 +289,53 @@ impl Generator for TauriGenerator {
             }
 
             // 判断是否为模板文件
-            if template_file.ends_with(".tmpl") {
-                // 获取模板内容
-                if let Some(template_content) =
-                    crate::template_engine::get_embedded_template_content(&template_file)
-                {
-                    // 渲染模板
-                    let rendered_content = match template_processor
-                        .render_template_content(&template_content, context.clone())
-                    {
-                        Ok(content) => content,
-                        Err(e) => {
-                            eprintln!("❌ Template rendering error for: {template_file}");
-                            eprintln!("   Error: {e:?}");
-                            eprintln!(
-                                "   Template preview: {}...",
-                                &template_content.chars().take(300).collect::<String>()
-                            );
-                            return Err(e).with_context(|| {
-                                format!("Failed to render embedded template: {template_file}")
-                            });
-                        }
-                    };
-
-                    // 写入文件
-                    fs::write(&output_file_path, rendered_content).with_context(|| {
-                        format!(
-                            "Failed to write rendered file: {}",
-                            output_file_path.display()
-                        )
-                    })?;
-
-                    println!("📝 Rendered: {relative_path} -> {output_relative_path}");
-                } else {
-                    return Err(anyhow::anyhow!(
-                        "Template content not found: {template_file}"
-                    ));
-                }
+            if let Some(merge) = manifest_merge(&output_relative_path, params.tauri_version)
+                && template_file.ends_with(".tmpl")
+                && output_file_path.exists()
+            {
+                let existing = fs::read_to_string(&output_file_path)
+                    .with_context(|| format!("Failed to read {}", output_file_path.display()))?;
+                let rendered =
+                    template_processor.render_embedded_content(&template_file, &render_context)?;
+                let merged = merge(&existing, &rendered)
+                    .with_context(|| format!("Failed to merge {output_relative_path}"))?;
+                fs::write(&output_file_path, merged)
+                    .with_context(|| format!("Failed to write {}", output_file_path.display()))?;
+                status!("🔀 Merged: {relative_path} -> {output_relative_path}");
+            } else if template_file.ends_with(".tmpl") {
+                template_processor.render_embedded_file(
+                    &template_file,
+                    &output_file_path,
+                    &output_relative_path,
+                    &render_context,
+                )?;
+                status!("📝 Rendered: {relative_path} -> {output_relative_path}");
             } else {
                 // 直接复制非模板文件
-                if let Some(file_content) =
-                    crate::template_engine::get_embedded_template_content(&template_file)
-                {
-                    fs::write(&output_file_path, file_content).with_context(|| {
-                        format!("Failed to write file: {}", output_file_path.display())
-                    })?;
-
-                    println!("📋 Copied: {relative_path} -> {output_relative_path}");
-                } else {
-                    return Err(anyhow::anyhow!("File content not found: {template_file}"));
-                }
+                copy_embedded_file(&template_file, &output_file_path, &output_relative_path)?;
+                status!("📋 Copied: {relative_path} -> {output_relative_path}");
             }
         }
 
         Ok(())
     }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        // `cargo install tauri-cli` 默认安装 v2，v1 项目需要匹配主版本的 CLI
+        if params.tauri_version == TauriVersion::V1 {
+            steps.command(
+                "cargo install tauri-cli --version \"^1\" --locked",
+                "Install the Tauri 1 CLI",
+            );
+        }
+        steps.command("cargo tauri dev", "Start development server");
+        steps.command("cargo tauri build", "Build for production");
+        let prerequisites = match params.tauri_version {
+            TauriVersion::V1 => "https://v1.tauri.app/v1/guides/getting-started/prerequisites",
+            TauriVersion::V2 => "https://tauri.app/start/prerequisites/",
+        };
+        steps.url("Tauri prerequisites", prerequisites);
+    }
 }
 
 impl FrameworkGeneratorTrait for TauriGenerator {
@@ -283,22 +347,38 @@ impl FrameworkGeneratorTrait for TauriGenerator {
         Language::Rust.as_str()
     }
 
-    fn generate_basic_structure(
-        &mut self,
-        _params: &Self::Params,
-        _output_path: &Path,
-    ) -> Result<()> {
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 不再需要自定义结构生成，完全依赖模板
         Ok(())
     }
 
-    fn generate_config(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 配置文件通过模板生成
         Ok(())
     }
 
-    fn generate_middleware(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // Tauri 不需要中间件
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_tauri_app_version() {
+        let list = "cargo-edit v0.13.0:\n    cargo-add\ncreate-tauri-app v3.14.0:\n    cargo-create-tauri-app\n";
+        assert_eq!(
+            parse_installed_version(list, "create-tauri-app").as_deref(),
+            Some("3.14.0")
+        );
+        assert_eq!(parse_installed_version(list, "tauri-cli"), None);
+
+        assert!(!is_supported_create_tauri_app("3.14.0"));
+        assert!(is_supported_create_tauri_app("4.0.0"));
+        assert!(is_supported_create_tauri_app("4.6.2"));
+        assert!(!is_supported_create_tauri_app("5.0.0"));
+    }
+}
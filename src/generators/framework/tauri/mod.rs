@@ -1,5 +1,5 @@
 mod generator;
 mod parameters;
 
-pub use generator::TauriGenerator;
+pub use generator::{CREATE_TAURI_APP_VERSION, TauriGenerator, is_supported_create_tauri_app};
 pub use parameters::TauriParams;
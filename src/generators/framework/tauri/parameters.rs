@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
 
+use crate::constants::{TailwindVersion, TauriVersion};
 use crate::generators::core::{BaseParams, InheritableParams};
 use crate::generators::language::rust::RustParams;
 use crate::generators::project::ProjectParams;
@@ -19,6 +22,10 @@ pub struct TauriParams {
     pub enable_dark_mode: bool,
     /// 是否启用骨架屏
     pub enable_skeleton: bool,
+    /// Tailwind CSS 主版本
+    pub tailwind_version: TailwindVersion,
+    /// Tauri 主版本，决定覆盖模板、配置结构和权限配置
+    pub tauri_version: TauriVersion,
     /// 窗口宽度
     pub window_width: u32,
     /// 窗口高度
@@ -44,6 +51,8 @@ impl Default for TauriParams {
             frontend_framework: "vue".to_string(),
             enable_dark_mode: true,
             enable_skeleton: true,
+            tailwind_version: TailwindVersion::default(),
+            tauri_version: TauriVersion::default(),
             window_width: 800,
             window_height: 600,
             identifier: "com.example.app".to_string(),
@@ -69,12 +78,38 @@ impl InheritableParams for TauriParams {
             frontend_framework: "vue".to_string(),
             enable_dark_mode: true,
             enable_skeleton: true,
+            tailwind_version: TailwindVersion::default(),
+            tauri_version: TauriVersion::default(),
             window_width: 800,
             window_height: 600,
             identifier: "com.example.app".to_string(),
             enable_proto_gen: true,
         }
     }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        HashMap::from([
+            (
+                "tailwind_v4".to_string(),
+                json!(self.tailwind_version == TailwindVersion::V4),
+            ),
+            (
+                "tauri_major".to_string(),
+                json!(self.tauri_version.as_str()),
+            ),
+            (
+                "enable_tauri_v1".to_string(),
+                json!(self.tauri_version == TauriVersion::V1),
+            ),
+            (
+                "enable_tauri_v2".to_string(),
+                json!(self.tauri_version == TauriVersion::V2),
+            ),
+            ("identifier".to_string(), json!(self.identifier)),
+            ("window_width".to_string(), json!(self.window_width)),
+            ("window_height".to_string(), json!(self.window_height)),
+        ])
+    }
 }
 
 impl TauriParams {
@@ -102,6 +137,8 @@ impl TauriParams {
             frontend_framework: "vue".to_string(),
             enable_dark_mode: true,
             enable_skeleton: true,
+            tailwind_version: TailwindVersion::default(),
+            tauri_version: TauriVersion::default(),
             window_width: 800,
             window_height: 600,
             identifier,
@@ -145,6 +182,18 @@ impl TauriParams {
         self
     }
 
+    /// 设置 Tailwind CSS 主版本
+    pub fn with_tailwind_version(mut self, version: TailwindVersion) -> Self {
+        self.tailwind_version = version;
+        self
+    }
+
+    /// 设置 Tauri 主版本
+    pub fn with_tauri_version(mut self, version: TauriVersion) -> Self {
+        self.tauri_version = version;
+        self
+    }
+
     /// 设置项目参数
     pub fn with_project(mut self, project: ProjectParams) -> Self {
         self.project = project;
@@ -165,6 +214,7 @@ impl TauriParams {
     }
 
     /// 获取是否启用pre-commit
+    #[allow(dead_code)]
     pub fn enable_precommit(&self) -> bool {
         self.base.enable_precommit
     }
@@ -1,10 +1,37 @@
 use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::Path;
-use std::process::Command;
+use std::time::Duration;
 
-use super::parameters::Vue3Params;
-use crate::constants::{Framework, Language};
-use crate::generators::core::{FrameworkGenerator as FrameworkGeneratorTrait, Generator};
+use super::parameters::{CREATE_VUE_VERSION, Vue3Params};
+use crate::constants::{Framework, Language, Runtime, TailwindVersion};
+use crate::generators::core::{
+    FrameworkGenerator as FrameworkGeneratorTrait, Generator, NextSteps, TemplateProcessor,
+};
+use crate::status;
+use crate::utils::code_inject::{self, MARKER_PREFIX};
+use crate::utils::json_patch::PackageJsonPatch;
+use crate::utils::{api_client, js_runtime, process, tailwind, tool_failures, vitest};
+
+/// create-vue 的最长运行时间，超时视为进入了交互模式
+const CREATE_VUE_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// 需要渲染的模板目录，其余文件由 create-vue 生成
+const RENDERED_FEATURE_DIRS: &[&str] = &[
+    "+api_client/",
+    "+tanstack_query/",
+    "+auth_pages/",
+    "+dark_mode/",
+    "+tests/",
+    "+ci/",
+];
+
+/// create-vue 生成的入口文件
+const MAIN_ENTRY: &str = "src/main.ts";
+
+/// create-vue 生成的路由文件
+const ROUTER_ENTRY: &str = "src/router/index.ts";
 
 /// Vue3框架级别生成器实现
 #[derive(Debug)]
@@ -16,40 +43,37 @@ impl Vue3Generator {
         Ok(Self {})
     }
 
-    /// 检查 pnpm 是否已安装
-    pub fn check_pnpm() -> Result<bool> {
-        match Command::new("pnpm").arg("--version").output() {
-            Ok(output) => Ok(output.status.success()),
-            Err(_) => Ok(false),
-        }
-    }
-
-    /// 使用 pnpm create vue 创建项目
-    pub fn create_vue3_project(project_name: &str, output_path: &Path) -> Result<()> {
-        println!("🚀 Creating Vue3 project with create-vue...");
+    /// 使用 create-vue 创建项目
+    pub fn create_vue3_project(params: &Vue3Params, output_path: &Path) -> Result<()> {
+        status!("🚀 Creating Vue3 project with create-vue@{CREATE_VUE_VERSION}...");
 
         // 获取父目录
         let parent_dir = output_path.parent().unwrap_or_else(|| Path::new("."));
 
-        // 使用 pnpm create vue 创建项目
-        // 使用非交互模式，指定所有选项
-        let output = Command::new("pnpm")
-            .args([
-                "create",
-                "vue@latest",
-                project_name,
-                "--typescript",
-                "--router",
-                "--pinia",
-                "--eslint",
-                "--prettier",
-            ])
-            .current_dir(parent_dir)
-            .output()
-            .context("Failed to execute pnpm create vue")?;
+        // 固定 create-vue 版本；pnpm create 的参数通过 `--` 传递，避免落入交互模式
+        let package = format!("vue@{CREATE_VUE_VERSION}");
+        let mut command = js_runtime::create(params.runtime, &package);
+        if params.runtime == Runtime::Node {
+            command.arg("--");
+        }
+        command
+            .arg(&params.base.project_name)
+            .args(params.create_vue_flags())
+            .current_dir(parent_dir);
+
+        let output = process::output_with_timeout(&mut command, CREATE_VUE_TIMEOUT)
+            .context("Failed to execute create-vue")?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "create-vue@{CREATE_VUE_VERSION} did not finish within {}s; it is probably waiting for interactive input. \
+                     Check that the pinned version still accepts: {}",
+                    CREATE_VUE_TIMEOUT.as_secs(),
+                    params.create_vue_flags().join(" ")
+                )
+            })?;
 
         if output.status.success() {
-            println!("✅ Vue3 project created successfully");
+            status!("✅ Vue3 project created successfully");
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -60,75 +84,83 @@ impl Vue3Generator {
         }
     }
 
-    /// 安装 Tailwind CSS
-    pub fn install_tailwind(output_path: &Path) -> Result<()> {
-        println!("📦 Installing Tailwind CSS...");
-
-        // 安装 Tailwind CSS 依赖
-        let output = Command::new("pnpm")
-            .args([
-                "add",
-                "-D",
-                "tailwindcss",
-                "postcss",
-                "autoprefixer",
-                "@tailwindcss/forms",
-                "@tailwindcss/typography",
-            ])
-            .current_dir(output_path)
-            .output()
-            .context("Failed to install Tailwind CSS")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to install Tailwind CSS: {stderr}");
+    /// 写入 package.json 的依赖：Tailwind CSS、API 客户端层，以及启用测试时的 Vitest
+    pub fn package_patch(params: &Vue3Params, enable_tests: bool) -> PackageJsonPatch {
+        let patch = PackageJsonPatch::new()
+            .with_dev_dependencies(tailwind::packages(params.tailwind_version))
+            .with_dependencies(&params.api_packages());
+        if enable_tests {
+            vitest::add_to(patch, Framework::Vue3)
+        } else {
+            patch
         }
+    }
 
-        // 初始化 Tailwind CSS
-        let output = Command::new("pnpm")
-            .args(["exec", "tailwindcss", "init", "-p"])
-            .current_dir(output_path)
-            .output()
-            .context("Failed to initialize Tailwind CSS")?;
+    /// 依赖安装完成后按所选主版本配置 Tailwind CSS
+    pub fn configure_tailwind(
+        output_path: &Path,
+        version: TailwindVersion,
+        runtime: Runtime,
+    ) -> Result<()> {
+        tailwind::configure_for_vite(output_path, version, runtime)
+    }
 
-        if output.status.success() {
-            println!("✅ Tailwind CSS installed successfully");
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to initialize Tailwind CSS: {stderr}");
-        }
+    /// 在 `src/main.ts` 中注册 VueQueryPlugin 和共享的 QueryClient
+    pub fn register_vue_query(output_path: &Path) -> Result<()> {
+        update_main_entry(
+            output_path,
+            "VueQueryPlugin",
+            "app.mount(",
+            with_vue_query_plugin,
+        )
+    }
 
-        Ok(())
+    /// 在 `src/main.ts` 中注册主题插件，并让 Tailwind CSS 按 `dark` 类切换深色样式
+    pub fn register_theme(output_path: &Path, version: TailwindVersion) -> Result<()> {
+        update_main_entry(output_path, "themePlugin", "app.mount(", with_theme_plugin)?;
+        tailwind::use_class_dark_mode(output_path, version)
+    }
+
+    /// 在 `src/main.ts` 中安装登录注册路由和认证守卫
+    pub fn register_auth_guard(output_path: &Path) -> Result<()> {
+        update_main_entry(
+            output_path,
+            "installAuthGuard",
+            "app.use(router)",
+            with_auth_guard,
+        )
+    }
+
+    /// 在 `src/router/index.ts` 的 `routes` 数组末尾加入 `// scafgen:routes` 标记，
+    /// 之后生成的页面在此注册；未启用 Vue Router 时没有路由文件，不做处理
+    pub fn mark_routes(output_path: &Path) -> Result<()> {
+        let router_path = output_path.join(ROUTER_ENTRY);
+        let Ok(router) = std::fs::read_to_string(&router_path) else {
+            return Ok(());
+        };
+        match with_routes_marker(&router) {
+            Some(updated) => std::fs::write(&router_path, updated)
+                .with_context(|| format!("Failed to write {}", router_path.display())),
+            None => Ok(()),
+        }
     }
 
     /// 安装前端依赖
-    pub fn install_dependencies(output_path: &Path) -> Result<()> {
-        println!("📦 Installing frontend dependencies...");
+    pub fn install_dependencies(output_path: &Path, runtime: Runtime) -> Result<()> {
+        status!("📦 Installing frontend dependencies...");
 
-        let output = Command::new("pnpm")
-            .arg("install")
+        let output = js_runtime::install(runtime)
             .current_dir(output_path)
             .output()
-            .context("Failed to execute pnpm install")?;
+            .with_context(|| format!("Failed to execute {} install", runtime.package_manager()))?;
 
         if output.status.success() {
-            println!("✅ Dependencies installed successfully");
+            status!("✅ Dependencies installed successfully");
             Ok(())
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            println!("⚠️ Warning: Failed to install dependencies: {stderr}");
-            // 不返回错误，让用户手动安装
-            Ok(())
-        }
-    }
-
-    /// 检查是否应该跳过pre-commit相关文件
-    #[allow(dead_code)]
-    fn should_skip_precommit_file(&self, file_name: &str, params: &Vue3Params) -> bool {
-        if !params.enable_precommit() {
-            file_name == ".pre-commit-config.yaml.tmpl" || file_name == ".pre-commit-config.yaml"
-        } else {
-            false
+            // lenient 策略下不返回错误，让用户手动安装
+            tool_failures::report(format!("Failed to install dependencies: {}", stderr.trim()))
         }
     }
 }
@@ -153,6 +185,50 @@ impl Generator for Vue3Generator {
     fn get_template_path(&self) -> &'static str {
         "frameworks/typescript/vue3"
     }
+
+    /// 渲染嵌入式模板 - 只渲染 API 客户端层，不覆盖 create-vue 生成的文件
+    fn render_embedded_templates(
+        &self,
+        template_processor: &TemplateProcessor,
+        template_path: &str,
+        output_path: &Path,
+        context: HashMap<String, Value>,
+        _params: &Self::Params,
+    ) -> Result<()> {
+        template_processor.process_embedded_template_directory_filtered(
+            template_path,
+            output_path,
+            context,
+            |relative_path| {
+                RENDERED_FEATURE_DIRS
+                    .iter()
+                    .any(|dir| relative_path.starts_with(dir))
+            },
+        )
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        let runtime = params.runtime;
+        steps.command(runtime.run_command("dev"), "Start development server");
+        steps.command(runtime.run_command("build"), "Build for production");
+        if params.base.enable_tests {
+            steps.command(
+                runtime.run_command("test:coverage"),
+                "Run unit tests with coverage",
+            );
+        }
+        steps.url("Dev server", "http://localhost:5173");
+        if params.api_layer().is_some() {
+            steps.caveat(api_client::BASE_URL_CAVEAT);
+        }
+        if params.enable_dark_mode {
+            steps.caveat("Place <ThemeToggle /> from src/components/ThemeToggle.vue in your layout to switch themes");
+        }
+        if params.enable_auth_pages {
+            steps.url("Sign in", "http://localhost:5173/login");
+            steps.caveat(api_client::AUTH_ENDPOINTS_CAVEAT);
+        }
+    }
 }
 
 impl FrameworkGeneratorTrait for Vue3Generator {
@@ -164,22 +240,197 @@ impl FrameworkGeneratorTrait for Vue3Generator {
         Language::TypeScript.as_str()
     }
 
-    fn generate_basic_structure(
-        &mut self,
-        _params: &Self::Params,
-        _output_path: &Path,
-    ) -> Result<()> {
+    fn generate_basic_structure(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 不再需要自定义结构生成，完全依赖模板
         Ok(())
     }
 
-    fn generate_config(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 配置文件通过模板生成
         Ok(())
     }
 
-    fn generate_middleware(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn generate_middleware(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // Vue3 不需要中间件
         Ok(())
     }
 }
+
+/// 用 `update` 修改入口文件，找不到 `anchor` 时按失败策略提示用户手动修改
+fn update_main_entry(
+    output_path: &Path,
+    marker: &str,
+    anchor: &str,
+    update: fn(&str) -> Option<String>,
+) -> Result<()> {
+    let main_path = output_path.join(MAIN_ENTRY);
+    let Ok(main) = std::fs::read_to_string(&main_path) else {
+        return tool_failures::report(format!(
+            "{MAIN_ENTRY} not found; register {marker} manually"
+        ));
+    };
+
+    match update(&main) {
+        Some(updated) => std::fs::write(&main_path, updated)
+            .with_context(|| format!("Failed to write {}", main_path.display())),
+        None if main.contains(marker) => Ok(()),
+        None => tool_failures::report(format!(
+            "Could not find {anchor} in {MAIN_ENTRY}; register {marker} manually"
+        )),
+    }
+}
+
+/// 在入口文件中加入 VueQueryPlugin 的导入和 `app.use` 调用，已注册或找不到 `app.mount(` 时返回 `None`
+fn with_vue_query_plugin(main: &str) -> Option<String> {
+    insert_setup(
+        main,
+        "VueQueryPlugin",
+        "app.mount(",
+        "app.use(VueQueryPlugin, { queryClient })\n",
+        "import { VueQueryPlugin } from '@tanstack/vue-query'\nimport { queryClient } from './api/queryClient'\n",
+    )
+}
+
+/// 在入口文件中注册主题插件，已注册或找不到 `app.mount(` 时返回 `None`
+fn with_theme_plugin(main: &str) -> Option<String> {
+    insert_setup(
+        main,
+        "themePlugin",
+        "app.mount(",
+        "app.use(themePlugin)\n",
+        "import { themePlugin } from './theme'\n",
+    )
+}
+
+/// 在 `app.use(router)` 之前安装认证路由和守卫，保证首次导航时登录页已注册
+fn with_auth_guard(main: &str) -> Option<String> {
+    insert_setup(
+        main,
+        "installAuthGuard",
+        "app.use(router)",
+        "installAuthGuard(router)\n",
+        "import { installAuthGuard } from './router/auth'\n",
+    )
+}
+
+/// 在 `anchor` 所在位置之前插入 `setup`，并把 `imports` 放在最后一个 import 之后；
+/// 已包含 `marker` 或找不到 `anchor` 时返回 `None`
+fn insert_setup(
+    main: &str,
+    marker: &str,
+    anchor: &str,
+    setup: &str,
+    imports: &str,
+) -> Option<String> {
+    if main.contains(marker) {
+        return None;
+    }
+    let anchor_at = main.find(anchor)?;
+
+    let mut updated = String::with_capacity(main.len() + setup.len() + imports.len());
+    updated.push_str(&main[..anchor_at]);
+    updated.push_str(setup);
+    updated.push_str(&main[anchor_at..]);
+
+    // 导入语句放在最后一个 import 之后
+    let mut insert_at = 0;
+    let mut offset = 0;
+    for line in updated.split_inclusive('\n') {
+        offset += line.len();
+        if line.starts_with("import ") {
+            insert_at = offset;
+        }
+    }
+    updated.insert_str(insert_at, imports);
+
+    Some(updated)
+}
+
+/// 在 `routes: [...]` 的最后一个元素之后插入路由标记，已有标记或找不到 `routes` 数组时返回 `None`
+fn with_routes_marker(router: &str) -> Option<String> {
+    if code_inject::has_marker(router, "routes") {
+        return None;
+    }
+    let start = router.find("routes: [")? + "routes: [".len();
+    let mut depth = 1;
+    let close = router[start..].char_indices().find_map(|(i, c)| {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + i);
+                }
+            }
+            _ => {}
+        }
+        None
+    })?;
+
+    let line_start = router[..close].rfind('\n').map_or(0, |i| i + 1);
+    let close_indent = &router[line_start..close];
+    if close_indent.trim().is_empty() {
+        // 多行数组：标记放在 `]` 所在行之前，比 `]` 多缩进一级
+        Some(format!(
+            "{}{close_indent}  // {MARKER_PREFIX}routes\n{}",
+            &router[..line_start],
+            &router[line_start..]
+        ))
+    } else if router[start..close].trim().is_empty() {
+        // 空数组 `routes: []` 展开为多行
+        let indent_start = router[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line = &router[indent_start..];
+        let indent = &line[..line.len() - line.trim_start().len()];
+        Some(format!(
+            "{}\n{indent}  // {MARKER_PREFIX}routes\n{indent}{}",
+            &router[..start],
+            &router[close..]
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_vue_query_plugin() {
+        let main = "import { createApp } from 'vue'\nimport App from './App.vue'\n\nconst app = createApp(App)\n\napp.mount('#app')\n";
+        assert_eq!(
+            with_vue_query_plugin(main).unwrap(),
+            "import { createApp } from 'vue'\nimport App from './App.vue'\nimport { VueQueryPlugin } from '@tanstack/vue-query'\nimport { queryClient } from './api/queryClient'\n\nconst app = createApp(App)\n\napp.use(VueQueryPlugin, { queryClient })\napp.mount('#app')\n"
+        );
+
+        let registered = with_vue_query_plugin(main).unwrap();
+        assert!(with_vue_query_plugin(&registered).is_none());
+        assert!(with_vue_query_plugin("createApp(App).mount('#app')\n").is_none());
+    }
+
+    #[test]
+    fn test_with_auth_guard() {
+        let main = "import { createApp } from 'vue'\nimport { createPinia } from 'pinia'\n\nimport App from './App.vue'\nimport router from './router'\n\nconst app = createApp(App)\n\napp.use(createPinia())\napp.use(router)\n\napp.mount('#app')\n";
+        assert_eq!(
+            with_auth_guard(main).unwrap(),
+            "import { createApp } from 'vue'\nimport { createPinia } from 'pinia'\n\nimport App from './App.vue'\nimport router from './router'\nimport { installAuthGuard } from './router/auth'\n\nconst app = createApp(App)\n\napp.use(createPinia())\ninstallAuthGuard(router)\napp.use(router)\n\napp.mount('#app')\n"
+        );
+        assert!(with_auth_guard(&with_auth_guard(main).unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_with_routes_marker() {
+        let router = "const router = createRouter({\n  history: createWebHistory(import.meta.env.BASE_URL),\n  routes: [\n    {\n      path: '/',\n      component: HomeView,\n      meta: { tags: ['home'] },\n    },\n  ],\n})\n";
+        let marked = with_routes_marker(router).unwrap();
+        assert_eq!(
+            marked,
+            "const router = createRouter({\n  history: createWebHistory(import.meta.env.BASE_URL),\n  routes: [\n    {\n      path: '/',\n      component: HomeView,\n      meta: { tags: ['home'] },\n    },\n    // scafgen:routes\n  ],\n})\n"
+        );
+        assert!(with_routes_marker(&marked).is_none());
+
+        assert_eq!(
+            with_routes_marker("const router = createRouter({\n  routes: [],\n})\n").unwrap(),
+            "const router = createRouter({\n  routes: [\n    // scafgen:routes\n  ],\n})\n"
+        );
+    }
+}
@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
 
+use crate::constants::{ApiClient, Runtime, TailwindVersion};
 use crate::generators::core::{BaseParams, InheritableParams};
 use crate::generators::project::ProjectParams;
+use crate::utils::{api_client, vitest};
+
+/// 固定的 create-vue 版本，不同版本的命令行参数并不兼容
+pub const CREATE_VUE_VERSION: &str = "3.18.0";
 
 /// Vue3框架参数 - 继承自BaseParams
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,10 +19,14 @@ pub struct Vue3Params {
     pub project: ProjectParams,
     /// Node.js 版本
     pub node_version: String,
+    /// JavaScript 运行时，决定包管理命令和 CI 配置
+    pub runtime: Runtime,
     /// 是否启用 TypeScript (强制启用)
     pub enable_typescript: bool,
     /// 是否启用 Tailwind CSS
     pub enable_tailwind: bool,
+    /// Tailwind CSS 主版本
+    pub tailwind_version: TailwindVersion,
     /// 是否启用 Vue Router
     pub enable_router: bool,
     /// 是否启用 Pinia 状态管理
@@ -24,8 +35,16 @@ pub struct Vue3Params {
     pub enable_eslint: bool,
     /// 是否启用 Prettier
     pub enable_prettier: bool,
-    /// 包管理器 (pnpm)
+    /// 包管理器，由运行时决定 (pnpm、bun、deno)
     pub package_manager: String,
+    /// API 客户端层实现，None 表示不生成 `src/api`
+    pub api_client: Option<ApiClient>,
+    /// 是否加入 TanStack Query 和示例查询 hook
+    pub enable_tanstack_query: bool,
+    /// 是否生成登录注册页面、认证状态和路由守卫
+    pub enable_auth_pages: bool,
+    /// 是否生成浅色/深色/跟随系统的主题切换
+    pub enable_dark_mode: bool,
 }
 
 impl Default for Vue3Params {
@@ -40,13 +59,19 @@ impl Default for Vue3Params {
             base,
             project: ProjectParams::default(),
             node_version: "20".to_string(),
+            runtime: Runtime::default(),
             enable_typescript: true, // 强制启用 TypeScript
             enable_tailwind: true,
+            tailwind_version: TailwindVersion::default(),
             enable_router: true,
             enable_pinia: true,
             enable_eslint: true,
             enable_prettier: true,
             package_manager: "pnpm".to_string(),
+            api_client: None,
+            enable_tanstack_query: false,
+            enable_auth_pages: false,
+            enable_dark_mode: false,
         }
     }
 }
@@ -65,15 +90,40 @@ impl InheritableParams for Vue3Params {
             base,
             project: ProjectParams::default(),
             node_version: "20".to_string(),
+            runtime: Runtime::default(),
             enable_typescript: true,
             enable_tailwind: true,
+            tailwind_version: TailwindVersion::default(),
             enable_router: true,
             enable_pinia: true,
             enable_eslint: true,
             enable_prettier: true,
             package_manager: "pnpm".to_string(),
+            api_client: None,
+            enable_tanstack_query: false,
+            enable_auth_pages: false,
+            enable_dark_mode: false,
         }
     }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        let mut context = HashMap::from([
+            (
+                "tailwind_v4".to_string(),
+                json!(self.tailwind_version == TailwindVersion::V4),
+            ),
+            ("enable_dark_mode".to_string(), json!(self.enable_dark_mode)),
+            ("node_version".to_string(), json!(self.node_version)),
+            ("runtime".to_string(), json!(self.runtime)),
+        ]);
+        context.extend(api_client::template_context(
+            self.api_layer(),
+            self.enable_tanstack_query,
+            self.enable_auth_pages,
+        ));
+        context.extend(vitest::template_context(self.base.enable_tests));
+        context
+    }
 }
 
 impl Vue3Params {
@@ -93,16 +143,28 @@ impl Vue3Params {
             base,
             project: ProjectParams::from_project_name(project_name),
             node_version: "20".to_string(),
+            runtime: Runtime::default(),
             enable_typescript: true,
             enable_tailwind: true,
+            tailwind_version: TailwindVersion::default(),
             enable_router: true,
             enable_pinia: true,
             enable_eslint: true,
             enable_prettier: true,
             package_manager: "pnpm".to_string(),
+            api_client: None,
+            enable_tanstack_query: false,
+            enable_auth_pages: false,
+            enable_dark_mode: false,
         }
     }
 
+    /// 设置 Tailwind CSS 主版本
+    pub fn with_tailwind_version(mut self, version: TailwindVersion) -> Self {
+        self.tailwind_version = version;
+        self
+    }
+
     /// 设置项目参数
     pub fn with_project(mut self, project: ProjectParams) -> Self {
         self.project = project;
@@ -115,7 +177,15 @@ impl Vue3Params {
         self
     }
 
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self.package_manager = runtime.package_manager().to_string();
+        self
+    }
+
     /// 获取是否启用pre-commit
+    #[allow(dead_code)]
     pub fn enable_precommit(&self) -> bool {
         self.base.enable_precommit
     }
@@ -141,6 +211,77 @@ impl Vue3Params {
         self
     }
 
+    /// 设置是否启用 ESLint
+    #[allow(dead_code)]
+    pub fn with_eslint(mut self, enable: bool) -> Self {
+        self.enable_eslint = enable;
+        self
+    }
+
+    /// 设置是否启用 Prettier
+    #[allow(dead_code)]
+    pub fn with_prettier(mut self, enable: bool) -> Self {
+        self.enable_prettier = enable;
+        self
+    }
+
+    /// 根据子选项生成 create-vue 的命令行参数
+    pub fn create_vue_flags(&self) -> Vec<&'static str> {
+        [
+            (self.enable_typescript, "--typescript"),
+            // 登录注册页面依赖 Vue Router 的路由守卫和 Pinia 的认证 store
+            (self.enable_router || self.enable_auth_pages, "--router"),
+            (self.enable_pinia || self.enable_auth_pages, "--pinia"),
+            (self.enable_eslint, "--eslint"),
+            (self.enable_prettier, "--prettier"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, flag)| enabled.then_some(flag))
+        .collect()
+    }
+
+    /// 设置 API 客户端层实现
+    pub fn with_api_client(mut self, api_client: Option<ApiClient>) -> Self {
+        self.api_client = api_client;
+        self
+    }
+
+    /// 设置是否加入 TanStack Query
+    pub fn with_tanstack_query(mut self, enable: bool) -> Self {
+        self.enable_tanstack_query = enable;
+        self
+    }
+
+    /// 设置是否生成登录注册页面
+    pub fn with_auth_pages(mut self, enable: bool) -> Self {
+        self.enable_auth_pages = enable;
+        self
+    }
+
+    /// 设置是否生成主题切换
+    pub fn with_dark_mode(mut self, enable: bool) -> Self {
+        self.enable_dark_mode = enable;
+        self
+    }
+
+    /// 实际生成的 API 客户端层，启用 TanStack Query 或登录注册页面时至少生成 fetch 实现
+    pub fn api_layer(&self) -> Option<ApiClient> {
+        self.api_client
+            .or((self.enable_tanstack_query || self.enable_auth_pages).then_some(ApiClient::Fetch))
+    }
+
+    /// API 客户端层需要安装的依赖
+    pub fn api_packages(&self) -> Vec<&'static str> {
+        let mut packages = Vec::new();
+        if self.api_layer() == Some(ApiClient::Axios) {
+            packages.push("axios@^1");
+        }
+        if self.enable_tanstack_query {
+            packages.push("@tanstack/vue-query@^5");
+        }
+        packages
+    }
+
     /// 设置 Node.js 版本
     #[allow(dead_code)]
     pub fn with_node_version(mut self, version: String) -> Self {
@@ -148,3 +289,37 @@ impl Vue3Params {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_vue_flags() {
+        assert_eq!(
+            Vue3Params::default().create_vue_flags(),
+            [
+                "--typescript",
+                "--router",
+                "--pinia",
+                "--eslint",
+                "--prettier"
+            ]
+        );
+
+        let params = Vue3Params::default()
+            .with_router(false)
+            .with_prettier(false);
+        assert_eq!(
+            params.create_vue_flags(),
+            ["--typescript", "--pinia", "--eslint"]
+        );
+
+        // 登录注册页面需要 Vue Router 和 Pinia
+        let params = params.with_auth_pages(true);
+        assert_eq!(
+            params.create_vue_flags(),
+            ["--typescript", "--router", "--pinia", "--eslint"]
+        );
+    }
+}
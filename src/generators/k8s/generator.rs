@@ -0,0 +1,97 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::K8sParams;
+use crate::constants::K8sFormat;
+use crate::generators::core::{Generator, NextSteps, Parameters, TemplateProcessor};
+use crate::status;
+
+/// Kubernetes 部署文件生成器：`deploy/k8s/` 下的清单或 `deploy/helm/<项目名>/` 下的 Helm chart
+///
+/// 工作负载使用 Docker 生成器构建的镜像，镜像名与资源名相同
+pub struct K8sGenerator {
+    template_processor: TemplateProcessor,
+}
+
+impl K8sGenerator {
+    /// 创建新的 Kubernetes 生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            template_processor: TemplateProcessor::new()?.with_feature("k8s"),
+        })
+    }
+}
+
+impl Default for K8sGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create K8sGenerator")
+    }
+}
+
+impl Generator for K8sGenerator {
+    type Params = K8sParams;
+
+    fn name(&self) -> &'static str {
+        "Kubernetes"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Generates Deployment, Service and Ingress manifests or a Helm chart")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "k8s"
+    }
+
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
+        status!("Generating {} files", self.name());
+
+        self.template_processor
+            .process_embedded_template_directory(
+                params.template_path(),
+                output_path,
+                params.to_template_context(),
+            )
+            .context("Failed to generate Kubernetes files")?;
+
+        status!("{} files generated", self.name());
+        Ok(())
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        for workload in &params.workloads {
+            steps.command(
+                workload.build_command(),
+                format!("Build the {} image", workload.name),
+            );
+        }
+        let project_name = &params.base.project_name;
+        match params.format {
+            K8sFormat::Manifests => steps.command(
+                "kubectl apply -k deploy/k8s",
+                "Deploy to the current cluster",
+            ),
+            K8sFormat::Helm => steps.command(
+                format!("helm install {project_name} deploy/helm/{project_name}"),
+                "Install the Helm chart into the current cluster",
+            ),
+        }
+        if params.workloads.iter().any(|workload| workload.ingress) {
+            steps.url("Ingress", format!("http://{}/", params.ingress_host));
+        }
+        if params
+            .workloads
+            .iter()
+            .any(|workload| !workload.env.is_empty())
+        {
+            steps
+                .caveat("Replace the `change-me` secrets in the Kubernetes files before deploying");
+        }
+        if !params.listens_on_all_interfaces() {
+            let host = params.listen_host.as_deref().unwrap_or_default();
+            steps.caveat(format!(
+                "The service listens on {host}, which is unreachable from outside the pod; generate with --host 0.0.0.0 or override the host in the deployed config"
+            ));
+        }
+    }
+}
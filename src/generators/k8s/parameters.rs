@@ -0,0 +1,380 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::{Framework, K8sFormat};
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::docker::parameters::container_port;
+
+/// 主机参数不是域名时 Ingress 使用的域名后缀，如 `my-api.local`
+const DEFAULT_INGRESS_SUFFIX: &str = "local";
+
+/// 一组 Deployment 和 Service，Go-Zero 的每个服务各对应一组
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct K8sWorkload {
+    /// 资源名称，同时也是镜像名
+    pub name: String,
+    /// 容器监听的端口
+    pub port: u16,
+    /// HTTP 健康检查路径，gRPC 服务为 `None`，改用 TCP 探针
+    pub health_path: Option<String>,
+    /// 是否通过 Ingress 对外暴露
+    pub ingress: bool,
+    /// 容器的环境变量
+    pub env: Vec<(String, String)>,
+    /// 构建镜像使用的 Dockerfile，`None` 为项目根目录的 `Dockerfile`
+    pub dockerfile: Option<String>,
+}
+
+impl K8sWorkload {
+    fn http(name: String, port: u16, health_path: &str) -> Self {
+        Self {
+            name,
+            port,
+            health_path: Some(health_path.to_string()),
+            ingress: false,
+            env: Vec::new(),
+            dockerfile: None,
+        }
+    }
+
+    /// Go-Zero 服务，镜像由框架模板中 `deploy/docker/Dockerfile.<服务>` 构建
+    fn go_zero(project_name: &str, service: &str, port: u16, health_path: &str) -> Self {
+        Self {
+            dockerfile: Some(format!("deploy/docker/Dockerfile.{service}")),
+            ..Self::http(format!("{project_name}-{service}"), port, health_path)
+        }
+    }
+
+    /// 构建镜像的命令，镜像名与资源名相同
+    pub fn build_command(&self) -> String {
+        match &self.dockerfile {
+            Some(dockerfile) => format!("docker build -f {dockerfile} -t {}:latest .", self.name),
+            None => format!("docker build -t {}:latest .", self.name),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let env: Vec<Value> = self
+            .env
+            .iter()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect();
+        json!({
+            "name": self.name,
+            "port": self.port,
+            "port_name": if self.health_path.is_some() { "http" } else { "grpc" },
+            "health_path": self.health_path,
+            "ingress": self.ingress,
+            "env": env,
+        })
+    }
+}
+
+/// Kubernetes 部署文件生成参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct K8sParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 清单或 Helm chart
+    pub format: K8sFormat,
+    /// 项目框架，决定端口和健康检查路径
+    pub framework: Framework,
+    /// 每个服务的 Deployment 和 Service
+    pub workloads: Vec<K8sWorkload>,
+    /// Ingress 使用的域名
+    pub ingress_host: String,
+    /// 服务自身监听的主机地址，gunicorn 固定监听 0.0.0.0 的 Python 框架为 `None`
+    pub listen_host: Option<String>,
+}
+
+impl Default for K8sParams {
+    fn default() -> Self {
+        Self::for_stack(
+            String::new(),
+            K8sFormat::Manifests,
+            Framework::Gin,
+            "0.0.0.0",
+            8080,
+        )
+        .expect("Gin projects have Kubernetes files")
+    }
+}
+
+impl InheritableParams for K8sParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            format: K8sFormat::Manifests,
+            framework: Framework::Gin,
+            workloads: Vec::new(),
+            ingress_host: String::new(),
+            listen_host: None,
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        let workloads: Vec<Value> = self.workloads.iter().map(K8sWorkload::to_value).collect();
+        HashMap::from([
+            ("k8s_workloads".to_string(), json!(workloads)),
+            ("ingress_host".to_string(), json!(self.ingress_host)),
+            (
+                "enable_ingress".to_string(),
+                json!(self.workloads.iter().any(|workload| workload.ingress)),
+            ),
+        ])
+    }
+}
+
+impl K8sParams {
+    /// 为后端服务创建 Kubernetes 参数，前端、桌面和命令行项目返回 `None`
+    ///
+    /// `host`、`port` 为命令行指定的服务地址：端口即容器端口，域名形式的主机用作 Ingress 域名
+    pub fn for_stack(
+        project_name: String,
+        format: K8sFormat,
+        framework: Framework,
+        host: &str,
+        port: u16,
+    ) -> Option<Self> {
+        if !Self::supports(framework) {
+            return None;
+        }
+        let port = container_port(framework, port)?;
+        let ingress_host = ingress_host(host, &project_name);
+        let (health_path, env, listen_host) = match framework {
+            Framework::Gin
+            | Framework::Fiber
+            | Framework::Chi
+            | Framework::GoZero
+            | Framework::Axum
+            | Framework::Actix
+            | Framework::NestJs => ("/health", Vec::new(), Some(host.to_string())),
+            // 探针带上 Ingress 域名作为 Host 头，生产配置只放行该域名
+            Framework::Django => (
+                "/health/",
+                vec![
+                    ("DJANGO_SECRET_KEY", "change-me".to_string()),
+                    ("DJANGO_ALLOWED_HOSTS", ingress_host.clone()),
+                    ("DJANGO_SECURE_SSL_REDIRECT", "false".to_string()),
+                ],
+                None,
+            ),
+            Framework::Flask => (
+                "/health",
+                vec![
+                    ("ENV", "prod".to_string()),
+                    ("SECRET_KEY", "change-me".to_string()),
+                    ("PORT", port.to_string()),
+                ],
+                None,
+            ),
+            _ => return None,
+        };
+
+        let mut workload = match framework {
+            Framework::GoZero => K8sWorkload::go_zero(&project_name, "api", port, health_path),
+            _ => K8sWorkload::http(project_name.clone(), port, health_path),
+        };
+        workload.ingress = true;
+        workload.env = env
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+
+        // 清单只需项目名和版本，不带 module_name 等源码层面的参数
+        let base = BaseParams {
+            project_name,
+            ..Default::default()
+        };
+        Some(Self {
+            base,
+            format,
+            framework,
+            workloads: vec![workload],
+            ingress_host,
+            listen_host,
+        })
+    }
+
+    /// 框架是否为可以部署到集群的后端服务
+    pub fn supports(framework: Framework) -> bool {
+        matches!(
+            framework,
+            Framework::Gin
+                | Framework::Fiber
+                | Framework::Chi
+                | Framework::GoZero
+                | Framework::Axum
+                | Framework::Actix
+                | Framework::NestJs
+                | Framework::Django
+                | Framework::Flask
+        )
+    }
+
+    /// 按 Go-Zero 选择的服务生成工作负载：api 通过 Ingress 暴露，admin 和 rpc 只在集群内访问
+    pub fn with_go_zero_services(mut self, services: &[String], grpc_port: u16) -> Self {
+        if self.framework != Framework::GoZero || services.is_empty() {
+            return self;
+        }
+        let Some(port) = self.workloads.first().map(|workload| workload.port) else {
+            return self;
+        };
+
+        let project_name = &self.base.project_name;
+        self.workloads = services
+            .iter()
+            .filter_map(|service| match service.as_str() {
+                "api" => Some(K8sWorkload::go_zero(project_name, service, port, "/health")),
+                // 端口与 GoZeroParams::admin_port 一致，路由带 /admin 前缀
+                "admin" => Some(K8sWorkload::go_zero(
+                    project_name,
+                    service,
+                    port.saturating_add(1),
+                    "/admin/health",
+                )),
+                "rpc" => Some(K8sWorkload {
+                    health_path: None,
+                    ..K8sWorkload::go_zero(project_name, service, grpc_port, "")
+                }),
+                _ => None,
+            })
+            .collect();
+        // 没有 api 服务时由第一个 HTTP 服务对外暴露
+        if let Some(workload) = self
+            .workloads
+            .iter_mut()
+            .find(|workload| workload.health_path.is_some())
+        {
+            workload.ingress = true;
+        }
+        self
+    }
+
+    /// 模板目录
+    pub fn template_path(&self) -> &'static str {
+        match self.format {
+            K8sFormat::Manifests => "k8s/manifests",
+            K8sFormat::Helm => "k8s/helm",
+        }
+    }
+
+    /// 服务是否监听所有网卡，只监听回环地址或域名时 Pod 外无法访问
+    pub fn listens_on_all_interfaces(&self) -> bool {
+        self.listen_host.as_deref().is_none_or(|host| {
+            host.trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<std::net::IpAddr>()
+                .is_ok_and(|ip| ip.is_unspecified())
+        })
+    }
+}
+
+/// Ingress 域名：主机参数是域名时直接使用，IP 地址或 localhost 时使用 `<项目名>.local`
+fn ingress_host(host: &str, project_name: &str) -> String {
+    let host = host.trim();
+    let is_domain = host.contains('.')
+        && host.parse::<std::net::IpAddr>().is_err()
+        && host.chars().any(|c| c.is_ascii_alphabetic());
+    if is_domain {
+        host.to_string()
+    } else {
+        format!("{project_name}.{DEFAULT_INGRESS_SUFFIX}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workloads_for_stack() {
+        let params = K8sParams::for_stack(
+            "shop".to_string(),
+            K8sFormat::Helm,
+            Framework::Django,
+            "127.0.0.1",
+            8080,
+        )
+        .unwrap();
+        let workload = &params.workloads[0];
+        assert_eq!(workload.port, 8000);
+        assert_eq!(workload.health_path.as_deref(), Some("/health/"));
+        assert!(
+            workload
+                .env
+                .contains(&("DJANGO_ALLOWED_HOSTS".to_string(), "shop.local".to_string()))
+        );
+        assert!(params.listens_on_all_interfaces());
+
+        let params = K8sParams::for_stack(
+            "shop".to_string(),
+            K8sFormat::Manifests,
+            Framework::Axum,
+            "api.example.com",
+            3000,
+        )
+        .unwrap();
+        assert_eq!(params.ingress_host, "api.example.com");
+        assert!(!params.listens_on_all_interfaces());
+        assert_eq!(params.workloads[0].port, 3000);
+
+        assert!(
+            K8sParams::for_stack(
+                "shop".to_string(),
+                K8sFormat::Manifests,
+                Framework::React,
+                "0.0.0.0",
+                5173,
+            )
+            .is_none()
+        );
+        assert!(
+            K8sParams::for_stack(
+                "shop".to_string(),
+                K8sFormat::Manifests,
+                Framework::Cli,
+                "0.0.0.0",
+                8080,
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_go_zero_services() {
+        let params = K8sParams::for_stack(
+            "mall".to_string(),
+            K8sFormat::Manifests,
+            Framework::GoZero,
+            "127.0.0.1",
+            8888,
+        )
+        .unwrap()
+        .with_go_zero_services(&["rpc".to_string(), "admin".to_string()], 9090);
+        assert!(!params.listens_on_all_interfaces());
+
+        let [rpc, admin] = params.workloads.as_slice() else {
+            panic!("expected two workloads");
+        };
+        assert_eq!((rpc.name.as_str(), rpc.port), ("mall-rpc", 9090));
+        assert_eq!(rpc.health_path, None);
+        assert!(!rpc.ingress);
+        assert_eq!((admin.name.as_str(), admin.port), ("mall-admin", 8889));
+        assert_eq!(admin.health_path.as_deref(), Some("/admin/health"));
+        assert!(admin.ingress);
+        assert_eq!(
+            admin.build_command(),
+            "docker build -f deploy/docker/Dockerfile.admin -t mall-admin:latest ."
+        );
+    }
+}
@@ -3,16 +3,17 @@ use std::path::Path;
 use std::process::Command;
 
 use super::parameters::GoParams;
-use crate::constants::Language;
+use crate::constants::{Language, defaults};
 use crate::generators::core::{
     Generator, InheritableParams, LanguageGenerator as LanguageGeneratorTrait, Parameters,
     TemplateProcessor,
 };
+use crate::status;
 use crate::utils::go_tools::GoTools;
+use crate::utils::{render_only, tool_failures};
 
 /// Go语言级别生成器实现
 pub struct GoGenerator {
-    #[allow(dead_code)]
     template_processor: TemplateProcessor,
 }
 
@@ -40,57 +41,82 @@ impl GoGenerator {
     }
 
     /// 初始化Go模块
-    fn init_go_module(&self, params: &GoParams, output_path: &Path) -> Result<()> {
-        // 使用项目名而不是完整的模块名
-        let project_name = &params.base_params().project_name;
+    ///
+    /// go.mod 已存在时跳过，保证多次调用结果一致；需要在 go mod tidy 之前生成代码的框架可以提前调用
+    pub fn init_go_module(&self, params: &GoParams, output_path: &Path) -> Result<()> {
+        if output_path.join("go.mod").exists() {
+            status!("go.mod already exists, skipping go mod init");
+            return Ok(());
+        }
+
+        let module_name = Self::module_path(params);
+
+        // 渲染模式下不执行 go 命令，直接写入 go.mod
+        if render_only::skip_tool("go mod init") {
+            return self.write_go_mod(params, output_path);
+        }
 
         // 尝试运行 go mod init
         let output = Command::new("go")
-            .args(["mod", "init", project_name])
+            .args(["mod", "init", module_name])
             .current_dir(output_path)
             .output();
 
         match output {
             Ok(result) if result.status.success() => {
-                println!("Go module initialized: {project_name}");
+                status!("Go module initialized: {module_name}");
                 Ok(())
             }
             Ok(result) => {
                 let stderr = String::from_utf8_lossy(&result.stderr);
-                eprintln!("go mod init failed: {stderr}");
-
-                // 手动创建 go.mod 文件
-                let go_mod_content = format!("module {project_name}\n\ngo 1.21\n");
-                let go_mod_path = output_path.join("go.mod");
-                std::fs::write(&go_mod_path, go_mod_content)?;
-                println!("Manually created go.mod file");
-                Ok(())
+                tool_failures::report(format!("go mod init failed: {}", stderr.trim()))?;
+                self.write_go_mod(params, output_path)
             }
             Err(e) => {
-                eprintln!("Failed to execute go mod init: {e}");
-
-                // 手动创建 go.mod 文件
-                let go_mod_content = format!("module {project_name}\n\ngo 1.21\n");
-                let go_mod_path = output_path.join("go.mod");
-                std::fs::write(&go_mod_path, go_mod_content)?;
-                println!("Manually created go.mod file");
-                Ok(())
+                tool_failures::report(format!("Failed to execute go mod init: {e}"))?;
+                self.write_go_mod(params, output_path)
             }
         }
     }
 
+    /// 模块路径：优先使用 module_name，未设置时回退到项目名
+    fn module_path(params: &GoParams) -> &str {
+        params
+            .module_name()
+            .map(String::as_str)
+            .unwrap_or(&params.base_params().project_name)
+    }
+
+    /// 生成 go.mod 文件内容
+    fn go_mod_content(params: &GoParams) -> String {
+        let version = params
+            .version()
+            .map(String::as_str)
+            .unwrap_or(defaults::GO_VERSION);
+        format!("module {}\n\ngo {version}\n", Self::module_path(params))
+    }
+
+    /// 手动创建 go.mod 文件
+    fn write_go_mod(&self, params: &GoParams, output_path: &Path) -> Result<()> {
+        let go_mod_path = output_path.join("go.mod");
+        std::fs::write(&go_mod_path, Self::go_mod_content(params))?;
+        status!("Manually created go.mod file");
+        Ok(())
+    }
+
     /// 设置依赖
     fn setup_dependencies(&self, output_path: &Path) -> Result<()> {
+        if render_only::skip_tool("go mod tidy") {
+            return Ok(());
+        }
+
         match GoTools::mod_tidy(output_path) {
             Ok(_) => {
-                println!("Dependencies organized with go mod tidy");
-                Ok(())
-            }
-            Err(e) => {
-                eprintln!("Warning: go mod tidy failed: {e}");
-                // 不返回错误，因为这不是致命的
+                status!("Dependencies organized with go mod tidy");
                 Ok(())
             }
+            // lenient 策略下不返回错误，因为这不是致命的
+            Err(e) => tool_failures::report(format!("go mod tidy failed: {e}")),
         }
     }
 }
@@ -116,27 +142,25 @@ impl Generator for GoGenerator {
         "languages/go"
     }
 
-    fn generate(&mut self, params: Self::Params, output_path: &Path) -> Result<()> {
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
         // 验证参数
         params.validate()?;
 
         // 检查Go安装
-        self.check_go_installation()?;
+        if !render_only::skip_tool("Go installation check") {
+            self.check_go_installation()?;
+        }
 
         // 处理嵌入式模板
-        let mut template_processor = TemplateProcessor::new()?;
         let template_path = self.get_template_path();
         let context = params.to_template_context();
 
-        println!("Generating {} structure", self.name());
+        status!("Generating {} structure", self.name());
 
         // 检查嵌入式模板目录是否存在
         if crate::template_engine::embedded_template_dir_exists(template_path) {
-            template_processor.process_embedded_template_directory(
-                template_path,
-                output_path,
-                context,
-            )?;
+            self.template_processor
+                .process_embedded_template_directory(template_path, output_path, context)?;
         } else {
             return Err(anyhow::anyhow!(
                 "{} embedded templates not found at: {}",
@@ -151,7 +175,7 @@ impl Generator for GoGenerator {
         // 设置依赖
         self.setup_dependencies(output_path)?;
 
-        println!("Go language generation completed successfully");
+        status!("Go language generation completed successfully");
         Ok(())
     }
 }
@@ -161,7 +185,7 @@ impl LanguageGeneratorTrait for GoGenerator {
         Language::Go.as_str()
     }
 
-    fn setup_environment(&mut self, params: &Self::Params, output_path: &Path) -> Result<()> {
+    fn setup_environment(&self, params: &Self::Params, output_path: &Path) -> Result<()> {
         // 初始化Go模块
         if params.enable_modules() {
             self.init_go_module(params, output_path)?;
@@ -173,19 +197,56 @@ impl LanguageGeneratorTrait for GoGenerator {
         Ok(())
     }
 
-    fn generate_language_config(
-        &mut self,
-        params: &Self::Params,
-        output_path: &Path,
-    ) -> Result<()> {
+    fn generate_language_config(&self, params: &Self::Params, output_path: &Path) -> Result<()> {
         // 如果启用了Go modules，确保go.mod文件存在
         if params.enable_modules() {
-            let go_mod_path = output_path.join("go.mod");
-            if !go_mod_path.exists() {
-                self.init_go_module(params, output_path)?;
-            }
+            self.init_go_module(params, output_path)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_go_mod_content_honors_params() {
+        let params =
+            GoParams::new("github.com/acme/demo".to_string()).with_version("1.22".to_string());
+        assert_eq!(
+            GoGenerator::go_mod_content(&params),
+            "module github.com/acme/demo\n\ngo 1.22\n"
+        );
+        // 未指定版本时使用环境检查要求的默认版本
+        assert_eq!(
+            GoGenerator::go_mod_content(&GoParams::new("demo".to_string())),
+            format!("module demo\n\ngo {}\n", defaults::GO_VERSION)
+        );
+        // 所有 Go 框架默认以项目名作为模块路径
+        assert_eq!(
+            GoParams::from_project_name("demo".to_string())
+                .module_name()
+                .map(String::as_str),
+            Some("demo")
+        );
+    }
+
+    #[test]
+    fn test_init_go_module_keeps_existing_go_mod() {
+        let dir = TempDir::new().unwrap();
+        let go_mod = dir.path().join("go.mod");
+        std::fs::write(&go_mod, "module existing\n\ngo 1.20\n").unwrap();
+
+        let generator = GoGenerator::new().unwrap();
+        let params = GoParams::new("github.com/acme/demo".to_string());
+        generator.init_go_module(&params, dir.path()).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&go_mod).unwrap(),
+            "module existing\n\ngo 1.20\n"
+        );
+    }
+}
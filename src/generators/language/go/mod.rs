@@ -1,6 +1,8 @@
+pub mod database;
 pub mod generator;
 pub mod parameters;
 
 // 明确导出具体类型，避免通配符导入
+pub use database::database_template_context;
 pub use generator::GoGenerator;
 pub use parameters::GoParams;
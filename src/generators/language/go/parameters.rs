@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::constants::defaults;
 use crate::generators::core::{BaseParams, InheritableParams};
 
 /// Go语言级别参数 - 现在继承自BaseParams
@@ -12,7 +13,7 @@ pub struct GoParams {
 impl Default for GoParams {
     fn default() -> Self {
         let base = BaseParams {
-            language_version: Some("1.21".to_string()),
+            language_version: Some(defaults::GO_VERSION.to_string()),
             enable_modules: true,
             enable_cgo: false,
             enable_vendor: false,
@@ -52,7 +53,7 @@ impl GoParams {
         let mut base = BaseParams::new(project_name);
 
         // 设置Go特定的默认值
-        base.language_version = Some("1.21".to_string());
+        base.language_version = Some(defaults::GO_VERSION.to_string());
         base.enable_modules = true;
         base.enable_cgo = false;
         base.enable_vendor = false;
@@ -93,22 +94,16 @@ impl GoParams {
         self
     }
 
-    /// 从项目名称推断模块名称
+    /// 从项目名称推断模块名称：与各 Go 框架的默认值一致，直接使用项目名
     pub fn infer_module_name(project_name: &str) -> String {
-        // 简单的模块名称推断逻辑
-        format!(
-            "github.com/example/{}",
-            project_name.to_lowercase().replace(' ', "-")
-        )
+        project_name.to_string()
     }
 
     // 为了向后兼容，提供访问器方法
-    #[allow(dead_code)]
     pub fn version(&self) -> Option<&String> {
         self.base.language_version.as_ref()
     }
 
-    #[allow(dead_code)]
     pub fn module_name(&self) -> Option<&String> {
         self.base.module_name.as_ref()
     }
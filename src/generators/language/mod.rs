@@ -1,6 +1,7 @@
 pub mod go;
 pub mod python;
 pub mod rust;
+pub mod zig;
 
 // 明确导出各语言生成器和参数类型
 // Python模块暂时没有完整实现，先不导出
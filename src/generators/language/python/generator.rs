@@ -4,10 +4,13 @@ use std::process::Command;
 
 use crate::constants::Language;
 use crate::generators::core::{
-    Generator, InheritableParams, LanguageGenerator as LanguageGeneratorTrait, Parameters,
-    TemplateProcessor,
+    Generator, InheritableParams, LanguageGenerator as LanguageGeneratorTrait, NextSteps,
+    Parameters, TemplateProcessor,
 };
 use crate::generators::language::python::parameters::PythonParams;
+use crate::status;
+use crate::utils::manifest::{self, ManifestMetadata};
+use crate::utils::{render_only, tool_failures};
 
 /// Python 语言生成器
 pub struct PythonGenerator {}
@@ -20,67 +23,88 @@ impl PythonGenerator {
 
     /// 使用 uv init 初始化项目
     fn init_uv_project(&self, params: &PythonParams, output_path: &Path) -> Result<()> {
-        println!("Initializing Python project with uv...");
+        if render_only::skip_tool("uv init") {
+            return Ok(());
+        }
+
+        status!("Initializing Python project with uv...");
 
         let project_name = &params.base_params().project_name;
 
         // 使用 uv init 创建项目
-        let status = Command::new("uv")
+        let output = Command::new("uv")
             .arg("init")
             .arg("--name")
             .arg(project_name)
             .arg(output_path)
             .env_remove("VIRTUAL_ENV")
-            .status()
+            .output()
             .context("Failed to execute uv init")?;
 
-        if !status.success() {
-            return Err(anyhow::anyhow!("uv init failed"));
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("uv init failed: {}", stderr.trim()));
         }
 
-        println!("Python project initialized with uv");
+        status!("Python project initialized with uv");
         Ok(())
     }
 
     /// 添加必要的依赖
     fn add_dependencies(&self, output_path: &Path) -> Result<()> {
-        println!("Adding Python dependencies...");
+        if render_only::skip_tool("uv add") {
+            return Ok(());
+        }
+
+        status!("Adding Python dependencies...");
 
         let dependencies = vec!["pydantic", "python-dotenv", "rich"];
 
         for dep in dependencies {
-            let status = Command::new("uv")
+            let output = Command::new("uv")
                 .arg("add")
                 .arg(dep)
                 .env_remove("VIRTUAL_ENV")
                 .current_dir(output_path)
-                .status()
+                .output()
                 .context(format!("Failed to add dependency: {}", dep))?;
 
-            if !status.success() {
-                println!("Warning: Failed to add dependency {}", dep);
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tool_failures::report(format!(
+                    "Failed to add dependency {dep}: {}",
+                    stderr.trim()
+                ))?;
             }
         }
 
-        println!("Dependencies added successfully");
+        status!("Dependencies added successfully");
         Ok(())
     }
 
     /// 安装依赖
     fn install_dependencies(&self, output_path: &Path) -> Result<()> {
-        println!("Installing Python dependencies...");
+        if render_only::skip_tool("uv sync") {
+            return Ok(());
+        }
 
-        let status = Command::new("uv")
+        status!("Installing Python dependencies...");
+
+        let output = Command::new("uv")
             .arg("sync")
             .env_remove("VIRTUAL_ENV")
             .current_dir(output_path)
-            .status()
+            .output()
             .context("Failed to execute uv sync")?;
 
-        if !status.success() {
-            println!("Warning: uv sync failed, you may need to run it manually");
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!(
+                "uv sync failed: {}\n   You may need to run it manually",
+                stderr.trim()
+            ))?;
         } else {
-            println!("Python dependencies installed successfully");
+            status!("Python dependencies installed successfully");
         }
 
         Ok(())
@@ -108,17 +132,22 @@ impl Generator for PythonGenerator {
         "languages/python"
     }
 
-    fn generate(&mut self, params: Self::Params, output_path: &Path) -> Result<()> {
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
         // 验证参数
         params.validate()?;
 
-        println!("Generating {} structure", self.name());
+        status!("Generating {} structure", self.name());
 
         // 1. 使用 uv init 创建基础项目结构
         self.init_uv_project(&params, output_path)?;
+        manifest::update_pyproject_metadata(
+            output_path,
+            &ManifestMetadata::from_base(&params.base),
+        )
+        .context("Failed to update pyproject.toml metadata")?;
 
         // 2. 处理嵌入式模板
-        let mut template_processor = TemplateProcessor::new()?;
+        let template_processor = TemplateProcessor::new()?;
         let template_path = self.get_template_path();
         let context = params.to_template_context();
 
@@ -143,9 +172,22 @@ impl Generator for PythonGenerator {
         // 4. 安装依赖
         self.install_dependencies(output_path)?;
 
-        println!("Python language generation completed successfully");
+        status!("Python language generation completed successfully");
         Ok(())
     }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        steps.command(
+            format!("uv run python -m {}", params.package_name()),
+            "Run the application",
+        );
+        if params.base.enable_tests {
+            steps.command(
+                "uv run python -m unittest discover -s tests -t .",
+                "Run the tests",
+            );
+        }
+    }
 }
 
 impl LanguageGeneratorTrait for PythonGenerator {
@@ -153,7 +195,7 @@ impl LanguageGeneratorTrait for PythonGenerator {
         Language::Python.as_str()
     }
 
-    fn setup_environment(&mut self, params: &Self::Params, output_path: &Path) -> Result<()> {
+    fn setup_environment(&self, params: &Self::Params, output_path: &Path) -> Result<()> {
         // 初始化 Python 项目
         self.init_uv_project(params, output_path)?;
 
@@ -163,11 +205,7 @@ impl LanguageGeneratorTrait for PythonGenerator {
         Ok(())
     }
 
-    fn generate_language_config(
-        &mut self,
-        params: &Self::Params,
-        output_path: &Path,
-    ) -> Result<()> {
+    fn generate_language_config(&self, params: &Self::Params, output_path: &Path) -> Result<()> {
         // 确保 pyproject.toml 文件存在
         let pyproject_path = output_path.join("pyproject.toml");
         if !pyproject_path.exists() {
@@ -1,5 +1,6 @@
 pub mod generator;
 pub mod parameters;
+pub mod uv;
 
 pub use generator::PythonGenerator;
 pub use parameters::PythonParams;
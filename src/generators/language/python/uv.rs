@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+use crate::status;
+use crate::utils::{render_only, tool_failures};
+
+/// 使用 `uv init --bare` 创建只包含 pyproject.toml 的项目
+///
+/// 供 Django、Flask 等框架项目使用，入口文件由模板提供，不需要 uv 生成的 main.py
+pub fn init_bare_project(project_name: &str, output_path: &Path) -> Result<()> {
+    if render_only::skip_tool("uv init") {
+        return Ok(());
+    }
+
+    status!("Initializing Python project with uv...");
+
+    let output = Command::new("uv")
+        .arg("init")
+        .arg("--bare")
+        .arg("--name")
+        .arg(project_name)
+        .arg(output_path)
+        .env_remove("VIRTUAL_ENV")
+        .output()
+        .context("Failed to execute uv init")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!("uv init failed: {}", stderr.trim()));
+    }
+
+    status!("Python project initialized with uv");
+    Ok(())
+}
+
+/// 使用一次 `uv add` 添加全部依赖并同步虚拟环境
+pub fn add_dependencies(output_path: &Path, dependencies: &[&str]) -> Result<()> {
+    if render_only::skip_tool("uv add") {
+        return Ok(());
+    }
+
+    status!("Adding Python dependencies...");
+
+    let output = Command::new("uv")
+        .arg("add")
+        .args(dependencies)
+        .env_remove("VIRTUAL_ENV")
+        .current_dir(output_path)
+        .output()
+        .context("Failed to execute uv add")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        tool_failures::report(format!(
+            "Failed to add dependencies {}: {}",
+            dependencies.join(", "),
+            stderr.trim()
+        ))?;
+    } else {
+        status!("Dependencies added successfully");
+    }
+
+    Ok(())
+}
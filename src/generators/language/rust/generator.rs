@@ -4,9 +4,12 @@ use std::process::Command;
 
 use crate::constants::Language;
 use crate::generators::core::{
-    Generator, LanguageGenerator as LanguageGeneratorTrait, Parameters, TemplateProcessor,
+    Generator, LanguageGenerator as LanguageGeneratorTrait, NextSteps, Parameters,
+    TemplateProcessor,
 };
 use crate::generators::language::rust::parameters::RustParams;
+use crate::status;
+use crate::utils::{render_only, tool_failures};
 
 /// Rust 语言生成器
 pub struct RustGenerator {}
@@ -17,20 +20,30 @@ impl RustGenerator {
         Ok(Self {})
     }
 
-    /// 构建项目以验证依赖
-    fn build_project(&self, output_path: &Path) -> Result<()> {
-        println!("Building Rust workspace project...");
+    /// 构建项目以拉取并验证 Cargo.toml 中声明的依赖
+    ///
+    /// 框架生成器（如 Axum、Actix-web）渲染完 Cargo.toml 后也通过此方法完成依赖安装
+    pub fn build_project(&self, output_path: &Path) -> Result<()> {
+        if render_only::skip_tool("cargo build") {
+            return Ok(());
+        }
+
+        status!("Building Rust workspace project...");
 
-        let status = Command::new("cargo")
+        let output = Command::new("cargo")
             .arg("build")
             .current_dir(output_path)
-            .status()
+            .output()
             .context("Failed to execute cargo build")?;
 
-        if !status.success() {
-            println!("Warning: cargo build failed, you may need to run it manually");
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!(
+                "cargo build failed: {}\n   You may need to run it manually",
+                stderr.trim()
+            ))?;
         } else {
-            println!("Rust workspace project built successfully");
+            status!("Rust workspace project built successfully");
         }
 
         Ok(())
@@ -58,26 +71,26 @@ impl Generator for RustGenerator {
         "languages/rust"
     }
 
-    fn generate(&mut self, params: Self::Params, output_path: &Path) -> Result<()> {
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
         // 验证参数
         params.validate()?;
 
-        println!("Generating {} structure with workspace", self.name());
+        status!("Generating {} structure with workspace", self.name());
 
         // 1. 处理嵌入式模板 (模板处理器会自动创建目录)
-        let mut template_processor = TemplateProcessor::new()?;
+        let template_processor = TemplateProcessor::new()?;
         let template_path = self.get_template_path();
         let context = params.to_template_context();
 
         // 检查嵌入式模板目录是否存在
         if crate::template_engine::embedded_template_dir_exists(template_path) {
-            println!("Processing embedded templates from: {template_path}");
+            status!("Processing embedded templates from: {template_path}");
             match template_processor.process_embedded_template_directory(
                 template_path,
                 output_path,
                 context,
             ) {
-                Ok(_) => println!("Embedded templates processed successfully"),
+                Ok(_) => status!("Embedded templates processed successfully"),
                 Err(e) => {
                     eprintln!("Failed to process embedded templates: {e}");
                     eprintln!("Error chain:");
@@ -98,9 +111,16 @@ impl Generator for RustGenerator {
         // 2. 构建项目
         self.build_project(output_path)?;
 
-        println!("Rust language generation completed successfully");
+        status!("Rust language generation completed successfully");
         Ok(())
     }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        steps.command("cargo run", "Run the application");
+        if params.base.enable_tests {
+            steps.command("cargo test", "Run the tests");
+        }
+    }
 }
 
 impl LanguageGeneratorTrait for RustGenerator {
@@ -108,16 +128,12 @@ impl LanguageGeneratorTrait for RustGenerator {
         Language::Rust.as_str()
     }
 
-    fn setup_environment(&mut self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+    fn setup_environment(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 模板处理器会自动创建目录结构
         Ok(())
     }
 
-    fn generate_language_config(
-        &mut self,
-        _params: &Self::Params,
-        _output_path: &Path,
-    ) -> Result<()> {
+    fn generate_language_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
         // 配置文件由模板生成
         Ok(())
     }
@@ -0,0 +1,122 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+use crate::constants::Language;
+use crate::generators::core::{
+    Generator, LanguageGenerator as LanguageGeneratorTrait, NextSteps, Parameters,
+    TemplateProcessor,
+};
+use crate::generators::language::zig::parameters::ZigParams;
+use crate::status;
+use crate::utils::{render_only, tool_failures};
+
+/// Zig 语言生成器
+pub struct ZigGenerator {
+    template_processor: TemplateProcessor,
+}
+
+impl ZigGenerator {
+    /// 创建新的 Zig 生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            template_processor: TemplateProcessor::new()?,
+        })
+    }
+
+    /// 执行 zig build，验证生成的 build.zig 可用
+    fn build_project(&self, output_path: &Path) -> Result<()> {
+        if render_only::skip_tool("zig build") {
+            return Ok(());
+        }
+
+        status!("Building Zig project...");
+
+        let output = Command::new("zig")
+            .arg("build")
+            .current_dir(output_path)
+            .output()
+            .context("Failed to execute zig build")?;
+
+        if output.status.success() {
+            status!("Zig project built successfully");
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!("zig build failed: {}", stderr.trim()))
+        }
+    }
+}
+
+impl Default for ZigGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create ZigGenerator")
+    }
+}
+
+impl Generator for ZigGenerator {
+    type Params = ZigParams;
+
+    fn name(&self) -> &'static str {
+        "Zig Language"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Zig language project generator with build.zig")
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "languages/zig"
+    }
+
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
+        // 验证参数
+        params.validate()?;
+
+        status!("Generating {} structure", self.name());
+
+        let template_path = self.get_template_path();
+        if !crate::template_engine::embedded_template_dir_exists(template_path) {
+            return Err(anyhow::anyhow!(
+                "{} embedded templates not found at: {}",
+                self.name(),
+                template_path
+            ));
+        }
+
+        self.template_processor
+            .process_embedded_template_directory(
+                template_path,
+                output_path,
+                params.to_template_context(),
+            )?;
+
+        self.build_project(output_path)?;
+
+        status!("Zig language generation completed successfully");
+        Ok(())
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        steps.command("zig build run", "Run the application");
+        if params.base.enable_tests {
+            steps.command("zig build test", "Run the tests");
+        }
+    }
+}
+
+impl LanguageGeneratorTrait for ZigGenerator {
+    fn language(&self) -> &'static str {
+        Language::Zig.as_str()
+    }
+
+    fn setup_environment(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // 模板处理器会自动创建目录结构
+        Ok(())
+    }
+
+    fn generate_language_config(&self, _params: &Self::Params, _output_path: &Path) -> Result<()> {
+        // build.zig 由模板生成
+        Ok(())
+    }
+}
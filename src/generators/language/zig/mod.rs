@@ -0,0 +1,5 @@
+pub mod generator;
+pub mod parameters;
+
+pub use generator::ZigGenerator;
+pub use parameters::ZigParams;
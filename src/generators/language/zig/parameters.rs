@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::defaults::ZIG_VERSION;
+use crate::generators::core::{BaseParams, InheritableParams};
+
+/// Zig语言级别参数 - 继承自BaseParams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZigParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// Zig版本 (如 "0.14.0")
+    pub zig_version: String,
+}
+
+impl Default for ZigParams {
+    fn default() -> Self {
+        let base = BaseParams {
+            language_version: Some(ZIG_VERSION.to_string()),
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            zig_version: ZIG_VERSION.to_string(),
+        }
+    }
+}
+
+impl InheritableParams for ZigParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            zig_version: ZIG_VERSION.to_string(),
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        HashMap::from([
+            ("zig_version".to_string(), json!(self.zig_version)),
+            (
+                "zig_archive".to_string(),
+                json!(release_archive(&self.zig_version)),
+            ),
+        ])
+    }
+}
+
+/// 官方 Linux x86_64 发布包的文件名（不含 `.tar.xz`），供非 GitHub 的 CI 下载 Zig
+///
+/// 0.14.1 起文件名中的平台由 `linux-x86_64` 改为 `x86_64-linux`
+fn release_archive(version: &str) -> String {
+    let parts: Vec<u32> = version
+        .split(['.', '-', '+'])
+        .take(3)
+        .map_while(|part| part.parse().ok())
+        .collect();
+    if parts.len() == 3 && (parts[0], parts[1], parts[2]) <= (0, 14, 0) {
+        format!("zig-linux-x86_64-{version}")
+    } else {
+        format!("zig-x86_64-linux-{version}")
+    }
+}
+
+impl ZigParams {
+    /// 创建新的Zig参数
+    pub fn new(project_name: String) -> Self {
+        let mut base = BaseParams::new(project_name);
+        base.language_version = Some(ZIG_VERSION.to_string());
+
+        Self {
+            base,
+            zig_version: ZIG_VERSION.to_string(),
+        }
+    }
+
+    /// 设置Zig版本
+    pub fn with_zig_version(mut self, version: String) -> Self {
+        self.zig_version = version.clone();
+        self.base.language_version = Some(version);
+        self
+    }
+    /// 设置是否启用pre-commit
+    pub fn with_precommit(mut self, enable_precommit: bool) -> Self {
+        self.base.enable_precommit = enable_precommit;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_archive() {
+        assert_eq!(release_archive("0.13.0"), "zig-linux-x86_64-0.13.0");
+        assert_eq!(release_archive("0.14.0"), "zig-linux-x86_64-0.14.0");
+        assert_eq!(release_archive("0.14.1"), "zig-x86_64-linux-0.14.1");
+        assert_eq!(release_archive("0.15.2"), "zig-x86_64-linux-0.15.2");
+    }
+}
@@ -1,9 +1,13 @@
 // 生成器模块
+pub mod component;
 pub mod core;
+pub mod docker;
 pub mod framework;
+pub mod k8s;
 pub mod language;
 pub mod orchestrator;
 pub mod project;
+pub mod workspace;
 
 // 重新导出核心类型
 
@@ -12,4 +16,9 @@ pub mod project;
 // 框架生成器
 
 // 编排器
-pub use orchestrator::{GeneratorOrchestrator, GinProjectOptions};
+pub use orchestrator::{
+    ActixProjectOptions, AstroProjectOptions, AxumProjectOptions, ChiProjectOptions,
+    CliProjectOptions, DjangoProjectOptions, FiberProjectOptions, FlaskProjectOptions,
+    GeneratorOrchestrator, GinProjectOptions, GoZeroProjectOptions, NestJsProjectOptions,
+    NuxtProjectOptions, ProjectMetadata, SvelteKitProjectOptions,
+};
@@ -1,36 +1,96 @@
 use anyhow::{Context, Result};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::constants::{
+    ApiClient, CiProvider, Database, Framework, Language, Logger, Orm, ProfileFeatures, Runtime,
+    TailwindVersion, TauriVersion,
+};
 use crate::generators::{
-    core::Generator,
-    framework::gin::{GinGenerator, GinParams},
-    framework::go_zero::GoZeroGenerator,
+    core::{BaseParams, Generator, InheritableParams, NextSteps},
+    docker::{DockerGenerator, DockerParams, DockerTarget},
+    framework::actix::{ActixGenerator, ActixParams, parameters::DEFAULT_ACTIX_PORT},
+    framework::astro::{AstroGenerator, AstroParams},
+    framework::axum::{AxumGenerator, AxumParams, parameters::DEFAULT_AXUM_PORT},
+    framework::chi::{ChiGenerator, ChiParams, parameters::DEFAULT_CHI_PORT},
+    framework::cli::{CliGenerator, CliParams},
+    framework::django::{DjangoGenerator, DjangoParams},
+    framework::fiber::{FiberGenerator, FiberParams, parameters::DEFAULT_FIBER_PORT},
+    framework::flask::{FlaskGenerator, FlaskParams},
+    framework::gin::{GinGenerator, GinParams, openapi::OpenApiSpec},
+    framework::go_zero::{
+        GoZeroGenerator,
+        parameters::{DEFAULT_GRPC_PORT, GoZeroParams},
+    },
+    framework::nestjs::{NestJsGenerator, NestJsParams, parameters::DEFAULT_NESTJS_PORT},
+    framework::nextjs::{NextJsGenerator, NextJsParams},
+    framework::nuxt::{NuxtGenerator, NuxtParams},
     framework::react::{ReactGenerator, ReactParams},
-    framework::tauri::{TauriGenerator, TauriParams},
+    framework::sveltekit::{SvelteKitGenerator, SvelteKitParams},
+    framework::tauri::{
+        CREATE_TAURI_APP_VERSION, TauriGenerator, TauriParams, is_supported_create_tauri_app,
+    },
     framework::vue3::{Vue3Generator, Vue3Params},
+    k8s::{K8sGenerator, K8sParams},
     language::go::{GoGenerator, GoParams},
     language::python::{PythonGenerator, PythonParams},
     language::rust::{RustGenerator, RustParams},
-    project::{ProjectGenerator, ProjectParams},
+    language::zig::{ZigGenerator, ZigParams},
+    project::{ProjectGenerator, ProjectParams, TaskParams},
+    workspace::{ApiProxy, WorkspaceGenerator, WorkspaceParams},
 };
+use crate::status;
+use crate::utils::api_client;
 use crate::utils::env_checker::EnvironmentChecker;
+use crate::utils::js_runtime;
+use crate::utils::manifest::{self, ManifestMetadata};
+use crate::utils::progress::StepProgress;
+use crate::utils::render_only;
 
 /// 生成器编排器，负责协调三层架构的生成器
+///
+/// 各生成器不保存状态，生成方法只需要 `&self` 并返回本次生成的后续步骤，
+/// 因此编排器是 `Send + Sync` 的，可以放进 `Arc` 在多个请求间复用。
+/// 渲染模式、失败策略等仍是进程级设置，嵌入服务时需在启动时统一设置
 pub struct GeneratorOrchestrator {
     project_generator: ProjectGenerator,
     go_generator: GoGenerator,
     python_generator: PythonGenerator,
-    #[allow(dead_code)]
     rust_generator: RustGenerator,
+    zig_generator: ZigGenerator,
     gin_generator: GinGenerator,
-    #[allow(dead_code)]
+    fiber_generator: FiberGenerator,
+    chi_generator: ChiGenerator,
     go_zero_generator: GoZeroGenerator,
+    django_generator: DjangoGenerator,
+    flask_generator: FlaskGenerator,
     #[allow(dead_code)]
     tauri_generator: TauriGenerator,
-    #[allow(dead_code)]
+    axum_generator: AxumGenerator,
+    actix_generator: ActixGenerator,
+    cli_generator: CliGenerator,
     vue3_generator: Vue3Generator,
-    #[allow(dead_code)]
     react_generator: ReactGenerator,
+    nextjs_generator: NextJsGenerator,
+    nuxt_generator: NuxtGenerator,
+    sveltekit_generator: SvelteKitGenerator,
+    nestjs_generator: NestJsGenerator,
+    astro_generator: AstroGenerator,
+    docker_generator: DockerGenerator,
+    k8s_generator: K8sGenerator,
+    workspace_generator: WorkspaceGenerator,
+    metadata: ProjectMetadata,
+    features: ProfileFeatures,
+    ci_provider: CiProvider,
+    tailwind_version: TailwindVersion,
+    tauri_version: TauriVersion,
+    runtime: Runtime,
+    api_client: Option<ApiClient>,
+    enable_tanstack_query: bool,
+    enable_auth_pages: bool,
+    enable_dark_mode: bool,
+    enable_compose_dev: bool,
+    enable_git: bool,
+    api_proxy: Option<ApiProxy>,
 }
 
 impl GeneratorOrchestrator {
@@ -41,31 +101,270 @@ impl GeneratorOrchestrator {
             go_generator: GoGenerator::new()?,
             python_generator: PythonGenerator::new()?,
             rust_generator: RustGenerator::new()?,
+            zig_generator: ZigGenerator::new()?,
             gin_generator: GinGenerator::new()?,
+            fiber_generator: FiberGenerator::new()?,
+            chi_generator: ChiGenerator::new()?,
             go_zero_generator: GoZeroGenerator::new()?,
+            django_generator: DjangoGenerator::new()?,
+            flask_generator: FlaskGenerator::new()?,
             tauri_generator: TauriGenerator::new()?,
+            axum_generator: AxumGenerator::new()?,
+            actix_generator: ActixGenerator::new()?,
+            cli_generator: CliGenerator::new()?,
             vue3_generator: Vue3Generator::new()?,
             react_generator: ReactGenerator::new()?,
+            nextjs_generator: NextJsGenerator::new()?,
+            nuxt_generator: NuxtGenerator::new()?,
+            sveltekit_generator: SvelteKitGenerator::new()?,
+            nestjs_generator: NestJsGenerator::new()?,
+            astro_generator: AstroGenerator::new()?,
+            docker_generator: DockerGenerator::new()?,
+            k8s_generator: K8sGenerator::new()?,
+            workspace_generator: WorkspaceGenerator::new()?,
+            metadata: ProjectMetadata::default(),
+            features: ProfileFeatures::default(),
+            ci_provider: CiProvider::default(),
+            tailwind_version: TailwindVersion::default(),
+            tauri_version: TauriVersion::default(),
+            runtime: Runtime::default(),
+            api_client: None,
+            enable_tanstack_query: false,
+            enable_auth_pages: false,
+            enable_dark_mode: false,
+            enable_compose_dev: false,
+            enable_git: true,
+            api_proxy: None,
         })
     }
 
+    /// 设置项目元数据（描述、关键字、主页）
+    pub fn with_metadata(mut self, metadata: ProjectMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// 设置可选功能开关（Docker、CI、示例测试），通常来自 --profile
+    pub fn with_features(mut self, features: ProfileFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// 设置 CI 配置针对的平台，仅在功能开关启用 CI 时生效
+    pub fn with_ci_provider(mut self, ci_provider: CiProvider) -> Self {
+        self.ci_provider = ci_provider;
+        self
+    }
+
+    /// 设置 Vue3、React 和 Tauri 项目使用的 Tailwind CSS 主版本
+    pub fn with_tailwind_version(mut self, tailwind_version: TailwindVersion) -> Self {
+        self.tailwind_version = tailwind_version;
+        self
+    }
+
+    /// 设置 Tauri 项目的主版本
+    pub fn with_tauri_version(mut self, tauri_version: TauriVersion) -> Self {
+        self.tauri_version = tauri_version;
+        self
+    }
+
+    /// 设置 TypeScript 项目使用的 JavaScript 运行时（Tauri 前端仍使用 pnpm）
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// 设置 Vue3 和 React 项目的 API 客户端层及是否加入 TanStack Query 示例
+    pub fn with_api_client(
+        mut self,
+        api_client: Option<ApiClient>,
+        enable_tanstack_query: bool,
+    ) -> Self {
+        self.api_client = api_client;
+        self.enable_tanstack_query = enable_tanstack_query;
+        self
+    }
+
+    /// 设置 Vue3 和 React 项目是否生成登录注册页面
+    pub fn with_auth_pages(mut self, enable_auth_pages: bool) -> Self {
+        self.enable_auth_pages = enable_auth_pages;
+        self
+    }
+
+    /// 设置 Vue3 和 React 项目是否生成主题切换
+    pub fn with_dark_mode(mut self, enable_dark_mode: bool) -> Self {
+        self.enable_dark_mode = enable_dark_mode;
+        self
+    }
+
+    /// 设置是否生成热重载的 docker-compose.dev.yaml
+    pub fn with_compose_dev(mut self, enable_compose_dev: bool) -> Self {
+        self.enable_compose_dev = enable_compose_dev;
+        self
+    }
+
+    /// 设置是否初始化 git 仓库，workspace 中的应用由根目录统一初始化
+    pub fn with_git(mut self, enable_git: bool) -> Self {
+        self.enable_git = enable_git;
+        self
+    }
+
+    /// 设置全栈项目中前端转发 API 请求的后端，Vue3 和 React 的开发服务器和镜像中的 nginx 使用
+    pub fn with_api_proxy(mut self, api_proxy: Option<ApiProxy>) -> Self {
+        self.api_proxy = api_proxy;
+        self
+    }
+
+    /// 按语言和框架生成 Docker 文件，框架模板已提供的文件保持不变
+    ///
+    /// 生产镜像文件由功能开关中的 Docker 决定，开发环境的 compose 文件由 `with_compose_dev` 决定
+    pub fn generate_docker(
+        &self,
+        project_name: String,
+        language: &Language,
+        framework: &Framework,
+        port: u16,
+        output_path: &Path,
+        next_steps: &mut NextSteps,
+    ) -> Result<()> {
+        let stack = match framework {
+            Framework::None => language.as_str(),
+            _ => framework.as_str(),
+        };
+        let Some(target) = DockerTarget::for_stack(language, framework) else {
+            status!("Skipping Docker files: {stack} projects have no container target");
+            return Ok(());
+        };
+
+        let docker_params = DockerParams::new(project_name, target, *framework, port)
+            .with_runtime(self.runtime)
+            .with_outputs(self.features.docker, self.enable_compose_dev)
+            .with_api_proxy(self.api_proxy.map(|proxy| proxy.container_target()))
+            .detect_from(output_path);
+        if self.enable_compose_dev && !docker_params.generates_compose_dev() {
+            status!("Skipping docker-compose.dev.yaml: {stack} projects have no single dev server");
+            if !self.features.docker {
+                return Ok(());
+            }
+        }
+        self.docker_generator
+            .generate(docker_params.clone(), output_path)
+            .context("Failed to generate Docker files")?;
+        self.docker_generator.next_steps(&docker_params, next_steps);
+        Ok(())
+    }
+
+    /// 生成统一任务入口（Makefile 或 justfile），命令由调用方按语言和框架确定
+    pub fn generate_task_runner(
+        &self,
+        params: TaskParams,
+        output_path: &Path,
+        next_steps: &mut NextSteps,
+    ) -> Result<()> {
+        let params = params.with_runtime(self.runtime).detect_from(output_path);
+        self.project_generator
+            .generate_task_runner(&params, output_path)?;
+        self.project_generator
+            .task_runner_next_steps(&params, next_steps);
+        Ok(())
+    }
+
+    /// 生成 Kubernetes 清单或 Helm chart，工作负载由调用方按框架和服务确定
+    pub fn generate_k8s(
+        &self,
+        params: K8sParams,
+        output_path: &Path,
+        next_steps: &mut NextSteps,
+    ) -> Result<()> {
+        self.k8s_generator
+            .generate(params.clone(), output_path)
+            .context("Failed to generate Kubernetes files")?;
+        self.k8s_generator.next_steps(&params, next_steps);
+        Ok(())
+    }
+
+    /// 在 `apps/` 下的应用生成完成后生成 workspace 根目录：workspace 文件、Makefile、CI 配置，
+    /// 以及 LICENSE、`.editorconfig` 等项目级文件，git 仓库在根目录初始化
+    pub fn generate_workspace(
+        &self,
+        params: WorkspaceParams,
+        output_path: &Path,
+    ) -> Result<NextSteps> {
+        let project_name = params.base.project_name.clone();
+        status!("Starting workspace generation: {project_name}");
+
+        let default_description = match params.api_proxy {
+            Some(_) => format!(
+                "A full-stack app with a {} backend and a {} frontend: {project_name}",
+                params.apps[0].framework.as_str(),
+                params.apps[1].framework.as_str()
+            ),
+            None => format!(
+                "A workspace with {} apps: {project_name}",
+                params.apps.len()
+            ),
+        };
+        let description = self.resolve_description(default_description);
+        let mut params = params.with_runtime(self.runtime).detect_from(output_path);
+        self.apply_options(params.base_params_mut(), &description);
+        self.workspace_generator
+            .generate(params.clone(), output_path)
+            .context("Failed to generate workspace files")?;
+
+        let mut project_params = ProjectParams::new(project_name)
+            .with_git(true)
+            .with_description(description.clone());
+        self.apply_options(project_params.base_params_mut(), &description);
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+
+        let mut next_steps = NextSteps::new();
+        self.workspace_generator
+            .next_steps(&params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Workspace generation completed successfully!");
+        Ok(next_steps)
+    }
+
+    /// 将项目元数据和功能开关写入基础参数
+    fn apply_options(&self, base: &mut BaseParams, description: &str) {
+        self.metadata.apply_to(base, description);
+        base.enable_docker = self.features.docker;
+        base.enable_ci = self.features.ci;
+        base.ci_provider = self.ci_provider;
+        base.enable_tests = self.features.tests;
+        base.enable_git &= self.enable_git;
+    }
+
+    /// 解析项目描述，未指定时使用各项目类型的默认描述
+    fn resolve_description(&self, default_description: String) -> String {
+        self.metadata
+            .description
+            .clone()
+            .unwrap_or(default_description)
+    }
+
     /// 生成完整的Gin项目
-    pub fn generate_gin_project(
-        &mut self,
+    pub async fn generate_gin_project(
+        &self,
         project_name: String,
         output_path: &Path,
         options: GinProjectOptions,
-    ) -> Result<()> {
-        println!("Starting Gin project generation: {project_name}");
+    ) -> Result<NextSteps> {
+        status!("Starting Gin project generation: {project_name}");
+        let go_version = detect_go_version(options.go_version.clone()).await;
+
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A Gin web application: {project_name}"))
+        });
 
         // 1. 创建项目级别参数
-        let project_params = ProjectParams::new(project_name.clone())
-            .with_description(
-                options
-                    .description
-                    .clone()
-                    .unwrap_or_else(|| format!("A {project_name} project")),
-            )
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Go, Framework::Gin)
+            .with_description(description.clone())
             .with_author(
                 options
                     .author
@@ -73,20 +372,16 @@ impl GeneratorOrchestrator {
                     .unwrap_or_else(|| "Unknown".to_string()),
             )
             .with_license(options.license.clone().unwrap_or_else(|| "MIT".to_string()));
+        self.apply_options(project_params.base_params_mut(), &description);
 
-        // 2. 创建Go语言级别参数
+        // 2. 创建Go语言级别参数（模板以项目名作为导入路径前缀）
         let go_params = GoParams::new(
             options
                 .module_name
                 .clone()
-                .unwrap_or_else(|| GoParams::infer_module_name(&project_name)),
+                .unwrap_or_else(|| project_name.clone()),
         )
-        .with_version(
-            options
-                .go_version
-                .clone()
-                .unwrap_or_else(|| "1.21".to_string()),
-        );
+        .with_version(go_version.clone());
 
         // 3. 创建Gin框架级别参数
         let mut gin_params = GinParams::from_project_name(project_name.clone())
@@ -102,418 +397,2430 @@ impl GeneratorOrchestrator {
             .with_swagger(options.enable_swagger.unwrap_or(true))
             .with_cors(options.enable_cors.unwrap_or(true))
             .with_jwt(options.enable_jwt.unwrap_or(false))
-            .with_precommit(options.enable_precommit.unwrap_or(true));
+            .with_precommit(options.enable_precommit.unwrap_or(true))
+            .with_logger(options.logger);
+
+        if let Some(origins) = options.cors_origins {
+            gin_params = gin_params.with_cors_origins(origins);
+        }
+
+        if let Some(methods) = options.cors_methods {
+            gin_params = gin_params.with_cors_methods(methods);
+        }
 
-        if let Some(db_type) = options.database_type {
-            gin_params = gin_params.with_database(db_type);
+        if let Some(database) = options.database {
+            gin_params = gin_params
+                .with_database(database.as_str().to_string())
+                .with_orm(options.orm);
         }
 
         if options.enable_redis.unwrap_or(false) {
             gin_params = gin_params.with_redis(true);
         }
 
+        if let Some(grpc_port) = options.grpc_port {
+            gin_params = gin_params.with_grpc(true, grpc_port);
+        }
+
+        if let Some(openapi) = options.openapi {
+            gin_params = gin_params.with_openapi(openapi);
+        }
+
+        self.apply_options(gin_params.base_params_mut(), &description);
+
+        let mut progress = StepProgress::new("gin", 4);
+        progress.step("Generating Gin framework files");
         self.gin_generator
             .generate(gin_params.clone(), output_path)
             .context("Failed to generate Gin framework files")?;
+        self.gin_generator
+            .generate_grpc_code(&gin_params, output_path)
+            .context("Failed to generate gRPC code")?;
+        self.gin_generator
+            .generate_openapi_code(&gin_params, output_path)
+            .context("Failed to generate code from the OpenAPI spec")?;
 
         // 2. 语言级别生成 (Go) - 然后执行 go mod init 和 go mod tidy
-        let module_name = options
-            .module_name
-            .unwrap_or_else(|| GoParams::infer_module_name(&project_name));
-
-        let go_params = GoParams::new(module_name)
-            .with_version(options.go_version.unwrap_or_else(|| "1.21".to_string()));
+        let module_name = options.module_name.unwrap_or_else(|| project_name.clone());
+
+        let go_params = GoParams::new(module_name).with_version(go_version.clone());
+
+        // ent 代码生成需要 go.mod，且要在 go mod tidy 之前完成，否则引用的 ent 包不存在
+        if gin_params.orm() == Some(Orm::Ent) {
+            self.go_generator
+                .init_go_module(&go_params, output_path)
+                .context("Failed to initialize Go module")?;
+            self.gin_generator
+                .generate_ent_code(&gin_params, output_path)
+                .context("Failed to generate ent code")?;
+        }
 
+        progress.step("Initializing Go module");
         self.go_generator
             .generate(go_params, output_path)
             .context("Failed to generate Go files")?;
 
         // 3. 项目级别生成 - 最后执行 git init 等项目级操作
         let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Go, Framework::Gin)
             .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
             .with_git(options.enable_git.unwrap_or(true))
             .with_precommit(options.enable_precommit.unwrap_or(true))
-            .with_description(
-                options
-                    .description
-                    .unwrap_or_else(|| format!("A Gin web application: {project_name}")),
-            );
+            .with_description(description.clone());
 
         if let Some(author) = options.author {
             project_params = project_params.with_author(author);
         }
+        self.apply_options(project_params.base_params_mut(), &description);
 
+        progress.step("Generating project files");
         self.project_generator
-            .generate(project_params, output_path)
+            .generate(project_params.clone(), output_path)
             .context("Failed to generate project files")?;
 
         // 4. 执行后处理逻辑 - 在所有生成完成后执行 post_process
+        progress.step("Running post-processing");
         self.gin_generator
             .post_process(&gin_params, output_path)
             .context("Failed to execute Gin post-processing")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.gin_generator.next_steps(&gin_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
 
-        println!("Gin project generation completed successfully!");
-        println!("Project created at: {}", output_path.display());
+        status!("Gin project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
 
-        Ok(())
+        Ok(next_steps)
     }
 
-    /// 生成完整的Python项目
-    pub async fn generate_python_project(
-        &mut self,
+    /// 生成完整的Fiber项目
+    pub async fn generate_fiber_project(
+        &self,
         project_name: String,
         output_path: &Path,
-        license: String,
-        enable_precommit: bool,
-    ) -> Result<()> {
-        println!("Starting Python project generation: {project_name}");
+        options: FiberProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Fiber project generation: {project_name}");
+        let go_version = detect_go_version(options.go_version.clone()).await;
 
-        // 获取实际的 uv 版本和 Python 版本
-        let env_checker = EnvironmentChecker::new();
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A Fiber web application: {project_name}"))
+        });
 
-        let uv_version = env_checker
-            .get_uv_version()
-            .await
-            .unwrap_or_else(|_| "uv 0.9.5".to_string());
+        // 1. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Go, Framework::Fiber)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(options.enable_precommit.unwrap_or(true))
+            .with_description(description.clone());
 
-        // 从 "uv x.y.z" 格式中提取版本号
-        let uv_version = uv_version
-            .strip_prefix("uv ")
-            .unwrap_or(&uv_version)
-            .trim()
-            .to_string();
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
 
-        // 获取系统 Python 版本，如果获取失败则使用默认值
-        let python_version = env_checker
-            .get_python_version()
-            .await
-            .unwrap_or_else(|_| "3.12".to_string());
+        // 2. 创建Go语言级别参数（模板以项目名作为导入路径前缀）
+        let module_name = options.module_name.unwrap_or_else(|| project_name.clone());
+        let go_params = GoParams::new(module_name).with_version(go_version.clone());
 
-        // 1. 语言级别生成 (Python) - 使用 uv init 创建项目
-        let python_params = PythonParams::new(project_name.clone())
-            .with_version(python_version)
-            .with_uv_version(uv_version)
-            .with_precommit(enable_precommit);
+        // 3. 创建Fiber框架级别参数
+        let mut fiber_params = FiberParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_go(go_params.clone())
+            .with_server(
+                options.host.unwrap_or_else(|| "localhost".to_string()),
+                options.port.unwrap_or(DEFAULT_FIBER_PORT),
+            )
+            .with_swagger(options.enable_swagger.unwrap_or(true))
+            .with_precommit(options.enable_precommit.unwrap_or(true));
 
-        self.python_generator
-            .generate(python_params, output_path)
-            .context("Failed to generate Python files")?;
+        if let Some(database) = options.database {
+            fiber_params = fiber_params.with_database(database.as_str().to_string());
+        }
 
-        // 2. 项目级别生成 - 生成 LICENSE、README 等
-        let project_params = ProjectParams::new(project_name.clone())
-            .with_license(license)
-            .with_git(true)
-            .with_precommit(enable_precommit)
-            .with_description(format!("A Python project: {project_name}"));
+        self.apply_options(fiber_params.base_params_mut(), &description);
 
+        let mut progress = StepProgress::new("fiber", 4);
+        progress.step("Generating Fiber framework files");
+        self.fiber_generator
+            .generate(fiber_params.clone(), output_path)
+            .context("Failed to generate Fiber framework files")?;
+
+        // 语言级别生成 (Go) - 然后执行 go mod init 和 go mod tidy
+        progress.step("Initializing Go module");
+        self.go_generator
+            .generate(go_params, output_path)
+            .context("Failed to generate Go files")?;
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
         self.project_generator
-            .generate(project_params, output_path)
+            .generate(project_params.clone(), output_path)
             .context("Failed to generate project files")?;
 
-        println!("Python project generation completed successfully!");
-        println!("Project created at: {}", output_path.display());
+        // 所有文件生成后再运行 swag
+        progress.step("Running post-processing");
+        self.fiber_generator
+            .post_process(&fiber_params, output_path)
+            .context("Failed to execute Fiber post-processing")?;
+        progress.finish();
 
-        Ok(())
+        let mut next_steps = NextSteps::new();
+        self.fiber_generator
+            .next_steps(&fiber_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Fiber project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
     }
 
-    /// 生成完整的Rust项目
-    #[allow(dead_code)]
-    pub async fn generate_rust_project(
-        &mut self,
+    /// 生成完整的Chi项目
+    pub async fn generate_chi_project(
+        &self,
         project_name: String,
         output_path: &Path,
-        license: String,
-        enable_precommit: bool,
-    ) -> Result<()> {
-        println!("Starting Rust project generation: {project_name}");
+        options: ChiProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Chi project generation: {project_name}");
+        let go_version = detect_go_version(options.go_version.clone()).await;
 
-        // 获取实际的 Rust 版本
-        let env_checker = EnvironmentChecker::new();
-        let rust_version = env_checker
-            .get_rust_version()
-            .await
-            .unwrap_or_else(|_| crate::constants::defaults::RUST_VERSION.to_string());
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A minimal chi HTTP service: {project_name}"))
+        });
 
-        // 1. 语言级别生成 (Rust) - 使用 cargo init 创建项目
-        let rust_params = RustParams::new(project_name.clone()).with_rust_version(rust_version);
+        // 1. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Go, Framework::Chi)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(options.enable_precommit.unwrap_or(true))
+            .with_description(description.clone());
 
-        self.rust_generator
-            .generate(rust_params, output_path)
-            .context("Failed to generate Rust files")?;
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
 
-        // 2. 项目级别生成 - 生成 LICENSE、README 等
-        let project_params = ProjectParams::new(project_name.clone())
-            .with_license(license)
-            .with_git(true)
-            .with_precommit(enable_precommit)
-            .with_description(format!("A Rust project: {project_name}"));
+        // 2. 创建Go语言级别参数（模板以项目名作为导入路径前缀）
+        let module_name = options.module_name.unwrap_or_else(|| project_name.clone());
+        let go_params = GoParams::new(module_name).with_version(go_version.clone());
+
+        // 3. 创建Chi框架级别参数
+        let mut chi_params = ChiParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_go(go_params.clone())
+            .with_server(
+                options.host.unwrap_or_else(|| "localhost".to_string()),
+                options.port.unwrap_or(DEFAULT_CHI_PORT),
+            )
+            .with_precommit(options.enable_precommit.unwrap_or(true));
+
+        self.apply_options(chi_params.base_params_mut(), &description);
+
+        let mut progress = StepProgress::new("chi", 3);
+        progress.step("Generating Chi framework files");
+        self.chi_generator
+            .generate(chi_params.clone(), output_path)
+            .context("Failed to generate Chi framework files")?;
 
+        // 语言级别生成 (Go) - 然后执行 go mod init 和 go mod tidy
+        progress.step("Initializing Go module");
+        self.go_generator
+            .generate(go_params, output_path)
+            .context("Failed to generate Go files")?;
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
         self.project_generator
-            .generate(project_params, output_path)
+            .generate(project_params.clone(), output_path)
             .context("Failed to generate project files")?;
+        progress.finish();
 
-        println!("Rust project generation completed successfully!");
-        println!("Project created at: {}", output_path.display());
+        let mut next_steps = NextSteps::new();
+        self.chi_generator.next_steps(&chi_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
 
-        Ok(())
+        status!("Chi project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
     }
 
-    /// 生成完整的Tauri项目
-    pub async fn generate_tauri_project(
-        &mut self,
+    /// 生成完整的Go-Zero项目
+    pub async fn generate_go_zero_project(
+        &self,
         project_name: String,
         output_path: &Path,
-        license: String,
-        enable_precommit: bool,
-    ) -> Result<()> {
-        println!("Starting Tauri project generation: {project_name}");
+        options: GoZeroProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting go-zero project generation: {project_name}");
 
-        // 1. 环境预检查
-        println!("🔍 Checking environment prerequisites...");
+        let mut progress = StepProgress::new("go-zero", 5);
 
-        // 检查 pnpm
-        if !TauriGenerator::check_pnpm()? {
-            return Err(anyhow::anyhow!(
-                "pnpm is not installed. Please install pnpm first:\n  npm install -g pnpm\n  or visit: https://pnpm.io/installation"
-            ));
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if !render_only::skip_tool("goctl and protoc checks") {
+            check_go_zero_prerequisites(options.enable_rpc).await?;
         }
-        println!("  ✅ pnpm: Available");
 
-        // 检查 create-tauri-app
-        if !TauriGenerator::check_create_tauri_app()? {
-            println!("  ⚠️ create-tauri-app not found, installing...");
-            TauriGenerator::install_create_tauri_app()?;
-        }
-        println!("  ✅ create-tauri-app: Available");
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A go-zero microservice project: {project_name}"))
+        });
 
-        // 2. 删除已存在的目录（如果存在）
-        if output_path.exists() {
-            std::fs::remove_dir_all(output_path).context("Failed to remove existing directory")?;
-        }
+        // 2. 语言级别生成 (Go) - goctl 需要 go.mod 确定导入路径，默认以项目名作为模块路径
+        let module_name = options
+            .module_name
+            .clone()
+            .unwrap_or_else(|| project_name.clone());
+        let go_params = GoParams::new(module_name)
+            .with_version(detect_go_version(options.go_version.clone()).await);
 
-        // 3. 使用 create-tauri-app 创建项目
-        TauriGenerator::create_tauri_project(&project_name, output_path)?;
+        progress.step("Initializing Go module");
+        self.go_generator
+            .generate(go_params.clone(), output_path)
+            .context("Failed to generate Go files")?;
 
-        // 4. 安装前端依赖
-        TauriGenerator::install_dependencies(output_path)?;
+        // 3. 框架级别生成 - 服务定义、公共包和部署文件
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Go, Framework::GoZero)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(options.enable_precommit.unwrap_or(true))
+            .with_description(description.clone());
 
-        // 5. 创建项目参数
-        let project_params = ProjectParams::new(project_name.clone())
-            .with_license(license.clone())
-            .with_git(true)
-            .with_precommit(enable_precommit)
-            .with_description(format!("A Tauri desktop application: {project_name}"));
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
 
-        // 6. 创建 Tauri 参数
-        let tauri_params = TauriParams::from_project_name(project_name.clone())
+        let mut go_zero_params = GoZeroParams::from_project_name(project_name.clone())
             .with_project(project_params.clone())
-            .with_precommit(enable_precommit);
-
-        // 7. 覆盖模板文件 - 添加骨架屏、Tailwind CSS 等功能
-        println!("📝 Applying enhanced templates...");
-        self.tauri_generator
-            .generate(tauri_params, output_path)
-            .context("Failed to apply Tauri templates")?;
-
-        // 8. 重新安装依赖（因为 package.json 可能已更新）
-        println!("📦 Reinstalling dependencies with updated package.json...");
-        TauriGenerator::install_dependencies(output_path)?;
-
-        // 9. 项目级别生成 - 生成 LICENSE 等
+            .with_go(go_params)
+            .with_server(
+                options.host.unwrap_or_else(|| "0.0.0.0".to_string()),
+                options.port.unwrap_or(8888),
+            )
+            .with_grpc_port(options.grpc_port.unwrap_or(DEFAULT_GRPC_PORT))
+            .with_api(options.enable_api)
+            .with_rpc(options.enable_rpc)
+            .with_admin(options.enable_admin);
+        go_zero_params.base.enable_precommit = options.enable_precommit.unwrap_or(true);
+        self.apply_options(go_zero_params.base_params_mut(), &description);
+
+        progress.step("Generating go-zero service definitions");
+        self.go_zero_generator
+            .generate(go_zero_params.clone(), output_path)
+            .context("Failed to generate go-zero framework files")?;
+
+        // 4. 使用 goctl 生成服务代码
+        progress.step("Running goctl code generation");
+        self.go_zero_generator
+            .post_process(&go_zero_params, output_path)
+            .context("Failed to execute go-zero post-processing")?;
+
+        // 5. 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
         self.project_generator
-            .generate(project_params, output_path)
+            .generate(project_params.clone(), output_path)
             .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.go_zero_generator
+            .next_steps(&go_zero_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
 
-        println!("✅ Tauri project generation completed successfully!");
-        println!("📁 Project created at: {}", output_path.display());
-        println!("\n📋 Next steps:");
-        println!("  cd {project_name}");
-        println!("  cargo tauri dev    # Start development server");
-        println!("  cargo tauri build  # Build for production");
+        status!("go-zero project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
 
-        Ok(())
+        Ok(next_steps)
     }
 
-    /// 生成完整的Vue3项目
-    pub async fn generate_vue3_project(
-        &mut self,
+    /// 生成完整的Python项目
+    pub async fn generate_python_project(
+        &self,
         project_name: String,
         output_path: &Path,
         license: String,
         enable_precommit: bool,
-    ) -> Result<()> {
-        println!("Starting Vue3 project generation: {project_name}");
-
-        // 1. 环境预检查
-        println!("🔍 Checking environment prerequisites...");
+    ) -> Result<NextSteps> {
+        status!("Starting Python project generation: {project_name}");
 
-        // 检查 pnpm
-        if !Vue3Generator::check_pnpm()? {
-            return Err(anyhow::anyhow!(
-                "pnpm is not installed. Please install pnpm first:\n  npm install -g pnpm\n  or visit: https://pnpm.io/installation"
-            ));
-        }
-        println!("  ✅ pnpm: Available");
+        let mut progress = StepProgress::new("python", 3);
 
-        // 2. 删除已存在的目录（如果存在）
-        if output_path.exists() {
-            std::fs::remove_dir_all(output_path).context("Failed to remove existing directory")?;
-        }
+        // 获取实际的 uv 版本和 Python 版本
+        progress.step("Detecting toolchain versions");
+        let (uv_version, python_version) = detect_python_toolchain().await;
 
-        // 3. 使用 pnpm create vue 创建项目
-        Vue3Generator::create_vue3_project(&project_name, output_path)?;
+        let description = self.resolve_description(format!("A Python project: {project_name}"));
 
-        // 4. 安装前端依赖
-        Vue3Generator::install_dependencies(output_path)?;
+        // 1. 语言级别生成 (Python) - 使用 uv init 创建项目
+        let mut python_params = PythonParams::new(project_name.clone())
+            .with_version(python_version)
+            .with_uv_version(uv_version)
+            .with_precommit(enable_precommit);
+        self.apply_options(python_params.base_params_mut(), &description);
 
-        // 5. 安装 Tailwind CSS
-        Vue3Generator::install_tailwind(output_path)?;
+        progress.step("Initializing Python project with uv");
+        self.python_generator
+            .generate(python_params.clone(), output_path)
+            .context("Failed to generate Python files")?;
 
-        // 6. 创建项目参数
-        let project_params = ProjectParams::new(project_name.clone())
-            .with_license(license.clone())
+        // 2. 项目级别生成 - 生成 LICENSE、README 等
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Python, Framework::None)
+            .with_license(license)
             .with_git(true)
-            .with_precommit(enable_precommit)
-            .with_description(format!("A Vue3 frontend application: {project_name}"));
-
-        // 7. 创建 Vue3 参数
-        let _vue3_params = Vue3Params::from_project_name(project_name.clone())
-            .with_project(project_params.clone())
             .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
 
-        // 8. 项目级别生成 - 生成 LICENSE 等
+        progress.step("Generating project files");
         self.project_generator
-            .generate(project_params, output_path)
+            .generate(project_params.clone(), output_path)
             .context("Failed to generate project files")?;
+        progress.finish();
 
-        println!("✅ Vue3 project generation completed successfully!");
-        println!("📁 Project created at: {}", output_path.display());
-        println!("\n📋 Next steps:");
-        println!("  cd {project_name}");
-        println!("  pnpm dev    # Start development server");
-        println!("  pnpm build  # Build for production");
+        let mut next_steps = NextSteps::new();
+        self.python_generator
+            .next_steps(&python_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
 
-        Ok(())
+        status!("Python project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
     }
 
-    /// 生成完整的React项目
-    pub async fn generate_react_project(
-        &mut self,
+    /// 生成完整的Django项目
+    pub async fn generate_django_project(
+        &self,
         project_name: String,
         output_path: &Path,
-        license: String,
+        options: DjangoProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Django project generation: {project_name}");
+
+        let mut progress = StepProgress::new("django", 3);
+
+        // 获取实际的 uv 版本和 Python 版本
+        progress.step("Detecting toolchain versions");
+        let (uv_version, python_version) = detect_python_toolchain().await;
+
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A Django project: {project_name}"))
+        });
+        let enable_precommit = options.enable_precommit.unwrap_or(true);
+
+        // 1. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Python, Framework::Django)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(enable_precommit)
+            .with_description(description.clone());
+
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        // 2. 创建Python语言级别参数，工具版本与纯 Python 项目保持一致
+        let python_params = PythonParams::new(project_name.clone())
+            .with_version(python_version)
+            .with_uv_version(uv_version)
+            .with_precommit(enable_precommit);
+
+        // 3. 创建Django框架级别参数
+        let mut django_params = DjangoParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_python(python_params)
+            .with_drf(options.enable_drf.unwrap_or(false))
+            .with_precommit(enable_precommit);
+        self.apply_options(django_params.base_params_mut(), &description);
+
+        // 框架级别生成 - uv init、django-admin startproject 和拆分配置
+        progress.step("Creating Django project");
+        self.django_generator
+            .generate(django_params.clone(), output_path)
+            .context("Failed to generate Django project files")?;
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.django_generator
+            .next_steps(&django_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Django project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Flask项目
+    pub async fn generate_flask_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: FlaskProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Flask project generation: {project_name}");
+
+        let mut progress = StepProgress::new("flask", 3);
+
+        // 获取实际的 uv 版本和 Python 版本
+        progress.step("Detecting toolchain versions");
+        let (uv_version, python_version) = detect_python_toolchain().await;
+
+        let description = options
+            .description
+            .clone()
+            .unwrap_or_else(|| self.resolve_description(format!("A Flask API: {project_name}")));
+        let enable_precommit = options.enable_precommit.unwrap_or(true);
+
+        // 1. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Python, Framework::Flask)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(enable_precommit)
+            .with_description(description.clone());
+
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        // 2. 创建Python语言级别参数，工具版本与纯 Python 项目保持一致
+        let python_params = PythonParams::new(project_name.clone())
+            .with_version(python_version)
+            .with_uv_version(uv_version)
+            .with_precommit(enable_precommit);
+
+        // 3. 创建Flask框架级别参数
+        let mut flask_params = FlaskParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_python(python_params)
+            .with_precommit(enable_precommit);
+        self.apply_options(flask_params.base_params_mut(), &description);
+
+        // 框架级别生成 - uv init、应用代码和依赖
+        progress.step("Generating Flask application");
+        self.flask_generator
+            .generate(flask_params.clone(), output_path)
+            .context("Failed to generate Flask project files")?;
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.flask_generator
+            .next_steps(&flask_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Flask project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Rust项目
+    #[allow(dead_code)]
+    pub async fn generate_rust_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        license: String,
         enable_precommit: bool,
-    ) -> Result<()> {
-        println!("Starting React project generation: {project_name}");
+    ) -> Result<NextSteps> {
+        status!("Starting Rust project generation: {project_name}");
+
+        let mut progress = StepProgress::new("rust", 3);
+
+        // 获取实际的 Rust 版本
+        progress.step("Detecting toolchain versions");
+        let env_checker = EnvironmentChecker::new();
+        // 渲染模式下使用默认版本，保证输出稳定
+        let rust_version = if render_only::skip_tool("toolchain version detection") {
+            None
+        } else {
+            env_checker.get_rust_version().await.ok()
+        };
+        let rust_version =
+            rust_version.unwrap_or_else(|| crate::constants::defaults::RUST_VERSION.to_string());
+
+        let description = self.resolve_description(format!("A Rust project: {project_name}"));
+
+        // 1. 语言级别生成 (Rust) - 使用 cargo init 创建项目
+        let mut rust_params = RustParams::new(project_name.clone()).with_rust_version(rust_version);
+        self.apply_options(rust_params.base_params_mut(), &description);
+
+        progress.step("Initializing Rust project with cargo");
+        self.rust_generator
+            .generate(rust_params.clone(), output_path)
+            .context("Failed to generate Rust files")?;
+
+        // 2. 项目级别生成 - 生成 LICENSE、README 等
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Rust, Framework::None)
+            .with_license(license)
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.rust_generator
+            .next_steps(&rust_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Rust project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Zig项目
+    pub async fn generate_zig_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        license: String,
+        enable_precommit: bool,
+    ) -> Result<NextSteps> {
+        status!("Starting Zig project generation: {project_name}");
+
+        let mut progress = StepProgress::new("zig", 3);
+
+        // 获取实际的 Zig 版本
+        progress.step("Detecting toolchain versions");
+        // 渲染模式下使用默认版本，保证输出稳定
+        let zig_version = if render_only::skip_tool("toolchain version detection") {
+            None
+        } else {
+            EnvironmentChecker::new().get_zig_version().await.ok()
+        };
+        let zig_version =
+            zig_version.unwrap_or_else(|| crate::constants::defaults::ZIG_VERSION.to_string());
+
+        let description = self.resolve_description(format!("A Zig project: {project_name}"));
+
+        // 1. 语言级别生成 (Zig) - build.zig 和 src 目录
+        let mut zig_params = ZigParams::new(project_name.clone())
+            .with_zig_version(zig_version)
+            .with_precommit(enable_precommit);
+        self.apply_options(zig_params.base_params_mut(), &description);
+
+        progress.step("Generating Zig project with build.zig");
+        self.zig_generator
+            .generate(zig_params.clone(), output_path)
+            .context("Failed to generate Zig files")?;
+
+        // 2. 项目级别生成 - 生成 LICENSE、README 等
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Zig, Framework::None)
+            .with_license(license)
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.zig_generator.next_steps(&zig_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Zig project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Axum项目
+    pub async fn generate_axum_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: AxumProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Axum project generation: {project_name}");
+
+        let mut progress = StepProgress::new("axum", 4);
+
+        // 获取实际的 Rust 版本
+        progress.step("Detecting toolchain versions");
+        let rust_version = match options.rust_version {
+            Some(version) => Some(version),
+            // 渲染模式下使用默认版本，保证输出稳定
+            None if render_only::skip_tool("toolchain version detection") => None,
+            None => EnvironmentChecker::new().get_rust_version().await.ok(),
+        };
+        let rust_version =
+            rust_version.unwrap_or_else(|| crate::constants::defaults::RUST_VERSION.to_string());
+
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("An axum HTTP service: {project_name}"))
+        });
+        let enable_precommit = options.enable_precommit.unwrap_or(true);
+
+        // 1. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Rust, Framework::Axum)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(enable_precommit)
+            .with_description(description.clone());
+
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        // 2. 创建Rust语言级别参数
+        let rust_params = RustParams::new(project_name.clone()).with_rust_version(rust_version);
+
+        // 3. 创建Axum框架级别参数
+        let mut axum_params = AxumParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_rust(rust_params)
+            .with_server(
+                options.host.unwrap_or_else(|| "127.0.0.1".to_string()),
+                options.port.unwrap_or(DEFAULT_AXUM_PORT),
+            )
+            .with_precommit(enable_precommit);
+        self.apply_options(axum_params.base_params_mut(), &description);
+
+        progress.step("Generating Axum service files");
+        self.axum_generator
+            .generate(axum_params.clone(), output_path)
+            .context("Failed to generate Axum framework files")?;
+
+        // 语言级别 (Rust) - 拉取 Cargo.toml 中的依赖并编译
+        progress.step("Building Rust project with cargo");
+        self.rust_generator
+            .build_project(output_path)
+            .context("Failed to build Rust project")?;
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.axum_generator
+            .next_steps(&axum_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Axum project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Actix-web项目
+    pub async fn generate_actix_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: ActixProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Actix-web project generation: {project_name}");
+
+        let mut progress = StepProgress::new("actix", 4);
+
+        // 获取实际的 Rust 版本
+        progress.step("Detecting toolchain versions");
+        let rust_version = match options.rust_version {
+            Some(version) => Some(version),
+            // 渲染模式下使用默认版本，保证输出稳定
+            None if render_only::skip_tool("toolchain version detection") => None,
+            None => EnvironmentChecker::new().get_rust_version().await.ok(),
+        };
+        let rust_version =
+            rust_version.unwrap_or_else(|| crate::constants::defaults::RUST_VERSION.to_string());
+
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("An actix-web HTTP service: {project_name}"))
+        });
+        let enable_precommit = options.enable_precommit.unwrap_or(true);
+
+        // 1. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Rust, Framework::Actix)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(enable_precommit)
+            .with_description(description.clone());
+
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        // 2. 创建Rust语言级别参数
+        let rust_params = RustParams::new(project_name.clone()).with_rust_version(rust_version);
+
+        // 3. 创建Actix-web框架级别参数
+        let mut actix_params = ActixParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_rust(rust_params)
+            .with_server(
+                options.host.unwrap_or_else(|| "127.0.0.1".to_string()),
+                options.port.unwrap_or(DEFAULT_ACTIX_PORT),
+            )
+            .with_precommit(enable_precommit);
+        self.apply_options(actix_params.base_params_mut(), &description);
+
+        progress.step("Generating Actix-web service files");
+        self.actix_generator
+            .generate(actix_params.clone(), output_path)
+            .context("Failed to generate Actix-web framework files")?;
+
+        // 语言级别 (Rust) - 拉取 Cargo.toml 中的依赖并编译
+        progress.step("Building Rust project with cargo");
+        self.rust_generator
+            .build_project(output_path)
+            .context("Failed to build Rust project")?;
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.actix_generator
+            .next_steps(&actix_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Actix-web project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Rust CLI项目
+    pub async fn generate_cli_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: CliProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Rust CLI project generation: {project_name}");
+
+        let mut progress = StepProgress::new("cli", 4);
+
+        // 获取实际的 Rust 版本
+        progress.step("Detecting toolchain versions");
+        let rust_version = match options.rust_version {
+            Some(version) => Some(version),
+            // 渲染模式下使用默认版本，保证输出稳定
+            None if render_only::skip_tool("toolchain version detection") => None,
+            None => EnvironmentChecker::new().get_rust_version().await.ok(),
+        };
+        let rust_version =
+            rust_version.unwrap_or_else(|| crate::constants::defaults::RUST_VERSION.to_string());
+
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A command-line application: {project_name}"))
+        });
+        let enable_precommit = options.enable_precommit.unwrap_or(true);
+
+        // 1. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Rust, Framework::Cli)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(options.enable_git.unwrap_or(true))
+            .with_precommit(enable_precommit)
+            .with_description(description.clone());
+
+        if let Some(author) = options.author {
+            project_params = project_params.with_author(author);
+        }
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        // 2. 创建Rust语言级别参数
+        let rust_params = RustParams::new(project_name.clone()).with_rust_version(rust_version);
+
+        // 3. 创建CLI应用框架级别参数
+        let mut cli_params = CliParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_rust(rust_params)
+            .with_precommit(enable_precommit);
+        self.apply_options(cli_params.base_params_mut(), &description);
+
+        progress.step("Generating CLI application files");
+        self.cli_generator
+            .generate(cli_params.clone(), output_path)
+            .context("Failed to generate CLI framework files")?;
+
+        // 语言级别 (Rust) - 拉取 Cargo.toml 中的依赖并编译
+        progress.step("Building Rust project with cargo");
+        self.rust_generator
+            .build_project(output_path)
+            .context("Failed to build Rust project")?;
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.cli_generator.next_steps(&cli_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("Rust CLI project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Tauri项目
+    pub async fn generate_tauri_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        license: String,
+        enable_precommit: bool,
+    ) -> Result<NextSteps> {
+        status!("Starting Tauri project generation: {project_name}");
+
+        let mut progress = StepProgress::new("tauri", 5);
+
+        // 渲染模式下不执行 create-tauri-app 和 pnpm，只输出模板
+        let run_tools = !render_only::skip_tool("create-tauri-app and pnpm");
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查 pnpm
+            if !TauriGenerator::check_pnpm()? {
+                return Err(anyhow::anyhow!(
+                    "pnpm is not installed. Please install pnpm first:\n  npm install -g pnpm\n  or visit: https://pnpm.io/installation"
+                ));
+            }
+            status!("  ✅ pnpm: Available");
+
+            // 检查 create-tauri-app 及其版本，旧版本生成的项目与覆盖模板不兼容
+            match TauriGenerator::installed_create_tauri_app_version()? {
+                Some(version) if is_supported_create_tauri_app(&version) => {}
+                Some(version) => {
+                    status!(
+                        "  ⚠️ create-tauri-app {version} does not match the supported {CREATE_TAURI_APP_VERSION}"
+                    );
+                    TauriGenerator::upgrade_create_tauri_app(&version)?;
+                }
+                None => {
+                    status!("  ⚠️ create-tauri-app not found, installing...");
+                    TauriGenerator::install_create_tauri_app()?;
+                }
+            }
+            status!("  ✅ create-tauri-app: Available");
+
+            // 2. 删除已存在的目录（如果存在）
+            if output_path.exists() {
+                std::fs::remove_dir_all(output_path)
+                    .context("Failed to remove existing directory")?;
+            }
+        }
+
+        // 3. 使用 create-tauri-app 创建项目
+        progress.step("Creating project with create-tauri-app");
+        if run_tools {
+            TauriGenerator::create_tauri_project(&project_name, output_path)?;
+        }
+
+        // 4. 创建项目参数
+        let description =
+            self.resolve_description(format!("A Tauri desktop application: {project_name}"));
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::Rust, Framework::Tauri)
+            .with_readme(true)
+            .with_license(license.clone())
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        // 5. 创建 Tauri 参数
+        let mut tauri_params = TauriParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_precommit(enable_precommit)
+            .with_tailwind_version(self.tailwind_version)
+            .with_tauri_version(self.tauri_version);
+        self.apply_options(tauri_params.base_params_mut(), &description);
+
+        // 6. 覆盖模板文件 - 添加骨架屏、Tailwind CSS 等功能
+        progress.step("Applying enhanced templates");
+        self.tauri_generator
+            .generate(tauri_params.clone(), output_path)
+            .context("Failed to apply Tauri templates")?;
+
+        // 7. 安装前端依赖：覆盖模板总会替换 package.json，因此只在覆盖之后安装一次
+        progress.step("Installing dependencies");
+        if run_tools {
+            TauriGenerator::install_dependencies(output_path)?;
+        }
+
+        // 8. 项目级别生成 - 生成 LICENSE 等
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.tauri_generator
+            .next_steps(&tauri_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("✅ Tauri project generation completed successfully!");
+        status!("📁 Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Vue3项目
+    pub async fn generate_vue3_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        license: String,
+        enable_precommit: bool,
+    ) -> Result<NextSteps> {
+        status!("Starting Vue3 project generation: {project_name}");
+
+        let mut progress = StepProgress::new("vue3", 8);
+
+        // Vue3 参数，create-vue 的子选项由此决定
+        let vue3_params = Vue3Params::from_project_name(project_name.clone())
+            .with_precommit(enable_precommit)
+            .with_tailwind_version(self.tailwind_version)
+            .with_runtime(self.runtime)
+            .with_api_client(self.api_client)
+            .with_tanstack_query(self.enable_tanstack_query)
+            .with_auth_pages(self.enable_auth_pages)
+            .with_dark_mode(self.enable_dark_mode);
+
+        // 渲染模式下不执行 create-vue 和包管理器，只生成项目级文件
+        let run_tools = !render_only::skip_tool(&format!(
+            "create-vue and {}",
+            self.runtime.package_manager()
+        ));
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查运行时的包管理器
+            js_runtime::ensure_available(self.runtime)?;
+
+            // 2. 删除已存在的目录（如果存在）
+            if output_path.exists() {
+                std::fs::remove_dir_all(output_path)
+                    .context("Failed to remove existing directory")?;
+            }
+        }
+
+        // 3. 使用 create-vue 创建项目
+        progress.step("Creating project with create-vue");
+        if run_tools {
+            Vue3Generator::create_vue3_project(&vue3_params, output_path)?;
+        }
+
+        // 4. 将 Tailwind CSS、API 客户端层和 Vitest 依赖写入 package.json
+        progress.step("Adding dependencies to package.json");
+        if run_tools {
+            Vue3Generator::package_patch(&vue3_params, self.features.tests)
+                .apply(output_path)
+                .context("Failed to add dependencies to package.json")?;
+        }
+
+        // 5. 一次安装全部前端依赖，再配置 Tailwind CSS
+        progress.step("Installing dependencies");
+        if run_tools {
+            Vue3Generator::install_dependencies(output_path, self.runtime)?;
+            Vue3Generator::configure_tailwind(
+                output_path,
+                vue3_params.tailwind_version,
+                vue3_params.runtime,
+            )?;
+        }
+
+        // 6. 注册主题插件，Tailwind CSS 改用 dark 类切换
+        progress.step("Configuring theme");
+        if run_tools && vue3_params.enable_dark_mode {
+            Vue3Generator::register_theme(output_path, vue3_params.tailwind_version)?;
+        }
+
+        // 7. 注册 VueQueryPlugin 和认证守卫，在路由表中加入页面注册标记
+        progress.step("Registering API client");
+        if run_tools {
+            if vue3_params.enable_tanstack_query {
+                Vue3Generator::register_vue_query(output_path)?;
+            }
+            if vue3_params.enable_auth_pages {
+                Vue3Generator::register_auth_guard(output_path)?;
+            }
+            Vue3Generator::mark_routes(output_path)?;
+            if let Some(proxy) = self.api_proxy {
+                api_client::configure_dev_proxy(output_path, &proxy.dev_target())?;
+            }
+        }
+
+        // 8. 创建项目参数
+        progress.step("Generating project files");
+        let description =
+            self.resolve_description(format!("A Vue3 frontend application: {project_name}"));
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::TypeScript, Framework::Vue3)
+            .with_runtime(self.runtime)
+            .with_readme(true)
+            .with_license(license.clone())
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+        manifest::update_package_json_metadata(
+            output_path,
+            &ManifestMetadata::from_base(project_params.base_params()),
+        )
+        .context("Failed to update package.json metadata")?;
+
+        // 9. 补充 Vue3 参数中的项目信息，渲染 API 客户端层、测试和 CI 配置
+        let mut vue3_params = vue3_params.with_project(project_params.clone());
+        self.apply_options(vue3_params.base_params_mut(), &description);
+        self.vue3_generator
+            .generate(vue3_params.clone(), output_path)
+            .context("Failed to generate Vue3 project files")?;
+
+        // 10. 项目级别生成 - 生成 LICENSE 等
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.vue3_generator
+            .next_steps(&vue3_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("✅ Vue3 project generation completed successfully!");
+        status!("📁 Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的React项目
+    pub async fn generate_react_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        license: String,
+        enable_precommit: bool,
+    ) -> Result<NextSteps> {
+        status!("Starting React project generation: {project_name}");
+
+        let mut progress = StepProgress::new("react", 6);
+
+        // React 参数，状态管理方案决定安装的依赖和渲染的示例 store
+        let react_params = ReactParams::from_project_name(project_name.clone())
+            .with_precommit(enable_precommit)
+            .with_tailwind_version(self.tailwind_version)
+            .with_runtime(self.runtime)
+            .with_api_client(self.api_client)
+            .with_tanstack_query(self.enable_tanstack_query)
+            .with_auth_pages(self.enable_auth_pages)
+            .with_dark_mode(self.enable_dark_mode);
+
+        // 渲染模式下不执行 create-vite 和包管理器，只生成项目级文件
+        let run_tools = !render_only::skip_tool(&format!(
+            "create-vite and {}",
+            self.runtime.package_manager()
+        ));
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查运行时的包管理器
+            js_runtime::ensure_available(self.runtime)?;
+
+            // 2. 删除已存在的目录（如果存在）
+            if output_path.exists() {
+                std::fs::remove_dir_all(output_path)
+                    .context("Failed to remove existing directory")?;
+            }
+        }
+
+        // 3. 使用 create-vite 创建项目
+        progress.step("Creating project with Vite");
+        if run_tools {
+            ReactGenerator::create_react_project(&project_name, output_path, self.runtime)?;
+        }
+
+        // 4. 将 Tailwind CSS、React Router、状态管理库 (默认使用 zustand)、API 客户端层和 Vitest 依赖写入 package.json
+        progress.step("Adding dependencies to package.json");
+        if run_tools {
+            ReactGenerator::package_patch(&react_params, self.features.tests)
+                .apply(output_path)
+                .context("Failed to add dependencies to package.json")?;
+        }
+
+        // 5. 一次安装全部前端依赖，再配置 Tailwind CSS
+        progress.step("Installing dependencies");
+        if run_tools {
+            ReactGenerator::install_dependencies(output_path, self.runtime)?;
+            ReactGenerator::configure_tailwind(output_path, self.tailwind_version, self.runtime)?;
+            if let Some(proxy) = self.api_proxy {
+                api_client::configure_dev_proxy(output_path, &proxy.dev_target())?;
+            }
+        }
+
+        // 6. 创建项目参数
+        progress.step("Generating project files");
+        let description =
+            self.resolve_description(format!("A React frontend application: {project_name}"));
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::TypeScript, Framework::React)
+            .with_runtime(self.runtime)
+            .with_readme(true)
+            .with_license(license.clone())
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+        manifest::update_package_json_metadata(
+            output_path,
+            &ManifestMetadata::from_base(project_params.base_params()),
+        )
+        .context("Failed to update package.json metadata")?;
+
+        // 7. 补充 React 参数中的项目信息，渲染路由骨架、布局、示例页面、store、测试和 CI 配置
+        let mut react_params = react_params.with_project(project_params.clone());
+        self.apply_options(react_params.base_params_mut(), &description);
+        if run_tools {
+            ReactGenerator::remove_vite_starter(output_path)?;
+        }
+        self.react_generator
+            .generate(react_params.clone(), output_path)
+            .context("Failed to generate React project files")?;
+
+        // 8. 项目级别生成 - 生成 LICENSE 等
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.react_generator
+            .next_steps(&react_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("✅ React project generation completed successfully!");
+        status!("📁 Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Next.js项目
+    pub async fn generate_nextjs_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        license: String,
+        enable_precommit: bool,
+    ) -> Result<NextSteps> {
+        status!("Starting Next.js project generation: {project_name}");
+
+        let mut progress = StepProgress::new("nextjs", 3);
+
+        // 渲染模式下不执行 create-next-app，只生成项目级文件
+        let run_tools = !render_only::skip_tool("create-next-app");
+
+        let nextjs_params =
+            NextJsParams::from_project_name(project_name.clone()).with_runtime(self.runtime);
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查运行时的包管理器
+            js_runtime::ensure_available(self.runtime)?;
+
+            // create-next-app 要求目标目录不存在或为空
+            if output_path.exists() {
+                std::fs::remove_dir_all(output_path)
+                    .context("Failed to remove existing directory")?;
+            }
+        }
+
+        // 2. 使用 create-next-app 创建项目并安装依赖
+        progress.step("Creating project with create-next-app");
+        if run_tools {
+            NextJsGenerator::create_nextjs_project(&project_name, output_path, &nextjs_params)?;
+        }
+
+        // 3. 项目级别生成 - 生成 LICENSE、pre-commit、CI 等
+        progress.step("Generating project files");
+        let description =
+            self.resolve_description(format!("A Next.js application: {project_name}"));
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::TypeScript, Framework::NextJs)
+            .with_runtime(self.runtime)
+            .with_readme(true)
+            .with_license(license.clone())
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+        manifest::update_package_json_metadata(
+            output_path,
+            &ManifestMetadata::from_base(project_params.base_params()),
+        )
+        .context("Failed to update package.json metadata")?;
+
+        // CI 和 pre-commit 配置按档位功能渲染到 create-next-app 生成的项目中
+        let mut nextjs_params = nextjs_params
+            .with_project(project_params.clone())
+            .with_precommit(enable_precommit);
+        self.apply_options(nextjs_params.base_params_mut(), &description);
+        self.nextjs_generator
+            .generate(nextjs_params.clone(), output_path)
+            .context("Failed to generate Next.js project files")?;
+
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.nextjs_generator
+            .next_steps(&nextjs_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("✅ Next.js project generation completed successfully!");
+        status!("📁 Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Nuxt项目
+    pub async fn generate_nuxt_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: NuxtProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Nuxt project generation: {project_name}");
+
+        let mut progress = StepProgress::new("nuxt", 4);
+
+        // 渲染模式下不执行 nuxi 和包管理器，只生成项目级文件
+        let run_tools =
+            !render_only::skip_tool(&format!("nuxi and {}", self.runtime.package_manager()));
+
+        let mut nuxt_params =
+            NuxtParams::from_project_name(project_name.clone()).with_runtime(self.runtime);
+        if let Some(ref modules) = options.modules {
+            nuxt_params = nuxt_params.with_modules(modules);
+        }
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查运行时的包管理器
+            js_runtime::ensure_available(self.runtime)?;
+
+            // nuxi init 要求目标目录不存在或为空
+            if output_path.exists() {
+                std::fs::remove_dir_all(output_path)
+                    .context("Failed to remove existing directory")?;
+            }
+        }
+
+        // 2. 使用 nuxi init 创建项目并安装依赖
+        progress.step("Creating project with nuxi");
+        if run_tools {
+            NuxtGenerator::create_nuxt_project(&project_name, output_path, self.runtime)?;
+        }
+
+        // 3. 安装选中的 Nuxt 模块
+        progress.step("Installing Nuxt modules");
+        if run_tools {
+            NuxtGenerator::install_modules(output_path, &nuxt_params)?;
+        }
+
+        // 4. 项目级别生成 - 生成 LICENSE、pre-commit、CI 等
+        progress.step("Generating project files");
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A Nuxt application: {project_name}"))
+        });
+        let enable_precommit = options.enable_precommit.unwrap_or(false);
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::TypeScript, Framework::Nuxt)
+            .with_runtime(self.runtime)
+            .with_readme(true)
+            .with_license(options.license.clone().unwrap_or_else(|| "MIT".to_string()))
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+        manifest::update_package_json_metadata(
+            output_path,
+            &ManifestMetadata::from_base(project_params.base_params()),
+        )
+        .context("Failed to update package.json metadata")?;
+
+        // CI 和 pre-commit 配置按档位功能渲染到 nuxi 生成的项目中
+        let mut nuxt_params = nuxt_params
+            .with_project(project_params.clone())
+            .with_precommit(enable_precommit);
+        self.apply_options(nuxt_params.base_params_mut(), &description);
+        self.nuxt_generator
+            .generate(nuxt_params.clone(), output_path)
+            .context("Failed to generate Nuxt project files")?;
+
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.nuxt_generator
+            .next_steps(&nuxt_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("✅ Nuxt project generation completed successfully!");
+        status!("📁 Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的SvelteKit项目
+    pub async fn generate_sveltekit_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: SvelteKitProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting SvelteKit project generation: {project_name}");
+
+        let mut progress = StepProgress::new("sveltekit", 4);
+
+        // 渲染模式下不执行 sv 和包管理器，只生成项目级文件
+        let run_tools =
+            !render_only::skip_tool(&format!("sv and {}", self.runtime.package_manager()));
+
+        let mut sveltekit_params =
+            SvelteKitParams::from_project_name(project_name.clone()).with_runtime(self.runtime);
+        if let Some(ref sveltekit_options) = options.options {
+            sveltekit_params = sveltekit_params.with_options(sveltekit_options);
+        }
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查运行时的包管理器
+            js_runtime::ensure_available(self.runtime)?;
+
+            // sv create 要求目标目录不存在或为空
+            if output_path.exists() {
+                std::fs::remove_dir_all(output_path)
+                    .context("Failed to remove existing directory")?;
+            }
+        }
+
+        // 2. 使用 sv create 创建项目并安装依赖
+        progress.step("Creating project with sv");
+        if run_tools {
+            SvelteKitGenerator::create_sveltekit_project(
+                &project_name,
+                output_path,
+                &sveltekit_params,
+            )?;
+        }
+
+        // 3. 安装选中的附加组件
+        progress.step("Installing SvelteKit add-ons");
+        if run_tools {
+            SvelteKitGenerator::install_add_ons(output_path, &sveltekit_params)?;
+        }
+
+        // 4. 项目级别生成 - 生成 LICENSE、pre-commit、CI 等
+        progress.step("Generating project files");
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A SvelteKit application: {project_name}"))
+        });
+        let enable_precommit = options.enable_precommit.unwrap_or(false);
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::TypeScript, Framework::SvelteKit)
+            .with_runtime(self.runtime)
+            .with_readme(true)
+            .with_license(options.license.clone().unwrap_or_else(|| "MIT".to_string()))
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+        manifest::update_package_json_metadata(
+            output_path,
+            &ManifestMetadata::from_base(project_params.base_params()),
+        )
+        .context("Failed to update package.json metadata")?;
+
+        // CI 和 pre-commit 配置按档位功能渲染到 sv 生成的项目中
+        let mut sveltekit_params = sveltekit_params
+            .with_project(project_params.clone())
+            .with_precommit(enable_precommit);
+        self.apply_options(sveltekit_params.base_params_mut(), &description);
+        self.sveltekit_generator
+            .generate(sveltekit_params.clone(), output_path)
+            .context("Failed to generate SvelteKit project files")?;
+
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.sveltekit_generator
+            .next_steps(&sveltekit_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("✅ SvelteKit project generation completed successfully!");
+        status!("📁 Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的NestJS项目
+    pub async fn generate_nestjs_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: NestJsProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting NestJS project generation: {project_name}");
+
+        let mut progress = StepProgress::new("nestjs", 4);
+
+        // 渲染模式下不执行包管理器，只生成模板文件
+        let run_tools =
+            !render_only::skip_tool(&format!("{} install", self.runtime.package_manager()));
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查运行时的包管理器
+            js_runtime::ensure_available(self.runtime)?;
+        }
+
+        let description = options.description.clone().unwrap_or_else(|| {
+            self.resolve_description(format!("A NestJS REST API: {project_name}"))
+        });
+        let enable_precommit = options.enable_precommit.unwrap_or(true);
+
+        // 2. 创建项目级别参数
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::TypeScript, Framework::NestJs)
+            .with_license(options.license.unwrap_or_else(|| "MIT".to_string()))
+            .with_git(true)
+            .with_precommit(enable_precommit)
+            .with_description(description.clone());
+        self.apply_options(project_params.base_params_mut(), &description);
+
+        // 3. 创建NestJS框架级别参数
+        let mut nestjs_params = NestJsParams::from_project_name(project_name.clone())
+            .with_project(project_params.clone())
+            .with_runtime(self.runtime)
+            .with_server(
+                options.host.unwrap_or_else(|| "0.0.0.0".to_string()),
+                options.port.unwrap_or(DEFAULT_NESTJS_PORT),
+            )
+            .with_swagger(options.enable_swagger.unwrap_or(true))
+            .with_precommit(enable_precommit);
+        self.apply_options(nestjs_params.base_params_mut(), &description);
+
+        progress.step("Generating NestJS service files");
+        self.nestjs_generator
+            .generate(nestjs_params.clone(), output_path)
+            .context("Failed to generate NestJS framework files")?;
+        manifest::update_package_json_metadata(
+            output_path,
+            &ManifestMetadata::from_base(project_params.base_params()),
+        )
+        .context("Failed to update package.json metadata")?;
+
+        // 语言级别 (TypeScript) - 安装 package.json 中的依赖
+        progress.step("Installing dependencies");
+        if run_tools {
+            NestJsGenerator::install_dependencies(output_path, self.runtime)?;
+        }
+
+        // 项目级别生成 - 最后执行 git init 等项目级操作
+        progress.step("Generating project files");
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.nestjs_generator
+            .next_steps(&nestjs_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("NestJS project generation completed successfully!");
+        status!("Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+
+    /// 生成完整的Astro项目
+    pub async fn generate_astro_project(
+        &self,
+        project_name: String,
+        output_path: &Path,
+        options: AstroProjectOptions,
+    ) -> Result<NextSteps> {
+        status!("Starting Astro project generation: {project_name}");
+
+        let mut progress = StepProgress::new("astro", 4);
+
+        // 渲染模式下不执行 create-astro 和包管理器，只生成模板文件
+        let run_tools = !render_only::skip_tool(&format!(
+            "create-astro and {}",
+            self.runtime.package_manager()
+        ));
+
+        let enable_precommit = options.enable_precommit.unwrap_or(false);
+        let astro_params = AstroParams::from_project_name(project_name.clone())
+            .with_precommit(enable_precommit)
+            .with_runtime(self.runtime);
+
+        // 1. 环境预检查
+        progress.step("Checking environment prerequisites");
+        if run_tools {
+            // 检查运行时的包管理器
+            js_runtime::ensure_available(self.runtime)?;
+
+            // create-astro 要求目标目录不存在或为空
+            if output_path.exists() {
+                std::fs::remove_dir_all(output_path)
+                    .context("Failed to remove existing directory")?;
+            }
+        }
+
+        // 2. 使用 create-astro 创建项目并安装依赖
+        progress.step("Creating project with create-astro");
+        if run_tools {
+            AstroGenerator::create_astro_project(&project_name, output_path, self.runtime)?;
+        }
+
+        // 3. 添加 Tailwind CSS 和 MDX 集成
+        progress.step("Adding Astro integrations");
+        if run_tools {
+            AstroGenerator::install_integrations(output_path, &astro_params)?;
+        }
+
+        // 4. 项目级别生成 - 生成 LICENSE、pre-commit、CI 等
+        progress.step("Generating project files");
+        let description = options
+            .description
+            .clone()
+            .unwrap_or_else(|| self.resolve_description(format!("An Astro site: {project_name}")));
+        let mut project_params = ProjectParams::new(project_name.clone())
+            .with_stack(Language::TypeScript, Framework::Astro)
+            .with_runtime(self.runtime)
+            .with_readme(true)
+            .with_license(options.license.clone().unwrap_or_else(|| "MIT".to_string()))
+            .with_git(true)
+            .with_precommit(enable_precommit);
+        self.apply_options(project_params.base_params_mut(), &description);
+        manifest::update_package_json_metadata(
+            output_path,
+            &ManifestMetadata::from_base(project_params.base_params()),
+        )
+        .context("Failed to update package.json metadata")?;
+
+        // 布局、示例文档页、CI 和 pre-commit 配置渲染到 create-astro 生成的项目中
+        let mut astro_params = astro_params.with_project(project_params.clone());
+        self.apply_options(astro_params.base_params_mut(), &description);
+        self.astro_generator
+            .generate(astro_params.clone(), output_path)
+            .context("Failed to generate Astro project files")?;
+
+        self.project_generator
+            .generate(project_params.clone(), output_path)
+            .context("Failed to generate project files")?;
+        progress.finish();
+
+        let mut next_steps = NextSteps::new();
+        self.astro_generator
+            .next_steps(&astro_params, &mut next_steps);
+        self.project_generator
+            .next_steps(&project_params, &mut next_steps);
+
+        status!("✅ Astro project generation completed successfully!");
+        status!("📁 Project created at: {}", output_path.display());
+
+        Ok(next_steps)
+    }
+}
+
+/// 检查 go-zero 项目依赖的 goctl，启用 rpc 服务时还需要 protoc
+async fn check_go_zero_prerequisites(enable_rpc: bool) -> Result<()> {
+    let env_checker = EnvironmentChecker::new();
+    if !env_checker.check_goctl().await? {
+        return Err(anyhow::anyhow!(
+            "goctl is not installed. Please install goctl first:\n  go install github.com/zeromicro/go-zero/tools/goctl@latest"
+        ));
+    }
+    status!("  ✅ goctl: Available");
+
+    if enable_rpc {
+        if !env_checker.check_protoc().await? {
+            return Err(anyhow::anyhow!(
+                "protoc is not installed, it is required by the rpc service. Install it with:\n  goctl env check --install --verbose --force"
+            ));
+        }
+        status!("  ✅ protoc: Available");
+    }
+
+    Ok(())
+}
+
+/// Go 版本：优先使用指定的版本，否则检测已安装的 Go
+///
+/// 渲染模式或检测失败时使用默认版本，保证输出稳定
+async fn detect_go_version(go_version: Option<String>) -> String {
+    let go_version = match go_version {
+        Some(version) => Some(version),
+        None if render_only::skip_tool("toolchain version detection") => None,
+        None => EnvironmentChecker::new().get_go_version().await.ok(),
+    };
+    go_version.unwrap_or_else(|| crate::constants::defaults::GO_VERSION.to_string())
+}
+
+/// 检测 uv 和 Python 版本，返回 `(uv_version, python_version)`
+///
+/// 渲染模式或检测失败时使用默认版本，保证输出稳定
+async fn detect_python_toolchain() -> (String, String) {
+    let env_checker = EnvironmentChecker::new();
+    let detect_versions = !render_only::skip_tool("toolchain version detection");
+
+    let uv_version = if detect_versions {
+        env_checker.get_uv_version().await.ok()
+    } else {
+        None
+    };
+    let uv_version = uv_version.unwrap_or_else(|| "uv 0.9.5".to_string());
+
+    // 从 "uv x.y.z" 格式中提取版本号
+    let uv_version = uv_version
+        .strip_prefix("uv ")
+        .unwrap_or(&uv_version)
+        .trim()
+        .to_string();
+
+    let python_version = if detect_versions {
+        env_checker.get_python_version().await.ok()
+    } else {
+        None
+    };
+    let python_version = python_version.unwrap_or_else(|| "3.12".to_string());
+
+    (uv_version, python_version)
+}
+
+impl Default for GeneratorOrchestrator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create GeneratorOrchestrator")
+    }
+}
+
+/// 项目元数据选项 - 描述、关键字、主页和许可证版权信息，适用于所有项目类型
+#[derive(Debug, Default, Clone)]
+pub struct ProjectMetadata {
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+    pub homepage: Option<String>,
+    /// 许可证标识符，框架级别参数也需要写入 Cargo.toml、package.json 和 README
+    pub license: Option<String>,
+    pub license_holder: Option<String>,
+    pub license_year: Option<String>,
+    /// 自定义许可证文本，复制为 LICENSE
+    pub license_file: Option<PathBuf>,
+    /// 作者和邮箱，来自 `--author`/`--email` 或 git 配置
+    pub author: Option<String>,
+    pub author_email: Option<String>,
+}
+
+impl ProjectMetadata {
+    /// 创建新的元数据选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置项目描述
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// 设置关键字
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// 设置项目主页
+    pub fn with_homepage(mut self, homepage: Option<String>) -> Self {
+        self.homepage = homepage;
+        self
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: Option<String>) -> Self {
+        self.license = license;
+        self
+    }
+
+    /// 设置 LICENSE 中的版权人
+    pub fn with_license_holder(mut self, license_holder: Option<String>) -> Self {
+        self.license_holder = license_holder;
+        self
+    }
+
+    /// 设置 LICENSE 中的版权年份
+    pub fn with_license_year(mut self, license_year: Option<String>) -> Self {
+        self.license_year = license_year;
+        self
+    }
+
+    /// 设置自定义许可证文本的路径
+    pub fn with_license_file(mut self, license_file: Option<PathBuf>) -> Self {
+        self.license_file = license_file;
+        self
+    }
+
+    /// 设置作者
+    pub fn with_author(mut self, author: Option<String>) -> Self {
+        self.author = author;
+        self
+    }
+
+    /// 设置作者邮箱
+    pub fn with_author_email(mut self, author_email: Option<String>) -> Self {
+        self.author_email = author_email;
+        self
+    }
+
+    /// 将元数据写入基础参数
+    fn apply_to(&self, base: &mut BaseParams, description: &str) {
+        base.project_description = Some(description.to_string());
+        base.keywords = self.keywords.clone();
+        base.homepage = self.homepage.clone();
+        if let Some(ref license) = self.license {
+            base.license = license.clone();
+        }
+        base.license_holder = self.license_holder.clone();
+        base.license_year = self.license_year.clone();
+        base.license_file = self.license_file.clone();
+        if let Some(ref author) = self.author {
+            base.author = Some(author.clone());
+        }
+        if let Some(ref author_email) = self.author_email {
+            base.author_email = Some(author_email.clone());
+        }
+    }
+}
+
+/// Go-Zero项目生成选项
+#[derive(Debug)]
+pub struct GoZeroProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
+    pub enable_precommit: Option<bool>,
+
+    // 语言级别选项 (Go)
+    pub go_version: Option<String>,
+    pub module_name: Option<String>,
+
+    // 框架级别选项 (Go-Zero)
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub grpc_port: Option<u16>,
+    pub enable_api: bool,
+    pub enable_rpc: bool,
+    pub enable_admin: bool,
+}
+
+impl Default for GoZeroProjectOptions {
+    fn default() -> Self {
+        Self {
+            description: None,
+            author: None,
+            license: None,
+            enable_git: None,
+            enable_precommit: None,
+            go_version: None,
+            module_name: None,
+            host: None,
+            port: None,
+            grpc_port: None,
+            enable_api: true,
+            enable_rpc: false,
+            enable_admin: false,
+        }
+    }
+}
+
+impl GoZeroProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.host = Some(host);
+        self.port = Some(port);
+        self
+    }
+
+    /// 设置 RPC 服务端口
+    pub fn with_grpc_port(mut self, grpc_port: u16) -> Self {
+        self.grpc_port = Some(grpc_port);
+        self
+    }
+
+    /// 设置要生成的服务（api、rpc、admin）
+    pub fn with_services(mut self, services: &[String]) -> Self {
+        let has = |name: &str| services.iter().any(|s| s == name);
+        self.enable_api = has("api");
+        self.enable_rpc = has("rpc");
+        self.enable_admin = has("admin");
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+}
+
+/// Gin项目生成选项
+#[derive(Debug, Default)]
+pub struct GinProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
+
+    // 语言级别选项 (Go)
+    pub go_version: Option<String>,
+    pub module_name: Option<String>,
+
+    // 框架级别选项 (Gin)
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub enable_swagger: Option<bool>,
+    pub enable_cors: Option<bool>,
+    pub cors_origins: Option<Vec<String>>,
+    pub cors_methods: Option<Vec<String>>,
+    pub enable_jwt: Option<bool>,
+    pub enable_precommit: Option<bool>,
+    pub enable_redis: Option<bool>,
+    /// 启用 gRPC 服务器时监听的端口
+    pub grpc_port: Option<u16>,
+    /// `--openapi` 规范中解析出的路由和模型
+    pub openapi: Option<OpenApiSpec>,
+    pub database: Option<Database>,
+    /// 访问 SQL 数据库的方式
+    pub orm: Orm,
+    /// 日志库
+    pub logger: Logger,
+}
+
+impl GinProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置项目描述
+    #[allow(dead_code)]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// 设置作者
+    #[allow(dead_code)]
+    pub fn with_author(mut self, author: String) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// 设置Go版本
+    #[allow(dead_code)]
+    pub fn with_go_version(mut self, version: String) -> Self {
+        self.go_version = Some(version);
+        self
+    }
+
+    /// 设置模块名称
+    #[allow(dead_code)]
+    pub fn with_module_name(mut self, module_name: String) -> Self {
+        self.module_name = Some(module_name);
+        self
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.host = Some(host);
+        self.port = Some(port);
+        self
+    }
+
+    /// 启用Swagger
+    pub fn with_swagger(mut self, enable: bool) -> Self {
+        self.enable_swagger = Some(enable);
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+
+    /// 启用CORS
+    pub fn with_cors(mut self, enable: bool) -> Self {
+        self.enable_cors = Some(enable);
+        self
+    }
+
+    /// 设置CORS允许的来源，None 表示使用默认值
+    pub fn with_cors_origins(mut self, origins: Option<Vec<String>>) -> Self {
+        self.cors_origins = origins;
+        self
+    }
+
+    /// 设置CORS允许的HTTP方法，None 表示使用默认值
+    pub fn with_cors_methods(mut self, methods: Option<Vec<String>>) -> Self {
+        self.cors_methods = methods;
+        self
+    }
+
+    /// 设置数据库，None 表示不使用数据库
+    pub fn with_database(mut self, database: Option<Database>) -> Self {
+        self.database = database;
+        self
+    }
+
+    /// 设置访问 SQL 数据库的方式
+    pub fn with_orm(mut self, orm: Orm) -> Self {
+        self.orm = orm;
+        self
+    }
+
+    /// 设置日志库
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// 启用Redis缓存
+    pub fn with_redis(mut self, enable: bool) -> Self {
+        self.enable_redis = Some(enable);
+        self
+    }
+
+    /// 启用 gRPC 服务器，None 表示不启用
+    pub fn with_grpc(mut self, grpc_port: Option<u16>) -> Self {
+        self.grpc_port = grpc_port;
+        self
+    }
+
+    /// 按 OpenAPI 规范生成路由、处理器和模型，None 表示不生成
+    pub fn with_openapi(mut self, openapi: Option<OpenApiSpec>) -> Self {
+        self.openapi = openapi;
+        self
+    }
+}
+
+/// Fiber项目生成选项
+#[derive(Debug, Default)]
+pub struct FiberProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
+
+    // 语言级别选项 (Go)
+    pub go_version: Option<String>,
+    pub module_name: Option<String>,
+
+    // 框架级别选项 (Fiber)
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub enable_swagger: Option<bool>,
+    pub enable_precommit: Option<bool>,
+    pub database: Option<Database>,
+}
+
+impl FiberProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.host = Some(host);
+        self.port = Some(port);
+        self
+    }
+
+    /// 启用Swagger
+    pub fn with_swagger(mut self, enable: bool) -> Self {
+        self.enable_swagger = Some(enable);
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+
+    /// 设置数据库，None 表示不使用数据库
+    pub fn with_database(mut self, database: Option<Database>) -> Self {
+        self.database = database;
+        self
+    }
+}
+
+/// Chi项目生成选项
+#[derive(Debug, Default)]
+pub struct ChiProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
+
+    // 语言级别选项 (Go)
+    pub go_version: Option<String>,
+    pub module_name: Option<String>,
+
+    // 框架级别选项 (Chi)
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub enable_precommit: Option<bool>,
+}
+
+impl ChiProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.host = Some(host);
+        self.port = Some(port);
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+}
+
+/// Django项目生成选项
+#[derive(Debug, Default)]
+pub struct DjangoProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
+
+    // 框架级别选项 (Django)
+    pub enable_drf: Option<bool>,
+    pub enable_precommit: Option<bool>,
+}
+
+impl DjangoProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        // 1. 环境预检查
-        println!("🔍 Checking environment prerequisites...");
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
 
-        // 检查 pnpm
-        if !ReactGenerator::check_pnpm()? {
-            return Err(anyhow::anyhow!(
-                "pnpm is not installed. Please install pnpm first:\n  npm install -g pnpm\n  or visit: https://pnpm.io/installation"
-            ));
-        }
-        println!("  ✅ pnpm: Available");
+    /// 启用 Django REST framework
+    pub fn with_drf(mut self, enable: bool) -> Self {
+        self.enable_drf = Some(enable);
+        self
+    }
 
-        // 2. 删除已存在的目录（如果存在）
-        if output_path.exists() {
-            std::fs::remove_dir_all(output_path).context("Failed to remove existing directory")?;
-        }
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+}
+
+/// Flask项目生成选项
+#[derive(Debug, Default)]
+pub struct FlaskProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
 
-        // 3. 使用 pnpm create vite 创建项目
-        ReactGenerator::create_react_project(&project_name, output_path)?;
+    // 框架级别选项 (Flask)
+    pub enable_precommit: Option<bool>,
+}
 
-        // 4. 安装前端依赖
-        ReactGenerator::install_dependencies(output_path)?;
+impl FlaskProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        // 5. 安装 Tailwind CSS
-        ReactGenerator::install_tailwind(output_path)?;
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
 
-        // 6. 安装 React Router
-        ReactGenerator::install_router(output_path)?;
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+}
 
-        // 7. 安装状态管理库 (默认使用 zustand)
-        ReactGenerator::install_state_management(output_path, "zustand")?;
+/// Axum项目生成选项
+#[derive(Debug, Default)]
+pub struct AxumProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
 
-        // 8. 创建项目参数
-        let project_params = ProjectParams::new(project_name.clone())
-            .with_license(license.clone())
-            .with_git(true)
-            .with_precommit(enable_precommit)
-            .with_description(format!("A React frontend application: {project_name}"));
+    // 语言级别选项 (Rust)
+    pub rust_version: Option<String>,
 
-        // 9. 创建 React 参数
-        let _react_params = ReactParams::from_project_name(project_name.clone())
-            .with_project(project_params.clone())
-            .with_precommit(enable_precommit);
+    // 框架级别选项 (Axum)
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub enable_precommit: Option<bool>,
+}
 
-        // 10. 项目级别生成 - 生成 LICENSE 等
-        self.project_generator
-            .generate(project_params, output_path)
-            .context("Failed to generate project files")?;
+impl AxumProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        println!("✅ React project generation completed successfully!");
-        println!("📁 Project created at: {}", output_path.display());
-        println!("\n📋 Next steps:");
-        println!("  cd {project_name}");
-        println!("  pnpm dev    # Start development server");
-        println!("  pnpm build  # Build for production");
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
 
-        Ok(())
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.host = Some(host);
+        self.port = Some(port);
+        self
     }
-}
 
-impl Default for GeneratorOrchestrator {
-    fn default() -> Self {
-        Self::new().expect("Failed to create GeneratorOrchestrator")
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
     }
 }
 
-/// Gin项目生成选项
+/// Actix-web项目生成选项
 #[derive(Debug, Default)]
-pub struct GinProjectOptions {
+pub struct ActixProjectOptions {
     // 项目级别选项
     pub description: Option<String>,
     pub author: Option<String>,
     pub license: Option<String>,
     pub enable_git: Option<bool>,
 
-    // 语言级别选项 (Go)
-    pub go_version: Option<String>,
-    pub module_name: Option<String>,
+    // 语言级别选项 (Rust)
+    pub rust_version: Option<String>,
 
-    // 框架级别选项 (Gin)
+    // 框架级别选项 (Actix-web)
     pub host: Option<String>,
     pub port: Option<u16>,
-    pub enable_swagger: Option<bool>,
-    pub enable_cors: Option<bool>,
-    pub enable_jwt: Option<bool>,
     pub enable_precommit: Option<bool>,
-    pub enable_redis: Option<bool>,
-    pub database_type: Option<String>,
 }
 
-impl GinProjectOptions {
+impl ActixProjectOptions {
     /// 创建新的选项
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// 设置项目描述
-    #[allow(dead_code)]
-    pub fn with_description(mut self, description: String) -> Self {
-        self.description = Some(description);
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
         self
     }
 
-    /// 设置作者
-    #[allow(dead_code)]
-    pub fn with_author(mut self, author: String) -> Self {
-        self.author = Some(author);
+    /// 设置服务器配置
+    pub fn with_server(mut self, host: String, port: u16) -> Self {
+        self.host = Some(host);
+        self.port = Some(port);
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+}
+
+/// Rust CLI项目生成选项
+#[derive(Debug, Default)]
+pub struct CliProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    pub enable_git: Option<bool>,
+
+    // 语言级别选项 (Rust)
+    pub rust_version: Option<String>,
+
+    // 框架级别选项 (CLI)
+    pub enable_precommit: Option<bool>,
+}
+
+impl CliProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
         self
     }
+}
+
+/// Nuxt项目生成选项
+#[derive(Debug, Default)]
+pub struct NuxtProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub license: Option<String>,
+
+    // 框架级别选项 (Nuxt)
+    pub enable_precommit: Option<bool>,
+    /// 要安装的模块（tailwind、eslint、pinia），未指定时全部安装
+    pub modules: Option<Vec<String>>,
+}
+
+impl NuxtProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
 
     /// 设置许可证
     pub fn with_license(mut self, license: String) -> Self {
@@ -521,17 +2828,80 @@ impl GinProjectOptions {
         self
     }
 
-    /// 设置Go版本
-    #[allow(dead_code)]
-    pub fn with_go_version(mut self, version: String) -> Self {
-        self.go_version = Some(version);
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
         self
     }
 
-    /// 设置模块名称
-    #[allow(dead_code)]
-    pub fn with_module_name(mut self, module_name: String) -> Self {
-        self.module_name = Some(module_name);
+    /// 设置要安装的模块
+    pub fn with_modules(mut self, modules: Vec<String>) -> Self {
+        self.modules = Some(modules);
+        self
+    }
+}
+
+/// SvelteKit项目生成选项
+#[derive(Debug, Default)]
+pub struct SvelteKitProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub license: Option<String>,
+
+    // 框架级别选项 (SvelteKit)
+    pub enable_precommit: Option<bool>,
+    /// 启用的选项（typescript、tailwind、playwright），未指定时全部启用
+    pub options: Option<Vec<String>>,
+}
+
+impl SvelteKitProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
+        self
+    }
+
+    /// 设置启用的选项
+    pub fn with_options(mut self, options: Vec<String>) -> Self {
+        self.options = Some(options);
+        self
+    }
+}
+
+/// NestJS项目生成选项
+#[derive(Debug, Default)]
+pub struct NestJsProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub license: Option<String>,
+
+    // 框架级别选项 (NestJS)
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub enable_swagger: Option<bool>,
+    pub enable_precommit: Option<bool>,
+}
+
+impl NestJsProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
         self
     }
 
@@ -542,7 +2912,7 @@ impl GinProjectOptions {
         self
     }
 
-    /// 启用Swagger
+    /// 设置是否生成 Swagger 文档
     pub fn with_swagger(mut self, enable: bool) -> Self {
         self.enable_swagger = Some(enable);
         self
@@ -553,11 +2923,58 @@ impl GinProjectOptions {
         self.enable_precommit = Some(enable);
         self
     }
+}
 
-    /// 启用数据库
-    #[allow(dead_code)]
-    pub fn with_database(mut self, db_type: String) -> Self {
-        self.database_type = Some(db_type);
+/// Astro项目生成选项
+#[derive(Debug, Default)]
+pub struct AstroProjectOptions {
+    // 项目级别选项
+    pub description: Option<String>,
+    pub license: Option<String>,
+
+    // 框架级别选项 (Astro)
+    pub enable_precommit: Option<bool>,
+}
+
+impl AstroProjectOptions {
+    /// 创建新的选项
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置许可证
+    pub fn with_license(mut self, license: String) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    /// 启用pre-commit
+    pub fn with_precommit(mut self, enable: bool) -> Self {
+        self.enable_precommit = Some(enable);
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn test_orchestrator_is_shareable() {
+        assert_send_sync::<GeneratorOrchestrator>();
+
+        // 异步生成的 future 可以交给多线程运行时执行
+        let orchestrator = std::sync::Arc::new(GeneratorOrchestrator::new().unwrap());
+        let future = orchestrator.generate_python_project(
+            "demo".to_string(),
+            Path::new("demo"),
+            "MIT".to_string(),
+            false,
+        );
+        assert_send(&future);
+    }
+}
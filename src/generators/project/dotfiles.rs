@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::{Framework, Language};
+use crate::generators::core::{BaseParams, InheritableParams};
+
+/// 项目根目录的通用配置文件，模板已提供的文件保持不变
+pub const DOTFILES: [&str; 2] = [".gitignore", ".editorconfig"];
+
+/// `.gitignore` 和 `.editorconfig` 生成参数，按语言和框架选择忽略规则与缩进
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DotfileParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 项目语言，未知时只生成通用规则
+    pub language: Option<Language>,
+    /// 项目框架
+    pub framework: Framework,
+}
+
+impl Default for DotfileParams {
+    fn default() -> Self {
+        Self::new(String::new(), None, Framework::None)
+    }
+}
+
+impl InheritableParams for DotfileParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            ..Self::default()
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        let language = self.language.map(|language| language.as_lowercase());
+        // Tauri 的前端与 TypeScript 项目共用 node_modules 等忽略规则
+        let uses_node = language == Some(Language::TypeScript.as_lowercase())
+            || self.framework == Framework::Tauri;
+        HashMap::from([
+            ("language".to_string(), json!(language.unwrap_or_default())),
+            (
+                "framework".to_string(),
+                json!(self.framework.as_lowercase()),
+            ),
+            ("uses_node".to_string(), json!(uses_node)),
+        ])
+    }
+}
+
+impl DotfileParams {
+    /// 为指定项目创建参数，`language` 为 `None` 时只包含编辑器、系统和环境变量等通用规则
+    pub fn new(project_name: String, language: Option<Language>, framework: Framework) -> Self {
+        let base = BaseParams {
+            project_name,
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            language,
+            framework,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::core::Parameters;
+
+    #[test]
+    fn test_tauri_uses_node_rules() {
+        let context = DotfileParams::new("app".to_string(), Some(Language::Rust), Framework::Tauri)
+            .to_template_context();
+        assert_eq!(context["language"], json!("rust"));
+        assert_eq!(context["framework"], json!("tauri"));
+        assert_eq!(context["uses_node"], json!(true));
+
+        let context = DotfileParams::default().to_template_context();
+        assert_eq!(context["language"], json!(""));
+        assert_eq!(context["uses_node"], json!(false));
+    }
+}
@@ -1,11 +1,18 @@
 use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::Path;
 use std::process::Command;
 
+use super::dotfiles::{DOTFILES, DotfileParams};
 use super::parameters::ProjectParams;
+use super::tasks::TaskParams;
+use crate::constants::{NO_LICENSE, TaskRunner};
 use crate::generators::core::{
-    Generator, Parameters, ProjectGenerator as ProjectGeneratorTrait, TemplateProcessor,
+    Generator, NextSteps, Parameters, ProjectGenerator as ProjectGeneratorTrait, TemplateProcessor,
 };
+use crate::status;
+use crate::utils::{git_hooks, git_identity, render_only, spdx, tool_failures, tool_install};
 
 /// 项目级别生成器实现
 pub struct ProjectGenerator {
@@ -20,22 +27,112 @@ impl ProjectGenerator {
         })
     }
 
-    /// 获取Git作者信息
-    fn get_git_author(&self) -> Result<String> {
-        let output = Command::new("git")
-            .args(["config", "--global", "user.name"])
-            .output()
-            .context("Failed to get git author name")?;
-
-        if output.status.success() {
-            let author = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            if !author.is_empty() {
-                return Ok(author);
-            }
+    /// 获取Git作者信息，渲染模式或未配置时为 `Unknown`
+    fn get_git_author(&self) -> String {
+        git_identity::user_name().unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    /// 许可证对应的内嵌模板，`GPL-3.0-only`、`GPL-3.0-or-later` 等写法使用同一份 `GPL-3.0` 文本
+    fn license_template(&self, license: &str) -> Option<String> {
+        [
+            license,
+            license.trim_end_matches("-only"),
+            license.trim_end_matches("-or-later"),
+        ]
+        .into_iter()
+        .map(|id| format!("licenses/{id}.tmpl"))
+        .find(|path| self.template_processor.template_exists(path))
+    }
+
+    /// 写入单个许可证的文本：内嵌模板离线可用，其余 SPDX 许可证下载后缓存
+    fn write_license(
+        &self,
+        id: &str,
+        license_file: &Path,
+        context: &HashMap<String, Value>,
+        holder: &str,
+    ) -> Result<()> {
+        if let Some(license_template) = self.license_template(id) {
+            let template_path = self
+                .template_processor
+                .get_template_path(&license_template)
+                .context("Failed to get license template path")?;
+            return self
+                .template_processor
+                .process_template_file(&template_path, license_file, context.clone())
+                .with_context(|| format!("Failed to generate {}", license_file.display()));
+        }
+
+        let Some(text) = spdx::license_text(id)? else {
+            return Ok(());
+        };
+        let year = context["copyright_year"].as_str().unwrap_or_default();
+        std::fs::write(license_file, spdx::fill_copyright(&text, year, holder))
+            .with_context(|| format!("Failed to generate {}", license_file.display()))
+    }
+
+    /// 生成统一 `build/test/lint/run/fmt` 任务的 Makefile 或 justfile，模板已提供时保持不变
+    pub fn generate_task_runner(&self, params: &TaskParams, output_path: &Path) -> Result<()> {
+        let file_name = params.runner.file_name();
+        // just 也识别首字母大写的 Justfile
+        let existing = match params.runner {
+            TaskRunner::Make => ["Makefile", "makefile"],
+            TaskRunner::Just => ["justfile", "Justfile"],
+        };
+        if existing.iter().any(|name| output_path.join(name).exists()) {
+            status!("Keeping the {file_name} provided by the project template");
+            return Ok(());
         }
 
-        // 如果Git配置不存在，返回默认值
-        Ok("Unknown".to_string())
+        self.template_processor
+            .process_embedded_template_directory_filtered(
+                "tasks",
+                output_path,
+                params.to_template_context(),
+                |relative_path| relative_path.strip_suffix(".tmpl") == Some(file_name),
+            )
+            .with_context(|| format!("Failed to generate {file_name}"))?;
+        status!("Generated {file_name}");
+        Ok(())
+    }
+
+    /// 按语言和框架生成 `.gitignore` 和 `.editorconfig`，框架模板或脚手架工具已生成的文件保持不变
+    pub fn generate_dotfiles(&self, params: &DotfileParams, output_path: &Path) -> Result<()> {
+        let missing: Vec<&str> = DOTFILES
+            .into_iter()
+            .filter(|name| !output_path.join(name).exists())
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        self.template_processor
+            .process_embedded_template_directory_filtered(
+                "dotfiles",
+                output_path,
+                params.to_template_context(),
+                |relative_path| {
+                    relative_path
+                        .strip_suffix(".tmpl")
+                        .is_some_and(|name| missing.contains(&name))
+                },
+            )
+            .context("Failed to generate .gitignore and .editorconfig")?;
+        status!("Generated {}", missing.join(" and "));
+        Ok(())
+    }
+
+    /// 任务入口的下一步提示
+    pub fn task_runner_next_steps(&self, params: &TaskParams, steps: &mut NextSteps) {
+        match params.runner {
+            TaskRunner::Make => {
+                steps.command("make help", "List the shared build/test/lint/run/fmt tasks")
+            }
+            TaskRunner::Just => steps.command(
+                "just --list",
+                "List the shared build/test/lint/run/fmt tasks",
+            ),
+        }
     }
 }
 
@@ -60,12 +157,20 @@ impl Generator for ProjectGenerator {
         "project"
     }
 
-    fn generate(&mut self, params: Self::Params, output_path: &Path) -> Result<()> {
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
         params.validate()?;
 
         // 生成LICENSE文件
         self.generate_license(&params, output_path)?;
 
+        // 生成 .gitignore 和 .editorconfig
+        self.generate_dotfiles(&params.dotfile_params(), output_path)?;
+
+        // 框架模板没有提供 README 时按语言和框架生成
+        if params.readme || !output_path.join("README.md").exists() {
+            self.generate_readme(&params, output_path)?;
+        }
+
         // 初始化Git仓库
         if params.enable_git() {
             self.init_git_repository(output_path)?;
@@ -78,119 +183,125 @@ impl Generator for ProjectGenerator {
 
         Ok(())
     }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        if params.enable_precommit() {
+            steps.command("pre-commit run --all-files", "Run the lint hooks");
+        }
+    }
 }
 
 impl ProjectGeneratorTrait for ProjectGenerator {
-    fn generate_license(&mut self, params: &Self::Params, output_path: &Path) -> Result<()> {
-        let license_template = format!("licenses/{}.tmpl", params.license());
-
-        if !self.template_processor.template_exists(&license_template) {
-            return Err(anyhow::anyhow!(
-                "License template not found: {}",
-                params.license()
-            ));
+    fn generate_license(&self, params: &Self::Params, output_path: &Path) -> Result<()> {
+        let license = params.license();
+        if license == NO_LICENSE {
+            return Ok(());
         }
 
-        let template_path = self
-            .template_processor
-            .get_template_path(&license_template)
-            .context("Failed to get license template path")?;
-
-        let license_file = output_path.join("LICENSE");
         let mut context = params.to_template_context();
 
-        // 如果参数中没有作者信息，尝试从Git获取
-        if params.author().is_none()
-            && let Ok(git_author) = self.get_git_author()
-        {
-            context.insert("author".to_string(), serde_json::json!(git_author));
+        // 版权人依次使用 --license-holder、作者（有邮箱时为 `Name <email>`）和 git 用户名
+        let holder = match (&params.base.license_holder, params.base.author_contact()) {
+            (Some(holder), _) => holder.clone(),
+            (None, Some(author)) => author,
+            (None, None) => self.get_git_author(),
+        };
+        context.insert("author".to_string(), serde_json::json!(holder));
+
+        // 自定义许可证文本原样复制为 LICENSE，只填入开头的年份和版权人占位符
+        if let Some(ref license_file) = params.base.license_file {
+            let text = std::fs::read_to_string(license_file)
+                .with_context(|| format!("Failed to read {}", license_file.display()))?;
+            let year = context["copyright_year"].as_str().unwrap_or_default();
+            return std::fs::write(
+                output_path.join("LICENSE"),
+                spdx::fill_copyright(&text, year, &holder),
+            )
+            .context("Failed to generate LICENSE");
         }
 
-        let mut template_processor =
-            TemplateProcessor::new().context("Failed to create template processor")?;
-
-        template_processor
-            .process_template_file(&template_path, &license_file, context)
-            .context("Failed to generate LICENSE file")?;
-
+        // 组合许可证按 Rust 惯例生成 LICENSE-MIT、LICENSE-APACHE 等多个文件
+        for (id, file_name) in spdx::license_files(license) {
+            self.write_license(id, &output_path.join(file_name), &context, &holder)?;
+        }
         Ok(())
     }
 
-    fn init_git_repository(&mut self, output_path: &Path) -> Result<()> {
-        let status = Command::new("git")
+    fn init_git_repository(&self, output_path: &Path) -> Result<()> {
+        if render_only::skip_tool("git init") {
+            return Ok(());
+        }
+
+        let output = Command::new("git")
             .args(["init"])
             .current_dir(output_path)
-            .status();
+            .output();
 
-        match status {
-            Ok(status) if status.success() => {
-                println!("Initialized Git repository");
-                Ok(())
-            }
-            _ => {
-                println!("⚠️  Warning: Failed to initialize Git repository");
+        match output {
+            Ok(output) if output.status.success() => {
+                status!("Initialized Git repository");
                 Ok(())
             }
+            Ok(output) => tool_failures::report(format!(
+                "Failed to initialize Git repository: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )),
+            Err(e) => tool_failures::report(format!("Failed to initialize Git repository: {e}")),
         }
     }
 
-    fn generate_readme(&mut self, params: &Self::Params, output_path: &Path) -> Result<()> {
-        let readme_template = "README.md.tmpl";
-
-        if !self.template_processor.template_exists(readme_template) {
-            // 如果没有模板，创建基础 README
-            let readme_content = format!(
-                "# {}\n\n{}\n\n## Author\n\n{}\n\n## License\n\n{}\n",
-                params.name(),
-                params
-                    .description()
-                    .as_deref()
-                    .unwrap_or("No description provided"),
-                params.author().as_deref().unwrap_or("Unknown"),
-                params.license()
-            );
-
-            let readme_file = output_path.join("README.md");
-            std::fs::write(&readme_file, readme_content)
-                .context("Failed to write README.md file")?;
-        } else {
-            let template_path = self.template_processor.get_template_path(readme_template)?;
-            let readme_file = output_path.join("README.md");
-            let context = params.to_template_context();
-
-            let mut template_processor = TemplateProcessor::new()?;
-            template_processor
-                .process_template_file(&template_path, &readme_file, context)
-                .context("Failed to generate README.md file")?;
-        }
-
-        println!("Generated README.md file");
+    fn generate_readme(&self, params: &Self::Params, output_path: &Path) -> Result<()> {
+        // 命令按已生成的 package.json 等文件确定，与任务入口保持一致
+        let readme = params.readme_params().detect_from(output_path);
+        self.template_processor
+            .process_embedded_template_directory(
+                "readme",
+                output_path,
+                readme.to_template_context(),
+            )
+            .context("Failed to generate README.md file")?;
+
+        status!("Generated README.md file");
         Ok(())
     }
 
-    fn install_precommit(&mut self, output_path: &Path) -> Result<()> {
+    fn install_precommit(&self, output_path: &Path) -> Result<()> {
         // 检查是否存在 .pre-commit-config.yaml 文件
-        let precommit_config = output_path.join(".pre-commit-config.yaml");
+        let precommit_config = output_path.join(git_hooks::PRECOMMIT_CONFIG);
         if !precommit_config.exists() {
-            println!("No .pre-commit-config.yaml found, skipping pre-commit installation");
+            status!("No .pre-commit-config.yaml found, skipping pre-commit installation");
             return Ok(());
         }
 
+        if render_only::skip_tool("pre-commit install") {
+            return Ok(());
+        }
+
+        // 没有 pre-commit 时先尝试用 uv 或 pipx 安装，仍不可用时写入等价的 git hook 脚本，避免检查悄悄失效
+        if which::which("pre-commit").is_err() && !tool_install::ensure_python_tool("pre-commit") {
+            return git_hooks::install_fallback(output_path);
+        }
+
         // 尝试安装 pre-commit hooks
-        let status = Command::new("pre-commit")
+        let output = Command::new("pre-commit")
             .args(["install"])
             .current_dir(output_path)
-            .status();
+            .output();
 
-        match status {
-            Ok(status) if status.success() => {
-                println!("Pre-commit hooks installed");
+        match output {
+            Ok(output) if output.status.success() => {
+                status!("Pre-commit hooks installed");
+            }
+            Ok(output) => {
+                tool_failures::report(format!(
+                    "Failed to install pre-commit hooks: {}\n   Run `pre-commit install` manually",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ))?;
             }
-            _ => {
-                println!(
-                    "⚠️  Warning: Failed to install pre-commit hooks, you may need to install them manually"
-                );
-                println!("   Run: pre-commit install");
+            Err(e) => {
+                tool_failures::report(format!(
+                    "Failed to install pre-commit hooks: {e}\n   Run `pre-commit install` manually"
+                ))?;
             }
         }
 
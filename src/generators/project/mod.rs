@@ -1,6 +1,12 @@
+pub mod dotfiles;
 pub mod generator;
 pub mod parameters;
+pub mod readme;
+pub mod tasks;
 
 // 明确导出具体类型，避免通配符导入
+pub use dotfiles::DotfileParams;
 pub use generator::ProjectGenerator;
 pub use parameters::ProjectParams;
+pub use readme::ReadmeParams;
+pub use tasks::TaskParams;
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use super::dotfiles::DotfileParams;
+use super::readme::ReadmeParams;
+use crate::constants::{Framework, Language, Runtime};
 use crate::generators::core::{BaseParams, InheritableParams};
 
 /// 项目级别参数 - 现在继承自BaseParams
@@ -7,6 +10,15 @@ use crate::generators::core::{BaseParams, InheritableParams};
 pub struct ProjectParams {
     /// 基础参数
     pub base: BaseParams,
+    /// 项目的语言和框架，决定 `.gitignore` 和 `.editorconfig` 的内容
+    #[serde(default)]
+    pub stack: Option<(Language, Framework)>,
+    /// JavaScript 运行时，决定 README 中 TypeScript 项目的命令
+    #[serde(default)]
+    pub runtime: Runtime,
+    /// 是否用 README 模板替换脚手架工具生成的 README.md，框架模板自带的 README 保持不变
+    #[serde(default)]
+    pub readme: bool,
 }
 
 impl InheritableParams for ProjectParams {
@@ -19,7 +31,10 @@ impl InheritableParams for ProjectParams {
     }
 
     fn from_base(base: BaseParams) -> Self {
-        Self { base }
+        Self {
+            base,
+            ..Self::default()
+        }
     }
 
     // ProjectParams没有额外的参数，所以不需要重写extended_template_context
@@ -33,7 +48,10 @@ impl ProjectParams {
         base.enable_git = true;
         base.enable_precommit = false;
 
-        Self { base }
+        Self {
+            base,
+            ..Self::default()
+        }
     }
 
     /// 从项目名称创建
@@ -53,12 +71,62 @@ impl ProjectParams {
         self
     }
 
-    /// 设置许可证
+    /// 设置许可证，可以是 `MIT OR Apache-2.0` 等组合表达式
     pub fn with_license(mut self, license: String) -> Self {
         self.base = self.base.with_license(license);
         self
     }
 
+    /// 设置关键字
+    #[allow(dead_code)]
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.base = self.base.with_keywords(keywords);
+        self
+    }
+
+    /// 设置项目主页
+    #[allow(dead_code)]
+    pub fn with_homepage(mut self, homepage: String) -> Self {
+        self.base = self.base.with_homepage(homepage);
+        self
+    }
+
+    /// 设置项目的语言和框架
+    pub fn with_stack(mut self, language: Language, framework: Framework) -> Self {
+        self.stack = Some((language, framework));
+        self
+    }
+
+    /// `.gitignore` 和 `.editorconfig` 的生成参数
+    pub fn dotfile_params(&self) -> DotfileParams {
+        let (language, framework) = match self.stack {
+            Some((language, framework)) => (Some(language), framework),
+            None => (None, Framework::None),
+        };
+        DotfileParams::new(self.base.project_name.clone(), language, framework)
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// 设置是否用 README 模板替换已有的 README.md
+    pub fn with_readme(mut self, readme: bool) -> Self {
+        self.readme = readme;
+        self
+    }
+
+    /// `README.md` 的生成参数
+    pub fn readme_params(&self) -> ReadmeParams {
+        let (language, framework) = match self.stack {
+            Some((language, framework)) => (Some(language), framework),
+            None => (None, Framework::None),
+        };
+        ReadmeParams::new(self.base.clone(), language, framework, self.runtime)
+    }
+
     /// 设置是否启用Git
     pub fn with_git(mut self, enable_git: bool) -> Self {
         self.base.enable_git = enable_git;
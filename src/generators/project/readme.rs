@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::tasks::{Task, TaskParams};
+use crate::constants::{Framework, Language, Runtime, TaskRunner};
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::utils::{spdx, vitest};
+
+/// 使用 Vitest 覆盖率门槛的框架，CI 中会运行 `test:coverage`
+const COVERAGE_FRAMEWORKS: [Framework; 2] = [Framework::Vue3, Framework::React];
+
+/// `README.md` 生成参数：按语言和框架给出运行、构建和测试命令以及徽章
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadmeParams {
+    /// 基础参数，包含项目描述、许可证和 Docker、CI 等功能开关
+    pub base: BaseParams,
+    /// 项目语言，未知时只列出项目信息和许可证
+    pub language: Option<Language>,
+    /// 项目框架，决定简介和功能列表
+    pub framework: Framework,
+    /// JavaScript 运行时，决定 TypeScript 项目的安装和脚本命令
+    pub runtime: Runtime,
+    /// 与 Makefile、justfile 一致的 `build/test/lint/run/fmt` 命令
+    pub tasks: Vec<Task>,
+}
+
+impl Default for ReadmeParams {
+    fn default() -> Self {
+        Self::new(
+            BaseParams::default(),
+            None,
+            Framework::None,
+            Runtime::default(),
+        )
+    }
+}
+
+impl InheritableParams for ReadmeParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            ..Self::default()
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        let tasks: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|task| task.command.is_some())
+            .collect();
+        HashMap::from([
+            (
+                "language".to_string(),
+                json!(
+                    self.language
+                        .map(|language| language.as_lowercase())
+                        .unwrap_or_default()
+                ),
+            ),
+            (
+                "framework".to_string(),
+                json!(self.framework.as_lowercase()),
+            ),
+            ("runtime".to_string(), json!(self.runtime.as_str())),
+            (
+                "package_manager".to_string(),
+                json!(self.runtime.package_manager()),
+            ),
+            ("tasks".to_string(), json!(tasks)),
+            ("badges".to_string(), json!(self.badges())),
+            (
+                "ci_config".to_string(),
+                json!(self.base.ci_provider.config_path()),
+            ),
+        ])
+    }
+}
+
+impl ReadmeParams {
+    /// 为指定项目创建参数，命令先按框架的默认脚本确定，生成后可用 [`Self::detect_from`] 更新
+    pub fn new(
+        base: BaseParams,
+        language: Option<Language>,
+        framework: Framework,
+        runtime: Runtime,
+    ) -> Self {
+        let mut params = Self {
+            base,
+            language,
+            framework,
+            runtime,
+            tasks: Vec::new(),
+        };
+        params.tasks = params
+            .task_params()
+            .map(|tasks| tasks.tasks())
+            .unwrap_or_default();
+        params
+    }
+
+    /// 根据已生成的 package.json 等文件确定实际可用的命令
+    pub fn detect_from(mut self, output_path: &Path) -> Self {
+        if let Some(tasks) = self.task_params() {
+            self.tasks = tasks.detect_from(output_path).tasks();
+        }
+        self
+    }
+
+    /// 复用任务入口的命令，README 与 Makefile、justfile 保持一致
+    fn task_params(&self) -> Option<TaskParams> {
+        let language = self.language?;
+        Some(
+            TaskParams::new(
+                self.base.project_name.clone(),
+                TaskRunner::Make,
+                language,
+                self.framework,
+            )
+            .with_runtime(self.runtime),
+        )
+    }
+
+    /// 标题下方的徽章：CI 状态、覆盖率和许可证
+    fn badges(&self) -> Vec<String> {
+        let project_name = &self.base.project_name;
+        let mut badges = Vec::new();
+        if self.base.enable_ci {
+            badges.push(self.base.ci_provider.badge(project_name));
+            if self.base.enable_tests && COVERAGE_FRAMEWORKS.contains(&self.framework) {
+                badges.push(vitest::coverage_badge(self.base.ci_provider, project_name));
+            }
+        }
+        // shields.io 中 `-` 是分隔符、`_` 表示空格，许可证名称中的 `-` 和 `_` 需要转义
+        let license = &self.base.license;
+        if let Some((_, file)) = spdx::license_files(license).first() {
+            badges.push(format!(
+                "[![License: {license}](https://img.shields.io/badge/license-{}-blue.svg)]({file})",
+                license
+                    .replace('-', "--")
+                    .replace('_', "__")
+                    .replace(' ', "_")
+            ));
+        }
+        badges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::CiProvider;
+    use crate::generators::core::Parameters;
+
+    #[test]
+    fn test_readme_follows_stack() {
+        let mut base = BaseParams::new("web".to_string()).with_license("Apache-2.0".to_string());
+        base.enable_ci = true;
+        base.enable_tests = true;
+        base.ci_provider = CiProvider::Gitlab;
+        let context = ReadmeParams::new(
+            base,
+            Some(Language::TypeScript),
+            Framework::Vue3,
+            Runtime::Bun,
+        )
+        .to_template_context();
+
+        assert_eq!(context["framework"], json!("vue3"));
+        assert_eq!(context["package_manager"], json!("bun"));
+        assert_eq!(context["ci_config"], json!(".gitlab-ci.yml"));
+        assert_eq!(context["tasks"][0]["command"], json!("bun run build"));
+        let badges = context["badges"].as_array().unwrap();
+        assert_eq!(badges.len(), 3);
+        assert!(
+            badges[0]
+                .as_str()
+                .unwrap()
+                .contains("/web/badges/main/pipeline.svg")
+        );
+        assert!(
+            badges[2]
+                .as_str()
+                .unwrap()
+                .contains("license-Apache--2.0-blue")
+        );
+
+        let context = ReadmeParams::default().to_template_context();
+        assert_eq!(context["tasks"], json!([]));
+        assert_eq!(context["badges"].as_array().unwrap().len(), 1);
+
+        // 组合许可证的徽章链接到第一个许可证文件
+        let base = BaseParams::new("web".to_string()).with_license("MIT/Apache-2.0".to_string());
+        let context = ReadmeParams::new(base, None, Framework::None, Runtime::default())
+            .to_template_context();
+        assert!(
+            context["badges"][0]
+                .as_str()
+                .unwrap()
+                .ends_with("license-MIT_OR_Apache--2.0-blue.svg)](LICENSE-MIT)")
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use crate::constants::{Framework, Language, Runtime, TaskRunner};
+use crate::generators::core::{BaseParams, InheritableParams};
+
+/// 每个项目都提供的任务，顺序即 Makefile 和 justfile 中的顺序
+const TASK_NAMES: [&str; 5] = ["build", "test", "lint", "run", "fmt"];
+/// 读不到 package.json 时（如只渲染模板）假定存在的脚本
+const DEFAULT_SCRIPTS: &[&str] = &["build", "dev", "lint", "test", "format"];
+/// go-zero 项目中按顺序查找的服务目录，`run` 启动第一个存在的服务
+const GO_ZERO_SERVICES: &[&str] = &["api", "admin", "rpc"];
+/// 重新生成 gRPC 代码的入口及其命令：buf 配置（Gin 的 --grpc）或 go-zero 的 rpc 生成脚本
+const PROTO_GENERATORS: [(&str, &str); 2] = [
+    ("buf.gen.yaml", "buf generate"),
+    ("scripts/gen-rpc.sh", "./scripts/gen-rpc.sh"),
+];
+/// 数据库迁移脚本，Gin 和 Fiber 项目使用 SQL 数据库时生成
+const MIGRATE_SCRIPT: &str = "scripts/migrate.sh";
+/// ent 客户端的代码生成入口，Gin 项目使用 `--orm ent` 时生成
+const ENT_GENERATE: &str = "ent/generate.go";
+
+/// 任务入口中的一个任务，没有对应命令的任务在运行时报错退出
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Task {
+    /// 任务名，如 `build`
+    pub name: String,
+    /// 任务说明，写入注释和帮助信息
+    pub description: String,
+    /// 任务执行的命令，项目尚未配置对应工具时为 `None`
+    pub command: Option<String>,
+}
+
+/// 任务入口生成参数：所有语言共用 `build/test/lint/run/fmt` 这组任务名
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskParams {
+    /// 基础参数
+    pub base: BaseParams,
+    /// 生成 Makefile 还是 justfile
+    pub runner: TaskRunner,
+    /// 项目语言
+    pub language: Language,
+    /// 项目框架
+    pub framework: Framework,
+    /// JavaScript 运行时，决定 TypeScript 项目的脚本命令
+    pub runtime: Runtime,
+    /// package.json 中的脚本，未读取时使用 [`DEFAULT_SCRIPTS`]
+    pub scripts: BTreeSet<String>,
+    /// package.json 中的依赖名，用于判断能否直接运行 eslint 和 prettier
+    pub packages: BTreeSet<String>,
+    /// 项目是否有 `tests/` 目录（Python 项目）
+    pub has_tests: bool,
+    /// go-zero 项目中 `run` 启动的服务目录
+    pub go_zero_service: String,
+    /// 重新生成 gRPC 代码的命令，项目包含 protobuf 定义时追加 `proto` 任务
+    pub proto_command: Option<String>,
+    /// 项目是否有数据库迁移脚本，有时追加 `migrate` 和 `migrate-down` 任务
+    pub has_migrations: bool,
+    /// 项目是否使用 ent，有时追加重新生成 ent 客户端的 `ent` 任务
+    pub has_ent: bool,
+}
+
+impl Default for TaskParams {
+    fn default() -> Self {
+        Self::new(
+            String::new(),
+            TaskRunner::Make,
+            Language::Go,
+            Framework::Gin,
+        )
+    }
+}
+
+impl InheritableParams for TaskParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            ..Self::default()
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        HashMap::from([("tasks".to_string(), json!(self.tasks()))])
+    }
+}
+
+impl TaskParams {
+    /// 为指定项目创建任务参数
+    pub fn new(
+        project_name: String,
+        runner: TaskRunner,
+        language: Language,
+        framework: Framework,
+    ) -> Self {
+        // 任务入口只需项目名，不带 module_name 等源码层面的参数
+        let base = BaseParams {
+            project_name,
+            ..Default::default()
+        };
+
+        Self {
+            base,
+            runner,
+            language,
+            framework,
+            runtime: Runtime::default(),
+            scripts: DEFAULT_SCRIPTS
+                .iter()
+                .map(|script| script.to_string())
+                .collect(),
+            packages: BTreeSet::new(),
+            has_tests: true,
+            go_zero_service: GO_ZERO_SERVICES[0].to_string(),
+            proto_command: None,
+            has_migrations: false,
+            has_ent: false,
+        }
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// 根据已生成的项目文件确定可用的脚本、测试目录、go-zero 服务、gRPC 和 ent 代码生成命令以及数据库迁移脚本
+    pub fn detect_from(mut self, output_path: &Path) -> Self {
+        self.has_tests = output_path.join("tests").is_dir();
+        self.proto_command = PROTO_GENERATORS
+            .iter()
+            .find(|(file, _)| output_path.join(file).is_file())
+            .map(|(_, command)| command.to_string());
+        self.has_migrations = output_path.join(MIGRATE_SCRIPT).is_file();
+        self.has_ent = output_path.join(ENT_GENERATE).is_file();
+        if let Some(service) = GO_ZERO_SERVICES
+            .iter()
+            .find(|service| output_path.join(service).is_dir())
+        {
+            self.go_zero_service = service.to_string();
+        }
+
+        let package: Option<Value> = std::fs::read_to_string(output_path.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+        if let Some(package) = package {
+            let keys = |field: &str| -> BTreeSet<String> {
+                package[field]
+                    .as_object()
+                    .map(|object| object.keys().cloned().collect())
+                    .unwrap_or_default()
+            };
+            self.scripts = keys("scripts");
+            self.packages = keys("dependencies");
+            self.packages.extend(keys("devDependencies"));
+        }
+        self
+    }
+
+    /// 按 [`TASK_NAMES`] 的顺序列出任务及其命令，有 protobuf 定义时追加 `proto`，使用 ent 时追加 `ent`，
+    /// 有迁移脚本时最后追加 `migrate` 和 `migrate-down`
+    pub fn tasks(&self) -> Vec<Task> {
+        let [build, test, lint, run, fmt] = self.commands();
+        let descriptions = [
+            "Build the project",
+            "Run the tests",
+            "Run the linters",
+            "Run the application",
+            "Format the code",
+        ];
+        TASK_NAMES
+            .iter()
+            .zip(descriptions)
+            .zip([build, test, lint, run, fmt])
+            .map(|((name, description), command)| Task {
+                name: name.to_string(),
+                description: description.to_string(),
+                command,
+            })
+            .chain(self.proto_command.as_ref().map(|command| Task {
+                name: "proto".to_string(),
+                description: "Generate gRPC code from the protobuf definitions".to_string(),
+                command: Some(command.clone()),
+            }))
+            .chain(self.has_ent.then(|| Task {
+                name: "ent".to_string(),
+                description: "Regenerate the ent client from ent/schema".to_string(),
+                command: Some("go generate ./ent".to_string()),
+            }))
+            .chain(self.migration_tasks())
+            .collect()
+    }
+
+    fn migration_tasks(&self) -> Vec<Task> {
+        if !self.has_migrations {
+            return Vec::new();
+        }
+        let task = |name: &str, description: &str, args: &str| Task {
+            name: name.to_string(),
+            description: description.to_string(),
+            command: Some(format!("./{MIGRATE_SCRIPT} {args}")),
+        };
+        vec![
+            task("migrate", "Apply the database migrations", "up"),
+            task(
+                "migrate-down",
+                "Roll back the last database migration",
+                "down 1",
+            ),
+        ]
+    }
+
+    /// 各语言的 `build/test/lint/run/fmt` 命令，与 CI 配置中的检查保持一致
+    fn commands(&self) -> [Option<String>; 5] {
+        let some = |command: &str| Some(command.to_string());
+        let project_name = &self.base.project_name;
+        match (&self.language, self.framework) {
+            (_, Framework::GoZero) => [
+                some("go build ./..."),
+                some("go test ./..."),
+                some("go vet ./..."),
+                Some(format!("cd {} && go run .", self.go_zero_service)),
+                some("go fmt ./..."),
+            ],
+            (Language::Go, _) => [
+                Some(format!("go build -o bin/{project_name} .")),
+                some("go test ./..."),
+                some("go vet ./..."),
+                some("go run ."),
+                some("go fmt ./..."),
+            ],
+            (Language::Python, framework) => {
+                let (test, run) = match framework {
+                    Framework::Django => (
+                        "uv run python manage.py test",
+                        "uv run python manage.py runserver",
+                    ),
+                    Framework::Flask => (
+                        "uv run python -m unittest discover -s tests -t .",
+                        "uv run flask --app wsgi run --debug",
+                    ),
+                    _ => (
+                        "uv run python -m unittest discover -s tests -t .",
+                        "uv run python main.py",
+                    ),
+                };
+                // Django 的测试命令在没有 tests/ 目录时同样可用
+                let has_tests = self.has_tests || framework == Framework::Django;
+                [
+                    some("uv sync"),
+                    has_tests.then(|| test.to_string()),
+                    some("uvx ruff check ."),
+                    some(run),
+                    some("uvx ruff format ."),
+                ]
+            }
+            (_, Framework::Tauri) => [
+                some("cargo tauri build"),
+                some("cargo test --all"),
+                some("cargo clippy --all-targets --all-features -- -D warnings"),
+                some("cargo tauri dev"),
+                some("cargo fmt --all"),
+            ],
+            // 纯 Rust 项目是包含 proto-gen 工具的 workspace
+            (Language::Rust, Framework::None) => [
+                some("cargo build --workspace"),
+                some("cargo test --workspace"),
+                some("cargo clippy --workspace --all-targets -- -D warnings"),
+                Some(format!("cargo run -p {project_name}")),
+                some("cargo fmt --all"),
+            ],
+            (Language::Rust, _) => [
+                some("cargo build"),
+                some("cargo test"),
+                some("cargo clippy --all-targets -- -D warnings"),
+                some("cargo run"),
+                some("cargo fmt"),
+            ],
+            (Language::Zig, _) => [
+                some("zig build"),
+                some("zig build test"),
+                some("zig fmt --check ."),
+                some("zig build run"),
+                some("zig fmt ."),
+            ],
+            (Language::TypeScript, framework) => {
+                let script = |name: &str| {
+                    self.scripts
+                        .contains(name)
+                        .then(|| self.runtime.run_command(name))
+                };
+                let package = |name: &str, command: &str| {
+                    self.packages
+                        .contains(name)
+                        .then(|| self.runtime.exec_command(command))
+                };
+                let run = match framework {
+                    Framework::NestJs => script("start:dev"),
+                    _ => script("dev"),
+                };
+                [
+                    script("build"),
+                    script("test"),
+                    // 没有 ESLint 时退回到类型检查，如 NestJS 的 typecheck、SvelteKit 的 check
+                    script("lint")
+                        .or_else(|| package("eslint", "eslint ."))
+                        .or_else(|| script("typecheck"))
+                        .or_else(|| script("check")),
+                    run,
+                    script("format").or_else(|| package("prettier", "prettier --write .")),
+                ]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tasks_follow_stack() {
+        let params = TaskParams::new(
+            "svc".to_string(),
+            TaskRunner::Just,
+            Language::Go,
+            Framework::Chi,
+        );
+        let tasks = params.tasks();
+        let names: Vec<&str> = tasks.iter().map(|task| task.name.as_str()).collect();
+        assert_eq!(names, TASK_NAMES);
+        assert_eq!(tasks[0].command.as_deref(), Some("go build -o bin/svc ."));
+
+        let gin = tempfile::tempdir().unwrap();
+        std::fs::write(gin.path().join("buf.gen.yaml"), "version: v2\n").unwrap();
+        std::fs::create_dir_all(gin.path().join("scripts")).unwrap();
+        std::fs::write(gin.path().join(MIGRATE_SCRIPT), "#!/usr/bin/env bash\n").unwrap();
+        std::fs::create_dir_all(gin.path().join("ent")).unwrap();
+        std::fs::write(gin.path().join(ENT_GENERATE), "package ent\n").unwrap();
+        let tasks = TaskParams::new(
+            "svc".to_string(),
+            TaskRunner::Make,
+            Language::Go,
+            Framework::Gin,
+        )
+        .detect_from(gin.path())
+        .tasks();
+        let names: Vec<&str> = tasks[5..].iter().map(|task| task.name.as_str()).collect();
+        assert_eq!(names, ["proto", "ent", "migrate", "migrate-down"]);
+        assert_eq!(tasks[5].command.as_deref(), Some("buf generate"));
+        assert_eq!(tasks[6].command.as_deref(), Some("go generate ./ent"));
+        assert_eq!(
+            tasks[8].command.as_deref(),
+            Some("./scripts/migrate.sh down 1")
+        );
+
+        let workspace = tempfile::tempdir().unwrap();
+        std::fs::write(
+            workspace.path().join("package.json"),
+            r#"{"scripts":{"build":"nuxt build","dev":"nuxt dev"},"devDependencies":{"eslint":"^9"}}"#,
+        )
+        .unwrap();
+        let params = TaskParams::new(
+            "web".to_string(),
+            TaskRunner::Make,
+            Language::TypeScript,
+            Framework::Nuxt,
+        )
+        .with_runtime(Runtime::Bun)
+        .detect_from(workspace.path());
+        let commands: Vec<Option<String>> = params
+            .tasks()
+            .into_iter()
+            .map(|task| task.command)
+            .collect();
+        assert_eq!(
+            commands,
+            [
+                Some("bun run build".to_string()),
+                None,
+                Some("bunx eslint .".to_string()),
+                Some("bun run dev".to_string()),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::parameters::{FRONTEND_DIR, WorkspaceParams};
+use crate::constants::{Language, Runtime};
+use crate::generators::core::{Generator, NextSteps, Parameters, TemplateProcessor};
+use crate::status;
+
+/// workspace 根目录生成器：把 `apps/` 下各自生成的应用组织成 Go 多模块（go.work）、
+/// Cargo workspace 和 pnpm workspace，并生成共享的 Makefile、README 和 CI 配置
+pub struct WorkspaceGenerator {
+    template_processor: TemplateProcessor,
+}
+
+impl WorkspaceGenerator {
+    /// 创建新的 workspace 生成器
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            template_processor: TemplateProcessor::new()?,
+        })
+    }
+
+    /// 应用目录中单独生成的锁文件由根目录的锁文件取代，删除以免与 workspace 的依赖解析不一致
+    fn remove_member_lockfiles(&self, params: &WorkspaceParams, output_path: &Path) -> Result<()> {
+        for app in &params.apps {
+            let app_dir = output_path.join(app.path());
+            let mut lockfiles = Vec::new();
+            if app.language == Language::Rust && !params.standalone_crates.contains(&app.name) {
+                lockfiles.push("Cargo.lock");
+            }
+            if params.js_packages.contains(&app.name) {
+                lockfiles.push(params.runtime.lockfile());
+            }
+            for lockfile in lockfiles {
+                let path = app_dir.join(lockfile);
+                if path.exists() {
+                    std::fs::remove_file(&path)
+                        .with_context(|| format!("Failed to remove {}", path.display()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 全栈项目的前端镜像把 API 请求转发到 `backend` 服务，只能通过根目录的 compose 运行，
+    /// 删除前端单独的 compose 文件
+    fn remove_frontend_compose(&self, params: &WorkspaceParams, output_path: &Path) -> Result<()> {
+        if params.api_proxy.is_none() || !params.base.enable_docker {
+            return Ok(());
+        }
+        let path = output_path.join(FRONTEND_DIR).join("docker-compose.yaml");
+        if path.exists() {
+            std::fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for WorkspaceGenerator {
+    fn default() -> Self {
+        Self::new().expect("Failed to create WorkspaceGenerator")
+    }
+}
+
+impl Generator for WorkspaceGenerator {
+    type Params = WorkspaceParams;
+
+    fn name(&self) -> &'static str {
+        "Workspace"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(
+            "Generates go.work, a Cargo or pnpm workspace, a root Makefile and CI for several apps",
+        )
+    }
+
+    fn get_template_path(&self) -> &'static str {
+        "workspace"
+    }
+
+    fn generate(&self, params: Self::Params, output_path: &Path) -> Result<()> {
+        status!("Generating {} files", self.name());
+
+        // pnpm 的 workspace 在 pnpm-workspace.yaml 中声明，Bun 和 Deno 使用 package.json 的 `workspaces`
+        let pnpm = params.runtime == Runtime::Node;
+        self.template_processor
+            .process_embedded_template_directory_filtered(
+                self.get_template_path(),
+                output_path,
+                params.to_template_context(),
+                |relative_path| relative_path != "+js_workspace/pnpm-workspace.yaml.tmpl" || pnpm,
+            )
+            .context("Failed to generate workspace files")?;
+        self.remove_member_lockfiles(&params, output_path)?;
+        self.remove_frontend_compose(&params, output_path)?;
+
+        status!("{} files generated", self.name());
+        Ok(())
+    }
+
+    fn next_steps(&self, params: &Self::Params, steps: &mut NextSteps) {
+        if !params.js_packages.is_empty() {
+            steps.command(
+                format!("{} install", params.runtime.package_manager()),
+                "Install the dependencies of every JavaScript app",
+            );
+        }
+        steps.command("make build", "Build every app");
+        if params.api_proxy.is_some() && params.base.enable_docker {
+            steps.command(
+                "docker compose up --build",
+                "Run the backend and the frontend in containers",
+            );
+        }
+        for (app, tasks) in params.apps.iter().zip(&params.tasks) {
+            if tasks
+                .iter()
+                .any(|task| task.name == "run" && task.command.is_some())
+            {
+                steps.command(
+                    format!("make run-{}", app.name),
+                    format!("Run {}", app.name),
+                );
+            }
+        }
+    }
+}
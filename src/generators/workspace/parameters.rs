@@ -0,0 +1,600 @@
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::constants::{self, Feature, Framework, Language, Runtime, TaskRunner};
+use crate::generators::core::{BaseParams, InheritableParams};
+use crate::generators::docker::parameters::{STATIC_SITE_PORT, container_port};
+use crate::generators::project::TaskParams;
+use crate::generators::project::tasks::Task;
+
+/// 应用所在的目录，每个应用位于 `apps/<名称>/`
+pub const APPS_DIR: &str = "apps";
+/// 全栈项目的后端目录，同时是根目录 compose 中的服务名
+pub const BACKEND_DIR: &str = "backend";
+/// 全栈项目的前端目录
+pub const FRONTEND_DIR: &str = "frontend";
+/// `--fullstack` 支持的后端，都提供 `GET /health` 和返回 `{ message }` 的 `GET /api/v1/ping`
+const FULLSTACK_BACKENDS: &[Framework] = &[
+    Framework::Gin,
+    Framework::Fiber,
+    Framework::Chi,
+    Framework::Axum,
+    Framework::Actix,
+    Framework::Flask,
+];
+/// `--fullstack` 支持的前端，都是基于 Vite 的单页应用
+const FULLSTACK_FRONTENDS: &[Framework] = &[Framework::Vue3, Framework::React];
+/// `flask run` 的默认端口
+const FLASK_DEV_PORT: u16 = 5000;
+/// CI 中 Node.js 的版本
+const DEFAULT_NODE_VERSION: &str = "20";
+/// 根目录 Makefile 中对所有应用执行的任务，`run` 只能按应用执行
+const AGGREGATE_TASKS: [(&str, &str); 4] = [
+    ("build", "Build every app"),
+    ("test", "Run the tests of every app"),
+    ("lint", "Run the linters of every app"),
+    ("fmt", "Format the code of every app"),
+];
+
+/// workspace 中的一个应用，由 `--app NAME=STACK` 指定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceApp {
+    /// 应用名，同时是目录名、Makefile 目标和 CI job 的后缀
+    pub name: String,
+    /// 技术栈简写，如 `go/gin`
+    pub stack: String,
+    /// 应用语言
+    pub language: Language,
+    /// 应用框架
+    pub framework: Framework,
+    /// 应用的上级目录，`--app` 的应用位于 `apps/` 下，全栈项目的前后端直接位于根目录
+    pub dir: String,
+}
+
+impl WorkspaceApp {
+    /// 解析 `NAME=STACK`，如 `api=go/gin`；技术栈与 `--stack` 的取值相同
+    pub fn parse(spec: &str) -> Result<Self> {
+        let Some((name, stack)) = spec.split_once('=') else {
+            bail!("Invalid app '{spec}': expected NAME=STACK, e.g. api=go/gin");
+        };
+        let (name, stack) = (name.trim(), stack.trim());
+        if name.is_empty()
+            || name.starts_with('-')
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            bail!(
+                "Invalid app name '{name}': use lowercase letters, digits and hyphens, starting with a letter or digit"
+            );
+        }
+        let (language, framework) = constants::STACKS
+            .contains(&stack)
+            .then(|| constants::parse_stack(stack))
+            .flatten()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unsupported stack '{stack}' for app '{name}'. Supported stacks: {}",
+                    constants::STACKS.join(", ")
+                )
+            })?;
+        if language == Language::Zig {
+            bail!("Zig apps are not supported in a workspace yet");
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            stack: stack.to_string(),
+            language,
+            framework,
+            dir: APPS_DIR.to_string(),
+        })
+    }
+
+    /// 应用相对于 workspace 根目录的路径
+    pub fn path(&self) -> String {
+        if self.dir.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.dir, self.name)
+        }
+    }
+}
+
+/// 解析 `--fullstack BACKEND+FRONTEND`，如 `gin+vue3` 或 `go/gin+ts/vue3`，返回后端和前端两个应用
+pub fn parse_fullstack(spec: &str) -> Result<Vec<WorkspaceApp>> {
+    let Some((backend, frontend)) = spec.split_once('+') else {
+        bail!("Invalid --fullstack '{spec}': expected BACKEND+FRONTEND, e.g. gin+vue3");
+    };
+    let app = |name: &str, stack: &str, supported: &[Framework]| -> Result<WorkspaceApp> {
+        let stack = stack.trim();
+        // 只写框架名时按框架找到对应的技术栈
+        let stack = Framework::parse_from_str(stack)
+            .filter(|_| !stack.contains('/'))
+            .and_then(|framework| {
+                constants::STACKS.iter().find(|candidate| {
+                    constants::parse_stack(candidate).map(|(_, f)| f) == Some(framework)
+                })
+            })
+            .map_or(stack, |candidate| candidate);
+        let app = WorkspaceApp::parse(&format!("{name}={stack}"))?;
+        if !supported.contains(&app.framework) {
+            let names: Vec<String> = supported
+                .iter()
+                .map(|framework| framework.as_lowercase().to_string())
+                .collect();
+            bail!(
+                "Unsupported {name} '{stack}' for --fullstack. Supported {name}s: {}",
+                names.join(", ")
+            );
+        }
+        Ok(WorkspaceApp {
+            dir: String::new(),
+            ..app
+        })
+    };
+    Ok(vec![
+        app(BACKEND_DIR, backend, FULLSTACK_BACKENDS)?,
+        app(FRONTEND_DIR, frontend, FULLSTACK_FRONTENDS)?,
+    ])
+}
+
+/// 全栈项目中前端把 `/api` 和 `/health` 请求转发到后端的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiProxy {
+    /// 后端开发服务器的端口，Vite 开发服务器转发到 `localhost` 上的该端口
+    pub dev_port: u16,
+    /// 后端镜像中服务监听的端口，前端镜像中的 nginx 转发到 compose 中的 `backend` 服务
+    pub container_port: u16,
+}
+
+impl ApiProxy {
+    /// 按后端框架和服务端口确定转发目标，Flask 开发时由 `flask run` 监听 5000 端口
+    pub fn for_backend(framework: Framework, port: u16) -> Self {
+        let dev_port = match framework {
+            Framework::Flask => FLASK_DEV_PORT,
+            _ => port,
+        };
+        Self {
+            dev_port,
+            container_port: container_port(framework, port).unwrap_or(port),
+        }
+    }
+
+    /// Vite 开发服务器的转发目标
+    pub fn dev_target(&self) -> String {
+        format!("http://localhost:{}", self.dev_port)
+    }
+
+    /// 前端镜像中 nginx 的转发目标
+    pub fn container_target(&self) -> String {
+        format!("http://{BACKEND_DIR}:{}", self.container_port)
+    }
+
+    /// 根目录 compose 中前端映射到宿主机的端口，与后端端口相同时顺延
+    pub fn frontend_port(&self) -> u16 {
+        if self.container_port == STATIC_SITE_PORT {
+            STATIC_SITE_PORT + 1
+        } else {
+            STATIC_SITE_PORT
+        }
+    }
+}
+
+/// 解析全部 `--app`，至少需要一个应用且名称不能重复
+pub fn parse_apps(specs: &[String]) -> Result<Vec<WorkspaceApp>> {
+    let mut apps: Vec<WorkspaceApp> = Vec::new();
+    for spec in specs {
+        let app = WorkspaceApp::parse(spec)?;
+        if apps.iter().any(|existing| existing.name == app.name) {
+            bail!("Duplicate app name '{}'", app.name);
+        }
+        apps.push(app);
+    }
+    if apps.is_empty() {
+        bail!("A workspace needs at least one --app NAME=STACK");
+    }
+    Ok(apps)
+}
+
+/// workspace 根目录的生成参数：go.work、Cargo workspace、pnpm workspace、Makefile 和 CI 配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceParams {
+    /// 基础参数，包含描述、许可证和 CI 等功能开关
+    pub base: BaseParams,
+    /// workspace 中的应用，按命令行中的顺序排列
+    pub apps: Vec<WorkspaceApp>,
+    /// JavaScript 运行时，决定 workspace 文件和脚本命令
+    pub runtime: Runtime,
+    /// go.work 中的 Go 版本，取各模块 go.mod 中的最高版本
+    pub go_version: String,
+    /// 自带 `[workspace]` 的 Rust 应用（纯 Rust 项目、Tauri），不加入根目录的 Cargo workspace
+    pub standalone_crates: Vec<String>,
+    /// 有 package.json 的应用（TypeScript 项目和 Tauri），加入 pnpm（或 Bun、Deno）workspace
+    pub js_packages: Vec<String>,
+    /// 各应用的 `build/test/lint/run/fmt` 命令，与单个项目的任务入口一致
+    pub tasks: Vec<Vec<Task>>,
+    /// 全栈项目中前端到后端的转发方式，`--app` 生成的 workspace 为 `None`
+    pub api_proxy: Option<ApiProxy>,
+    /// 后端的 compose 文件名，根目录的 compose 通过 `include` 引入；Gin 模板自带 `docker-compose.yml`
+    pub backend_compose: String,
+}
+
+impl Default for WorkspaceParams {
+    fn default() -> Self {
+        let apps = [
+            "api=go/gin",
+            "svc=rust/axum",
+            "web=ts/vue3",
+            "worker=python",
+        ]
+        .iter()
+        .map(|spec| WorkspaceApp::parse(spec).expect("Valid example app"))
+        .collect();
+        Self::new(String::new(), apps)
+    }
+}
+
+impl InheritableParams for WorkspaceParams {
+    fn base_params(&self) -> &BaseParams {
+        &self.base
+    }
+
+    fn base_params_mut(&mut self) -> &mut BaseParams {
+        &mut self.base
+    }
+
+    fn from_base(base: BaseParams) -> Self {
+        Self {
+            base,
+            ..Self::default()
+        }
+    }
+
+    fn extended_template_context(&self) -> HashMap<String, Value> {
+        let paths = |filter: &dyn Fn(&WorkspaceApp) -> bool| -> Vec<String> {
+            self.apps
+                .iter()
+                .filter(|app| filter(app))
+                .map(WorkspaceApp::path)
+                .collect()
+        };
+        let go_modules = paths(&|app| app.language == Language::Go);
+        let cargo_members = paths(&|app| {
+            app.language == Language::Rust && !self.standalone_crates.contains(&app.name)
+        });
+        let cargo_excluded = paths(&|app| self.standalone_crates.contains(&app.name));
+        let js_packages = paths(&|app| self.js_packages.contains(&app.name));
+
+        let apps: Vec<Value> = self
+            .apps
+            .iter()
+            .zip(&self.tasks)
+            .map(|(app, tasks)| self.app_context(app, tasks))
+            .collect();
+        let ci_apps: Vec<&Value> = apps
+            .iter()
+            .filter(|app| app["ci"].as_bool() == Some(true))
+            .collect();
+        let aggregates: Vec<Value> = AGGREGATE_TASKS
+            .iter()
+            .map(|(name, description)| {
+                let targets: Vec<String> = self
+                    .apps
+                    .iter()
+                    .zip(&self.tasks)
+                    .filter(|(_, tasks)| has_command(tasks, name))
+                    .map(|(app, _)| format!("{name}-{}", app.name))
+                    .collect();
+                json!({ "name": name, "description": description, "targets": targets })
+            })
+            .collect();
+
+        let mut context = HashMap::from([
+            ("apps".to_string(), json!(apps)),
+            ("ci_apps".to_string(), json!(ci_apps)),
+            ("aggregates".to_string(), json!(aggregates)),
+            (
+                "ci_config".to_string(),
+                json!(self.base.ci_provider.config_path()),
+            ),
+            ("enable_go_work".to_string(), json!(!go_modules.is_empty())),
+            ("go_modules".to_string(), json!(go_modules)),
+            ("go_version".to_string(), json!(self.go_version)),
+            (
+                "enable_cargo_workspace".to_string(),
+                json!(!cargo_members.is_empty()),
+            ),
+            ("cargo_members".to_string(), json!(cargo_members)),
+            ("cargo_excluded".to_string(), json!(cargo_excluded)),
+            (
+                "enable_js_workspace".to_string(),
+                json!(!js_packages.is_empty()),
+            ),
+            ("js_packages".to_string(), json!(js_packages)),
+            ("runtime".to_string(), json!(self.runtime.as_str())),
+            (
+                "package_manager".to_string(),
+                json!(self.runtime.package_manager()),
+            ),
+            ("node_version".to_string(), json!(DEFAULT_NODE_VERSION)),
+            (
+                "enable_fullstack".to_string(),
+                json!(self.api_proxy.is_some()),
+            ),
+        ]);
+        if let Some(proxy) = self.api_proxy {
+            let backend = self.apps.iter().find(|app| app.name == BACKEND_DIR);
+            context.extend([
+                (
+                    "backend_framework".to_string(),
+                    json!(backend.map(|app| app.framework.as_str())),
+                ),
+                ("backend_dev_port".to_string(), json!(proxy.dev_port)),
+                ("frontend_port".to_string(), json!(proxy.frontend_port())),
+                ("backend_compose".to_string(), json!(self.backend_compose)),
+            ]);
+        }
+        context
+    }
+}
+
+impl WorkspaceParams {
+    /// 为指定的应用创建参数，应用的任务在生成后由 [`Self::detect_from`] 按实际文件确定
+    pub fn new(project_name: String, apps: Vec<WorkspaceApp>) -> Self {
+        let js_packages = apps
+            .iter()
+            .filter(|app| app.language == Language::TypeScript || app.framework == Framework::Tauri)
+            .map(|app| app.name.clone())
+            .collect();
+        let standalone_crates = apps
+            .iter()
+            .filter(|app| {
+                app.language == Language::Rust
+                    && matches!(app.framework, Framework::None | Framework::Tauri)
+            })
+            .map(|app| app.name.clone())
+            .collect();
+        let mut params = Self {
+            // 模块名由各应用自己决定，workspace 根目录没有模块名
+            base: BaseParams {
+                module_name: None,
+                ..BaseParams::new(project_name)
+            },
+            apps,
+            runtime: Runtime::default(),
+            // 读不到 go.mod 时与 go.mod 和环境检查使用同一默认版本
+            go_version: constants::defaults::GO_VERSION.to_string(),
+            standalone_crates,
+            js_packages,
+            tasks: Vec::new(),
+            api_proxy: None,
+            backend_compose: "docker-compose.yaml".to_string(),
+        };
+        params.tasks = params
+            .apps
+            .iter()
+            .map(|app| params.task_params(app).tasks())
+            .collect();
+        params
+    }
+
+    /// 设置 JavaScript 运行时
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// 设置全栈项目中前端到后端的转发方式，生成根目录的 compose 和前端的 API 类型
+    pub fn with_api_proxy(mut self, api_proxy: Option<ApiProxy>) -> Self {
+        self.api_proxy = api_proxy;
+        self
+    }
+
+    /// 根据已生成的应用确定任务命令和 go.work 的 Go 版本
+    ///
+    /// 前端项目的 package.json 由脚手架工具创建，只渲染模板时不存在，
+    /// 因此 JavaScript 包和独立的 Rust workspace 按技术栈确定
+    pub fn detect_from(mut self, output_path: &Path) -> Self {
+        let app_dirs: Vec<_> = self
+            .apps
+            .iter()
+            .map(|app| output_path.join(app.path()))
+            .collect();
+
+        self.tasks = self
+            .apps
+            .iter()
+            .zip(&app_dirs)
+            .map(|(app, dir)| self.task_params(app).detect_from(dir).tasks())
+            .collect();
+        if let Some(version) = app_dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_to_string(dir.join("go.mod")).ok())
+            .filter_map(|content| {
+                content
+                    .lines()
+                    .find_map(|line| line.strip_prefix("go "))
+                    .map(|version| version.trim().to_string())
+            })
+            .max_by_key(|version| version_key(version))
+        {
+            self.go_version = version;
+        }
+        if output_path
+            .join(BACKEND_DIR)
+            .join("docker-compose.yml")
+            .exists()
+        {
+            self.backend_compose = "docker-compose.yml".to_string();
+        }
+        self
+    }
+
+    fn task_params(&self, app: &WorkspaceApp) -> TaskParams {
+        TaskParams::new(
+            app.name.clone(),
+            TaskRunner::Make,
+            app.language,
+            app.framework,
+        )
+        .with_runtime(self.runtime)
+    }
+
+    /// 模板中单个应用的信息：Makefile 目标和 CI job 的镜像、步骤
+    fn app_context(&self, app: &WorkspaceApp, tasks: &[Task]) -> Value {
+        let command = |name: &str| {
+            tasks
+                .iter()
+                .find(|task| task.name == name)
+                .and_then(|task| task.command.clone())
+        };
+        let uses_js = self.js_packages.contains(&app.name);
+
+        // CI 与单个项目相同：安装依赖后依次运行 lint、测试和构建，go-zero 和 Tauri 没有 CI 模板
+        let mut steps = Vec::new();
+        let install = match app.language {
+            Language::Python => Some("uv sync".to_string()),
+            _ if uses_js => Some(self.runtime.frozen_install_command().to_string()),
+            _ => None,
+        };
+        if uses_js && self.runtime == Runtime::Node {
+            steps.push(json!({ "name": "Enable pnpm", "run": "corepack enable" }));
+        }
+        if let Some(ref install) = install {
+            steps.push(json!({ "name": "Install dependencies", "run": install }));
+        }
+        for (name, label) in [("lint", "Lint"), ("test", "Test"), ("build", "Build")] {
+            if name == "test" && !self.base.enable_tests {
+                continue;
+            }
+            if let Some(run) = command(name).filter(|run| Some(run) != install.as_ref()) {
+                steps.push(json!({ "name": label, "run": run }));
+            }
+        }
+        let ci = Feature::Ci.impact(&app.language, &app.framework).is_some();
+
+        let image = match app.language {
+            Language::Go => "golang:1".to_string(),
+            Language::Rust => "rust:1".to_string(),
+            Language::Python => "ghcr.io/astral-sh/uv:bookworm".to_string(),
+            _ => match self.runtime {
+                Runtime::Node => format!("node:{DEFAULT_NODE_VERSION}"),
+                Runtime::Bun => "oven/bun:1".to_string(),
+                Runtime::Deno => "denoland/deno:latest".to_string(),
+            },
+        };
+        let targets: Vec<Value> = tasks
+            .iter()
+            .filter_map(|task| {
+                let command = task.command.as_ref()?;
+                Some(json!({
+                    "target": format!("{}-{}", task.name, app.name),
+                    "description": task.description,
+                    "command": command,
+                }))
+            })
+            .collect();
+
+        json!({
+            "name": app.name,
+            // Azure Pipelines 的 job 名只能包含字母、数字和下划线
+            "job_id": app.name.replace('-', "_"),
+            "path": app.path(),
+            "stack": app.stack,
+            "language": app.language.as_lowercase(),
+            "uses_js": uses_js,
+            "image": image,
+            "ci": ci && !steps.is_empty(),
+            "steps": steps,
+            "targets": targets,
+        })
+    }
+}
+
+/// 任务是否有可执行的命令
+fn has_command(tasks: &[Task], name: &str) -> bool {
+    tasks
+        .iter()
+        .any(|task| task.name == name && task.command.is_some())
+}
+
+/// 按数值比较 `1.21`、`1.22.3` 这样的版本号
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::core::Parameters;
+
+    #[test]
+    fn test_workspace_apps() {
+        let apps = parse_apps(&[
+            "api=go/gin".to_string(),
+            "admin-api=go/fiber".to_string(),
+            "web=ts/react".to_string(),
+            "desktop=rust/tauri".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(apps[0].framework, Framework::Gin);
+        assert_eq!(apps[1].path(), "apps/admin-api");
+
+        for invalid in ["api", "Api=go/gin", "api=go/django", "tool=zig", "-x=rust"] {
+            assert!(WorkspaceApp::parse(invalid).is_err(), "{invalid}");
+        }
+        assert!(parse_apps(&["api=go/gin".to_string(), "api=rust/axum".to_string()]).is_err());
+        assert!(parse_apps(&[]).is_err());
+
+        let workspace = tempfile::tempdir().unwrap();
+        let api = workspace.path().join("apps/api");
+        let admin = workspace.path().join("apps/admin-api");
+        std::fs::create_dir_all(&api).unwrap();
+        std::fs::create_dir_all(&admin).unwrap();
+        std::fs::write(api.join("go.mod"), "module api\n\ngo 1.21\n").unwrap();
+        std::fs::write(admin.join("go.mod"), "module admin\n\ngo 1.22.3\n").unwrap();
+
+        let context = WorkspaceParams::new("mono".to_string(), apps)
+            .detect_from(workspace.path())
+            .to_template_context();
+        assert_eq!(context["go_version"], "1.22.3");
+        assert_eq!(context["go_modules"], json!(["apps/api", "apps/admin-api"]));
+        assert_eq!(context["js_packages"], json!(["apps/web", "apps/desktop"]));
+        // Tauri 应用自带 `[workspace]`，不加入根目录的 Cargo workspace
+        assert_eq!(context["enable_cargo_workspace"], false);
+        assert_eq!(context["cargo_excluded"], json!(["apps/desktop"]));
+        assert_eq!(context["apps"][1]["job_id"], "admin_api");
+        // Tauri 应用没有 CI 模板
+        assert_eq!(context["ci_apps"].as_array().unwrap().len(), 3);
+        assert_eq!(
+            context["aggregates"][0]["targets"],
+            json!(["build-api", "build-admin-api", "build-web", "build-desktop"])
+        );
+
+        // 全栈项目的前后端直接位于根目录，框架名和技术栈简写都可以使用
+        let apps = parse_fullstack("gin+ts/react").unwrap();
+        assert_eq!(apps[0].stack, "go/gin");
+        assert_eq!(apps[0].path(), "backend");
+        assert_eq!(apps[1].path(), "frontend");
+        for invalid in ["gin", "django+vue3", "gin+nuxt", "vue3+gin"] {
+            assert!(parse_fullstack(invalid).is_err(), "{invalid}");
+        }
+        let proxy = ApiProxy::for_backend(Framework::Flask, 8080);
+        assert_eq!(proxy.dev_target(), "http://localhost:5000");
+        assert_eq!(proxy.container_target(), "http://backend:8000");
+        assert_eq!(proxy.frontend_port(), 8080);
+        let context = WorkspaceParams::new("shop".to_string(), apps)
+            .with_api_proxy(Some(ApiProxy::for_backend(Framework::Gin, 8080)))
+            .to_template_context();
+        assert_eq!(context["enable_fullstack"], true);
+        assert_eq!(context["frontend_port"], 8081);
+        assert_eq!(context["js_packages"], json!(["frontend"]));
+    }
+}
@@ -1,5 +1,6 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use colored::*;
+use std::path::PathBuf;
 use std::process;
 
 mod commands;
@@ -9,7 +10,18 @@ mod scaffold;
 mod template_engine;
 mod utils;
 
+use commands::catalog::CatalogCommand;
+use commands::check::CheckCommand;
+use commands::compare::CompareCommand;
+use commands::generate::GenerateCommand;
 use commands::new::NewCommand;
+use commands::remove::RemoveCommand;
+#[cfg(feature = "serve")]
+use commands::serve::ServeCommand;
+use commands::template::{
+    TemplateBenchCommand, TemplateCheckCommand, TemplateExportCommand, TemplateNewCommand,
+    TemplateVarsCommand,
+};
 
 #[derive(Parser)]
 #[command(name = env!("CARGO_PKG_NAME"))]
@@ -17,11 +29,15 @@ use commands::new::NewCommand;
 #[command(version = env!("CARGO_PKG_VERSION"))]
 #[command(color = clap::ColorChoice::Auto)]
 struct Cli {
+    /// Suppress the welcome banner and progress output (warnings and errors are still shown)
+    #[arg(short, long, global = true)]
+    quiet: bool,
     #[command(subcommand)]
     command: Commands,
 }
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 enum Commands {
     /// Create a new project
     New {
@@ -30,8 +46,8 @@ enum Commands {
         /// Target directory (optional, defaults to current directory)
         #[arg(short, long)]
         path: Option<String>,
-        /// Framework type (gin or go-zero)
-        #[arg(long, help = "Framework type (gin or go-zero)")]
+        /// Framework type
+        #[arg(long, help = format!("Framework type ({})", constants::stack_frameworks().join(", ")))]
         framework: Option<String>,
         /// Host address
         #[arg(long)]
@@ -42,25 +58,359 @@ enum Commands {
         /// gRPC port
         #[arg(long)]
         grpc_port: Option<u16>,
-        /// Project language (go, etc.)
-        #[arg(long, help = "Project language (go, etc.)")]
+        /// go-zero services to generate (comma-separated: api, rpc, admin)
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_parser = clap::builder::PossibleValuesParser::new(constants::GO_ZERO_SERVICES)
+        )]
+        services: Option<Vec<String>>,
+        /// Generate the go-zero HTTP API service (`--api false` to skip it)
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        api: Option<bool>,
+        /// Generate the go-zero RPC service
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        rpc: Option<bool>,
+        /// Generate the go-zero admin service
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        admin: Option<bool>,
+        /// Nuxt modules to install (comma-separated: tailwind, eslint, pinia, or none; default: all)
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_parser = clap::builder::PossibleValuesParser::new(
+                constants::NUXT_MODULES.iter().copied().chain(["none"])
+            )
+        )]
+        nuxt_modules: Option<Vec<String>>,
+        /// SvelteKit options (comma-separated: typescript, tailwind, playwright, or none; default: all)
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_parser = clap::builder::PossibleValuesParser::new(
+                constants::SVELTEKIT_OPTIONS.iter().copied().chain(["none"])
+            )
+        )]
+        sveltekit_options: Option<Vec<String>>,
+        /// Project language (go, etc.; inferred from --framework when omitted)
+        #[arg(
+            long,
+            help = "Project language (go, etc.; inferred from --framework when omitted)"
+        )]
         language: Option<String>,
+        /// Language and framework shorthand (e.g. go/gin, ts/react)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::STACKS),
+            conflicts_with_all = ["language", "framework"]
+        )]
+        stack: Option<String>,
+        /// Generate a workspace with one app per NAME=STACK under apps/ (repeatable, e.g. --app api=go/gin --app web=ts/vue3)
+        #[arg(
+            long = "app",
+            value_name = "NAME=STACK",
+            conflicts_with_all = ["language", "framework", "stack"]
+        )]
+        apps: Vec<String>,
+        /// Generate a full-stack repo with backend/ and frontend/ apps wired together (e.g. gin+vue3, axum+react)
+        #[arg(
+            long,
+            value_name = "BACKEND+FRONTEND",
+            conflicts_with_all = ["language", "framework", "stack", "apps"]
+        )]
+        fullstack: Option<String>,
+        /// Feature profile: minimal, standard or full
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::PROFILES),
+            help = "Feature profile (minimal: bare skeleton, standard: docker/docs/tests, full: adds CI and lint)"
+        )]
+        profile: Option<String>,
+        /// How to handle non-critical tool failures (go mod tidy, pnpm install, swag, ...)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::FAILURE_POLICIES),
+            help = "How to handle non-critical tool failures such as go mod tidy, pnpm install or swag (strict: abort, lenient: warn and continue)"
+        )]
+        failure_policy: Option<String>,
+        /// Tailwind CSS major version for Vue3, React and Tauri projects (default: 4)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::TAILWIND_VERSIONS)
+        )]
+        tailwind_version: Option<String>,
+        /// Tauri major version for Tauri projects (default: 2)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::TAURI_VERSIONS),
+            help = "Tauri major version for Tauri projects; selects the overlay templates, tauri.conf.json schema and permission model (default: 2)"
+        )]
+        tauri_version: Option<String>,
+        /// JavaScript runtime for TypeScript projects (default: node with pnpm)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::RUNTIMES),
+            help = "JavaScript runtime for TypeScript projects; selects the package manager, lockfile and CI setup (node: pnpm, bun, deno)"
+        )]
+        runtime: Option<String>,
+        /// API client layer for Vue3 and React projects (fetch or axios)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::API_CLIENTS),
+            help = "Generate a typed API client layer for Vue3 and React projects (fetch: native fetch wrapper, axios: axios instance with interceptors)"
+        )]
+        api_client: Option<String>,
+        /// Add TanStack Query and an example query hook (implies --api-client fetch)
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        tanstack_query: Option<bool>,
+        /// Add login/register pages, an auth store and route guards (implies --api-client fetch)
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        auth_pages: Option<bool>,
+        /// Add a light/dark/system theme provider, a toggle component and Tailwind's class-based dark mode
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        dark_mode: Option<bool>,
+        /// Generate a multi-stage Dockerfile, .dockerignore and docker-compose.yaml (`--docker false` to skip them)
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        docker: Option<bool>,
+        /// CI provider for the generated pipeline config
+        #[arg(
+            long,
+            num_args = 0..=1,
+            default_missing_value = "github",
+            value_parser = clap::builder::PossibleValuesParser::new(
+                constants::CI_PROVIDERS.iter().copied().chain(["none"])
+            ),
+            help = "Generate a CI pipeline for the given provider (github, gitlab, azure, circleci; `--ci` alone means github, `--ci none` skips it; default: from --profile)"
+        )]
+        ci: Option<String>,
+        /// Kubernetes deployment files for backend services
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::K8S_FORMATS),
+            help = "Generate Kubernetes Deployment/Service/Ingress manifests or a Helm chart for backend services (implies --docker)"
+        )]
+        k8s: Option<String>,
+        /// Generate docker-compose.dev.yaml running the dev server with hot reload
+        #[arg(
+            long,
+            help = "Generate docker-compose.dev.yaml that runs the backend with hot reload or the frontend dev server, listening on all interfaces with .env injected"
+        )]
+        compose_dev: bool,
+        /// Task runner file with the shared build/test/lint/run/fmt tasks
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(
+                constants::TASK_RUNNERS.iter().copied().chain(["none"])
+            ),
+            help = "Generate a Makefile or justfile with the same build/test/lint/run/fmt tasks for every project type (make, just, none; default: none)"
+        )]
+        task_runner: Option<String>,
         /// Enable pre-commit hooks
         #[arg(long)]
         precommit: Option<bool>,
-        /// License type
+        /// License as an SPDX identifier, e.g. MIT or AGPL-3.0-only (None to skip the LICENSE file)
         #[arg(long)]
         license: Option<String>,
+        /// Author name for LICENSE, README and package manifests (default: git user.name)
+        #[arg(long, value_name = "NAME")]
+        author: Option<String>,
+        /// Author email for package manifests (default: git user.email)
+        #[arg(long, value_name = "EMAIL")]
+        email: Option<String>,
+        /// Copyright holder in LICENSE (default: author or git user.name)
+        #[arg(long, value_name = "NAME")]
+        license_holder: Option<String>,
+        /// Copyright year or range in LICENSE, e.g. 2020-2026 (default: current year)
+        #[arg(long, value_name = "YEAR")]
+        license_year: Option<String>,
+        /// Custom license text copied to LICENSE (license: LicenseRef-Custom unless --license is given)
+        #[arg(long, value_name = "PATH")]
+        license_file: Option<PathBuf>,
         /// Enable Swagger documentation
         #[arg(long)]
         swagger: Option<bool>,
+        /// Database for Gin and Fiber projects (postgres, mysql, sqlite, mongo or none)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(
+                constants::DATABASES.iter().copied().chain(["none"])
+            )
+        )]
+        database: Option<String>,
+        /// Data access layer for Gin projects with a SQL database (gorm, sqlx or ent; default: gorm)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::ORMS)
+        )]
+        orm: Option<String>,
+        /// Logging library for Gin projects (zap, zerolog or slog; default: zap)
+        #[arg(
+            long,
+            value_parser = clap::builder::PossibleValuesParser::new(constants::LOGGERS)
+        )]
+        logger: Option<String>,
+        /// Enable the CORS middleware for Gin projects (enabled by default)
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        cors: Option<bool>,
+        /// Origins allowed by the CORS middleware (comma-separated, default: *)
+        #[arg(long, value_delimiter = ',', value_name = "ORIGINS")]
+        cors_origins: Option<Vec<String>>,
+        /// HTTP methods allowed by the CORS middleware (comma-separated)
+        #[arg(long, value_delimiter = ',', value_name = "METHODS")]
+        cors_methods: Option<Vec<String>>,
+        /// Enable Redis cache for Gin projects
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        redis: Option<bool>,
+        /// Add a gRPC server with buf-managed protobufs to Gin projects (same as --rpc for go-zero)
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        grpc: Option<bool>,
+        /// Add Django REST framework to Django projects
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        drf: Option<bool>,
+        /// Generate Gin routes, handler stubs and models from an OpenAPI 3 spec (YAML or JSON)
+        #[arg(long, value_name = "SPEC")]
+        openapi: Option<PathBuf>,
+        /// Project description
+        #[arg(long)]
+        description: Option<String>,
+        /// Project keywords/topics (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        keywords: Option<Vec<String>>,
+        /// Project homepage URL
+        #[arg(long)]
+        homepage: Option<String>,
+        /// Open the generated project in an editor (detected automatically when no editor is given)
+        #[arg(
+            long,
+            num_args = 0..=1,
+            value_name = "EDITOR",
+            value_parser = clap::builder::PossibleValuesParser::new(constants::EDITORS)
+        )]
+        open: Option<Option<String>>,
+        /// Run hooks declared by templates without asking for confirmation
+        #[arg(long)]
+        trust: bool,
+        /// Render templates into DIR without running any external tools (go, pnpm, git, ...)
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["path", "open"])]
+        render_to: Option<String>,
+        /// Package the rendered project into FILE (.zip, .tar or .tar.gz) without running any external tools; combine with --render-to to keep the files as well
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["path", "open"])]
+        archive: Option<String>,
+        /// Use template sets found in DIR (e.g. from `scafgen template export`) instead of the embedded ones
+        #[arg(long, value_name = "DIR")]
+        template_dir: Option<PathBuf>,
+        /// Layer the files in DIR or GIT_URL (optionally `#branch`) over the templates, overriding `template_overlay` in the config
+        #[arg(long, value_name = "DIR|GIT_URL")]
+        overlay: Option<String>,
+        /// Also render `+NAME/` feature directories that no built-in option controls (comma-separated, e.g. from an overlay feature pack)
+        #[arg(long = "feature", value_delimiter = ',', value_name = "NAME")]
+        features: Vec<String>,
+    },
+    /// Remove a feature added at generation time (e.g. swagger, redis, docker): its files and its marked code blocks
+    Remove {
+        /// Feature name as recorded in the project's .scafgen.json
+        feature: String,
+        /// Project directory (defaults to the current directory)
+        #[arg(short, long, default_value = ".")]
+        path: PathBuf,
+        /// List the files that would be deleted or edited without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Add a component to an existing project: a handler, model or middleware to Gin, a component or view to React and Vue3
+    #[command(alias = "g")]
+    Generate {
+        /// Component type: handler, model or middleware (Gin); component or view (React, Vue3)
+        kind: String,
+        /// Component name, e.g. user, order_item or Button
+        name: String,
+        /// Project directory (defaults to the current directory)
+        #[arg(short, long, default_value = ".")]
+        path: PathBuf,
+        /// Project framework (gin, react or vue3), detected from go.mod or package.json by default
+        #[arg(short, long)]
+        framework: Option<String>,
+    },
+    /// Fail when CI workflows or lint configs drifted from the templates the project was generated with (for use in CI)
+    Check {
+        /// Project directory (defaults to the current directory)
+        #[arg(short, long, default_value = ".")]
+        path: PathBuf,
+    },
+    /// List the languages, stacks, features, options and defaults that `scafgen new` supports
+    Catalog {
+        /// Print the full catalog as JSON (for web UIs and docs sites)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare the features, dependencies and layout that several stacks generate
+    Compare {
+        /// Stacks (e.g. go/gin) or frameworks (e.g. go-zero) to compare; prompts when fewer than two
+        stacks: Vec<String>,
+    },
+    /// Inspect the embedded templates
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+    /// Serve a REST API for listing templates, validating options and generating projects
+    #[cfg(feature = "serve")]
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Port to listen on
+        #[arg(long, default_value_t = 8787)]
+        port: u16,
     },
 }
 
-#[tokio::main]
-async fn main() {
+#[derive(Subcommand)]
+enum TemplateCommands {
+    /// Lint embedded templates: parse errors, unknown helpers, render errors and unused variables
+    Check {
+        /// Render every template set N times and report timings instead of linting
+        #[arg(long, hide = true, value_name = "ITERATIONS")]
+        bench_render: Option<usize>,
+        /// Fail when the median render time of any template set exceeds this budget
+        #[arg(long, hide = true, value_name = "MS", requires = "bench_render")]
+        bench_budget: Option<u64>,
+    },
+    /// List the variables, helpers and partials referenced by templates and cross-check them against the generator parameters
+    Vars {
+        /// Template file or directory, e.g. frameworks/go/gin or languages/go/go.mod.tmpl
+        template_path: String,
+    },
+    /// Scaffold a new template set: manifest, partial, example templates and a golden test fixture
+    New {
+        /// Template set name, e.g. echo (lowercase letters, digits and '-')
+        name: String,
+        /// Language of the template set (go, python, rust, typescript, zig)
+        #[arg(long)]
+        language: String,
+        /// Templates directory to create the set in
+        #[arg(long, value_name = "DIR", default_value = "templates")]
+        dir: PathBuf,
+    },
+    /// Copy an embedded template set to DIR for local customization with `scafgen new --template-dir DIR`
+    Export {
+        /// Template set or directory, e.g. frameworks/go/gin or licenses
+        family: String,
+        /// Templates directory to export into; files are written to DIR/<family>
+        dir: PathBuf,
+    },
+}
+
+fn main() {
     let cli = Cli::parse();
+    utils::console::set_quiet(cli.quiet);
+    // 子进程继承的 CA 证书环境变量需要在异步运行时的线程启动之前设置
+    utils::tls::export_ca_bundle();
+    run(cli);
+}
 
+#[tokio::main]
+async fn run(cli: Cli) {
     let result = match cli.command {
         Commands::New {
             name,
@@ -69,26 +419,174 @@ async fn main() {
             host,
             port,
             grpc_port,
+            services,
+            api,
+            rpc,
+            admin,
+            nuxt_modules,
+            sveltekit_options,
             language,
+            stack,
+            apps,
+            fullstack,
+            profile,
+            failure_policy,
+            tailwind_version,
+            tauri_version,
+            runtime,
+            api_client,
+            tanstack_query,
+            auth_pages,
+            dark_mode,
+            docker,
+            ci,
+            k8s,
+            compose_dev,
+            task_runner,
             precommit,
             license,
+            author,
+            email,
+            license_holder,
+            license_year,
+            license_file,
             swagger,
+            database,
+            orm,
+            logger,
+            cors,
+            cors_origins,
+            cors_methods,
+            redis,
+            grpc,
+            drf,
+            openapi,
+            description,
+            keywords,
+            homepage,
+            open,
+            trust,
+            render_to,
+            archive,
+            template_dir,
+            overlay,
+            features,
         } => {
             let new_cmd = NewCommand::new(name, path)
                 .with_framework(framework)
                 .with_host(host)
                 .with_port(port)
                 .with_grpc_port(grpc_port)
+                .with_services(services)
+                .with_api(api)
+                .with_rpc(rpc)
+                .with_admin(admin)
+                .with_nuxt_modules(nuxt_modules)
+                .with_sveltekit_options(sveltekit_options)
                 .with_language(language)
+                .with_stack(stack)
+                .with_apps(apps)
+                .with_fullstack(fullstack)
+                .with_profile(profile)
+                .with_failure_policy(failure_policy)
+                .with_tailwind_version(tailwind_version)
+                .with_tauri_version(tauri_version)
+                .with_runtime(runtime)
+                .with_api_client(api_client)
+                .with_tanstack_query(tanstack_query)
+                .with_auth_pages(auth_pages)
+                .with_dark_mode(dark_mode)
+                .with_docker(docker)
+                .with_ci(ci)
+                .with_k8s(k8s)
+                .with_compose_dev(compose_dev)
+                .with_task_runner(task_runner)
                 .with_precommit(precommit)
                 .with_license(license)
-                .with_swagger(swagger);
+                .with_author(author)
+                .with_email(email)
+                .with_license_holder(license_holder)
+                .with_license_year(license_year)
+                .with_license_file(license_file)
+                .with_swagger(swagger)
+                .with_database(database)
+                .with_orm(orm)
+                .with_logger(logger)
+                .with_cors(cors, cors_origins, cors_methods)
+                .with_redis(redis)
+                .with_grpc(grpc)
+                .with_drf(drf)
+                .with_openapi(openapi)
+                .with_description(description)
+                .with_keywords(keywords)
+                .with_homepage(homepage)
+                .with_open(open)
+                .with_trust(trust)
+                .with_render_to(render_to)
+                .with_archive(archive)
+                .with_template_dir(template_dir)
+                .with_overlay(overlay)
+                .with_extra_features(features);
             new_cmd.execute().await
         }
+        Commands::Remove {
+            feature,
+            path,
+            dry_run,
+        } => RemoveCommand::new(feature, path)
+            .with_dry_run(dry_run)
+            .execute(),
+        Commands::Generate {
+            kind,
+            name,
+            path,
+            framework,
+        } => GenerateCommand::new(kind, name, path)
+            .with_framework(framework)
+            .execute(),
+        Commands::Check { path } => CheckCommand::new(path).execute(),
+        Commands::Catalog { json } => {
+            let cli = Cli::command();
+            let new = cli
+                .find_subcommand("new")
+                .cloned()
+                .expect("the new subcommand is defined");
+            CatalogCommand::new(new, json).execute()
+        }
+        Commands::Compare { stacks } => CompareCommand::new(stacks).execute(),
+        Commands::Template {
+            command:
+                TemplateCommands::Check {
+                    bench_render: Some(iterations),
+                    bench_budget,
+                },
+        } => TemplateBenchCommand::new(iterations, bench_budget).execute(),
+        Commands::Template {
+            command: TemplateCommands::Check { .. },
+        } => TemplateCheckCommand::new().and_then(TemplateCheckCommand::execute),
+        Commands::Template {
+            command: TemplateCommands::Vars { template_path },
+        } => TemplateVarsCommand::new(template_path).and_then(TemplateVarsCommand::execute),
+        Commands::Template {
+            command:
+                TemplateCommands::New {
+                    name,
+                    language,
+                    dir,
+                },
+        } => TemplateNewCommand::new(name, &language, dir).and_then(TemplateNewCommand::execute),
+        Commands::Template {
+            command: TemplateCommands::Export { family, dir },
+        } => TemplateExportCommand::new(family, dir).and_then(TemplateExportCommand::execute),
+        #[cfg(feature = "serve")]
+        Commands::Serve { host, port } => ServeCommand::new(host, port).execute().await,
     };
 
     if let Err(e) = result {
-        eprintln!("{} {}", "Error:".red().bold(), e);
+        eprintln!("{} {e:#}", "Error:".red().bold());
+        if let Some(guidance) = utils::tls::diagnose(&format!("{e:#}")) {
+            eprintln!("{guidance}");
+        }
         process::exit(1);
     }
 }
@@ -3,8 +3,13 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
 
-use crate::template_engine::TemplateEngine;
+use crate::generators::core::{apply_file_mode, copy_embedded_file};
+use crate::status;
+use crate::template_engine::{TemplateEngine, TemplateManifest};
+use crate::utils::hooks::{self, HookOutcome};
+use crate::utils::render_only;
 
 /// 参数作用域，用于管理模板参数
 #[derive(Debug, Clone)]
@@ -63,7 +68,7 @@ pub struct Scaffold {
     template_path: PathBuf,
     output_path: Option<PathBuf>,
     params: ParameterScope,
-    template_engine: TemplateEngine,
+    template_engine: Arc<TemplateEngine>,
     post_processors: Vec<PostProcessor>,
 }
 
@@ -82,7 +87,7 @@ impl Scaffold {
             templates_root.join(&template_path)
         };
 
-        let template_engine = TemplateEngine::new(templates_root)?;
+        let template_engine = TemplateEngine::shared()?;
 
         Ok(Self {
             template_path: full_template_path,
@@ -193,7 +198,7 @@ impl Scaffold {
             .unwrap_or(template_file_path);
 
         if self.should_skip_file(file_name) {
-            println!("⏭️  Skipped: {file_name} (disabled by configuration)");
+            status!("⏭️  Skipped: {file_name} (disabled by configuration)");
             return Ok(());
         }
 
@@ -205,26 +210,28 @@ impl Scaffold {
                 })?;
 
             // 渲染模板
-            let rendered_content = self
-                .template_engine
-                .handlebars
-                .render_template(&content, self.params.get_all())
-                .with_context(|| {
-                    format!("Failed to render embedded template: {template_file_path}")
-                })?;
+            let rendered_content = self.template_engine.render_named_template(
+                template_file_path,
+                &content,
+                self.params.get_all(),
+            )?;
 
-            std::fs::write(output_file, rendered_content)
+            std::fs::write(output_file, &rendered_content)
                 .with_context(|| format!("Failed to write file: {}", output_file.display()))?;
+            let output_relative_path = template_file_path
+                .strip_suffix(".tmpl")
+                .unwrap_or(template_file_path);
+            apply_file_mode(
+                output_file,
+                output_relative_path,
+                rendered_content.as_bytes(),
+            )?;
         } else {
             // 直接复制非模板文件
-            let content = crate::template_engine::read_embedded_template(template_file_path)
-                .with_context(|| format!("Failed to read embedded file: {template_file_path}"))?;
-
-            std::fs::write(output_file, content)
-                .with_context(|| format!("Failed to write file: {}", output_file.display()))?;
+            copy_embedded_file(template_file_path, output_file, template_file_path)?;
         }
 
-        println!("Generated: {}", output_file.display());
+        status!("Generated: {}", output_file.display());
         Ok(())
     }
 
@@ -238,7 +245,7 @@ impl Scaffold {
     ) -> Result<()> {
         // 检查是否应该跳过此文件
         if self.should_skip_file(file_name) {
-            println!("⏭️  Skipped: {file_name} (disabled by configuration)");
+            status!("⏭️  Skipped: {file_name} (disabled by configuration)");
             return Ok(());
         }
 
@@ -268,7 +275,7 @@ impl Scaffold {
             })?;
         }
 
-        println!("Generated: {}", output_file.display());
+        status!("Generated: {}", output_file.display());
         Ok(())
     }
 
@@ -328,9 +335,10 @@ impl CompletedScaffold {
     }
 }
 
-/// 后置处理器
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+/// 后置处理器，即模板声明的钩子
+///
+/// 执行前需要用户确认（或通过 `--trust` 允许），并记录到审计日志
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PostProcessor {
     /// 执行自定义命令
     Command {
@@ -342,7 +350,6 @@ pub enum PostProcessor {
 
 impl PostProcessor {
     /// 创建命令处理器
-    #[allow(dead_code)]
     pub fn command<S: Into<String>>(command: S, args: Vec<S>, description: S) -> Self {
         Self::Command {
             command: command.into(),
@@ -351,6 +358,26 @@ impl PostProcessor {
         }
     }
 
+    /// 模板组清单 `template.json` 中 `hooks` 声明的钩子，模板组没有清单时为空
+    pub fn from_manifest(template_path: &str) -> Result<Vec<Self>> {
+        let Some(manifest) = TemplateManifest::load(template_path)? else {
+            return Ok(Vec::new());
+        };
+        Ok(manifest
+            .hooks
+            .into_iter()
+            .map(|hook| {
+                let description = hook.description.unwrap_or_else(|| {
+                    std::iter::once(hook.command.as_str())
+                        .chain(hook.args.iter().map(String::as_str))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                });
+                Self::command(hook.command, hook.args, description)
+            })
+            .collect())
+    }
+
     /// 执行后置处理器
     pub fn execute(&self, output_path: &Path) -> Result<()> {
         match self {
@@ -359,11 +386,26 @@ impl PostProcessor {
                 args,
                 description,
             } => {
-                println!("{description}");
+                if render_only::skip_tool(&format!("template hook: {description}")) {
+                    return Ok(());
+                }
+                if !hooks::approve(command, args, output_path) {
+                    hooks::audit(command, args, output_path, HookOutcome::Declined);
+                    status!("Skipped template hook: {description}");
+                    return Ok(());
+                }
+
+                status!("{description}");
                 let output = Command::new(command)
                     .args(args)
                     .current_dir(output_path)
-                    .output()
+                    .output();
+                let outcome = match output {
+                    Ok(ref output) if output.status.success() => HookOutcome::Succeeded,
+                    _ => HookOutcome::Failed,
+                };
+                hooks::audit(command, args, output_path, outcome);
+                let output = output
                     .with_context(|| format!("Failed to execute command: {command} {args:?}"))?;
 
                 if !output.status.success() {
@@ -372,7 +414,7 @@ impl PostProcessor {
                         "Command failed: {description}\nError: {stderr}"
                     ));
                 }
-                println!("{description}");
+                status!("Template hook finished: {description}");
             }
         }
         Ok(())
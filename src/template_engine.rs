@@ -1,9 +1,19 @@
 use anyhow::{Context, Result};
-use handlebars::Handlebars;
+use chrono::Datelike;
+use handlebars::template::{HelperTemplate, Parameter, Template, TemplateElement};
+use handlebars::{
+    Handlebars, HelperDef, RenderError, RenderErrorReason, ScopedJson, TemplateError,
+    handlebars_helper,
+};
 use include_dir::{Dir, include_dir};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::constants::{Runtime, string_utils};
+use crate::status;
 
 /// 将路径标准化为Unix风格的路径分隔符
 /// 这对于嵌入式模板路径是必要的，因为rust-embed使用Unix风格的路径
@@ -14,61 +24,440 @@ fn normalize_path(path: &str) -> String {
 /// 模板引擎，负责处理Handlebars模板的渲染
 pub struct TemplateEngine {
     pub handlebars: Handlebars<'static>,
+    /// 渲染模板路径用的注册表，与 `handlebars` 共享辅助函数和片段，但不做 HTML 转义
+    path_handlebars: Handlebars<'static>,
     #[allow(dead_code)]
     templates_dir: PathBuf,
+    custom_helpers: Vec<&'static str>,
+}
+
+/// Handlebars 内置的辅助函数
+const BUILTIN_HELPERS: &[&str] = &[
+    "if", "unless", "each", "with", "lookup", "raw", "log", "eq", "ne", "gt", "gte", "lt", "lte",
+    "and", "or", "not", "len",
+];
+
+/// 模板中引用的变量、辅助函数和片段
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TemplateUsage {
+    /// 引用的顶层变量名（如 `{{server.port}}` 记为 `server`）
+    pub variables: BTreeSet<String>,
+    /// 作为 `{{#if}}`/`{{#unless}}` 条件的变量，严格模式下允许缺失
+    pub conditions: BTreeSet<String>,
+    /// 调用的辅助函数名
+    pub helpers: BTreeSet<String>,
+    /// 引用的片段名
+    pub partials: BTreeSet<String>,
+    /// each/with 块内通过 `../` 引用的外层路径，已去掉一层 `../`
+    pub outer_paths: BTreeSet<String>,
+}
+
+/// 预先转换好的渲染上下文
+///
+/// Handlebars 每次渲染都会把变量表序列化为 JSON，目录中的文件共用同一份上下文时只需转换一次
+pub struct RenderContext<'a> {
+    variables: &'a HashMap<String, Value>,
+    data: handlebars::Context,
+}
+
+impl<'a> RenderContext<'a> {
+    /// 从变量表创建渲染上下文
+    pub fn new(variables: &'a HashMap<String, Value>) -> Result<Self> {
+        let data = handlebars::Context::wraps(variables)
+            .map_err(|e| anyhow::anyhow!("Failed to build template context: {e}"))?;
+        Ok(Self { variables, data })
+    }
 }
 
 impl TemplateEngine {
     /// 创建新的模板引擎实例
     pub fn new(templates_dir: PathBuf) -> Result<Self> {
         let mut handlebars = Handlebars::new();
+        // 严格模式：引用未定义的变量时报错，而不是静默渲染为空字符串
+        handlebars.set_strict_mode(true);
 
         // 注册辅助函数
-        handlebars.register_helper("to_camel_case", Box::new(to_camel_case_helper));
-        handlebars.register_helper("to_snake_case", Box::new(to_snake_case_helper));
+        let helpers: Vec<(&'static str, Box<dyn HelperDef + Send + Sync>)> = vec![
+            ("to_camel_case", Box::new(to_camel_case_helper)),
+            ("to_snake_case", Box::new(to_snake_case_helper)),
+            ("to_pascal_case", Box::new(to_pascal_case_helper)),
+            ("to_kebab_case", Box::new(to_kebab_case_helper)),
+            ("upper", Box::new(upper_helper)),
+            ("lower", Box::new(lower_helper)),
+            ("pluralize", Box::new(pluralize_helper)),
+            ("default", Box::new(DefaultHelper)),
+            ("year", Box::new(year_helper)),
+            ("env", Box::new(env_helper)),
+            ("pm_install", Box::new(pm_install_helper)),
+            ("pm_run", Box::new(pm_run_helper)),
+            ("pm_exec", Box::new(pm_exec_helper)),
+        ];
+        let mut custom_helpers = Vec::with_capacity(helpers.len());
+        for (name, helper) in helpers {
+            handlebars.register_helper(name, helper);
+            custom_helpers.push(name);
+        }
+
+        // 注册共享模板片段
+        register_embedded_partials(&mut handlebars)?;
+
+        let mut path_handlebars = handlebars.clone();
+        path_handlebars.register_escape_fn(handlebars::no_escape);
 
         Ok(Self {
             handlebars,
+            path_handlebars,
             templates_dir,
+            custom_helpers,
         })
     }
 
-    /// 渲染模板内容
-    pub fn render_template_content(
-        &mut self,
+    /// 获取整个进程共享的模板引擎，辅助函数和片段只在首次调用时注册一次
+    pub fn shared() -> Result<Arc<Self>> {
+        static SHARED: OnceLock<Arc<TemplateEngine>> = OnceLock::new();
+
+        if let Some(engine) = SHARED.get() {
+            return Ok(Arc::clone(engine));
+        }
+        let engine = Arc::new(Self::new(get_templates_dir()?)?);
+        Ok(Arc::clone(SHARED.get_or_init(|| engine)))
+    }
+
+    /// 检查辅助函数是否已注册（包括 Handlebars 内置辅助函数）
+    pub fn is_helper_registered(&self, name: &str) -> bool {
+        BUILTIN_HELPERS.contains(&name) || self.custom_helpers.contains(&name)
+    }
+
+    /// 解析模板并收集其中引用的变量、辅助函数和片段，不进行渲染
+    ///
+    /// 与渲染不同，未执行的分支（如 `{{#if}}` 的 false 分支）同样会被检查
+    pub fn analyze_template(&self, name: &str, template_content: &str) -> Result<TemplateUsage> {
+        let template = Template::compile(template_content)
+            .map_err(|error| describe_template_error(name, &error))?;
+
+        let mut usage = TemplateUsage::default();
+        self.collect_usage(&template, &mut usage);
+        Ok(usage)
+    }
+
+    fn collect_usage(&self, template: &Template, usage: &mut TemplateUsage) {
+        for element in &template.elements {
+            match element {
+                TemplateElement::Expression(helper)
+                | TemplateElement::HtmlExpression(helper)
+                | TemplateElement::HelperBlock(helper) => self.collect_helper_usage(helper, usage),
+                TemplateElement::PartialExpression(partial)
+                | TemplateElement::PartialBlock(partial) => {
+                    if let Some(name) = partial.name.as_name() {
+                        usage.partials.insert(name.to_string());
+                    }
+                    for param in partial.params.iter().chain(partial.hash.values()) {
+                        self.collect_param_usage(param, usage);
+                    }
+                    if let Some(inner) = &partial.template {
+                        self.collect_usage(inner, usage);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn collect_helper_usage(&self, helper: &HelperTemplate, usage: &mut TemplateUsage) {
+        let is_call = helper.block || !helper.params.is_empty() || !helper.hash.is_empty();
+        match &helper.name {
+            Parameter::Subexpression(_) => self.collect_param_usage(&helper.name, usage),
+            name => {
+                if let Some(name) = name.as_name() {
+                    // `{{year}}` 这类无参数的表达式既可能是变量，也可能是辅助函数
+                    if is_call || self.is_helper_registered(name) {
+                        usage.helpers.insert(name.to_string());
+                    } else {
+                        record_variable(usage, name);
+                    }
+                }
+            }
+        }
+
+        for param in helper.params.iter().chain(helper.hash.values()) {
+            self.collect_param_usage(param, usage);
+        }
+
+        if helper.block
+            && matches!(helper.name.as_name(), Some("if" | "unless"))
+            && let Some(root) = helper
+                .params
+                .first()
+                .and_then(Parameter::as_name)
+                .and_then(variable_root)
+        {
+            usage.conditions.insert(root.to_string());
+        }
+
+        // each/with 块内的相对路径指向当前元素，不是顶层变量
+        let scoped = helper.block && matches!(helper.name.as_name(), Some("each" | "with"));
+        if let Some(inner) = &helper.template {
+            if scoped {
+                let mut inner_usage = TemplateUsage::default();
+                self.collect_usage(inner, &mut inner_usage);
+                usage.helpers.extend(inner_usage.helpers);
+                usage.partials.extend(inner_usage.partials);
+                for path in &inner_usage.outer_paths {
+                    record_variable(usage, path);
+                }
+            } else {
+                self.collect_usage(inner, usage);
+            }
+        }
+        if let Some(inverse) = &helper.inverse {
+            self.collect_usage(inverse, usage);
+        }
+    }
+
+    fn collect_param_usage(&self, param: &Parameter, usage: &mut TemplateUsage) {
+        match param {
+            Parameter::Path(_) => {
+                if let Some(name) = param.as_name() {
+                    record_variable(usage, name);
+                }
+            }
+            Parameter::Subexpression(subexpression) => {
+                if let TemplateElement::Expression(helper) = subexpression.as_element() {
+                    self.collect_helper_usage(helper, usage);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 注册额外的模板片段，如尚未嵌入二进制的新模板组中的片段
+    pub fn register_partial(&mut self, name: &str, content: &str) -> Result<()> {
+        for handlebars in [&mut self.handlebars, &mut self.path_handlebars] {
+            handlebars
+                .register_partial(name, content)
+                .with_context(|| format!("Failed to register partial: {name}"))?;
+        }
+        Ok(())
+    }
+
+    /// 渲染模板内容，出错时附带模板名称、行号和可用的变量列表
+    pub fn render_named_template(
+        &self,
+        name: &str,
+        template_content: &str,
+        context: &HashMap<String, Value>,
+    ) -> Result<String> {
+        self.render_with_context(name, template_content, &RenderContext::new(context)?)
+    }
+
+    /// 使用预先转换好的上下文渲染模板内容，出错时同样附带模板名称、行号和可用的变量列表
+    pub fn render_with_context(
+        &self,
+        name: &str,
         template_content: &str,
-        context: HashMap<String, Value>,
+        context: &RenderContext,
     ) -> Result<String> {
-        let template = self
-            .handlebars
-            .render_template(template_content, &context)
-            .context("Failed to render template content")?;
-        Ok(template)
+        self.handlebars
+            .render_template_with_context(template_content, &context.data)
+            .map_err(|error| describe_render_error(name, &error, context.variables))
+    }
+
+    /// 渲染模板路径中的变量，如 `cmd/{{project_name}}/main.go`
+    ///
+    /// 按路径段逐个渲染且不做 HTML 转义；渲染结果为空、包含路径分隔符或为 `.`/`..` 时报错
+    pub fn render_path(&self, path: &str, context: &HashMap<String, Value>) -> Result<String> {
+        if !path.contains("{{") {
+            return Ok(path.to_string());
+        }
+
+        let rendered = path
+            .split('/')
+            .map(|segment| render_path_segment(&self.path_handlebars, segment, context, path))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(rendered.join("/"))
     }
 
     /// 渲染指定的模板文件（强制使用嵌入式模板）
     pub fn render_template(
-        &mut self,
+        &self,
         template_path: &Path,
         data: &HashMap<String, Value>,
     ) -> Result<String> {
         let relative_path = normalize_path(&template_path.to_string_lossy());
 
-        println!("Reading embedded template: {relative_path}");
+        status!("Reading embedded template: {relative_path}");
         let template_content = read_embedded_template(&relative_path)
             .with_context(|| format!("Failed to read embedded template: {relative_path}"))?;
 
-        println!(
+        status!(
             "Embedded template read successfully, content length: {}",
             template_content.len()
         );
 
-        self.handlebars
-            .render_template(&template_content, data)
-            .with_context(|| {
-                format!("Template rendering failed for embedded template: {relative_path}")
-            })
+        self.render_named_template(&relative_path, &template_content, data)
+    }
+}
+
+/// 共享模板片段目录（相对于templates目录）
+pub const PARTIALS_DIR: &str = "_partials";
+
+/// 模板组根目录下的清单文件，描述模板组本身，不会输出到生成的项目中
+pub const TEMPLATE_MANIFEST: &str = "template.json";
+
+/// 模板组清单 `template.json` 的内容，所有字段均可省略
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct TemplateManifest {
+    /// 模板组的一句话描述
+    #[serde(default)]
+    pub description: Option<String>,
+    /// 生成的项目使用的主要依赖
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// 随选项变化的依赖：选项名（如 `logger`）→ 取值 → 该取值使用的依赖
+    #[serde(default)]
+    pub selectable_dependencies: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+    /// 生成的项目的主要文件和目录，目录以 `/` 结尾
+    #[serde(default)]
+    pub layout: Vec<String>,
+    /// 模板文件改名规则（模板中的名称 → 输出名称）
+    #[serde(default)]
+    pub renames: Option<Value>,
+    /// 项目生成后在项目目录中执行的命令，执行前需要用户确认或 `--trust`
+    #[serde(default)]
+    pub hooks: Vec<TemplateHook>,
+}
+
+/// 模板组声明的钩子命令
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateHook {
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// 执行时输出的说明，省略时使用命令本身
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl TemplateManifest {
+    /// 读取模板组的清单，模板组没有清单时返回 None
+    pub fn load(template_path: &str) -> Result<Option<Self>> {
+        let manifest_path = format!("{template_path}/{TEMPLATE_MANIFEST}");
+        if !embedded_template_exists(&manifest_path) {
+            return Ok(None);
+        }
+        serde_json::from_str(&read_embedded_template(&manifest_path)?)
+            .map(Some)
+            .with_context(|| format!("Invalid template manifest: {manifest_path}"))
+    }
+}
+
+/// 注册 `templates/_partials` 下的共享片段
+///
+/// 片段名称为去掉 `.hbs` 后缀的相对路径，如 `_partials/go/gitignore.hbs` 通过 `{{> go/gitignore}}` 引用
+fn register_embedded_partials(handlebars: &mut Handlebars<'static>) -> Result<()> {
+    let prefix = format!("{PARTIALS_DIR}/");
+    for partial_file in get_embedded_template_files(PARTIALS_DIR)? {
+        let name = partial_file.strip_prefix(&prefix).unwrap_or(&partial_file);
+        let name = name.strip_suffix(".hbs").unwrap_or(name);
+        let content = read_embedded_template(&partial_file)?;
+
+        handlebars
+            .register_partial(name, content)
+            .with_context(|| format!("Failed to register partial: {partial_file}"))?;
+    }
+    Ok(())
+}
+
+/// 记录引用的变量，`../` 开头的路径留给外层的 each/with 块处理
+fn record_variable(usage: &mut TemplateUsage, name: &str) {
+    if let Some(outer) = name.strip_prefix("../") {
+        usage.outer_paths.insert(outer.to_string());
+    } else if let Some(root) = variable_root(name) {
+        usage.variables.insert(root.to_string());
+    }
+}
+
+/// 提取变量路径的顶层名称，`this`、`@index`、`../name` 等上下文相关路径返回 None
+fn variable_root(path: &str) -> Option<&str> {
+    if path.starts_with('@') || path.starts_with('.') || path == "this" || path.starts_with("this.")
+    {
+        return None;
+    }
+    path.split(['.', '/', '['])
+        .next()
+        .filter(|root| !root.is_empty())
+}
+
+/// 将模板解析错误转换为包含模板位置的错误信息
+fn describe_template_error(name: &str, error: &TemplateError) -> anyhow::Error {
+    match error.pos() {
+        Some((line, column)) => anyhow::anyhow!("{name}:{line}:{column}: {}", error.reason()),
+        None => anyhow::anyhow!("{name}: {}", error.reason()),
+    }
+}
+
+/// 将 Handlebars 渲染错误转换为包含模板位置和可用变量的错误信息
+fn describe_render_error(
+    name: &str,
+    error: &RenderError,
+    context: &HashMap<String, Value>,
+) -> anyhow::Error {
+    let position = match error.reason() {
+        RenderErrorReason::TemplateError(template_error) => template_error.pos(),
+        _ => error.line_no.zip(error.column_no),
+    };
+    let location = match position {
+        Some((line, column)) => format!("{name}:{line}:{column}"),
+        None => name.to_string(),
+    };
+
+    let message = match error.reason() {
+        RenderErrorReason::MissingVariable(Some(variable)) => {
+            format!("undefined variable '{variable}'")
+        }
+        RenderErrorReason::MissingVariable(None) => "undefined variable".to_string(),
+        reason => reason.to_string(),
+    };
+
+    let mut keys: Vec<&str> = context.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+
+    anyhow::anyhow!(
+        "{location}: {message}\n  available variables: {}",
+        keys.join(", ")
+    )
+}
+
+/// 渲染单个路径段并校验结果
+fn render_path_segment(
+    handlebars: &Handlebars<'static>,
+    segment: &str,
+    context: &HashMap<String, Value>,
+    path: &str,
+) -> Result<String> {
+    if !segment.contains("{{") {
+        return Ok(segment.to_string());
+    }
+
+    let rendered = handlebars
+        .render_template(segment, context)
+        .with_context(|| format!("Failed to render template path: {path}"))?;
+    let rendered = rendered.trim();
+
+    if rendered.is_empty() || rendered == "." || rendered == ".." {
+        return Err(anyhow::anyhow!(
+            "Template path segment '{segment}' in '{path}' rendered to invalid name '{rendered}'"
+        ));
     }
+    if rendered.contains('/') || rendered.contains('\\') {
+        return Err(anyhow::anyhow!(
+            "Template path segment '{segment}' in '{path}' rendered to '{rendered}', which contains a path separator"
+        ));
+    }
+
+    Ok(rendered.to_string())
 }
 
 // Handlebars辅助函数
@@ -98,6 +487,66 @@ fn to_snake_case_helper(
     Ok(())
 }
 
+handlebars_helper!(to_pascal_case_helper: |s: str| string_utils::to_pascal_case(s));
+handlebars_helper!(to_kebab_case_helper: |s: str| string_utils::to_kebab_case(s));
+handlebars_helper!(upper_helper: |s: str| s.to_uppercase());
+handlebars_helper!(lower_helper: |s: str| s.to_lowercase());
+
+// {{pluralize "item"}} 或 {{pluralize "item" count=1}}，count 为 1 时保持单数
+handlebars_helper!(pluralize_helper: |word: str, { count: i64 = 2 }| {
+    if count == 1 {
+        word.to_string()
+    } else {
+        string_utils::pluralize(word)
+    }
+});
+
+/// `{{default value "fallback"}}`：value 缺失、为 null、空字符串或 false 时使用 fallback
+///
+/// 严格模式下宏生成的辅助函数会拒绝缺失的参数，因此这里手动实现
+struct DefaultHelper;
+
+impl HelperDef for DefaultHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &handlebars::Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc handlebars::Context,
+        _: &mut handlebars::RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let fallback = h
+            .param(1)
+            .ok_or(RenderErrorReason::ParamNotFoundForIndex("default", 1))?
+            .value();
+        let value = h.param(0).map(|p| p.value()).unwrap_or(&Value::Null);
+
+        let chosen = match value {
+            Value::Null | Value::Bool(false) => fallback,
+            Value::String(s) if s.is_empty() => fallback,
+            _ => value,
+        };
+        Ok(ScopedJson::Derived(chosen.clone()))
+    }
+}
+
+handlebars_helper!(year_helper: | | chrono::Local::now().year());
+
+// {{env "NAME"}} 或 {{env "NAME" default="fallback"}}
+handlebars_helper!(env_helper: |name: str, { default: str = "" }| {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+});
+
+// {{pm_run runtime "build"}}、{{pm_exec runtime "eslint ."}}：按运行时生成包管理命令
+handlebars_helper!(pm_install_helper: |runtime: str| {
+    Runtime::parse_from_str(runtime).unwrap_or_default().frozen_install_command()
+});
+handlebars_helper!(pm_run_helper: |runtime: str, script: str| {
+    Runtime::parse_from_str(runtime).unwrap_or_default().run_command(script)
+});
+handlebars_helper!(pm_exec_helper: |runtime: str, command: str| {
+    Runtime::parse_from_str(runtime).unwrap_or_default().exec_command(command)
+});
+
 /// 将字符串转换为驼峰命名
 fn to_camel_case(s: &str) -> String {
     s.split('-')
@@ -121,14 +570,89 @@ fn to_snake_case(s: &str) -> String {
 // 嵌入模板目录
 static EMBEDDED_TEMPLATES: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/templates");
 
+/// `--template-dir` 指定的本地模板目录，通常由 `scafgen template export` 导出后修改
+static LOCAL_TEMPLATES_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// 组织模板覆盖层目录，由 `--overlay` 或配置中的 `template_overlay` 指定
+static OVERLAY_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// 设置本地模板目录
+///
+/// 本地目录中存在的模板组（如 `frameworks/go/gin`）整体替换同路径的嵌入式模板组，
+/// 删除的文件不会再从嵌入式模板中补回；其余模板仍使用嵌入式版本。
+/// 共享片段在模板引擎首次创建时注册，因此需要在生成前设置
+pub fn set_local_templates_dir(dir: Option<PathBuf>) -> Result<()> {
+    set_templates_layer(&LOCAL_TEMPLATES_DIR, dir, "Template directory")
+}
+
+/// 设置组织模板覆盖层目录
+///
+/// 覆盖层按文件叠加在基础模板（嵌入式模板或 `--template-dir` 中的模板组）之上：
+/// 同路径的文件替换基础模板，新文件追加到模板组中。与本地模板目录一样需要在生成前设置
+pub fn set_overlay_dir(dir: Option<PathBuf>) -> Result<()> {
+    set_templates_layer(&OVERLAY_DIR, dir, "Template overlay directory")
+}
+
+fn set_templates_layer(
+    layer: &RwLock<Option<PathBuf>>,
+    dir: Option<PathBuf>,
+    kind: &str,
+) -> Result<()> {
+    if let Some(ref dir) = dir
+        && !dir.is_dir()
+    {
+        return Err(anyhow::anyhow!("{kind} not found: {}", dir.display()));
+    }
+    *layer.write().unwrap_or_else(|e| e.into_inner()) = dir;
+    Ok(())
+}
+
+fn templates_layer(layer: &RwLock<Option<PathBuf>>) -> Option<PathBuf> {
+    layer.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// 覆盖层或本地模板目录中的模板文件，覆盖层优先，都不存在时返回 None
+fn local_template_file(relative_path: &str) -> Option<PathBuf> {
+    [&OVERLAY_DIR, &LOCAL_TEMPLATES_DIR]
+        .into_iter()
+        .filter_map(templates_layer)
+        .map(|dir| dir.join(relative_path))
+        .find(|path| path.is_file())
+}
+
+/// 覆盖层或本地模板目录中是否存在该目录
+fn local_template_dir_exists(relative_path: &str) -> bool {
+    [&OVERLAY_DIR, &LOCAL_TEMPLATES_DIR]
+        .into_iter()
+        .filter_map(templates_layer)
+        .any(|dir| dir.join(relative_path).is_dir())
+}
+
+/// 列出本地目录下的所有文件，返回相对于 `root` 的 Unix 风格路径
+fn collect_local_files(root: &Path, dir: &Path) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry.with_context(|| format!("Failed to read {}", dir.display()))?;
+        if entry.file_type().is_file() {
+            let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+            files.push(normalize_path(&relative.to_string_lossy()));
+        }
+    }
+    Ok(files)
+}
+
 /// 获取模板目录路径（强制使用嵌入式模板）
 pub fn get_templates_dir() -> Result<PathBuf> {
     // 直接返回空路径，因为所有模板都是嵌入式的
     Ok(PathBuf::new())
 }
 
-/// 从嵌入式模板读取文件内容
+/// 从嵌入式模板读取文件内容，本地模板目录中的同名文件优先
 pub fn read_embedded_template(relative_path: &str) -> Result<String> {
+    if let Some(path) = local_template_file(relative_path) {
+        return std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read template: {}", path.display()));
+    }
     if let Some(file) = EMBEDDED_TEMPLATES.get_file(relative_path) {
         Ok(String::from_utf8_lossy(file.contents()).to_string())
     } else {
@@ -138,16 +662,20 @@ pub fn read_embedded_template(relative_path: &str) -> Result<String> {
     }
 }
 
-/// 检查嵌入式模板文件是否存在
+/// 检查嵌入式模板文件是否存在（包括本地模板目录中的文件）
 pub fn embedded_template_exists(relative_path: &str) -> bool {
-    EMBEDDED_TEMPLATES.get_file(relative_path).is_some()
+    local_template_file(relative_path).is_some()
+        || EMBEDDED_TEMPLATES.get_file(relative_path).is_some()
 }
 
-/// 检查嵌入式模板目录是否存在
+/// 检查嵌入式模板目录是否存在（包括本地模板目录中的目录）
 pub fn embedded_template_dir_exists(relative_path: &str) -> bool {
     if relative_path.is_empty() {
         return true; // 根目录总是存在
     }
+    if local_template_dir_exists(relative_path) {
+        return true;
+    }
 
     // 检查是否有文件以该路径开头
     for file in EMBEDDED_TEMPLATES.files() {
@@ -182,15 +710,53 @@ pub fn embedded_template_dir_exists(relative_path: &str) -> bool {
     check_dir_recursive(&EMBEDDED_TEMPLATES, relative_path, "")
 }
 
-/// 获取嵌入式模板内容
-pub fn get_embedded_template_content(relative_path: &str) -> Option<String> {
+/// 获取嵌入式文件的原始字节，直接引用二进制中的数据而不复制；本地模板目录中的同名文件优先
+pub fn get_embedded_template_bytes(relative_path: &str) -> Option<Cow<'static, [u8]>> {
+    if let Some(path) = local_template_file(relative_path) {
+        return std::fs::read(path).ok().map(Cow::Owned);
+    }
     EMBEDDED_TEMPLATES
         .get_file(relative_path)
-        .map(|file| String::from_utf8_lossy(file.contents()).to_string())
+        .map(|file| Cow::Borrowed(file.contents()))
+}
+
+/// 获取嵌入式模板内容
+#[allow(dead_code)]
+pub fn get_embedded_template_content(relative_path: &str) -> Option<String> {
+    get_embedded_template_bytes(relative_path)
+        .map(|contents| String::from_utf8_lossy(&contents).to_string())
 }
 
 /// 获取嵌入式模板目录中的所有文件
+///
+/// 本地模板目录中存在同路径的目录时只列出本地文件，再追加覆盖层中新增的文件；
+/// 根目录始终只列出嵌入式模板
 pub fn get_embedded_template_files(relative_path: &str) -> Result<Vec<String>> {
+    if relative_path.is_empty() {
+        return list_embedded_files(relative_path);
+    }
+
+    let local_dir =
+        templates_layer(&LOCAL_TEMPLATES_DIR).filter(|root| root.join(relative_path).is_dir());
+    let mut files = match local_dir {
+        Some(root) => collect_local_files(&root, &root.join(relative_path))?,
+        None => list_embedded_files(relative_path)?,
+    };
+
+    if let Some(overlay) =
+        templates_layer(&OVERLAY_DIR).filter(|root| root.join(relative_path).is_dir())
+    {
+        for file in collect_local_files(&overlay, &overlay.join(relative_path))? {
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// 列出嵌入式模板目录中的所有文件，不考虑本地模板目录
+pub fn list_embedded_files(relative_path: &str) -> Result<Vec<String>> {
     fn collect_files_recursive(dir: &Dir, current_path: &str, files: &mut Vec<String>) {
         for file in dir.files() {
             let file_path = if current_path.is_empty() {
@@ -233,3 +799,270 @@ pub fn get_embedded_template_files(relative_path: &str) -> Result<Vec<String>> {
         Ok(filtered_files)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_path() {
+        let engine = TemplateEngine::new(PathBuf::new()).unwrap();
+        let mut context = HashMap::new();
+        context.insert("project_name".to_string(), Value::from("my-app"));
+        context.insert("package_name".to_string(), Value::from("my_app"));
+        context.insert("empty".to_string(), Value::from(""));
+        context.insert("nested".to_string(), Value::from("a/b"));
+
+        assert_eq!(
+            engine
+                .render_path("cmd/{{project_name}}/main.go", &context)
+                .unwrap(),
+            "cmd/my-app/main.go"
+        );
+        assert_eq!(
+            engine
+                .render_path("{{package_name}}/__init__.py", &context)
+                .unwrap(),
+            "my_app/__init__.py"
+        );
+        assert_eq!(
+            engine.render_path("src/lib.rs", &context).unwrap(),
+            "src/lib.rs"
+        );
+        assert!(engine.render_path("{{empty}}/main.go", &context).is_err());
+        assert!(engine.render_path("{{nested}}/main.go", &context).is_err());
+    }
+
+    #[test]
+    fn test_shared_engine_keeps_body_escaping() {
+        let first = TemplateEngine::shared().unwrap();
+        let second = TemplateEngine::shared().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // 路径渲染不转义，正文渲染仍然进行 HTML 转义
+        let mut context = HashMap::new();
+        context.insert("name".to_string(), Value::from("a&b"));
+        assert_eq!(
+            first.render_path("{{name}}.txt", &context).unwrap(),
+            "a&b.txt"
+        );
+        assert_eq!(
+            first
+                .render_named_template("test", "{{name}}", &context)
+                .unwrap(),
+            "a&amp;b"
+        );
+    }
+
+    fn render(template: &str) -> String {
+        let engine = TemplateEngine::new(PathBuf::new()).unwrap();
+        let mut context = HashMap::new();
+        context.insert("project_name".to_string(), Value::from("my_app"));
+        context.insert("empty".to_string(), Value::from(""));
+        context.insert("count".to_string(), Value::from(1));
+        engine
+            .render_named_template("test", template, &context)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_case_helpers() {
+        assert_eq!(render("{{to_pascal_case project_name}}"), "MyApp");
+        assert_eq!(render("{{to_kebab_case project_name}}"), "my-app");
+        assert_eq!(render("{{upper project_name}}"), "MY_APP");
+        assert_eq!(render("{{lower \"MyApp\"}}"), "myapp");
+    }
+
+    #[test]
+    fn test_pluralize_helper() {
+        assert_eq!(render("{{pluralize \"category\"}}"), "categories");
+        assert_eq!(render("{{pluralize \"user\" count=count}}"), "user");
+        assert_eq!(render("{{pluralize \"user\" count=3}}"), "users");
+    }
+
+    #[test]
+    fn test_default_helper() {
+        assert_eq!(render("{{default empty \"fallback\"}}"), "fallback");
+        assert_eq!(render("{{default missing \"fallback\"}}"), "fallback");
+        assert_eq!(render("{{default project_name \"fallback\"}}"), "my_app");
+        assert_eq!(
+            render("{{to_pascal_case (default missing \"demo-app\")}}"),
+            "DemoApp"
+        );
+    }
+
+    #[test]
+    fn test_strict_mode_reports_missing_variable() {
+        let engine = TemplateEngine::new(PathBuf::new()).unwrap();
+        let mut context = HashMap::new();
+        context.insert("project_name".to_string(), Value::from("demo"));
+        context.insert("port".to_string(), Value::from(8080));
+
+        let error = engine
+            .render_named_template(
+                "main.go.tmpl",
+                "package main\n// {{projet_name}}\n",
+                &context,
+            )
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("main.go.tmpl:2:"), "{error}");
+        assert!(
+            error.contains("undefined variable 'projet_name'"),
+            "{error}"
+        );
+        assert!(
+            error.contains("available variables: port, project_name"),
+            "{error}"
+        );
+
+        // 条件判断中引用缺失变量是允许的
+        assert_eq!(
+            engine
+                .render_named_template("t", "{{#if homepage}}x{{/if}}", &context)
+                .unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_partials_registered() {
+        let engine = TemplateEngine::new(PathBuf::new()).unwrap();
+        assert!(engine.handlebars.has_template("go/gitignore"));
+        assert!(engine.handlebars.has_template("frontend/tailwind-styles"));
+
+        let rendered = render("{{> go/pre-commit-config}}");
+        assert!(rendered.starts_with("repos:"), "{rendered}");
+    }
+
+    #[test]
+    fn test_analyze_template() {
+        let engine = TemplateEngine::new(PathBuf::new()).unwrap();
+        let usage = engine
+            .analyze_template(
+                "t",
+                "{{to_pascal_case project_name}} {{server.port}} {{year}}\n\
+                 {{#if enable_cors}}{{#each origins}}{{this}}{{@index}}{{host}}{{../scheme}}{{/each}}\
+                 {{else}}{{default (lower fallback) \"x\"}}{{/if}}{{> go/gitignore}}",
+            )
+            .unwrap();
+
+        let set = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<BTreeSet<_>>();
+        assert_eq!(
+            usage.variables,
+            set(&[
+                "enable_cors",
+                "fallback",
+                "origins",
+                "project_name",
+                "scheme",
+                "server"
+            ])
+        );
+        assert_eq!(
+            usage.helpers,
+            set(&["default", "each", "if", "lower", "to_pascal_case", "year"])
+        );
+        assert_eq!(usage.partials, set(&["go/gitignore"]));
+        assert_eq!(usage.conditions, set(&["enable_cors"]));
+        assert!(!engine.is_helper_registered("unknown_helper"));
+
+        let error = engine
+            .analyze_template("broken.tmpl", "line1\n{{#if x}}")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("broken.tmpl:"), "{error}");
+    }
+
+    #[test]
+    fn test_year_helper() {
+        assert_eq!(render("{{year}}"), chrono::Local::now().year().to_string());
+    }
+
+    #[test]
+    fn test_env_helper() {
+        assert_eq!(render("{{env \"PATH\"}}"), std::env::var("PATH").unwrap());
+        assert_eq!(
+            render("{{env \"SCAFGEN_TEST_UNSET_VAR\" default=\"none\"}}"),
+            "none"
+        );
+        assert_eq!(render("{{env \"SCAFGEN_TEST_UNSET_VAR\"}}"), "");
+    }
+
+    #[test]
+    fn test_package_manager_helpers() {
+        assert_eq!(
+            render("{{pm_install \"node\"}}"),
+            "pnpm install --frozen-lockfile"
+        );
+        assert_eq!(render("{{pm_run \"bun\" \"build\"}}"), "bun run build");
+        assert_eq!(
+            render("{{pm_exec \"deno\" \"eslint .\"}}"),
+            "deno run -A npm:eslint ."
+        );
+    }
+
+    #[test]
+    fn test_local_templates_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let family = dir.path().join("frameworks/local/demo");
+        std::fs::create_dir_all(family.join("src")).unwrap();
+        std::fs::write(family.join("src/main.go.tmpl"), "package main\n").unwrap();
+
+        assert!(set_local_templates_dir(Some(dir.path().join("missing"))).is_err());
+        set_local_templates_dir(Some(dir.path().to_path_buf())).unwrap();
+        // 本地模板组替换同路径的嵌入式模板组，其他模板仍从二进制中读取
+        assert!(embedded_template_dir_exists("frameworks/local/demo"));
+        assert_eq!(
+            get_embedded_template_files("frameworks/local/demo").unwrap(),
+            ["frameworks/local/demo/src/main.go.tmpl"]
+        );
+        assert_eq!(
+            read_embedded_template("frameworks/local/demo/src/main.go.tmpl").unwrap(),
+            "package main\n"
+        );
+        assert!(embedded_template_exists("licenses/MIT.tmpl"));
+
+        // 覆盖层按文件叠加：同路径的文件替换基础模板，新文件追加到模板组
+        let overlay = tempfile::tempdir().unwrap();
+        let overlay_family = overlay.path().join("frameworks/local/demo");
+        std::fs::create_dir_all(overlay_family.join("src")).unwrap();
+        std::fs::write(overlay_family.join("src/main.go.tmpl"), "package acme\n").unwrap();
+        std::fs::write(overlay_family.join("src/logging.go.tmpl"), "package log\n").unwrap();
+        assert!(set_overlay_dir(Some(overlay.path().join("missing"))).is_err());
+        set_overlay_dir(Some(overlay.path().to_path_buf())).unwrap();
+        assert_eq!(
+            get_embedded_template_files("frameworks/local/demo").unwrap(),
+            [
+                "frameworks/local/demo/src/main.go.tmpl",
+                "frameworks/local/demo/src/logging.go.tmpl"
+            ]
+        );
+        assert_eq!(
+            read_embedded_template("frameworks/local/demo/src/main.go.tmpl").unwrap(),
+            "package acme\n"
+        );
+
+        // 清单中声明的钩子随覆盖层一起读取
+        std::fs::write(
+            overlay_family.join(TEMPLATE_MANIFEST),
+            r#"{"hooks": [{"command": "make", "args": ["setup"]}]}"#,
+        )
+        .unwrap();
+        let manifest = TemplateManifest::load("frameworks/local/demo")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            manifest.hooks,
+            [TemplateHook {
+                command: "make".to_string(),
+                args: vec!["setup".to_string()],
+                description: None,
+            }]
+        );
+        set_overlay_dir(None).unwrap();
+        set_local_templates_dir(None).unwrap();
+
+        assert!(!embedded_template_dir_exists("frameworks/local/demo"));
+    }
+}
@@ -0,0 +1,316 @@
+use anyhow::{Context, Result};
+use inquire::list_option::ListOption;
+use inquire::validator::{ErrorMessage, Validation};
+use inquire::{Confirm, MultiSelect, Select, Text};
+use serde_json::{Map, Value};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::io::{IsTerminal, Read};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::status;
+
+/// 从标准输入读取的答案，首次提问时加载
+static ANSWERS: Mutex<Option<Answers>> = Mutex::new(None);
+
+/// 是否已经询问过任何选项
+static PROMPTED: AtomicBool = AtomicBool::new(false);
+
+/// 标准输入不是终端时的答案来源
+///
+/// 输入以 `{` 开头时按 JSON 对象解析，键为提问的标识（如 `language`、`port`），
+/// 否则每行依次回答一个提问
+#[derive(Debug)]
+enum Answers {
+    Lines(VecDeque<String>),
+    Keyed(Map<String, Value>),
+}
+
+impl Answers {
+    fn parse(input: &str) -> Result<Self> {
+        if input.trim_start().starts_with('{') {
+            let answers = serde_json::from_str(input)
+                .context("Failed to parse answers from stdin as a JSON object")?;
+            return Ok(Answers::Keyed(answers));
+        }
+        Ok(Answers::Lines(
+            input
+                .lines()
+                .map(|line| line.trim_end_matches('\r').to_string())
+                .collect(),
+        ))
+    }
+
+    /// 取出下一个答案，`None` 表示使用提问的默认值
+    ///
+    /// 逐行回答时空行表示默认值，行数不够时报错；JSON 中缺少的键使用默认值
+    fn next(&mut self, key: &str, message: &str) -> Result<Option<String>> {
+        match self {
+            Answers::Lines(lines) => {
+                let line = lines
+                    .pop_front()
+                    .ok_or_else(|| anyhow::anyhow!("No answer on stdin for prompt '{message}'"))?;
+                Ok(Some(line).filter(|line| !line.trim().is_empty()))
+            }
+            Answers::Keyed(answers) => match answers.get(key) {
+                None | Some(Value::Null) => Ok(None),
+                Some(Value::String(answer)) => Ok(Some(answer.clone())),
+                Some(Value::Bool(answer)) => Ok(Some(if *answer { "yes" } else { "no" }.into())),
+                Some(Value::Number(answer)) => Ok(Some(answer.to_string())),
+                Some(Value::Array(items)) => Ok(Some(
+                    items
+                        .iter()
+                        .map(|item| match item {
+                            Value::String(item) => item.clone(),
+                            item => item.to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(","),
+                )),
+                Some(Value::Object(_)) => Err(anyhow::anyhow!(
+                    "Invalid answer for '{key}': expected a string, number, boolean or array"
+                )),
+            },
+        }
+    }
+}
+
+/// 是否从标准输入读取答案（标准输入不是终端）
+pub fn is_piped() -> bool {
+    !std::io::stdin().is_terminal()
+}
+
+/// 本次运行是否询问过任何选项，全部由命令行参数指定时为 false
+pub fn has_prompted() -> bool {
+    PROMPTED.load(Ordering::Relaxed)
+}
+
+/// 读取下一个答案，首次调用时读入全部标准输入
+fn next_answer(key: &str, message: &str) -> Result<Option<String>> {
+    PROMPTED.store(true, Ordering::Relaxed);
+    let mut answers = ANSWERS.lock().unwrap_or_else(|e| e.into_inner());
+    if answers.is_none() {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read answers from stdin")?;
+        *answers = Some(Answers::parse(&input)?);
+    }
+    match answers.as_mut() {
+        Some(answers) => answers.next(key, message),
+        None => Ok(None),
+    }
+}
+
+/// 单选提问，标准输入不是终端时按选项文本（不区分大小写，可省略括号中的说明）或从 1 开始的序号匹配
+pub fn select<T: Display>(key: &str, prompt: Select<T>) -> Result<T> {
+    if !is_piped() {
+        PROMPTED.store(true, Ordering::Relaxed);
+        return Ok(prompt.prompt()?);
+    }
+    let answer = next_answer(key, prompt.message)?;
+    let selected = resolve_select(prompt.options, prompt.starting_cursor, answer.as_deref())
+        .with_context(|| format!("Invalid answer for '{}'", prompt.message))?;
+    status!("{} {selected}", prompt.message);
+    Ok(selected)
+}
+
+/// 多选提问，标准输入不是终端时读取逗号分隔的选项
+pub fn multi_select<T: Display>(key: &str, prompt: MultiSelect<T>) -> Result<Vec<T>> {
+    if !is_piped() {
+        PROMPTED.store(true, Ordering::Relaxed);
+        return Ok(prompt.prompt()?);
+    }
+    let answer = next_answer(key, prompt.message)?;
+    let message = prompt.message;
+    let indices = match answer {
+        Some(answer) => answer
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| option_index(&prompt.options, item))
+            .collect::<Result<Vec<_>>>()
+            .with_context(|| format!("Invalid answer for '{message}'"))?,
+        None => prompt.default.clone().unwrap_or_default(),
+    };
+
+    if let Some(ref validator) = prompt.validator {
+        let selected: Vec<ListOption<&T>> = indices
+            .iter()
+            .map(|&index| ListOption::new(index, &prompt.options[index]))
+            .collect();
+        check_validation(message, validator.validate(&selected))?;
+    }
+
+    let selected: Vec<T> = prompt
+        .options
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| indices.contains(index))
+        .map(|(_, option)| option)
+        .collect();
+    status!(
+        "{message} {}",
+        selected
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(selected)
+}
+
+/// 确认提问，标准输入不是终端时读取 y/yes/true 或 n/no/false
+pub fn confirm(key: &str, prompt: Confirm) -> Result<bool> {
+    if !is_piped() {
+        PROMPTED.store(true, Ordering::Relaxed);
+        return Ok(prompt.prompt()?);
+    }
+    let answer = next_answer(key, prompt.message)?;
+    let confirmed = resolve_confirm(prompt.default, answer.as_deref())
+        .with_context(|| format!("Invalid answer for '{}'", prompt.message))?;
+    status!(
+        "{} {}",
+        prompt.message,
+        if confirmed { "Yes" } else { "No" }
+    );
+    Ok(confirmed)
+}
+
+/// 文本提问，标准输入不是终端时读取答案并执行提问上的校验
+pub fn text(key: &str, prompt: Text) -> Result<String> {
+    if !is_piped() {
+        PROMPTED.store(true, Ordering::Relaxed);
+        return Ok(prompt.prompt()?);
+    }
+    let answer = next_answer(key, prompt.message)?
+        .or_else(|| prompt.default.map(str::to_string))
+        .unwrap_or_default();
+    for validator in &prompt.validators {
+        check_validation(prompt.message, validator.validate(&answer))?;
+    }
+    status!("{} {answer}", prompt.message);
+    Ok(answer)
+}
+
+fn check_validation(
+    message: &str,
+    validation: Result<Validation, inquire::CustomUserError>,
+) -> Result<()> {
+    match validation {
+        Ok(Validation::Valid) => Ok(()),
+        Ok(Validation::Invalid(ErrorMessage::Custom(error))) => {
+            Err(anyhow::anyhow!("Invalid answer for '{message}': {error}"))
+        }
+        Ok(Validation::Invalid(ErrorMessage::Default)) => {
+            Err(anyhow::anyhow!("Invalid answer for '{message}'"))
+        }
+        Err(e) => Err(anyhow::anyhow!("Invalid answer for '{message}': {e}")),
+    }
+}
+
+fn resolve_select<T: Display>(options: Vec<T>, default: usize, answer: Option<&str>) -> Result<T> {
+    let index = match answer {
+        Some(answer) => option_index(&options, answer.trim())?,
+        None => default,
+    };
+    options
+        .into_iter()
+        .nth(index)
+        .ok_or_else(|| anyhow::anyhow!("No options to choose from"))
+}
+
+/// 按选项文本、省略括号说明后的名称或从 1 开始的序号查找选项
+fn option_index<T: Display>(options: &[T], answer: &str) -> Result<usize> {
+    let labels: Vec<String> = options.iter().map(ToString::to_string).collect();
+    let position = labels.iter().position(|label| {
+        let name = label.split(" (").next().unwrap_or(label);
+        label.eq_ignore_ascii_case(answer) || name.eq_ignore_ascii_case(answer)
+    });
+    let position = position.or_else(|| {
+        answer
+            .parse::<usize>()
+            .ok()
+            .filter(|number| (1..=options.len()).contains(number))
+            .map(|number| number - 1)
+    });
+    position.ok_or_else(|| {
+        anyhow::anyhow!(
+            "'{answer}' is not one of the options: {}",
+            labels.join(", ")
+        )
+    })
+}
+
+fn resolve_confirm(default: Option<bool>, answer: Option<&str>) -> Result<bool> {
+    let Some(answer) = answer else {
+        return default.ok_or_else(|| anyhow::anyhow!("An answer is required"));
+    };
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" | "true" => Ok(true),
+        "n" | "no" | "false" => Ok(false),
+        _ => Err(anyhow::anyhow!("'{answer}' is not a yes/no answer")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let mut answers = Answers::parse("Go\r\n\n8081\n").unwrap();
+        assert_eq!(answers.next("language", "").unwrap().as_deref(), Some("Go"));
+        assert_eq!(answers.next("framework", "").unwrap(), None);
+        assert_eq!(answers.next("port", "").unwrap().as_deref(), Some("8081"));
+        let error = answers.next("license", "Select a license:").unwrap_err();
+        assert!(error.to_string().contains("Select a license:"));
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let mut answers = Answers::parse(
+            r#"{"language": "rust", "port": 8081, "redis": true, "services": ["api", "rpc"]}"#,
+        )
+        .unwrap();
+        assert_eq!(answers.next("port", "").unwrap().as_deref(), Some("8081"));
+        assert_eq!(answers.next("redis", "").unwrap().as_deref(), Some("yes"));
+        assert_eq!(
+            answers.next("services", "").unwrap().as_deref(),
+            Some("api,rpc")
+        );
+        // 未提供的键使用默认值，顺序无关
+        assert_eq!(answers.next("license", "").unwrap(), None);
+        assert_eq!(
+            answers.next("language", "").unwrap().as_deref(),
+            Some("rust")
+        );
+
+        assert!(Answers::parse("{ invalid").is_err());
+    }
+
+    #[test]
+    fn test_option_index() {
+        let options = ["Gin (Go Web Framework)", "go-zero (Microservice Framework)"];
+        assert_eq!(option_index(&options, "gin").unwrap(), 0);
+        assert_eq!(option_index(&options, "Go-Zero").unwrap(), 1);
+        assert_eq!(option_index(&options, "2").unwrap(), 1);
+        assert!(option_index(&options, "3").is_err());
+        assert!(option_index(&options, "chi").is_err());
+
+        assert_eq!(
+            resolve_select(options.to_vec(), 0, None).unwrap(),
+            options[0]
+        );
+    }
+
+    #[test]
+    fn test_resolve_confirm() {
+        assert!(resolve_confirm(Some(false), Some("Yes")).unwrap());
+        assert!(!resolve_confirm(Some(true), Some("n")).unwrap());
+        assert!(resolve_confirm(Some(true), None).unwrap());
+        assert!(resolve_confirm(None, None).is_err());
+        assert!(resolve_confirm(Some(true), Some("maybe")).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::constants::ApiClient;
+use crate::utils::tailwind::VITE_CONFIGS;
+use crate::utils::tool_failures;
+
+/// 全栈项目中由 Vite 开发服务器转发到后端的路径
+const PROXY_PATHS: &[&str] = &["/api", "/health"];
+
+/// 生成 API 客户端层后提示用户配置后端地址
+pub const BASE_URL_CAVEAT: &str =
+    "Set VITE_API_BASE_URL in .env to point the API client at your backend (default: /api)";
+
+/// 生成登录注册页面后提示后端需要提供的接口
+pub const AUTH_ENDPOINTS_CAVEAT: &str = "The auth pages call POST /auth/login, POST /auth/register and GET /auth/me under VITE_API_BASE_URL; \
+     login and register must return { token, user } with a JWT sent back as a Bearer token";
+
+/// Vue3 和 React 共用的 API 客户端层模板变量，文件位于 `+api_client`、`+tanstack_query` 和 `+auth_pages` 目录
+pub fn template_context(
+    api_layer: Option<ApiClient>,
+    enable_tanstack_query: bool,
+    enable_auth_pages: bool,
+) -> HashMap<String, Value> {
+    HashMap::from([
+        ("enable_api_client".to_string(), json!(api_layer.is_some())),
+        (
+            "api_client".to_string(),
+            json!(api_layer.unwrap_or_default().as_str()),
+        ),
+        (
+            "enable_tanstack_query".to_string(),
+            json!(enable_tanstack_query),
+        ),
+        ("enable_auth_pages".to_string(), json!(enable_auth_pages)),
+    ])
+}
+
+/// 在 Vite 配置中加入开发服务器的代理，把 `/api` 和 `/health` 转发到后端 `target`
+pub fn configure_dev_proxy(output_path: &Path, target: &str) -> Result<()> {
+    let Some(config_path) = VITE_CONFIGS
+        .iter()
+        .map(|name| output_path.join(name))
+        .find(|path| path.exists())
+    else {
+        return tool_failures::report(format!(
+            "No Vite config found; proxy /api to {target} manually"
+        ));
+    };
+
+    let config = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    match with_dev_proxy(&config, target) {
+        Some(updated) => std::fs::write(&config_path, updated)
+            .with_context(|| format!("Failed to write {}", config_path.display())),
+        None if config.contains(target) => Ok(()),
+        None => tool_failures::report(format!(
+            "Could not add a dev server proxy to {}; proxy /api to {target} manually",
+            config_path.display()
+        )),
+    }
+}
+
+/// 在 `defineConfig({` 之后加入 `server.proxy`，已有 `server` 配置或找不到 `defineConfig({` 时返回 `None`
+fn with_dev_proxy(config: &str, target: &str) -> Option<String> {
+    if config.contains("server:") {
+        return None;
+    }
+    let insert_at = config.find("defineConfig({")? + "defineConfig({".len();
+
+    let mut server = String::from("\n  server: {\n    proxy: {\n");
+    for path in PROXY_PATHS {
+        server.push_str(&format!("      '{path}': '{target}',\n"));
+    }
+    server.push_str("    },\n  },");
+
+    let mut updated = config.to_string();
+    updated.insert_str(insert_at, &server);
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_dev_proxy() {
+        let config = "import { defineConfig } from 'vite'\nimport react from '@vitejs/plugin-react'\n\nexport default defineConfig({\n  plugins: [react()],\n})\n";
+        let updated = with_dev_proxy(config, "http://localhost:8080").unwrap();
+        assert_eq!(
+            updated,
+            "import { defineConfig } from 'vite'\nimport react from '@vitejs/plugin-react'\n\nexport default defineConfig({\n  server: {\n    proxy: {\n      '/api': 'http://localhost:8080',\n      '/health': 'http://localhost:8080',\n    },\n  },\n  plugins: [react()],\n})\n"
+        );
+        assert!(with_dev_proxy(&updated, "http://localhost:8080").is_none());
+        assert!(with_dev_proxy("export default {}\n", "http://localhost:8080").is_none());
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// 应用目录名称
+const APP_NAME: &str = "scafgen";
+
+/// 旧版本使用的配置文件名，用于识别已有的配置目录
+const LEGACY_CONFIG_FILE: &str = "config.json";
+
+fn project_dirs() -> Option<ProjectDirs> {
+    ProjectDirs::from("", "", APP_NAME)
+}
+
+fn non_empty(key: &str) -> Option<PathBuf> {
+    std::env::var_os(key)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// 配置目录，保存 `config.json`
+///
+/// 设置了 `$XDG_CONFIG_HOME` 时在所有平台上使用 `$XDG_CONFIG_HOME/scafgen`；
+/// 旧版本的 `~/.config/scafgen`（Windows 为 `%APPDATA%\scafgen`）中已有配置文件时继续使用它，
+/// 否则使用平台的配置目录（Linux `~/.config/scafgen`，macOS `~/Library/Application Support/scafgen`，
+/// Windows `%APPDATA%\scafgen\config`）
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = non_empty("XDG_CONFIG_HOME") {
+        return Some(dir.join(APP_NAME));
+    }
+    if let Some(dir) = legacy_config_dir().filter(|dir| dir.join(LEGACY_CONFIG_FILE).is_file()) {
+        return Some(dir);
+    }
+    project_dirs().map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+/// 缓存目录，保存下载的许可证文本等可以重新获取的文件
+///
+/// Linux 为 `$XDG_CACHE_HOME/scafgen`（默认 `~/.cache/scafgen`），macOS 为 `~/Library/Caches/scafgen`，
+/// Windows 为 `%LOCALAPPDATA%\scafgen\cache`
+pub fn cache_dir() -> Option<PathBuf> {
+    project_dirs().map(|dirs| dirs.cache_dir().to_path_buf())
+}
+
+/// 状态目录，保存步骤耗时和钩子审计日志等运行记录
+///
+/// Linux 为 `$XDG_STATE_HOME/scafgen`（默认 `~/.local/state/scafgen`），
+/// 其他平台没有单独的状态目录，使用本地数据目录
+pub fn state_dir() -> Option<PathBuf> {
+    project_dirs().map(|dirs| {
+        dirs.state_dir()
+            .unwrap_or_else(|| dirs.data_local_dir())
+            .to_path_buf()
+    })
+}
+
+fn legacy_config_dir() -> Option<PathBuf> {
+    if cfg!(windows)
+        && let Some(dir) = non_empty("APPDATA")
+    {
+        return Some(dir.join(APP_NAME));
+    }
+    non_empty("HOME").map(|home| home.join(".config").join(APP_NAME))
+}
+
+/// 持有 `<文件>.lock` 的排他锁执行 `f`，用于同时运行的多个 scafgen 进程读改写同一个文件
+///
+/// 锁在 `f` 返回后随锁文件关闭释放，进程异常退出时由系统释放
+pub fn with_lock<T>(path: &Path, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let mut lock_name = path.file_name().unwrap_or_default().to_os_string();
+    lock_name.push(".lock");
+    let lock_path = path.with_file_name(lock_name);
+    if let Some(parent) = lock_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let lock = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .with_context(|| format!("Failed to open lock file: {}", lock_path.display()))?;
+    lock.lock()
+        .with_context(|| format!("Failed to lock {}", lock_path.display()))?;
+    f()
+}
+
+/// 先写入同目录的临时文件再重命名，其他进程只会读到完整的旧文件或新文件
+pub fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let parent = path.parent().unwrap_or(Path::new("."));
+    std::fs::create_dir_all(parent)
+        .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    let mut file = tempfile::NamedTempFile::new_in(parent)
+        .with_context(|| format!("Failed to create a temporary file in {}", parent.display()))?;
+    file.write_all(contents.as_bytes())
+        .and_then(|_| file.as_file().sync_all())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    file.persist(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// 以追加方式打开文件并持有排他锁，多个进程同时写入的记录不会交错
+pub fn open_append_locked(path: &Path) -> Result<File> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    file.lock()
+        .with_context(|| format!("Failed to lock {}", path.display()))?;
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_lock_serializes_updates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state").join("counter.json");
+
+        let workers: Vec<_> = (0..8)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..10 {
+                        with_lock(&path, || {
+                            let count: u32 = std::fs::read_to_string(&path)
+                                .ok()
+                                .and_then(|content| content.parse().ok())
+                                .unwrap_or_default();
+                            write_atomic(&path, &(count + 1).to_string())
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "80");
+        assert!(path.with_file_name("counter.json.lock").exists());
+    }
+}
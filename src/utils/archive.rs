@@ -0,0 +1,455 @@
+use anyhow::{Context, Result};
+use chrono::{Datelike, Local, Timelike};
+use flate2::{Compression, write::GzEncoder};
+use std::io::Write;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// 打包格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// 未压缩的 ustar 格式 tar 包
+    Tar,
+    /// gzip 压缩的 tar 包
+    TarGz,
+    /// 仅存储（不压缩）的 zip 包
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// 从字符串解析打包格式
+    pub fn parse_from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "tar" => Some(ArchiveFormat::Tar),
+            "tar.gz" | "tgz" => Some(ArchiveFormat::TarGz),
+            "zip" => Some(ArchiveFormat::Zip),
+            _ => None,
+        }
+    }
+
+    /// 文件扩展名
+    #[allow(dead_code)]
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+
+    /// 按文件扩展名（`.zip`、`.tar`、`.tar.gz`、`.tgz`）确定打包格式
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_string_lossy().to_lowercase();
+        ["tar.gz", "tgz", "tar", "zip"]
+            .into_iter()
+            .find(|extension| file_name.ends_with(&format!(".{extension}")))
+            .and_then(Self::parse_from_str)
+    }
+
+    /// HTTP 响应的 Content-Type
+    #[allow(dead_code)]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "application/x-tar",
+            ArchiveFormat::TarGz => "application/gzip",
+            ArchiveFormat::Zip => "application/zip",
+        }
+    }
+}
+
+/// 支持的打包格式
+#[allow(dead_code)]
+pub const ARCHIVE_FORMATS: &[&str] = &["tar", "tar.gz", "zip"];
+
+/// 打包条目
+struct Entry {
+    /// 包内路径，以 `/` 分隔，目录以 `/` 结尾
+    name: String,
+    mode: u32,
+    data: Option<Vec<u8>>,
+}
+
+/// 将目录打包写入 writer，包内路径以 `root_name/` 为前缀
+///
+/// 条目按文件名排序，依次读取并写出，写完一个条目后再读取下一个文件
+pub fn write_archive<W: Write>(
+    format: ArchiveFormat,
+    dir: &Path,
+    root_name: &str,
+    writer: W,
+) -> Result<()> {
+    let mut writer = match format {
+        ArchiveFormat::Tar => write_entries(dir, root_name, TarWriter::new(writer))?,
+        ArchiveFormat::TarGz => {
+            let encoder = GzEncoder::new(writer, Compression::default());
+            write_entries(dir, root_name, TarWriter::new(encoder))?
+                .finish()
+                .context("Failed to finish gzip stream")?
+        }
+        ArchiveFormat::Zip => write_entries(dir, root_name, ZipWriter::new(writer))?,
+    };
+    writer.flush()?;
+    Ok(())
+}
+
+/// 写入目录下的全部条目，返回写入器内部的 writer
+fn write_entries<A: ArchiveWriter>(dir: &Path, root_name: &str, mut writer: A) -> Result<A::Inner> {
+    for entry in WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry.with_context(|| format!("Failed to walk {}", dir.display()))?;
+        let relative = entry
+            .path()
+            .strip_prefix(dir)
+            .context("Walked path is outside the archive root")?;
+
+        let mut name = root_name.to_string();
+        for component in relative.components() {
+            name.push('/');
+            name.push_str(&component.as_os_str().to_string_lossy());
+        }
+
+        let metadata = entry
+            .metadata()
+            .with_context(|| format!("Failed to read metadata of {}", entry.path().display()))?;
+        let entry = if metadata.is_dir() {
+            Entry {
+                name: format!("{name}/"),
+                mode: 0o755,
+                data: None,
+            }
+        } else if metadata.is_file() {
+            let data = std::fs::read(entry.path())
+                .with_context(|| format!("Failed to read {}", entry.path().display()))?;
+            Entry {
+                name,
+                mode: file_mode(&metadata),
+                data: Some(data),
+            }
+        } else {
+            // 生成结果中不会出现符号链接等特殊文件，忽略
+            continue;
+        };
+        writer.append(&entry)?;
+    }
+
+    writer.finish()
+}
+
+/// 普通文件的权限，保留可执行位
+#[cfg(unix)]
+fn file_mode(metadata: &std::fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    if metadata.permissions().mode() & 0o111 != 0 {
+        0o755
+    } else {
+        0o644
+    }
+}
+
+#[cfg(not(unix))]
+fn file_mode(_metadata: &std::fs::Metadata) -> u32 {
+    0o644
+}
+
+/// 各打包格式的写入器
+trait ArchiveWriter {
+    type Inner;
+
+    fn append(&mut self, entry: &Entry) -> Result<()>;
+    /// 写出结尾数据，返回内部的 writer 以便继续结束外层的压缩流
+    fn finish(self) -> Result<Self::Inner>;
+}
+
+/// ustar 格式写入器
+struct TarWriter<W: Write> {
+    writer: W,
+    mtime: u64,
+}
+
+impl<W: Write> TarWriter<W> {
+    fn new(writer: W) -> Self {
+        let mtime = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        Self { writer, mtime }
+    }
+
+    /// 生成 512 字节的 ustar 头部，路径超过 100 字节时拆分到 prefix 字段
+    fn header(&self, entry: &Entry) -> Result<[u8; 512]> {
+        let (prefix, name) = split_ustar_path(&entry.name)
+            .ok_or_else(|| anyhow::anyhow!("Path is too long for a tar archive: {}", entry.name))?;
+        let size = entry.data.as_ref().map_or(0, Vec::len) as u64;
+
+        let mut header = [0u8; 512];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        write_octal(&mut header[100..108], entry.mode as u64);
+        write_octal(&mut header[108..116], 0);
+        write_octal(&mut header[116..124], 0);
+        write_octal(&mut header[124..136], size);
+        write_octal(&mut header[136..148], self.mtime);
+        header[156] = if entry.data.is_some() { b'0' } else { b'5' };
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+        header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+
+        // 校验和按校验和字段全为空格计算
+        header[148..156].fill(b' ');
+        let checksum: u64 = header.iter().map(|&b| b as u64).sum();
+        write_octal(&mut header[148..155], checksum);
+        header[155] = b' ';
+        Ok(header)
+    }
+}
+
+impl<W: Write> ArchiveWriter for TarWriter<W> {
+    type Inner = W;
+
+    fn append(&mut self, entry: &Entry) -> Result<()> {
+        let header = self.header(entry)?;
+        self.writer.write_all(&header)?;
+        if let Some(ref data) = entry.data {
+            self.writer.write_all(data)?;
+            let padding = (512 - data.len() % 512) % 512;
+            self.writer.write_all(&[0u8; 512][..padding])?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<W> {
+        // 两个全零块表示结束
+        self.writer.write_all(&[0u8; 1024])?;
+        Ok(self.writer)
+    }
+}
+
+/// 拆分 ustar 路径为 (prefix, name)，name 最长 100 字节、prefix 最长 155 字节
+fn split_ustar_path(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+    // 目录结尾的 `/` 不能作为拆分点
+    let trimmed = path.strip_suffix('/').unwrap_or(path);
+    trimmed
+        .match_indices('/')
+        .map(|(index, _)| index)
+        .find(|&index| index <= 155 && path.len() - index - 1 <= 100)
+        .map(|index| (&path[..index], &path[index + 1..]))
+}
+
+/// 以 0 结尾的八进制数字段
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = format!("{value:0width$o}", width = field.len() - 1);
+    field[..digits.len()].copy_from_slice(digits.as_bytes());
+    field[digits.len()] = 0;
+}
+
+/// 仅存储的 zip 写入器，写完全部条目后写出中央目录
+struct ZipWriter<W: Write> {
+    writer: W,
+    offset: u64,
+    dos_time: u16,
+    dos_date: u16,
+    central_directory: Vec<u8>,
+    entries: u64,
+}
+
+impl<W: Write> ZipWriter<W> {
+    fn new(writer: W) -> Self {
+        let now = Local::now();
+        let dos_time = ((now.hour() << 11) | (now.minute() << 5) | (now.second() / 2)) as u16;
+        let dos_date =
+            (((now.year().max(1980) - 1980) as u32) << 9 | now.month() << 5 | now.day()) as u16;
+        Self {
+            writer,
+            offset: 0,
+            dos_time,
+            dos_date,
+            central_directory: Vec::new(),
+            entries: 0,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        self.writer.write_all(bytes)?;
+        self.offset += bytes.len() as u64;
+        Ok(())
+    }
+}
+
+impl<W: Write> ArchiveWriter for ZipWriter<W> {
+    type Inner = W;
+
+    fn append(&mut self, entry: &Entry) -> Result<()> {
+        let data = entry.data.as_deref().unwrap_or_default();
+        let size = u32::try_from(data.len())
+            .with_context(|| format!("File is too large for a zip archive: {}", entry.name))?;
+        let offset = u32::try_from(self.offset).context("Zip archive exceeds 4 GiB")?;
+        let crc = crc32(data);
+        let name = entry.name.as_bytes();
+        // 通用标志位 11：文件名使用 UTF-8
+        let flags: u16 = 0x0800;
+
+        let mut local = Vec::with_capacity(30 + name.len());
+        local.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        local.extend_from_slice(&20u16.to_le_bytes());
+        local.extend_from_slice(&flags.to_le_bytes());
+        local.extend_from_slice(&0u16.to_le_bytes());
+        local.extend_from_slice(&self.dos_time.to_le_bytes());
+        local.extend_from_slice(&self.dos_date.to_le_bytes());
+        local.extend_from_slice(&crc.to_le_bytes());
+        local.extend_from_slice(&size.to_le_bytes());
+        local.extend_from_slice(&size.to_le_bytes());
+        local.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        local.extend_from_slice(&0u16.to_le_bytes());
+        local.extend_from_slice(name);
+        self.write(&local)?;
+        self.write(data)?;
+
+        // 外部属性高 16 位为 Unix 权限，目录同时设置 MS-DOS 目录标志
+        let external_attributes = if entry.data.is_some() {
+            (0o100000 | entry.mode) << 16
+        } else {
+            ((0o040000 | entry.mode) << 16) | 0x10
+        };
+        let central = &mut self.central_directory;
+        central.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        // 创建系统为 Unix (3)，版本 2.0
+        central.extend_from_slice(&((3u16 << 8) | 20).to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes());
+        central.extend_from_slice(&flags.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&self.dos_time.to_le_bytes());
+        central.extend_from_slice(&self.dos_date.to_le_bytes());
+        central.extend_from_slice(&crc.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&[0u8; 8]);
+        central.extend_from_slice(&external_attributes.to_le_bytes());
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name);
+        self.entries += 1;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<W> {
+        let entries = u16::try_from(self.entries).context("Too many files for a zip archive")?;
+        let directory_offset = u32::try_from(self.offset).context("Zip archive exceeds 4 GiB")?;
+        let central_directory = std::mem::take(&mut self.central_directory);
+        self.write(&central_directory)?;
+
+        let mut end = Vec::with_capacity(22);
+        end.extend_from_slice(&0x06054b50u32.to_le_bytes());
+        end.extend_from_slice(&[0u8; 4]);
+        end.extend_from_slice(&entries.to_le_bytes());
+        end.extend_from_slice(&entries.to_le_bytes());
+        end.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+        end.extend_from_slice(&directory_offset.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes());
+        self.write(&end)?;
+        Ok(self.writer)
+    }
+}
+
+/// CRC-32 (IEEE 802.3) 校验值
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("README.md"), "# demo\n").unwrap();
+        std::fs::write(dir.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_split_ustar_path() {
+        assert_eq!(split_ustar_path("demo/a.txt"), Some(("", "demo/a.txt")));
+        let long = format!("demo/{}/file.txt", "d".repeat(120));
+        let (prefix, name) = split_ustar_path(&long).unwrap();
+        assert_eq!(format!("{prefix}/{name}"), long);
+        assert!(name.len() <= 100 && prefix.len() <= 155);
+        assert_eq!(split_ustar_path(&"x".repeat(101)), None);
+    }
+
+    #[test]
+    fn test_write_tar() {
+        let dir = sample_dir();
+        let mut output = Vec::new();
+        write_archive(ArchiveFormat::Tar, dir.path(), "demo", &mut output).unwrap();
+
+        assert_eq!(output.len() % 512, 0);
+        // 条目按名称排序：根目录、README.md、src/、src/main.rs
+        assert!(output.starts_with(b"demo/\0"));
+        assert_eq!(output[156], b'5');
+        assert_eq!(&output[257..263], b"ustar\0");
+        assert!(output[512..].starts_with(b"demo/README.md\0"));
+        assert_eq!(&output[1024..1031], b"# demo\n");
+        assert!(output.ends_with(&[0u8; 1024]));
+    }
+
+    #[test]
+    fn test_write_tar_gz() {
+        let dir = sample_dir();
+        let mut output = Vec::new();
+        write_archive(ArchiveFormat::TarGz, dir.path(), "demo", &mut output).unwrap();
+
+        let mut tar = Vec::new();
+        std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&output[..]), &mut tar)
+            .unwrap();
+        assert!(tar.starts_with(b"demo/\0"));
+        assert!(tar.ends_with(&[0u8; 1024]));
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        let format = |path: &str| ArchiveFormat::from_path(Path::new(path));
+        assert_eq!(format("out/demo.zip"), Some(ArchiveFormat::Zip));
+        assert_eq!(format("demo.TAR"), Some(ArchiveFormat::Tar));
+        assert_eq!(format("demo.tar.gz"), Some(ArchiveFormat::TarGz));
+        assert_eq!(format("demo.tgz"), Some(ArchiveFormat::TarGz));
+        assert_eq!(format("demo.gz"), None);
+        assert_eq!(format("zip"), None);
+    }
+
+    #[test]
+    fn test_write_zip() {
+        let dir = sample_dir();
+        let mut output = Vec::new();
+        write_archive(ArchiveFormat::Zip, dir.path(), "demo", &mut output).unwrap();
+
+        assert!(output.starts_with(&0x04034b50u32.to_le_bytes()));
+        let end = &output[output.len() - 22..];
+        assert_eq!(&end[..4], &0x06054b50u32.to_le_bytes());
+        // 4 个条目：根目录、README.md、src/、src/main.rs
+        assert_eq!(u16::from_le_bytes([end[10], end[11]]), 4);
+        let directory_offset = u32::from_le_bytes([end[16], end[17], end[18], end[19]]) as usize;
+        assert_eq!(
+            &output[directory_offset..directory_offset + 4],
+            &0x02014b50u32.to_le_bytes()
+        );
+    }
+}
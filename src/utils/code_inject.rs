@@ -0,0 +1,272 @@
+use anyhow::{Context, Result, bail};
+use std::path::Path;
+
+/// 标记注释的前缀，如 `// scafgen:routes`、`# scafgen:urls`
+pub const MARKER_PREFIX: &str = "scafgen:";
+
+/// 标记前允许的注释起始符
+const COMMENT_STARTS: &[&str] = &["//", "#", "/*", "<!--", "--", "{/*"];
+
+/// 在已有源文件的标记注释处插入渲染好的代码片段
+///
+/// 片段插入在标记行之前并沿用标记行的缩进，多次插入按顺序排列，标记始终留在最后；
+/// 文件中已有相同的片段（忽略缩进）时不再插入，重复生成同一个处理器或页面不会产生重复代码
+///
+/// 生成新项目时只在模板中写入标记注释，不做插入；插入由 `scafgen generate` 在已有项目中执行
+#[derive(Debug, Default, Clone)]
+pub struct CodeInjection {
+    snippets: Vec<(String, String)>,
+}
+
+impl CodeInjection {
+    /// 创建空的插入
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 在标记 `marker`（不含 `scafgen:` 前缀，如 `routes`）处插入片段，片段内的相对缩进保持不变
+    pub fn with_snippet(mut self, marker: &str, snippet: &str) -> Self {
+        self.snippets
+            .push((marker.to_string(), snippet.trim_end().to_string()));
+        self
+    }
+
+    /// 将插入应用到源文件文本，返回修改后的文本；找不到标记时返回错误
+    pub fn apply_to(&self, content: &str) -> Result<String> {
+        let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+        for (marker, snippet) in &self.snippets {
+            let Some(index) = find_marker(&lines, marker) else {
+                bail!("Marker `{MARKER_PREFIX}{marker}` not found");
+            };
+            if contains_snippet(&lines, snippet) {
+                continue;
+            }
+            let marker_line = &lines[index];
+            let indent =
+                marker_line[..marker_line.len() - marker_line.trim_start().len()].to_string();
+            lines.splice(
+                index..index,
+                snippet.lines().map(|line| {
+                    if line.trim().is_empty() {
+                        String::new()
+                    } else {
+                        format!("{indent}{line}")
+                    }
+                }),
+            );
+        }
+
+        let mut output = lines.join("\n");
+        if content.ends_with('\n') {
+            output.push('\n');
+        }
+        Ok(output)
+    }
+
+    /// 读取、插入并写回源文件，文件不存在或找不到标记时返回错误
+    pub fn apply(&self, file_path: &Path) -> Result<()> {
+        let content = std::fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read {}", file_path.display()))?;
+        let output = self
+            .apply_to(&content)
+            .with_context(|| format!("Failed to inject code into {}", file_path.display()))?;
+        if output != content {
+            std::fs::write(file_path, output)
+                .with_context(|| format!("Failed to write {}", file_path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// 源文件中是否有标记 `marker`
+pub fn has_marker(content: &str, marker: &str) -> bool {
+    content.lines().any(|line| is_marker_line(line, marker))
+}
+
+fn find_marker(lines: &[String], marker: &str) -> Option<usize> {
+    lines.iter().position(|line| is_marker_line(line, marker))
+}
+
+/// 标记必须写在注释中，`scafgen:routes` 之后是行尾、空白或注释结束符，不会匹配到 `scafgen:routes-admin`
+fn is_marker_line(line: &str, marker: &str) -> bool {
+    let tag = format!("{MARKER_PREFIX}{marker}");
+    line.match_indices(&tag).any(|(at, _)| {
+        let before = line[..at].trim_end();
+        let after = &line[at + tag.len()..];
+        COMMENT_STARTS.iter().any(|start| before.ends_with(start))
+            && (after.is_empty()
+                || after.starts_with(char::is_whitespace)
+                || after.starts_with("*/")
+                || after.starts_with("-->"))
+    })
+}
+
+/// 文件中 `scafgen:begin <功能>` 代码段所属的功能，按出现顺序去重
+pub fn region_features(content: &str) -> Vec<String> {
+    let mut features: Vec<String> = Vec::new();
+    for (begin, feature) in content.lines().filter_map(region_marker) {
+        if begin && !features.iter().any(|existing| existing == feature) {
+            features.push(feature.to_string());
+        }
+    }
+    features
+}
+
+/// 删除功能 `feature` 的所有 `scafgen:begin`/`scafgen:end` 代码段（包括标记行），返回删除的段数
+///
+/// 代码段前后都是空行（或位于文件末尾）时一并删除前面的空行，避免留下连续的空行；
+/// 缺少结束标记时返回错误，不修改内容
+pub fn remove_regions(content: &str, feature: &str) -> Result<(String, usize)> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut output: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut removed = 0;
+    let mut index = 0;
+    while index < lines.len() {
+        if region_marker(lines[index]) != Some((true, feature)) {
+            output.push(lines[index]);
+            index += 1;
+            continue;
+        }
+        let Some(end) =
+            (index + 1..lines.len()).find(|&i| region_marker(lines[i]) == Some((false, feature)))
+        else {
+            bail!(
+                "Marker `{MARKER_PREFIX}begin {feature}` on line {} has no matching `{MARKER_PREFIX}end {feature}`",
+                index + 1
+            );
+        };
+        let blank_after = lines.get(end + 1).is_none_or(|line| line.trim().is_empty());
+        if blank_after && output.last().is_some_and(|line| line.trim().is_empty()) {
+            output.pop();
+        }
+        removed += 1;
+        index = end + 1;
+    }
+
+    let mut output = output.join("\n");
+    if content.ends_with('\n') && !output.is_empty() {
+        output.push('\n');
+    }
+    Ok((output, removed))
+}
+
+/// 解析代码段标记行，返回是否为开始标记和所属功能，如 `# scafgen:begin redis` 得到 `(true, "redis")`
+fn region_marker(line: &str) -> Option<(bool, &str)> {
+    let begin = is_marker_line(line, "begin");
+    if !begin && !is_marker_line(line, "end") {
+        return None;
+    }
+    let tag = if begin { "begin" } else { "end" };
+    let (_, rest) = line.split_once(&format!("{MARKER_PREFIX}{tag}"))?;
+    let feature = rest
+        .split_whitespace()
+        .next()?
+        .trim_end_matches("*/")
+        .trim_end_matches("-->");
+    Some((begin, feature)).filter(|(_, feature)| !feature.is_empty())
+}
+
+/// 片段的非空行（忽略缩进）是否已经连续出现在文件中
+fn contains_snippet(lines: &[String], snippet: &str) -> bool {
+    let needle: Vec<&str> = snippet
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if needle.is_empty() {
+        return true;
+    }
+    let haystack: Vec<&str> = lines
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect();
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_injection() {
+        let content = "\
+func (a *ApiRouter) InitApiRoutes(rg *gin.RouterGroup) {
+\tv1 := rg.Group(\"/v1\")
+\t{
+\t\tv1.GET(\"/ping\", a.Ping)
+\t\t// scafgen:routes
+\t}
+}
+";
+        let injection = CodeInjection::new()
+            .with_snippet(
+                "routes",
+                "v1.GET(\"/users\", a.ListUsers)\nv1.POST(\"/users\", a.CreateUser)",
+            )
+            .with_snippet("routes", "v1.GET(\"/orders\", a.ListOrders)");
+        let injected = injection.apply_to(content).unwrap();
+        assert_eq!(
+            injected,
+            "\
+func (a *ApiRouter) InitApiRoutes(rg *gin.RouterGroup) {
+\tv1 := rg.Group(\"/v1\")
+\t{
+\t\tv1.GET(\"/ping\", a.Ping)
+\t\tv1.GET(\"/users\", a.ListUsers)
+\t\tv1.POST(\"/users\", a.CreateUser)
+\t\tv1.GET(\"/orders\", a.ListOrders)
+\t\t// scafgen:routes
+\t}
+}
+"
+        );
+        // 重复插入不会产生重复代码
+        assert_eq!(injection.apply_to(&injected).unwrap(), injected);
+
+        assert!(has_marker("  <!-- scafgen:routes -->", "routes"));
+        assert!(!has_marker("// scafgen:routes-admin", "routes"));
+        assert!(!has_marker("const tag = 'scafgen:routes'", "routes"));
+        assert!(
+            CodeInjection::new()
+                .with_snippet("models", "&User{},")
+                .apply_to(content)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_remove_regions() {
+        let content = "\
+[server]
+port = 8080
+
+# scafgen:begin redis
+[redis]
+addr = \"localhost:6379\"
+# scafgen:end redis
+";
+        assert_eq!(region_features(content), ["redis"]);
+        let (removed, count) = remove_regions(content, "redis").unwrap();
+        assert_eq!(removed, "[server]\nport = 8080\n");
+        assert_eq!(count, 1);
+
+        let content = "\
+import (
+\t// scafgen:begin swagger
+\t\"demo/docs\"
+\t// scafgen:end swagger
+\t\"github.com/gin-gonic/gin\"
+)
+";
+        let (removed, _) = remove_regions(content, "swagger").unwrap();
+        assert_eq!(removed, "import (\n\t\"github.com/gin-gonic/gin\"\n)\n");
+        assert_eq!(
+            remove_regions(content, "redis").unwrap(),
+            (content.to_string(), 0)
+        );
+        assert!(remove_regions("// scafgen:begin redis\nx\n", "redis").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::app_dirs::config_dir;
+use super::git_auth::HostAuth;
+use crate::constants::{Editor, FailurePolicy};
+
+/// 用户配置文件名
+const CONFIG_FILE: &str = "config.json";
+
+/// 用户配置，保存在配置目录下的 `config.json`
+///
+/// 面向内部分发场景，可以替换欢迎横幅或指定组织名称
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserConfig {
+    /// 自定义欢迎横幅，设置后替换默认的欢迎信息
+    pub banner: Option<String>,
+    /// 组织名称，显示在默认欢迎信息中
+    pub organization: Option<String>,
+    /// 非关键工具失败的默认处理策略，可被 `--failure-policy` 覆盖
+    pub failure_policy: Option<FailurePolicy>,
+    /// `--open` 未指定编辑器时使用的编辑器，未设置时自动检测
+    pub editor: Option<Editor>,
+    /// 组织模板覆盖层，本地目录或 git 仓库地址，可被 `--overlay` 覆盖
+    pub template_overlay: Option<String>,
+    /// 按主机配置的 git 认证，用于克隆自建 GitLab、Gitea 等实例上的私有仓库
+    pub git_hosts: BTreeMap<String, HostAuth>,
+    /// 组织的 CA 证书包（PEM），通过环境变量传给 npm、pnpm、uv、cargo、go 等子进程，用于拦截 HTTPS 的代理环境
+    pub ca_bundle: Option<PathBuf>,
+}
+
+impl UserConfig {
+    /// 配置文件路径
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join(CONFIG_FILE))
+    }
+
+    /// 加载用户配置，配置文件不存在时返回默认配置
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid config file: {}", path.display()))
+    }
+
+    /// 欢迎横幅文本
+    pub fn welcome_banner(&self) -> String {
+        match (&self.banner, &self.organization) {
+            (Some(banner), _) => banner.clone(),
+            (None, Some(organization)) => {
+                format!("Welcome to Scaffold-Gen Project Generator for {organization}!")
+            }
+            (None, None) => "Welcome to Scaffold-Gen Project Generator!".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_welcome_banner() {
+        assert_eq!(
+            UserConfig::default().welcome_banner(),
+            "Welcome to Scaffold-Gen Project Generator!"
+        );
+
+        let config: UserConfig = serde_json::from_str(r#"{"organization": "Acme"}"#).unwrap();
+        assert_eq!(
+            config.welcome_banner(),
+            "Welcome to Scaffold-Gen Project Generator for Acme!"
+        );
+
+        let config: UserConfig =
+            serde_json::from_str(r#"{"banner": "Acme Scaffolder", "organization": "Acme"}"#)
+                .unwrap();
+        assert_eq!(config.welcome_banner(), "Acme Scaffolder");
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 全局静默标志，由 `--quiet` 设置
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// 设置静默模式：开启后不再输出欢迎横幅和进度信息，警告和错误仍会输出
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// 是否处于静默模式
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// 输出进度信息，静默模式下不输出
+#[macro_export]
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if !$crate::utils::console::is_quiet() {
+            println!($($arg)*);
+        }
+    };
+}
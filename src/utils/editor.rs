@@ -0,0 +1,36 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use which::which;
+
+use crate::constants::Editor;
+
+/// 编辑器命令是否在 PATH 中
+pub fn is_available(editor: Editor) -> bool {
+    which(editor.as_str()).is_ok()
+}
+
+/// 按优先顺序检测第一个可用的编辑器
+pub fn detect() -> Option<Editor> {
+    Editor::all()
+        .iter()
+        .copied()
+        .find(|editor| is_available(*editor))
+}
+
+/// 在编辑器中打开目录，不等待编辑器退出
+pub fn open(editor: Editor, path: &Path) -> Result<()> {
+    // 使用 which 解析出的完整路径，Windows 上的 code.cmd 等脚本才能被启动
+    let program = which(editor.as_str())
+        .with_context(|| format!("Editor '{editor}' was not found in PATH"))?;
+
+    Command::new(program)
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to launch {editor}"))?;
+
+    Ok(())
+}
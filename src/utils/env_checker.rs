@@ -3,6 +3,8 @@ use regex::Regex;
 use std::process::Command;
 use which::which;
 
+use crate::constants::Runtime;
+
 pub struct EnvironmentChecker;
 
 impl Default for EnvironmentChecker {
@@ -38,6 +40,39 @@ impl EnvironmentChecker {
         }
     }
 
+    /// 检查 goctl（go-zero 代码生成工具）是否可用
+    pub async fn check_goctl(&self) -> Result<bool> {
+        match which("goctl") {
+            Ok(_) => match Command::new("goctl").args(["--version"]).output() {
+                Ok(output) => Ok(output.status.success()),
+                Err(_) => Ok(false),
+            },
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// 检查 protoc 是否可用（goctl rpc protoc 依赖）
+    pub async fn check_protoc(&self) -> Result<bool> {
+        match which("protoc") {
+            Ok(_) => match Command::new("protoc").args(["--version"]).output() {
+                Ok(output) => Ok(output.status.success()),
+                Err(_) => Ok(false),
+            },
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// 检查 buf（protobuf 代码生成和检查工具）是否可用
+    pub async fn check_buf(&self) -> Result<bool> {
+        match which("buf") {
+            Ok(_) => match Command::new("buf").args(["--version"]).output() {
+                Ok(output) => Ok(output.status.success()),
+                Err(_) => Ok(false),
+            },
+            Err(_) => Ok(false),
+        }
+    }
+
     /// 检查 Go 是否可用并验证版本
     pub async fn check_go(&self) -> Result<bool> {
         match which("go") {
@@ -78,7 +113,6 @@ impl EnvironmentChecker {
     }
 
     /// 获取Go版本字符串（用于模板参数）
-    #[allow(dead_code)]
     pub async fn get_go_version(&self) -> Result<String> {
         let output = Command::new("go").arg("version").output()?;
 
@@ -258,6 +292,28 @@ impl EnvironmentChecker {
         }
     }
 
+    /// 检查 Zig 是否可用
+    pub async fn check_zig(&self) -> Result<bool> {
+        match which("zig") {
+            Ok(_) => match Command::new("zig").arg("version").output() {
+                Ok(output) => Ok(output.status.success()),
+                Err(_) => Ok(false),
+            },
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// 获取Zig版本字符串（如 "0.14.0"）
+    pub async fn get_zig_version(&self) -> Result<String> {
+        let output = Command::new("zig").arg("version").output()?;
+
+        if !output.status.success() {
+            return Err(anyhow!("Failed to get Zig version"));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
     /// 获取Cargo版本字符串
     #[allow(dead_code)]
     pub async fn get_cargo_version(&self) -> Result<String> {
@@ -295,7 +351,6 @@ impl EnvironmentChecker {
     }
 
     /// 获取Node.js版本字符串（用于模板参数）
-    #[allow(dead_code)]
     pub async fn get_node_version(&self) -> Result<String> {
         let output = Command::new("node").arg("--version").output()?;
 
@@ -318,10 +373,35 @@ impl EnvironmentChecker {
         }
     }
 
+    /// 检查 Node.js 版本是否满足框架的最低要求（如 SvelteKit 要求 18.13）
+    pub async fn check_node_version(&self, min_major: u32, min_minor: u32) -> Result<bool> {
+        let version = self.get_node_version().await?;
+        let (major, minor) = version
+            .split_once('.')
+            .and_then(|(major, minor)| {
+                Some((major.parse::<u32>().ok()?, minor.parse::<u32>().ok()?))
+            })
+            .ok_or_else(|| anyhow!("Unable to parse Node.js version"))?;
+
+        if (major, minor) >= (min_major, min_minor) {
+            Ok(true)
+        } else {
+            Err(anyhow!(
+                "Node.js version {major}.{minor} is not supported. Minimum required version is {min_major}.{min_minor}"
+            ))
+        }
+    }
+
     /// 检查 pnpm 是否可用
     pub async fn check_pnpm(&self) -> Result<bool> {
-        match which("pnpm") {
-            Ok(_) => match Command::new("pnpm").args(["--version"]).output() {
+        self.check_package_manager(Runtime::Node).await
+    }
+
+    /// 检查 JavaScript 运行时的包管理器（pnpm、bun、deno）是否可用
+    pub async fn check_package_manager(&self, runtime: Runtime) -> Result<bool> {
+        let package_manager = runtime.package_manager();
+        match which(package_manager) {
+            Ok(_) => match Command::new(package_manager).args(["--version"]).output() {
                 Ok(output) => Ok(output.status.success()),
                 Err(_) => Ok(false),
             },
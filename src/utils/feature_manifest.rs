@@ -0,0 +1,297 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::generators::core::feature_dirs;
+use crate::utils::code_inject;
+
+/// 生成的项目根目录中记录各功能文件的清单
+pub const MANIFEST_FILE: &str = ".scafgen.json";
+
+/// 由模板统一维护、不归项目所有的文件（CI 流水线和 lint 配置），`scafgen check` 检查其是否偏离生成时的内容
+const PINNED_FILE_NAMES: [&str; 13] = [
+    ".gitlab-ci.yml",
+    ".pre-commit-config.yaml",
+    ".editorconfig",
+    ".golangci.yml",
+    ".golangci.yaml",
+    "ruff.toml",
+    "rustfmt.toml",
+    "clippy.toml",
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.ts",
+    ".prettierrc",
+    ".prettierrc.json",
+];
+
+/// 查找固定文件时跳过的目录（依赖和构建产物）
+const PINNED_SKIP_DIRS: [&str; 6] = [".git", "node_modules", "target", ".venv", "dist", "build"];
+
+/// 本次生成中写入的功能文件，生成结束时按项目根目录写入清单
+static RECORDED: Mutex<BTreeMap<String, RecordedFeature>> = Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Default)]
+struct RecordedFeature {
+    files: Vec<PathBuf>,
+    regions: Vec<PathBuf>,
+}
+
+/// 功能在项目中留下的内容：只属于该功能的文件，以及包含 `scafgen:begin <功能>` 代码段的文件
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureEntry {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regions: Vec<String>,
+}
+
+/// `.scafgen.json`：生成项目的 scafgen 版本、各功能的文件和固定文件的指纹，
+/// 供 `scafgen remove` 和 `scafgen check` 使用
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureManifest {
+    pub version: String,
+    #[serde(default)]
+    pub features: BTreeMap<String, FeatureEntry>,
+    /// 固定文件的相对路径到内容指纹
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pinned: BTreeMap<String, String>,
+}
+
+impl FeatureManifest {
+    /// 读取项目根目录中的清单
+    pub fn load(project_path: &Path) -> Result<Self> {
+        let path = project_path.join(MANIFEST_FILE);
+        let content = std::fs::read_to_string(&path).with_context(|| {
+            format!(
+                "No {MANIFEST_FILE} found in {}; only projects generated by scafgen record their features",
+                project_path.display()
+            )
+        })?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// 写入项目根目录，没有记录任何功能和固定文件时删除已有的清单
+    pub fn save(&self, project_path: &Path) -> Result<()> {
+        let path = project_path.join(MANIFEST_FILE);
+        if self.features.is_empty() && self.pinned.is_empty() {
+            if path.exists() {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+            }
+            return Ok(());
+        }
+        let mut content = serde_json::to_string_pretty(self)?;
+        content.push('\n');
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// 去掉已不存在的文件，以及不再留下任何内容的功能
+    pub fn prune(&mut self, project_path: &Path) {
+        for entry in self.features.values_mut() {
+            entry.files.retain(|file| project_path.join(file).exists());
+            entry
+                .regions
+                .retain(|file| project_path.join(file).exists());
+        }
+        self.features
+            .retain(|_, entry| !entry.files.is_empty() || !entry.regions.is_empty());
+    }
+
+    /// 记录项目中所有固定文件当前内容的指纹
+    pub fn pin_files(&mut self, project_path: &Path) -> Result<()> {
+        self.pinned.clear();
+        let walker = walkdir::WalkDir::new(project_path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                !(entry.file_type().is_dir()
+                    && PINNED_SKIP_DIRS.contains(&entry.file_name().to_string_lossy().as_ref()))
+            });
+        for entry in walker {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(project_path) else {
+                continue;
+            };
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            if is_pinned(&relative) {
+                self.pin(project_path, &relative)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 更新单个固定文件的指纹，文件已不存在时不再固定
+    pub fn pin(&mut self, project_path: &Path, relative: &str) -> Result<()> {
+        let path = project_path.join(relative);
+        if !path.is_file() {
+            self.pinned.remove(relative);
+            return Ok(());
+        }
+        let content =
+            std::fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        self.pinned
+            .insert(relative.to_string(), fingerprint(&content));
+        Ok(())
+    }
+}
+
+/// 是否为固定文件：`.github/workflows/` 下的流水线，以及任意目录中的 CI 和 lint 配置
+pub fn is_pinned(relative_path: &str) -> bool {
+    if relative_path.starts_with(".github/workflows/") {
+        return true;
+    }
+    let file_name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+    PINNED_FILE_NAMES.contains(&file_name)
+}
+
+/// 文件内容的 64 位 FNV-1a 指纹，行尾统一为 `\n`，避免 Windows 检出时误报
+pub fn fingerprint(content: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in content.iter().filter(|&&byte| byte != b'\r') {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// 记录写入的模板文件：所在的每个功能片段目录（如 `+docker/`、`+ci/+ci-github/`）各记录一次，
+/// 内容中带有 `scafgen:begin` 代码段的文件记录到对应功能的代码段中
+pub fn record_template_file(relative_path: &str, output_file_path: &Path, content: &[u8]) {
+    for feature in feature_dirs(relative_path) {
+        record_file(feature, output_file_path);
+    }
+    if let Ok(content) = std::str::from_utf8(content) {
+        for feature in code_inject::region_features(content) {
+            record(&feature, |entry| {
+                entry.regions.push(output_file_path.to_path_buf())
+            });
+        }
+    }
+}
+
+/// 记录只属于功能 `feature` 的文件，如 Redis 客户端或 swag 生成的文档
+pub fn record_file(feature: &str, path: &Path) {
+    record(feature, |entry| entry.files.push(path.to_path_buf()));
+}
+
+fn record(feature: &str, update: impl FnOnce(&mut RecordedFeature)) {
+    if let Ok(mut recorded) = RECORDED.lock() {
+        update(recorded.entry(feature.to_string()).or_default());
+    }
+}
+
+/// 取出本次生成中位于 `project_path` 下的记录，写入项目根目录的清单；
+/// 其他目录的记录保留，`scafgen serve` 同时生成多个项目时互不影响
+pub fn write(project_path: &Path) -> Result<()> {
+    let mut features = BTreeMap::new();
+    if let Ok(mut recorded) = RECORDED.lock() {
+        for (feature, entry) in recorded.iter_mut() {
+            let entry = FeatureEntry {
+                files: take_relative(&mut entry.files, project_path),
+                regions: take_relative(&mut entry.regions, project_path),
+            };
+            features.insert(feature.clone(), entry);
+        }
+        recorded.retain(|_, entry| !entry.files.is_empty() || !entry.regions.is_empty());
+    }
+
+    let mut manifest = FeatureManifest {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        features,
+        pinned: BTreeMap::new(),
+    };
+    manifest.prune(project_path);
+    manifest.pin_files(project_path)?;
+    manifest.save(project_path)
+}
+
+/// 取出位于 `project_path` 下的路径，转换为排序去重后的相对路径
+fn take_relative(paths: &mut Vec<PathBuf>, project_path: &Path) -> Vec<String> {
+    let (inside, outside) = std::mem::take(paths)
+        .into_iter()
+        .partition(|path| path.starts_with(project_path));
+    *paths = outside;
+    let mut relative: Vec<String> = inside
+        .iter()
+        .filter_map(|path: &PathBuf| path.strip_prefix(project_path).ok())
+        .map(|path| path.to_string_lossy().replace('\\', "/"))
+        .collect();
+    relative.sort();
+    relative.dedup();
+    relative
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_feature_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(root.join("Dockerfile"), "FROM scratch\n").unwrap();
+        std::fs::write(
+            root.join("app.go"),
+            "// scafgen:begin redis\n// scafgen:end redis\n",
+        )
+        .unwrap();
+
+        record_template_file(
+            "+docker/Dockerfile.tmpl",
+            &root.join("Dockerfile"),
+            b"FROM scratch\n",
+        );
+        record_template_file(
+            "core/app.go.tmpl",
+            &root.join("app.go"),
+            b"// scafgen:begin redis\n// scafgen:end redis\n",
+        );
+        // 已被删除和位于项目之外的文件不写入清单
+        record_file("redis", &root.join("pkg/cache/redis.go"));
+        record_file("redis", Path::new("/elsewhere/redis.go"));
+        write(root).unwrap();
+
+        let manifest = FeatureManifest::load(root).unwrap();
+        assert_eq!(manifest.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(manifest.features["docker"].files, ["Dockerfile"]);
+        assert_eq!(manifest.features["redis"].files, Vec::<String>::new());
+        assert_eq!(manifest.features["redis"].regions, ["app.go"]);
+        assert!(manifest.pinned.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_files() {
+        assert!(is_pinned(".github/workflows/ci.yml"));
+        assert!(is_pinned("apps/api/.golangci.yml"));
+        assert!(!is_pinned("src/main.rs"));
+        assert!(!is_pinned("docs/.github/workflows.md"));
+        assert_eq!(fingerprint(b"a\r\nb\r\n"), fingerprint(b"a\nb\n"));
+        assert_ne!(fingerprint(b"a\n"), fingerprint(b"b\n"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join(".github/workflows")).unwrap();
+        std::fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        std::fs::write(root.join(".github/workflows/ci.yml"), "on: push\n").unwrap();
+        std::fs::write(root.join("node_modules/pkg/.editorconfig"), "root = true\n").unwrap();
+        std::fs::write(root.join("main.go"), "package main\n").unwrap();
+
+        let mut manifest = FeatureManifest::default();
+        manifest.pin_files(root).unwrap();
+        assert_eq!(
+            manifest.pinned.keys().collect::<Vec<_>>(),
+            [".github/workflows/ci.yml"]
+        );
+        std::fs::remove_file(root.join(".github/workflows/ci.yml")).unwrap();
+        manifest.pin(root, ".github/workflows/ci.yml").unwrap();
+        assert!(manifest.pinned.is_empty());
+    }
+}
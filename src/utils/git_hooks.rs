@@ -0,0 +1,332 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::status;
+use crate::utils::tool_failures;
+
+/// pre-commit 配置文件名
+pub const PRECOMMIT_CONFIG: &str = ".pre-commit-config.yaml";
+/// check-added-large-files 的默认上限（KB）
+const LARGE_FILE_KB: u64 = 500;
+
+/// 所有脚本共用的开头：按工具是否存在执行命令，以及检查 hook 是否改写了文件
+const SCRIPT_PRELUDE: &str = r#"status=0
+
+# run NAME TOOL COMMAND [FILES...]: warn and skip when TOOL is not installed
+run() {
+  name=$1 tool=$2 command=$3
+  shift 3
+  if ! command -v "$tool" >/dev/null 2>&1; then
+    echo "$hook: skipping $name ($tool is not installed)" >&2
+    return
+  fi
+  echo "$hook: $name"
+  sh -c "$command" "$name" "$@" || status=1
+}
+
+# fixed NAME FILES...: fail when a hook rewrote files, so the changes can be reviewed and staged
+fixed() {
+  name=$1
+  shift
+  if ! git diff --quiet -- "$@"; then
+    echo "$hook: $name modified files; review and stage them" >&2
+    status=1
+  fi
+}
+"#;
+
+/// 配置文件中的一个 hook
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Hook {
+    /// hook id，如 `ruff`
+    pub id: String,
+    /// 本地 hook 执行的命令
+    pub entry: Option<String>,
+    /// 只对匹配该正则的暂存文件执行
+    pub files: Option<String>,
+    /// 是否把暂存文件作为参数传入
+    pub pass_filenames: bool,
+    /// 是否在 commit-msg 阶段执行
+    pub commit_msg: bool,
+}
+
+/// 没有 pre-commit 时内置 hook 的等价检查：(工具, 命令, 文件正则)
+///
+/// 命令中的 `"$@"` 为匹配正则的暂存文件；会改写文件的 hook 改为只检查
+fn builtin_check(id: &str) -> Option<(&'static str, &'static str, Option<&'static str>)> {
+    let check = match id {
+        // git 自带的空白和冲突标记检查
+        "trailing-whitespace" | "check-merge-conflict" => {
+            ("git", "git diff --cached --check", None)
+        }
+        "go-fumpt" | "go-fmt" => ("gofmt", r#"! gofmt -l "$@" | grep ."#, Some(r"\.go$")),
+        "go-vet-mod" | "go-vet" => ("go", "go vet ./...", None),
+        "go-test-mod" | "go-test" => ("go", "go test ./...", None),
+        "golangci-lint-mod" | "golangci-lint" => ("golangci-lint", "golangci-lint run", None),
+        "uv-lock" => ("uv", "uv lock --check", None),
+        "ruff" => ("uvx", r#"uvx ruff check "$@""#, Some(r"\.pyi?$")),
+        "ruff-format" => ("uvx", r#"uvx ruff format --check "$@""#, Some(r"\.pyi?$")),
+        "fmt" => ("cargo", "cargo fmt -- --check", None),
+        "cargo-check" => ("cargo", "cargo check", None),
+        "clippy" => ("cargo", "cargo clippy -- -D warnings", None),
+        // commit-msg 阶段的参数是提交信息文件
+        "commitizen" => ("cz", r#"cz check --commit-msg-file "$1""#, None),
+        _ => return None,
+    };
+    Some(check)
+}
+
+/// 解析 scafgen 生成的 `.pre-commit-config.yaml` 中的 hook
+///
+/// 只处理模板使用的写法：`- id:` 开始一个 hook，其后是该 hook 的 `key: value`
+pub fn parse_hooks(config: &str) -> Vec<Hook> {
+    let mut hooks: Vec<Hook> = Vec::new();
+    for line in config.lines() {
+        let line = line.trim();
+        if let Some(id) = line.strip_prefix("- id:") {
+            hooks.push(Hook {
+                id: id.trim().to_string(),
+                pass_filenames: true,
+                ..Hook::default()
+            });
+            continue;
+        }
+        let (Some(hook), Some((key, value))) = (hooks.last_mut(), line.split_once(':')) else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "entry" => hook.entry = Some(value.to_string()),
+            "files" => hook.files = Some(value.to_string()),
+            "pass_filenames" => hook.pass_filenames = value != "false",
+            "stages" => hook.commit_msg = value.contains("commit-msg"),
+            _ => {}
+        }
+    }
+    hooks
+}
+
+/// 按 hook 列表生成 `pre-commit` 和 `commit-msg` 脚本，没有对应阶段的 hook 时为 `None`
+pub fn render_scripts(hooks: &[Hook]) -> (Option<String>, Option<String>) {
+    let (commit_msg, pre_commit): (Vec<&Hook>, Vec<&Hook>) =
+        hooks.iter().partition(|hook| hook.commit_msg);
+    (
+        render_script("pre-commit", &pre_commit),
+        render_script("commit-msg", &commit_msg),
+    )
+}
+
+fn render_script(hook_name: &str, hooks: &[&Hook]) -> Option<String> {
+    if hooks.is_empty() {
+        return None;
+    }
+
+    let mut steps = Vec::new();
+    let mut skipped = Vec::new();
+    let mut checks = Vec::new();
+    for hook in hooks {
+        let step = match (hook.entry.as_deref(), hook.id.as_str()) {
+            // 本地 hook 原样执行，可能改写传入的文件
+            (Some(entry), id) => {
+                let tool = entry.split_whitespace().next().unwrap_or_default();
+                if hook.pass_filenames {
+                    files_step(
+                        id,
+                        tool,
+                        &format!(r#"{entry} "$@""#),
+                        hook.files.as_deref(),
+                        true,
+                    )
+                } else {
+                    run_step(id, tool, entry, "")
+                }
+            }
+            (None, "end-of-file-fixer") => eof_step(),
+            (None, "check-added-large-files") => large_files_step(),
+            (None, id) => match builtin_check(id) {
+                // trailing-whitespace 和 check-merge-conflict 共用同一条 git 检查
+                Some((_, command, _)) if checks.contains(&command) => continue,
+                Some((tool, command, Some(files))) => {
+                    checks.push(command);
+                    files_step(id, tool, command, Some(files), false)
+                }
+                Some((tool, command, None)) => {
+                    checks.push(command);
+                    let args = if hook_name == "commit-msg" {
+                        r#" "$1""#
+                    } else {
+                        ""
+                    };
+                    run_step(id, tool, command, args)
+                }
+                None => {
+                    skipped.push(id);
+                    continue;
+                }
+            },
+        };
+        steps.push(step);
+    }
+
+    let mut script = format!(
+        "#!/bin/sh\n\
+         # Generated by scafgen from {PRECOMMIT_CONFIG} because pre-commit was not installed.\n\
+         # Install pre-commit and run `pre-commit install` to replace it with the full hook set.\n"
+    );
+    if !skipped.is_empty() {
+        script.push_str(&format!(
+            "# Not checked without pre-commit: {}\n",
+            skipped.join(", ")
+        ));
+    }
+    script.push_str(&format!("\nhook={hook_name}\n"));
+    script.push_str(SCRIPT_PRELUDE);
+    for step in steps {
+        script.push('\n');
+        script.push_str(&step);
+    }
+    script.push_str("\nexit $status\n");
+    Some(script)
+}
+
+/// 单引号包裹命令，作为 `run` 的参数
+fn quote(command: &str) -> String {
+    format!("'{}'", command.replace('\'', r"'\''"))
+}
+
+/// 不传文件的 hook，`args` 为附加给 `run` 的参数
+fn run_step(id: &str, tool: &str, command: &str, args: &str) -> String {
+    format!("run {id} {tool} {}{args}\n", quote(command))
+}
+
+/// 对匹配 `files` 正则的暂存文件执行的 hook，`rewrites` 为 true 时检查文件是否被改写
+fn files_step(id: &str, tool: &str, command: &str, files: Option<&str>, rewrites: bool) -> String {
+    let filter = files
+        .map(|pattern| format!(" | grep -E {}", quote(pattern)))
+        .unwrap_or_default();
+    let fixed = if rewrites {
+        format!("  fixed {id} $files\n")
+    } else {
+        String::new()
+    };
+    format!(
+        "files=$(git diff --cached --name-only --diff-filter=ACM{filter})\n\
+         if [ -n \"$files\" ]; then\n  \
+         run {id} {tool} {} $files\n\
+         {fixed}\
+         fi\n",
+        quote(command)
+    )
+}
+
+/// end-of-file-fixer 的只读版本：文本文件必须以换行结尾
+fn eof_step() -> String {
+    r#"echo "$hook: end-of-file-fixer"
+for file in $(git diff --cached --name-only --diff-filter=ACM); do
+  if [ -s "$file" ] && grep -Iq . "$file" && [ -n "$(tail -c 1 "$file")" ]; then
+    echo "$file: missing newline at end of file" >&2
+    status=1
+  fi
+done
+"#
+    .to_string()
+}
+
+/// check-added-large-files：暂存内容超过上限的文件
+fn large_files_step() -> String {
+    format!(
+        r#"echo "$hook: check-added-large-files"
+for file in $(git diff --cached --name-only --diff-filter=A); do
+  if [ "$(git cat-file -s ":$file")" -gt {limit} ]; then
+    echo "$file: larger than {LARGE_FILE_KB} KB" >&2
+    status=1
+  fi
+done
+"#,
+        limit = LARGE_FILE_KB * 1024
+    )
+}
+
+/// pre-commit 不可用时，按 `.pre-commit-config.yaml` 写入等价的 `.git/hooks` 脚本
+pub fn install_fallback(output_path: &Path) -> Result<()> {
+    let hooks_dir = output_path.join(".git").join("hooks");
+    if !output_path.join(".git").is_dir() {
+        return tool_failures::report(
+            "pre-commit is not installed and there is no git repository to write fallback hooks into",
+        );
+    }
+
+    let config_path = output_path.join(PRECOMMIT_CONFIG);
+    let config = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    let (pre_commit, commit_msg) = render_scripts(&parse_hooks(&config));
+
+    std::fs::create_dir_all(&hooks_dir)
+        .with_context(|| format!("Failed to create {}", hooks_dir.display()))?;
+    for (name, script) in [("pre-commit", pre_commit), ("commit-msg", commit_msg)] {
+        let Some(script) = script else {
+            continue;
+        };
+        let path = hooks_dir.join(name);
+        std::fs::write(&path, script)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        make_executable(&path)?;
+    }
+
+    status!("pre-commit is not installed; wrote plain git hooks to .git/hooks instead");
+    Ok(())
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("Failed to make {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hooks() {
+        let config = r#"repos:
+  - repo: https://github.com/astral-sh/ruff-pre-commit
+    rev: v0.6.0
+    hooks:
+      - id: ruff
+        args: [--fix]
+      - id: check-yaml
+  - repo: local
+    hooks:
+      - id: tsc
+        name: tsc
+        entry: pnpm typecheck
+        language: system
+        files: \.ts$
+        pass_filenames: false
+  - repo: https://github.com/commitizen-tools/commitizen
+    hooks:
+      - id: commitizen
+        stages: [commit-msg]
+"#;
+        let hooks = parse_hooks(config);
+        let ids: Vec<&str> = hooks.iter().map(|hook| hook.id.as_str()).collect();
+        assert_eq!(ids, ["ruff", "check-yaml", "tsc", "commitizen"]);
+        assert_eq!(hooks[2].entry.as_deref(), Some("pnpm typecheck"));
+        assert!(!hooks[2].pass_filenames);
+        assert!(hooks[3].commit_msg);
+
+        let (pre_commit, commit_msg) = render_scripts(&hooks);
+        let pre_commit = pre_commit.unwrap();
+        assert!(pre_commit.contains("# Not checked without pre-commit: check-yaml"));
+        assert!(pre_commit.contains(r#"run ruff uvx 'uvx ruff check "$@"' $files"#));
+        assert!(pre_commit.contains("run tsc pnpm 'pnpm typecheck'\n"));
+        assert!(commit_msg.unwrap().contains("cz check --commit-msg-file"));
+    }
+}
@@ -0,0 +1,29 @@
+use std::process::Command;
+
+use super::render_only;
+
+/// 读取全局 git 配置中的 `user.name`，未配置时返回 `None`
+pub fn user_name() -> Option<String> {
+    read("user.name")
+}
+
+/// 读取全局 git 配置中的 `user.email`，未配置时返回 `None`
+pub fn user_email() -> Option<String> {
+    read("user.email")
+}
+
+/// 渲染模式下不读取 git 配置，保证输出稳定
+fn read(key: &str) -> Option<String> {
+    if render_only::is_render_only() {
+        return None;
+    }
+    let output = Command::new("git")
+        .args(["config", "--global", key])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|value| !value.is_empty())
+}
@@ -1,25 +1,215 @@
+use crate::status;
+use crate::utils::{feature_manifest, tool_failures};
 use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
 
+/// swag init 生成的文档文件
+const SWAG_OUTPUTS: [&str; 3] = ["docs/docs.go", "docs/swagger.json", "docs/swagger.yaml"];
+
+/// buf.gen.yaml 中生成代码的输出目录
+const BUF_OUTPUT_DIR: &str = "gen";
+
 /// Go 工具集，提供常用的 Go 命令封装
 pub struct GoTools;
 
 impl GoTools {
-    /// 运行 go mod tidy 命令
+    /// 运行 go mod tidy 命令，命令失败时返回错误，由调用方按失败策略处理
     pub fn mod_tidy(output_path: &Path) -> Result<()> {
-        println!("Running go mod tidy...");
+        status!("Running go mod tidy...");
 
-        let status = Command::new("go")
+        let output = Command::new("go")
             .args(["mod", "tidy"])
             .current_dir(output_path)
-            .status()
+            .output()
             .context("Failed to execute go mod tidy command")?;
 
-        if status.success() {
-            println!("Dependencies organized with go mod tidy");
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "go mod tidy exited with {}: {}\n   You may need to run it manually",
+                output.status,
+                stderr.trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// 运行 buf generate，按 buf.gen.yaml 把 proto/ 中的定义生成到 gen/
+    ///
+    /// 生成的文件记录为 grpc 功能；buf 未安装或执行失败时按失败策略处理
+    pub fn buf_generate(output_path: &Path) -> Result<()> {
+        let has_buf = match Command::new("buf").arg("--version").output() {
+            Ok(output) => output.status.success(),
+            Err(_) => false,
+        };
+        if !has_buf {
+            return tool_failures::report(
+                "'buf' command not found. Install it to generate the gRPC code, then run `buf generate`:\n   go install github.com/bufbuild/buf/cmd/buf@latest",
+            );
+        }
+
+        status!("Generating gRPC code with buf...");
+        let output = Command::new("buf")
+            .arg("generate")
+            .current_dir(output_path)
+            .output()
+            .context("Failed to execute buf generate command")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return tool_failures::report(format!(
+                "Failed to generate gRPC code: {}\n   Fix the issue and run `buf generate`",
+                stderr.trim()
+            ));
+        }
+
+        for entry in walkdir::WalkDir::new(output_path.join(BUF_OUTPUT_DIR))
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+        {
+            feature_manifest::record_file("grpc", entry.path());
+        }
+        status!("gRPC code generated successfully");
+        Ok(())
+    }
+
+    /// 运行 go generate ./ent，按 ent/schema 生成 ent 客户端，失败时按失败策略处理
+    pub fn ent_generate(output_path: &Path) -> Result<()> {
+        status!("Generating ent code...");
+        let output = match Command::new("go")
+            .args(["generate", "./ent"])
+            .current_dir(output_path)
+            .output()
+        {
+            Ok(output) => output,
+            Err(_) => {
+                return tool_failures::report(
+                    "'go' command not found; run `go generate ./ent` after installing Go",
+                );
+            }
+        };
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return tool_failures::report(format!(
+                "Failed to generate ent code: {}\n   Fix the issue and run `go generate ./ent`",
+                stderr.trim()
+            ));
+        }
+        status!("ent code generated successfully");
+        Ok(())
+    }
+
+    /// 用 gofmt 格式化按规范生成的代码，对齐结构体字段等；失败时按失败策略处理
+    pub fn gofmt(output_path: &Path, paths: &[&str]) -> Result<()> {
+        let output = match Command::new("gofmt")
+            .arg("-w")
+            .args(paths)
+            .current_dir(output_path)
+            .output()
+        {
+            Ok(output) => output,
+            Err(_) => {
+                return tool_failures::report(format!(
+                    "'gofmt' command not found; run `gofmt -w {}` after installing Go",
+                    paths.join(" ")
+                ));
+            }
+        };
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return tool_failures::report(format!(
+                "Failed to format the generated code: {}",
+                stderr.trim()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 运行 swag init 生成 Swagger 文档，并整理新增的依赖
+    ///
+    /// swag 未安装或执行失败时按失败策略处理
+    pub fn swag_init(output_path: &Path) -> Result<()> {
+        status!("Checking for swag command...");
+
+        // 使用同步方式检查 swag 命令
+        let has_swag = match Command::new("swag").arg("--version").output() {
+            Ok(output) => output.status.success(),
+            Err(_) => false,
+        };
+
+        if !has_swag {
+            return tool_failures::report(
+                "'swag' command not found. Install it to generate Swagger documentation:\n   go install github.com/swaggo/swag/cmd/swag@latest",
+            );
+        }
+
+        // 执行 swag init 命令
+        let output = Command::new("swag")
+            .arg("init")
+            .arg("-g")
+            .arg("main.go")
+            .current_dir(output_path)
+            .output()
+            .context("Failed to execute swag init command")?;
+
+        if output.status.success() {
+            status!("Swagger documentation generated successfully");
+            for file in SWAG_OUTPUTS {
+                feature_manifest::record_file("swagger", &output_path.join(file));
+            }
+
+            // 升级 swag 版本以确保兼容性
+            status!("Upgrading swag to latest version...");
+            let upgrade_output = Command::new("go")
+                .arg("get")
+                .arg("-u")
+                .arg("github.com/swaggo/swag")
+                .current_dir(output_path)
+                .output()
+                .context("Failed to execute go get -u github.com/swaggo/swag command")?;
+
+            if upgrade_output.status.success() {
+                status!("Swag upgraded successfully");
+            } else {
+                let stderr = String::from_utf8_lossy(&upgrade_output.stderr);
+                tool_failures::report(format!("Failed to upgrade swag: {}", stderr.trim()))?;
+            }
+
+            // 向 swagger.json 文件末尾添加空白行以符合格式规范
+            let swagger_json_path = output_path.join("docs").join("swagger.json");
+            if swagger_json_path.exists()
+                && let Ok(mut content) = std::fs::read_to_string(&swagger_json_path)
+            {
+                // 检查文件是否以换行符结尾（支持不同平台的换行符）
+                let needs_newline = !content.ends_with('\n') && !content.ends_with("\r\n");
+
+                if needs_newline {
+                    content.push('\n');
+
+                    if let Err(e) = std::fs::write(&swagger_json_path, content) {
+                        tool_failures::report(format!(
+                            "Failed to add newline to swagger.json: {e}"
+                        ))?;
+                    } else {
+                        status!("Added newline to swagger.json for proper formatting");
+                    }
+                }
+            }
+
+            // 生成 Swagger 文档后，重新运行 go mod tidy 来整理新增的依赖
+            if let Err(e) = Self::mod_tidy(output_path) {
+                tool_failures::report(format!(
+                    "Failed to run go mod tidy after Swagger generation: {e}"
+                ))?;
+            }
         } else {
-            println!("Warning: Failed to run go mod tidy, you may need to run it manually");
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!(
+                "Failed to generate Swagger documentation: {}",
+                stderr.trim()
+            ))?;
         }
 
         Ok(())
@@ -28,18 +218,22 @@ impl GoTools {
     /// 运行 go mod init 命令
     #[allow(dead_code)]
     pub fn mod_init(output_path: &Path, module_name: &str) -> Result<()> {
-        println!("Initializing Go module: {module_name}");
+        status!("Initializing Go module: {module_name}");
 
-        let status = Command::new("go")
+        let output = Command::new("go")
             .args(["mod", "init", module_name])
             .current_dir(output_path)
-            .status()
+            .output()
             .context("Failed to execute go mod init command")?;
 
-        if status.success() {
-            println!("Go module initialized: {module_name}");
+        if output.status.success() {
+            status!("Go module initialized: {module_name}");
         } else {
-            return Err(anyhow::anyhow!("Failed to initialize Go module"));
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "Failed to initialize Go module: {}",
+                stderr.trim()
+            ));
         }
 
         Ok(())
@@ -64,18 +258,22 @@ impl GoTools {
     /// 运行 go get 命令安装依赖
     #[allow(dead_code)]
     pub fn get_dependency(output_path: &Path, dependency: &str) -> Result<()> {
-        println!("Installing Go dependency: {dependency}");
+        status!("Installing Go dependency: {dependency}");
 
-        let status = Command::new("go")
+        let output = Command::new("go")
             .args(["get", dependency])
             .current_dir(output_path)
-            .status()
+            .output()
             .context("Failed to execute go get command")?;
 
-        if status.success() {
-            println!("Dependency installed: {dependency}");
+        if output.status.success() {
+            status!("Dependency installed: {dependency}");
         } else {
-            println!("Warning: Failed to install dependency: {dependency}");
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tool_failures::report(format!(
+                "Failed to install dependency {dependency}: {}",
+                stderr.trim()
+            ))?;
         }
 
         Ok(())
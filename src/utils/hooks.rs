@@ -0,0 +1,138 @@
+use anyhow::{Context, Result};
+use inquire::Confirm;
+use serde_json::json;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::answers;
+use super::app_dirs::{self, state_dir};
+use crate::status;
+
+/// 审计日志文件名，保存在状态目录下
+const AUDIT_LOG_FILE: &str = "hooks.log";
+
+tokio::task_local! {
+    /// 当前生成是否信任模板声明的钩子，由 `scope` 设置
+    static TRUSTED: bool;
+}
+
+/// 在指定的信任设置（`--trust`）下执行一次生成：信任时不再逐个确认钩子
+pub async fn scope<F: Future>(trusted: bool, future: F) -> F::Output {
+    TRUSTED.scope(trusted, future).await
+}
+
+fn is_trusted() -> bool {
+    TRUSTED.try_with(|trusted| *trusted).unwrap_or(false)
+}
+
+/// 钩子的处理结果，写入审计日志
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookOutcome {
+    Declined,
+    Succeeded,
+    Failed,
+}
+
+impl HookOutcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            HookOutcome::Declined => "declined",
+            HookOutcome::Succeeded => "succeeded",
+            HookOutcome::Failed => "failed",
+        }
+    }
+}
+
+/// 执行模板声明的钩子前展示命令、参数和工作目录并征求确认，返回是否允许执行
+///
+/// 指定 `--trust` 时直接允许；无法确认（如非终端环境且标准输入中没有答案）时视为拒绝
+pub fn approve(command: &str, args: &[String], cwd: &Path) -> bool {
+    let command_line = display_command(command, args);
+
+    if is_trusted() {
+        status!("Running template hook (--trust): {command_line}");
+        return true;
+    }
+
+    println!("⚠️  The template declares a hook command:");
+    println!("   command: {command}");
+    println!("   args:    {}", display_command("", args).trim_start());
+    println!("   cwd:     {}", cwd.display());
+
+    match answers::confirm("hook", Confirm::new("Run this hook?").with_default(false)) {
+        Ok(approved) => approved,
+        Err(e) => {
+            println!("⚠️  Warning: Cannot confirm hook ({e:#}), skipping: {command_line}");
+            println!("   Rerun with --trust to allow template hooks");
+            false
+        }
+    }
+}
+
+/// 将钩子的处理结果追加到审计日志，写入失败时只输出警告
+pub fn audit(command: &str, args: &[String], cwd: &Path, outcome: HookOutcome) {
+    if let Err(e) = append_audit_entry(command, args, cwd, outcome) {
+        println!("⚠️  Warning: Failed to write hook audit log: {e:#}");
+    }
+}
+
+/// 审计日志路径
+pub fn audit_log_path() -> Option<PathBuf> {
+    state_dir().map(|dir| dir.join(AUDIT_LOG_FILE))
+}
+
+fn append_audit_entry(
+    command: &str,
+    args: &[String],
+    cwd: &Path,
+    outcome: HookOutcome,
+) -> Result<()> {
+    let Some(path) = audit_log_path() else {
+        return Ok(());
+    };
+    // 每行一条 JSON 记录
+    let entry = json!({
+        "time": chrono::Local::now().to_rfc3339(),
+        "command": command,
+        "args": args,
+        "cwd": cwd.display().to_string(),
+        "trusted": is_trusted(),
+        "outcome": outcome.as_str(),
+    });
+    let mut file = app_dirs::open_append_locked(&path)?;
+    writeln!(file, "{entry}").with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// 将命令和参数拼接为便于阅读的命令行，包含空白或引号的参数加单引号
+fn display_command(command: &str, args: &[String]) -> String {
+    let mut parts = vec![command.to_string()];
+    for arg in args {
+        if arg.is_empty() || arg.contains(|c: char| c.is_whitespace() || c == '\'' || c == '"') {
+            parts.push(format!("'{}'", arg.replace('\'', "'\\''")));
+        } else {
+            parts.push(arg.clone());
+        }
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_command() {
+        let args = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            display_command("npm", &args(&["install", "--save"])),
+            "npm install --save"
+        );
+        assert_eq!(
+            display_command("sh", &args(&["-c", "echo hi", ""])),
+            "sh -c 'echo hi' ''"
+        );
+        assert_eq!(display_command("echo", &args(&["it's"])), "echo 'it'\\''s'");
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::Result;
+use std::process::Command;
+
+use crate::constants::Runtime;
+
+/// 运行时未安装时给出的安装提示
+pub fn install_hint(runtime: Runtime) -> &'static str {
+    match runtime {
+        Runtime::Node => "npm install -g pnpm\n  or visit: https://pnpm.io/installation",
+        Runtime::Bun => "curl -fsSL https://bun.sh/install | bash\n  or visit: https://bun.sh/",
+        Runtime::Deno => {
+            "curl -fsSL https://deno.land/install.sh | sh\n  or visit: https://docs.deno.com/runtime/getting_started/installation/"
+        }
+    }
+}
+
+/// 检查运行时的包管理器是否可用
+pub fn is_available(runtime: Runtime) -> bool {
+    Command::new(runtime.package_manager())
+        .arg("--version")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// 确认运行时的包管理器可用，否则返回带安装提示的错误
+pub fn ensure_available(runtime: Runtime) -> Result<()> {
+    let package_manager = runtime.package_manager();
+    if is_available(runtime) {
+        crate::status!("  ✅ {package_manager}: Available");
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "{package_manager} is not installed. Please install {package_manager} first:\n  {}",
+            install_hint(runtime)
+        ))
+    }
+}
+
+/// 运行 `create-<name>` 脚手架，如 `pnpm create vite@latest`、`bunx create-vite@latest`
+pub fn create(runtime: Runtime, package: &str) -> Command {
+    match runtime {
+        Runtime::Node => {
+            let mut command = Command::new("pnpm");
+            command.args(["create", package]);
+            command
+        }
+        Runtime::Bun | Runtime::Deno => dlx(runtime, &format!("create-{package}")),
+    }
+}
+
+/// 临时下载并运行 npm 包，如 `pnpm dlx sv`、`bunx sv`、`deno run -A npm:sv`
+pub fn dlx(runtime: Runtime, package: &str) -> Command {
+    match runtime {
+        Runtime::Node => {
+            let mut command = Command::new("pnpm");
+            command.args(["dlx", package]);
+            command
+        }
+        Runtime::Bun => {
+            let mut command = Command::new("bunx");
+            command.arg(package);
+            command
+        }
+        Runtime::Deno => {
+            let mut command = Command::new("deno");
+            command.args(["run", "-A", &format!("npm:{package}")]);
+            command
+        }
+    }
+}
+
+/// 添加依赖，`dev` 为 true 时加入 devDependencies
+pub fn add(runtime: Runtime, dev: bool, packages: &[&str]) -> Command {
+    let mut command = Command::new(runtime.package_manager());
+    command.arg("add");
+    match runtime {
+        Runtime::Node => {
+            if dev {
+                command.arg("-D");
+            }
+            command.args(packages);
+        }
+        Runtime::Bun => {
+            if dev {
+                command.arg("--dev");
+            }
+            command.args(packages);
+        }
+        // deno add 需要 npm: 说明符才会写入 package.json
+        Runtime::Deno => {
+            if dev {
+                command.arg("--dev");
+            }
+            command.args(packages.iter().map(|package| format!("npm:{package}")));
+        }
+    }
+    command
+}
+
+/// 按 package.json 安装依赖
+pub fn install(runtime: Runtime) -> Command {
+    let mut command = Command::new(runtime.package_manager());
+    command.arg("install");
+    command
+}
+
+/// 运行已安装依赖中的可执行文件
+pub fn exec(runtime: Runtime, bin: &str) -> Command {
+    match runtime {
+        Runtime::Node => {
+            let mut command = Command::new("pnpm");
+            command.args(["exec", bin]);
+            command
+        }
+        Runtime::Bun | Runtime::Deno => dlx(runtime, bin),
+    }
+}
+
+/// 运行 package.json 中的脚本
+pub fn run(runtime: Runtime, script: &str) -> Command {
+    let mut command = Command::new(runtime.package_manager());
+    match runtime {
+        Runtime::Node => command.arg(script),
+        Runtime::Bun => command.args(["run", script]),
+        Runtime::Deno => command.args(["task", script]),
+    };
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command_line(command: &Command) -> Vec<String> {
+        std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_commands_follow_runtime() {
+        assert_eq!(
+            command_line(&create(Runtime::Node, "vite@latest")),
+            ["pnpm", "create", "vite@latest"]
+        );
+        assert_eq!(
+            command_line(&create(Runtime::Bun, "vite@latest")),
+            ["bunx", "create-vite@latest"]
+        );
+        assert_eq!(
+            command_line(&create(Runtime::Deno, "vite@latest")),
+            ["deno", "run", "-A", "npm:create-vite@latest"]
+        );
+        assert_eq!(
+            command_line(&add(Runtime::Deno, true, &["tailwindcss@^4"])),
+            ["deno", "add", "--dev", "npm:tailwindcss@^4"]
+        );
+        assert_eq!(
+            command_line(&add(Runtime::Node, true, &["clsx"])),
+            ["pnpm", "add", "-D", "clsx"]
+        );
+        assert_eq!(
+            command_line(&run(Runtime::Deno, "astro")),
+            ["deno", "task", "astro"]
+        );
+        assert_eq!(
+            command_line(&exec(Runtime::Bun, "tailwindcss")),
+            ["bunx", "tailwindcss"]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use anyhow::{Context, Result, bail};
+use serde_json::{Map, Value, json};
+use std::path::Path;
+
+/// package.json 的结构化修改：记录要加入的字段、脚本和依赖，一次读写完成
+///
+/// 生成器先把所有依赖写入 package.json，最后只运行一次包管理器的 install，
+/// 不再为每组依赖单独调用 `pnpm add`
+#[derive(Debug, Default, Clone)]
+pub struct PackageJsonPatch {
+    fields: Vec<(String, Value)>,
+    scripts: Vec<(String, String)>,
+    dependencies: Vec<(String, String)>,
+    dev_dependencies: Vec<(String, String)>,
+}
+
+impl PackageJsonPatch {
+    /// 创建空的修改
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置顶层字段，已有的值会被替换
+    pub fn with_field(mut self, key: &str, value: Value) -> Self {
+        self.fields.push((key.to_string(), value));
+        self
+    }
+
+    /// 加入脚本，已有的同名脚本保持不变
+    pub fn with_script(mut self, name: &str, command: &str) -> Self {
+        self.scripts.push((name.to_string(), command.to_string()));
+        self
+    }
+
+    /// 加入 dependencies，包的格式为 `name@range`，如 `@tanstack/vue-query@^5`
+    pub fn with_dependencies(mut self, packages: &[&str]) -> Self {
+        self.dependencies
+            .extend(packages.iter().map(|package| split_package(package)));
+        self
+    }
+
+    /// 加入 devDependencies，包的格式与 [`Self::with_dependencies`] 相同
+    pub fn with_dev_dependencies(mut self, packages: &[&str]) -> Self {
+        self.dev_dependencies
+            .extend(packages.iter().map(|package| split_package(package)));
+        self
+    }
+
+    /// 将修改应用到已解析的 package.json
+    ///
+    /// 依赖与 `pnpm add` 一样覆盖已有版本并按名称排序
+    pub fn apply_to(&self, package: &mut Value) -> Result<()> {
+        let Some(object) = package.as_object_mut() else {
+            bail!("Invalid package.json: top-level value is not an object");
+        };
+
+        for (key, value) in &self.fields {
+            object.insert(key.clone(), value.clone());
+        }
+        if !self.scripts.is_empty() {
+            let scripts = table(object, "scripts")?;
+            for (name, command) in &self.scripts {
+                scripts
+                    .entry(name.clone())
+                    .or_insert_with(|| json!(command));
+            }
+        }
+        for (key, dependencies) in [
+            ("dependencies", &self.dependencies),
+            ("devDependencies", &self.dev_dependencies),
+        ] {
+            if dependencies.is_empty() {
+                continue;
+            }
+            let table = table(object, key)?;
+            for (name, range) in dependencies {
+                table.insert(name.clone(), json!(range));
+            }
+            table.sort_keys();
+        }
+        Ok(())
+    }
+
+    /// 读取、修改并写回 `project_path/package.json`，文件不存在时不做处理
+    pub fn apply(&self, project_path: &Path) -> Result<()> {
+        let package_json_path = project_path.join("package.json");
+        if !package_json_path.exists() {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&package_json_path)
+            .with_context(|| format!("Failed to read {}", package_json_path.display()))?;
+        let mut package: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", package_json_path.display()))?;
+        self.apply_to(&mut package)?;
+
+        let mut output = serde_json::to_string_pretty(&package)?;
+        output.push('\n');
+        std::fs::write(&package_json_path, output)
+            .with_context(|| format!("Failed to write {}", package_json_path.display()))
+    }
+}
+
+/// 取出顶层的对象字段，不存在时创建
+fn table<'a>(object: &'a mut Map<String, Value>, key: &str) -> Result<&'a mut Map<String, Value>> {
+    object
+        .entry(key)
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .with_context(|| format!("Invalid package.json: {key} is not an object"))
+}
+
+/// 拆分 `name@range`，作用域包的名称以 `@` 开头；没有版本时使用 `latest`
+fn split_package(package: &str) -> (String, String) {
+    let (scope, rest) = package.split_at(usize::from(package.starts_with('@')));
+    match rest.split_once('@') {
+        Some((name, range)) => (format!("{scope}{name}"), range.to_string()),
+        None => (package.to_string(), "latest".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_package_json_patch() {
+        let mut package = json!({
+            "name": "web",
+            "scripts": { "test": "jest" },
+            "dependencies": { "vue": "^3.5.13", "axios": "^0.27.0" }
+        });
+        PackageJsonPatch::new()
+            .with_field("description", json!("A web app"))
+            .with_script("test", "vitest")
+            .with_script("test:coverage", "vitest run --coverage")
+            .with_dependencies(&["axios@^1", "@tanstack/vue-query@^5"])
+            .with_dev_dependencies(&["vitest@^3", "jsdom"])
+            .apply_to(&mut package)
+            .unwrap();
+
+        assert_eq!(package["description"], "A web app");
+        assert_eq!(package["scripts"]["test"], "jest");
+        assert_eq!(package["scripts"]["test:coverage"], "vitest run --coverage");
+        let dependencies: Vec<_> = package["dependencies"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(name, range)| format!("{name}@{}", range.as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            dependencies,
+            ["@tanstack/vue-query@^5", "axios@^1", "vue@^3.5.13"]
+        );
+        assert_eq!(package["devDependencies"]["jsdom"], "latest");
+
+        assert!(
+            PackageJsonPatch::new()
+                .with_script("dev", "vite")
+                .apply_to(&mut json!([]))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::path::Path;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Table};
+
+use crate::generators::core::BaseParams;
+use crate::utils::json_patch::PackageJsonPatch;
+use crate::utils::toml_patch::{TomlPatch, unpositioned};
+
+/// package.json 中的依赖表
+const PACKAGE_JSON_DEPENDENCY_KEYS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// Cargo.toml 中的依赖表
+const CARGO_DEPENDENCY_KEYS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// 项目清单元数据（描述、关键字、主页和作者），用于回填由外部工具生成的清单文件
+#[derive(Debug, Clone, Default)]
+pub struct ManifestMetadata<'a> {
+    pub description: Option<&'a str>,
+    pub keywords: &'a [String],
+    pub homepage: Option<&'a str>,
+    pub author: Option<&'a str>,
+    pub author_email: Option<&'a str>,
+}
+
+impl<'a> ManifestMetadata<'a> {
+    /// 从基础参数提取清单元数据
+    pub fn from_base(base: &'a BaseParams) -> Self {
+        Self {
+            description: base.project_description.as_deref(),
+            keywords: &base.keywords,
+            homepage: base.homepage.as_deref(),
+            author: base.author.as_deref(),
+            author_email: base.author_email.as_deref(),
+        }
+    }
+}
+
+/// 更新 package.json 中的 description/keywords/homepage/author 字段
+pub fn update_package_json_metadata(
+    project_path: &Path,
+    metadata: &ManifestMetadata,
+) -> Result<()> {
+    let mut patch = PackageJsonPatch::new();
+    if let Some(description) = metadata.description {
+        patch = patch.with_field("description", json!(description));
+    }
+    if !metadata.keywords.is_empty() {
+        patch = patch.with_field("keywords", json!(metadata.keywords));
+    }
+    if let Some(homepage) = metadata.homepage {
+        patch = patch.with_field("homepage", json!(homepage));
+    }
+    if let Some(author) = metadata.author {
+        let author = match metadata.author_email {
+            Some(email) => format!("{author} <{email}>"),
+            None => author.to_string(),
+        };
+        patch = patch.with_field("author", json!(author));
+    }
+    patch.apply(project_path)
+}
+
+/// 更新 pyproject.toml 中 [project] 的 description/keywords/authors 以及 [project.urls] 主页
+pub fn update_pyproject_metadata(project_path: &Path, metadata: &ManifestMetadata) -> Result<()> {
+    pyproject_patch(metadata).apply(&project_path.join("pyproject.toml"))
+}
+
+/// uv init 生成的 pyproject.toml 需要回填的字段，作者替换 uv 按 git 配置写入的 authors
+fn pyproject_patch(metadata: &ManifestMetadata) -> TomlPatch {
+    let mut patch = TomlPatch::new();
+    if let Some(description) = metadata.description {
+        patch = patch.with_value("project.description", description);
+    }
+    if !metadata.keywords.is_empty() {
+        patch = patch.with_value("project.keywords", Array::from_iter(metadata.keywords));
+    }
+    if let Some(author) = metadata.author {
+        let mut entry = InlineTable::new();
+        entry.insert("name", author.into());
+        if let Some(email) = metadata.author_email {
+            entry.insert("email", email.into());
+        }
+        patch = patch.with_value("project.authors", Array::from_iter([entry]));
+    }
+    if let Some(homepage) = metadata.homepage {
+        patch = patch.with_value("project.urls.Homepage", homepage);
+    }
+    patch
+}
+
+/// 将覆盖模板渲染出的 package.json 合并到外部工具生成的 package.json 中
+///
+/// 对象逐键合并，其余字段以覆盖模板为准；依赖表中已有的依赖主版本相同时保留上游版本
+pub fn merge_package_json(existing: &str, overlay: &str) -> Result<String> {
+    let mut merged: Value =
+        serde_json::from_str(existing).context("Failed to parse the existing package.json")?;
+    let overlay: Value =
+        serde_json::from_str(overlay).context("Failed to parse the overlay package.json")?;
+    merge_json_value(&mut merged, overlay, false);
+
+    let mut output = serde_json::to_string_pretty(&merged)?;
+    output.push('\n');
+    Ok(output)
+}
+
+fn merge_json_value(target: &mut Value, overlay: Value, in_dependencies: bool) {
+    let (Value::Object(target), Value::Object(overlay)) = (&mut *target, &overlay) else {
+        *target = overlay;
+        return;
+    };
+    for (key, value) in overlay {
+        if let Some(existing) = target.get_mut(key) {
+            if !in_dependencies {
+                let is_dependencies = PACKAGE_JSON_DEPENDENCY_KEYS.contains(&key.as_str());
+                merge_json_value(existing, value.clone(), is_dependencies);
+            } else if !same_major(existing.as_str(), value.as_str()) {
+                *existing = value.clone();
+            }
+        } else {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+/// 将覆盖模板渲染出的 Cargo.toml 合并到外部工具生成的 Cargo.toml 中，保留原有注释和格式
+///
+/// 表逐键合并，其余键以覆盖模板为准；依赖表中已有的依赖主版本相同时保留上游版本，
+/// 改为 `workspace = true` 等不含版本的写法时使用覆盖模板
+pub fn merge_cargo_toml(existing: &str, overlay: &str) -> Result<String> {
+    let mut merged: DocumentMut = existing
+        .parse()
+        .context("Failed to parse the existing Cargo.toml")?;
+    let overlay: DocumentMut = overlay
+        .parse()
+        .context("Failed to parse the overlay Cargo.toml")?;
+    merge_toml_table(merged.as_table_mut(), overlay.as_table(), false);
+    Ok(merged.to_string())
+}
+
+fn merge_toml_table(target: &mut Table, overlay: &Table, in_dependencies: bool) {
+    for (key, item) in overlay.iter() {
+        if let Some(existing) = target.get_mut(key) {
+            match (&mut *existing, item) {
+                _ if in_dependencies => {
+                    if !same_major(cargo_version(existing), cargo_version(item)) {
+                        *existing = item.clone();
+                    }
+                }
+                (Item::Table(existing), Item::Table(table)) => {
+                    merge_toml_table(existing, table, CARGO_DEPENDENCY_KEYS.contains(&key));
+                }
+                _ => *existing = unpositioned(item),
+            }
+        } else if let Some(key) = overlay.key(key) {
+            target.insert_formatted(key, unpositioned(item));
+        }
+    }
+}
+
+/// 依赖的版本要求，`"1.0"` 和 `{ version = "1.0" }` 两种写法都支持
+fn cargo_version(item: &Item) -> Option<&str> {
+    item.as_str().or_else(|| {
+        item.as_table_like()?
+            .get("version")
+            .and_then(|version| version.as_str())
+    })
+}
+
+/// 两个版本要求的主版本是否相同，如 `^2`、`~2.1` 和 `2.0.3`
+fn same_major(existing: Option<&str>, overlay: Option<&str>) -> bool {
+    let major = |version: &str| {
+        let version = version.trim_start_matches(['^', '~', '=', '>', '<', 'v', ' ']);
+        let end = version
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(version.len());
+        version[..end].parse::<u64>().ok()
+    };
+    match (existing.and_then(major), overlay.and_then(major)) {
+        (Some(existing), Some(overlay)) => existing == overlay,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_manifests() {
+        let existing = r#"{
+  "name": "demo",
+  "scripts": { "dev": "vite", "tauri": "tauri" },
+  "dependencies": { "@tauri-apps/api": "^2.1.0", "vue": "^3.5.13" },
+  "devDependencies": { "@tauri-apps/cli": "^2" }
+}"#;
+        let overlay = r#"{
+  "name": "demo",
+  "description": "A desktop app",
+  "scripts": { "dev": "vite --host" },
+  "dependencies": { "@tauri-apps/api": "^2", "pinia": "^3.0.3", "vue": "^4.0.0" }
+}"#;
+        let merged: Value =
+            serde_json::from_str(&merge_package_json(existing, overlay).unwrap()).unwrap();
+        assert_eq!(merged["description"], "A desktop app");
+        assert_eq!(merged["scripts"]["dev"], "vite --host");
+        assert_eq!(merged["scripts"]["tauri"], "tauri");
+        assert_eq!(merged["dependencies"]["@tauri-apps/api"], "^2.1.0");
+        assert_eq!(merged["dependencies"]["pinia"], "^3.0.3");
+        assert_eq!(merged["dependencies"]["vue"], "^4.0.0");
+        assert_eq!(merged["devDependencies"]["@tauri-apps/cli"], "^2");
+
+        let existing = r#"[package]
+name = "demo"
+version = "0.1.0"
+authors = ["you"]
+
+[dependencies]
+# The Tauri runtime
+tauri = { version = "2.2", features = [] }
+serde = { version = "1", features = ["derive"] }
+serde_json = "1"
+"#;
+        let overlay = r#"[package]
+name = "demo"
+version.workspace = true
+
+[lib]
+name = "demo_lib"
+
+[dependencies]
+tauri = { version = "2" }
+serde = { workspace = true }
+tauri-plugin-store = "2"
+"#;
+        let merged = merge_cargo_toml(existing, overlay).unwrap();
+        assert_eq!(
+            merged,
+            r#"[package]
+name = "demo"
+version.workspace = true
+authors = ["you"]
+
+[dependencies]
+# The Tauri runtime
+tauri = { version = "2.2", features = [] }
+serde = { workspace = true }
+serde_json = "1"
+tauri-plugin-store = "2"
+
+[lib]
+name = "demo_lib"
+"#
+        );
+    }
+
+    #[test]
+    fn test_pyproject_patch() {
+        let mut document: DocumentMut = r#"[project]
+name = "demo"
+version = "0.1.0"
+description = "Add your description here"
+authors = [
+    { name = "git user", email = "git@example.com" },
+]
+requires-python = ">=3.12"
+"#
+        .parse()
+        .unwrap();
+        let metadata = ManifestMetadata {
+            description: Some("A demo"),
+            author: Some("Jane Doe"),
+            author_email: Some("jane@example.com"),
+            ..Default::default()
+        };
+        pyproject_patch(&metadata).apply_to(&mut document).unwrap();
+        assert_eq!(
+            document.to_string(),
+            r#"[project]
+name = "demo"
+version = "0.1.0"
+description = "A demo"
+authors = [{ name = "Jane Doe", email = "jane@example.com" }]
+requires-python = ">=3.12"
+"#
+        );
+    }
+}
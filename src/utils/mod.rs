@@ -1,2 +1,30 @@
+pub mod answers;
+pub mod api_client;
+pub mod app_dirs;
+pub mod archive;
+pub mod code_inject;
+pub mod config;
+pub mod console;
+pub mod editor;
 pub mod env_checker;
+pub mod feature_manifest;
+pub mod git_auth;
+pub mod git_hooks;
+pub mod git_identity;
 pub mod go_tools;
+pub mod hooks;
+pub mod js_runtime;
+pub mod json_patch;
+pub mod manifest;
+pub mod overlay;
+pub mod process;
+pub mod progress;
+pub mod render_only;
+pub mod spdx;
+pub mod tailwind;
+pub mod tls;
+pub mod toml_patch;
+pub mod tool_failures;
+pub mod tool_install;
+pub mod vitest;
+pub mod yaml_patch;
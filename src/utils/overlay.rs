@@ -0,0 +1,155 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+use super::git_auth::{self, HostAuth};
+use crate::status;
+
+/// 组织模板覆盖层的来源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverlaySource {
+    /// 本地目录
+    Dir(PathBuf),
+    /// git 仓库地址，可选的分支或标签写在 `#` 之后，如 `https://git.acme.io/templates.git#v2`
+    Git {
+        url: String,
+        reference: Option<String>,
+    },
+}
+
+impl OverlaySource {
+    /// 解析 `--overlay` 或配置中的 `template_overlay`：URL、`git@` 地址或以 `.git` 结尾时视为 git 仓库
+    pub fn parse(source: &str) -> Self {
+        let (location, reference) = match source.split_once('#') {
+            Some((location, reference)) if !reference.is_empty() => {
+                (location, Some(reference.to_string()))
+            }
+            _ => (source, None),
+        };
+        let is_git = ["https://", "http://", "ssh://", "git://", "file://", "git@"]
+            .iter()
+            .any(|prefix| location.starts_with(prefix))
+            || location.ends_with(".git");
+
+        if is_git {
+            OverlaySource::Git {
+                url: location.to_string(),
+                reference,
+            }
+        } else {
+            OverlaySource::Dir(PathBuf::from(source))
+        }
+    }
+}
+
+/// 准备好的覆盖层目录；git 仓库克隆到临时目录，随该值一起删除
+#[derive(Debug)]
+pub struct Overlay {
+    dir: PathBuf,
+    _checkout: Option<TempDir>,
+}
+
+impl Overlay {
+    /// 覆盖层根目录，目录结构与 `templates/` 相同
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+/// 准备覆盖层：本地目录直接使用，git 仓库按 `hosts` 中的认证方式浅克隆到临时目录
+///
+/// 覆盖层是模板的一部分，渲染模式下同样会克隆
+pub fn prepare(source: &str, hosts: &BTreeMap<String, HostAuth>) -> Result<Overlay> {
+    match OverlaySource::parse(source) {
+        OverlaySource::Dir(dir) => {
+            if !dir.is_dir() {
+                return Err(anyhow::anyhow!(
+                    "Template overlay directory not found: {}",
+                    dir.display()
+                ));
+            }
+            Ok(Overlay {
+                dir,
+                _checkout: None,
+            })
+        }
+        OverlaySource::Git { url, reference } => {
+            // 以 `-` 开头的地址或分支会被 git 当作选项（如 `--upload-pack`）
+            if url.starts_with('-') || reference.as_deref().is_some_and(|r| r.starts_with('-')) {
+                return Err(anyhow::anyhow!(
+                    "Invalid template overlay source: {}",
+                    git_auth::redact_url(source)
+                ));
+            }
+            let checkout = tempfile::Builder::new()
+                .prefix("scafgen-overlay-")
+                .tempdir()
+                .context("Failed to create temporary directory")?;
+            // 地址中可能带有令牌，输出前隐藏用户信息
+            let display_url = git_auth::redact_url(&url);
+            status!("Fetching template overlay from {display_url}...");
+
+            let mut command = Command::new("git");
+            command.args(["clone", "--depth", "1", "--quiet"]);
+            if let Some(ref reference) = reference {
+                command.args(["--branch", reference]);
+            }
+            git_auth::configure(&mut command, &url, hosts)?;
+            let output = command
+                .arg("--")
+                .arg(&url)
+                .arg(checkout.path())
+                .output()
+                .context("Failed to execute git clone")?;
+            if !output.status.success() {
+                return Err(anyhow::anyhow!(
+                    "Failed to clone template overlay {display_url}: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ));
+            }
+
+            Ok(Overlay {
+                dir: checkout.path().to_path_buf(),
+                _checkout: Some(checkout),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_overlay_source() {
+        assert_eq!(
+            OverlaySource::parse("./acme-templates"),
+            OverlaySource::Dir(PathBuf::from("./acme-templates"))
+        );
+        assert_eq!(
+            OverlaySource::parse("https://git.acme.io/platform/templates#v2"),
+            OverlaySource::Git {
+                url: "https://git.acme.io/platform/templates".to_string(),
+                reference: Some("v2".to_string()),
+            }
+        );
+        assert_eq!(
+            OverlaySource::parse("git@github.com:acme/templates.git"),
+            OverlaySource::Git {
+                url: "git@github.com:acme/templates.git".to_string(),
+                reference: None,
+            }
+        );
+        assert!(prepare("/nonexistent/overlay", &BTreeMap::new()).is_err());
+        assert!(prepare("--upload-pack=touch.git", &BTreeMap::new()).is_err());
+        assert!(
+            prepare(
+                "https://git.acme.io/templates#--upload-pack=x",
+                &BTreeMap::new()
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use anyhow::{Context, Result};
+use std::io::Read;
+use std::process::{Child, Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 轮询子进程状态的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 以非交互方式执行命令并限制运行时间
+///
+/// 标准输入被关闭，命令若等待交互输入会在超时后被终止，此时返回 `None`。
+/// Unix 上命令在单独的进程组中运行，超时时连同它启动的子进程（如 pnpm 调起的 node）一起终止
+pub fn output_with_timeout(command: &mut Command, timeout: Duration) -> Result<Option<Output>> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    let mut child = command.spawn().context("Failed to spawn command")?;
+
+    // 后台读取输出，避免管道写满导致子进程阻塞
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() >= timeout {
+            kill(&mut child);
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    Ok(Some(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+fn kill(child: &mut Child) {
+    // 进程组号与子进程的 pid 相同
+    #[cfg(unix)]
+    if let Ok(pid) = i32::try_from(child.id()) {
+        // SAFETY: 只向该命令所在的进程组发送信号，不涉及内存操作
+        unsafe {
+            libc::kill(-pid, libc::SIGKILL);
+        }
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_with_timeout() {
+        let output = output_with_timeout(
+            Command::new("sh").args(["-c", "echo ok"]),
+            Duration::from_secs(10),
+        )
+        .unwrap()
+        .expect("command should finish");
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+
+        let timed_out = output_with_timeout(
+            Command::new("sh").args(["-c", "sleep 5"]),
+            Duration::from_millis(200),
+        )
+        .unwrap();
+        assert!(timed_out.is_none());
+
+        // 命令启动的后台进程也随超时一起终止
+        let dir = tempfile::tempdir().unwrap();
+        let pid_file = dir.path().join("pid");
+        let timed_out = output_with_timeout(
+            Command::new("sh").args([
+                "-c",
+                &format!("sleep 30 & echo $! > {}; wait", pid_file.display()),
+            ]),
+            Duration::from_millis(500),
+        )
+        .unwrap();
+        assert!(timed_out.is_none());
+        // 已终止但未被回收的进程（僵尸进程）视为已退出
+        #[cfg(target_os = "linux")]
+        {
+            let pid = std::fs::read_to_string(&pid_file).unwrap();
+            thread::sleep(Duration::from_millis(200));
+            let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid.trim()));
+            assert!(stat.is_err() || stat.unwrap().contains(") Z "));
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::app_dirs::{self, state_dir};
+use crate::status;
+
+/// 步骤耗时记录文件名
+const TIMINGS_FILE: &str = "timings.json";
+
+/// 低于该耗时（秒）的步骤不显示预估时间
+const MIN_ESTIMATE_SECS: f64 = 1.0;
+
+/// 单个步骤的历史耗时
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct StepTiming {
+    pub average_secs: f64,
+    pub runs: u32,
+}
+
+impl StepTiming {
+    /// 记录一次新的耗时，更新滑动平均值
+    pub fn record(&mut self, elapsed: Duration) {
+        let secs = elapsed.as_secs_f64();
+        self.average_secs = if self.runs == 0 {
+            secs
+        } else {
+            // 对最近的运行赋予更高权重，网络和缓存状态变化时估算能较快跟上
+            self.average_secs * 0.7 + secs * 0.3
+        };
+        self.runs = self.runs.saturating_add(1);
+    }
+}
+
+/// 流水线步骤进度显示
+///
+/// 以 "[3/7] Installing dependencies… (~45s)" 的形式输出步骤编号，
+/// 并根据状态目录中记录的历史耗时给出预估时间
+pub struct StepProgress {
+    pipeline: String,
+    total: usize,
+    current: usize,
+    active: Option<(String, Instant)>,
+    timings: BTreeMap<String, StepTiming>,
+    /// 本次运行完成的步骤耗时，保存时合并到最新的记录中
+    elapsed: Vec<(String, Duration)>,
+}
+
+impl StepProgress {
+    /// 创建指定流水线的进度显示，并加载历史耗时
+    pub fn new(pipeline: &str, total: usize) -> Self {
+        Self {
+            pipeline: pipeline.to_string(),
+            total,
+            current: 0,
+            active: None,
+            timings: load_timings(),
+            elapsed: Vec::new(),
+        }
+    }
+
+    /// 结束上一个步骤并开始新的步骤
+    pub fn step(&mut self, label: &str) {
+        self.complete_active();
+        self.current += 1;
+
+        let estimate = self
+            .timings
+            .get(&self.key(label))
+            .filter(|timing| timing.average_secs >= MIN_ESTIMATE_SECS)
+            .map(|timing| format!(" (~{})", format_duration(timing.average_secs)))
+            .unwrap_or_default();
+        status!("[{}/{}] {label}…{estimate}", self.current, self.total);
+
+        self.active = Some((label.to_string(), Instant::now()));
+    }
+
+    /// 结束流水线并保存本次耗时，保存失败不影响生成结果
+    pub fn finish(&mut self) {
+        self.complete_active();
+        let _ = save_timings(&self.elapsed);
+    }
+
+    fn complete_active(&mut self) {
+        if let Some((label, started)) = self.active.take() {
+            self.elapsed.push((self.key(&label), started.elapsed()));
+        }
+    }
+
+    fn key(&self, label: &str) -> String {
+        format!("{}/{label}", self.pipeline)
+    }
+}
+
+/// 将秒数格式化为简短的预估时间，如 "8s"、"2m 5s"
+pub fn format_duration(secs: f64) -> String {
+    let secs = secs.round().max(1.0) as u64;
+    match (secs / 60, secs % 60) {
+        (0, s) => format!("{s}s"),
+        (m, 0) => format!("{m}m"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+fn timings_path() -> Option<PathBuf> {
+    state_dir().map(|dir| dir.join(TIMINGS_FILE))
+}
+
+fn load_timings() -> BTreeMap<String, StepTiming> {
+    timings_path()
+        .map(|path| read_timings(&path))
+        .unwrap_or_default()
+}
+
+fn read_timings(path: &Path) -> BTreeMap<String, StepTiming> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_timings(elapsed: &[(String, Duration)]) -> anyhow::Result<()> {
+    match timings_path() {
+        Some(path) if !elapsed.is_empty() => merge_timings(&path, elapsed),
+        _ => Ok(()),
+    }
+}
+
+/// 加锁后重新读取记录再合并本次耗时，同时运行的其他 scafgen 进程写入的记录不会丢失
+fn merge_timings(path: &Path, elapsed: &[(String, Duration)]) -> anyhow::Result<()> {
+    app_dirs::with_lock(path, || {
+        let mut timings = read_timings(path);
+        for (key, duration) in elapsed {
+            timings.entry(key.clone()).or_default().record(*duration);
+        }
+        app_dirs::write_atomic(path, &serde_json::to_string_pretty(&timings)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0.2), "1s");
+        assert_eq!(format_duration(44.6), "45s");
+        assert_eq!(format_duration(120.0), "2m");
+        assert_eq!(format_duration(125.0), "2m 5s");
+    }
+
+    #[test]
+    fn test_step_timing_record() {
+        let mut timing = StepTiming::default();
+        timing.record(Duration::from_secs(10));
+        assert_eq!(
+            timing,
+            StepTiming {
+                average_secs: 10.0,
+                runs: 1
+            }
+        );
+
+        timing.record(Duration::from_secs(20));
+        assert_eq!(timing.runs, 2);
+        assert!((timing.average_secs - 13.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_merge_timings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(TIMINGS_FILE);
+
+        merge_timings(&path, &[("new/Install".into(), Duration::from_secs(10))]).unwrap();
+        // 另一个进程的记录在两次保存之间写入，合并后保留
+        merge_timings(
+            &path,
+            &[
+                ("new/Install".into(), Duration::from_secs(20)),
+                ("new/Format".into(), Duration::from_secs(2)),
+            ],
+        )
+        .unwrap();
+
+        let timings = read_timings(&path);
+        assert_eq!(timings["new/Install"].runs, 2);
+        assert!((timings["new/Install"].average_secs - 13.0).abs() < f64::EPSILON);
+        assert_eq!(timings["new/Format"].runs, 1);
+    }
+}
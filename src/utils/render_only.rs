@@ -0,0 +1,28 @@
+use crate::status;
+
+tokio::task_local! {
+    /// 当前生成是否处于渲染模式，由 `scope` 设置
+    static RENDER_ONLY: bool;
+}
+
+/// 在指定的渲染模式下执行一次生成：开启后只写入模板渲染结果，不执行任何外部工具
+///
+/// 渲染模式由 `--render-to`、`--archive` 或 `scafgen serve` 的请求决定，只作用于这次生成
+pub async fn scope<F: Future>(render_only: bool, future: F) -> F::Output {
+    RENDER_ONLY.scope(render_only, future).await
+}
+
+/// 当前生成是否处于渲染模式
+pub fn is_render_only() -> bool {
+    RENDER_ONLY
+        .try_with(|render_only| *render_only)
+        .unwrap_or(false)
+}
+
+/// 渲染模式下跳过外部工具并输出提示，返回是否跳过
+pub fn skip_tool(description: &str) -> bool {
+    if is_render_only() {
+        status!("Skipping {description} (render only)");
+    }
+    is_render_only()
+}
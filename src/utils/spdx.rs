@@ -0,0 +1,261 @@
+use anyhow::{Context, Result, bail};
+use std::path::PathBuf;
+use std::process::Command;
+
+use super::app_dirs::{self, cache_dir};
+use super::render_only;
+use crate::constants::{LICENSES, NO_LICENSE};
+use crate::status;
+use crate::template_engine;
+
+/// SPDX 许可证列表中纯文本许可证的下载地址
+const SPDX_TEXT_URL: &str = "https://raw.githubusercontent.com/spdx/license-list-data/main/text";
+
+/// 许可证文本的缓存目录，位于缓存目录下
+const CACHE_DIR: &str = "licenses";
+
+/// 只替换许可证开头几行中的占位符，条款和附录中的示例保持原样
+const HEADER_LINES: usize = 10;
+
+/// SPDX 文本中的年份占位符
+const YEAR_PLACEHOLDERS: &[&str] = &["<year>", "[year]", "[yyyy]", "<yyyy>"];
+
+/// SPDX 文本中的版权人占位符
+const HOLDER_PLACEHOLDERS: &[&str] = &[
+    "<copyright holders>",
+    "<copyright holder>",
+    "<owner>",
+    "[fullname]",
+    "[name of copyright owner]",
+];
+
+/// SPDX 许可证表达式中连接多个许可证的运算符
+const OPERATORS: [&str; 2] = ["OR", "AND"];
+
+/// 规范化许可证表达式：`MIT/Apache-2.0` 等旧写法和小写运算符统一为 `MIT OR Apache-2.0`
+pub fn normalize_expression(expression: &str) -> String {
+    expression
+        .replace('/', " OR ")
+        .split_whitespace()
+        .map(|token| {
+            OPERATORS
+                .into_iter()
+                .find(|operator| token.eq_ignore_ascii_case(operator))
+                .unwrap_or(token)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 拆分许可证表达式中的各个许可证标识符，如 `MIT OR Apache-2.0` 得到 `MIT` 和 `Apache-2.0`
+pub fn license_ids(expression: &str) -> Vec<&str> {
+    expression
+        .split(|c: char| c.is_whitespace() || matches!(c, '(' | ')'))
+        .filter(|token| !token.is_empty() && !OPERATORS.contains(token))
+        .collect()
+}
+
+/// 表达式中每个许可证对应的文件名：单一许可证为 `LICENSE`，`None` 不生成文件，
+/// 组合许可证按 Rust 项目的惯例为 `LICENSE-MIT`、`LICENSE-APACHE` 等
+pub fn license_files(expression: &str) -> Vec<(&str, String)> {
+    if expression == NO_LICENSE {
+        return Vec::new();
+    }
+    let ids = license_ids(expression);
+    if ids.len() == 1 {
+        return vec![(ids[0], "LICENSE".to_string())];
+    }
+
+    // 去掉版本号作为后缀，如 Apache-2.0 为 APACHE；去掉后重名时（如 GPL-2.0 OR GPL-3.0）保留完整标识符
+    let short = |id: &str| {
+        let id = id.trim_end_matches("-only").trim_end_matches("-or-later");
+        match id.rsplit_once('-') {
+            Some((name, version)) if version.contains('.') => name.to_uppercase(),
+            _ => id.to_uppercase(),
+        }
+    };
+    let mut suffixes: Vec<String> = ids.iter().map(|id| short(id)).collect();
+    if (1..suffixes.len()).any(|i| suffixes[..i].contains(&suffixes[i])) {
+        suffixes = ids.iter().map(|id| id.to_uppercase()).collect();
+    }
+    ids.into_iter()
+        .zip(suffixes)
+        .map(|(id, suffix)| (id, format!("LICENSE-{suffix}")))
+        .collect()
+}
+
+/// package.json 中的 `license` 字段：组合表达式需要加括号，没有许可证时为 `UNLICENSED`
+pub fn package_json_license(expression: &str) -> String {
+    if expression == NO_LICENSE {
+        "UNLICENSED".to_string()
+    } else if license_ids(expression).len() > 1 && !expression.starts_with('(') {
+        format!("({expression})")
+    } else {
+        expression.to_string()
+    }
+}
+
+/// 可选的许可证：许可证目录加上模板中有而目录中没有的许可证（包括 `--template-dir` 和 `--overlay` 中的模板），
+/// 按目录顺序排列，模板中新增的许可证按标识符排序追加在后面
+pub fn available_licenses() -> Vec<(String, String)> {
+    let mut licenses: Vec<(String, String)> = LICENSES
+        .iter()
+        .map(|(id, name)| (id.to_string(), name.to_string()))
+        .collect();
+    let mut templates: Vec<String> = template_engine::get_embedded_template_files("licenses")
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|path| path.strip_prefix("licenses/")?.strip_suffix(".tmpl"))
+        .filter(|id| !id.contains('/') && !licenses.iter().any(|(known, _)| known == id))
+        .map(str::to_string)
+        .collect();
+    templates.sort();
+    templates.dedup();
+    licenses.extend(
+        templates
+            .into_iter()
+            .map(|id| (id, "bundled template".to_string())),
+    );
+    licenses
+}
+
+/// 读取 SPDX 许可证文本：优先使用缓存，否则通过 curl 下载并写入缓存
+///
+/// 渲染模式下不访问网络，没有缓存时返回 `None`
+pub fn license_text(id: &str) -> Result<Option<String>> {
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+'))
+    {
+        bail!("Invalid SPDX license identifier: {id}");
+    }
+
+    let cache_path = cache_path(id);
+    if let Some(text) = cache_path
+        .as_ref()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+    {
+        return Ok(Some(text));
+    }
+    if render_only::skip_tool(&format!("downloading the {id} license text")) {
+        return Ok(None);
+    }
+    if which::which("curl").is_err() {
+        bail!(
+            "The {id} license text is not bundled and curl is not installed to download it; choose a bundled license or install curl"
+        );
+    }
+
+    let url = format!("{SPDX_TEXT_URL}/{id}.txt");
+    status!("Downloading the {id} license text from the SPDX license list...");
+    let output = Command::new("curl")
+        .args(["-fsSL", &url])
+        .output()
+        .context("Failed to execute curl")?;
+    if !output.status.success() {
+        bail!(
+            "Failed to download the {id} license text from {url}; check the SPDX identifier at https://spdx.org/licenses/"
+        );
+    }
+    let text = String::from_utf8(output.stdout).context("License text is not valid UTF-8")?;
+
+    // 缓存失败不影响本次生成
+    if let Some(path) = cache_path {
+        let _ = app_dirs::write_atomic(&path, &text);
+    }
+    Ok(Some(text))
+}
+
+/// 在许可证开头的版权行中填入年份和版权人
+pub fn fill_copyright(text: &str, year: &str, holder: &str) -> String {
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    for line in lines.iter_mut().take(HEADER_LINES) {
+        for placeholder in YEAR_PLACEHOLDERS {
+            *line = line.replace(placeholder, year);
+        }
+        for placeholder in HOLDER_PLACEHOLDERS {
+            *line = line.replace(placeholder, holder);
+        }
+    }
+    let mut filled = lines.join("\n");
+    if text.ends_with('\n') {
+        filled.push('\n');
+    }
+    filled
+}
+
+fn cache_path(id: &str) -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join(CACHE_DIR).join(format!("{id}.txt")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_copyright() {
+        let text = "MIT License\n\nCopyright (c) <year> <copyright holders>\n\nPermission is hereby granted\n";
+        assert_eq!(
+            fill_copyright(text, "2020-2026", "Acme Inc."),
+            "MIT License\n\nCopyright (c) 2020-2026 Acme Inc.\n\nPermission is hereby granted\n"
+        );
+
+        // 附录中的示例版权行不替换
+        let appendix = format!("{}Copyright (C) <year>  <name of author>", "\n".repeat(20));
+        assert_eq!(fill_copyright(&appendix, "2026", "Acme"), appendix);
+
+        assert!(license_text("../MIT").is_err());
+    }
+
+    #[test]
+    fn test_available_licenses() {
+        let licenses = available_licenses();
+        let ids: Vec<&str> = licenses.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(
+            ids[..LICENSES.len()],
+            LICENSES.iter().map(|(id, _)| *id).collect::<Vec<_>>()
+        );
+
+        // 每个内嵌的许可证模板都可以选择，且不重复列出
+        for path in template_engine::list_embedded_files("licenses").unwrap() {
+            let id = path
+                .trim_start_matches("licenses/")
+                .trim_end_matches(".tmpl");
+            assert_eq!(ids.iter().filter(|known| **known == id).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_license_expressions() {
+        assert_eq!(normalize_expression("MIT/Apache-2.0"), "MIT OR Apache-2.0");
+        assert_eq!(
+            normalize_expression(" MIT  or Apache-2.0"),
+            "MIT OR Apache-2.0"
+        );
+
+        assert_eq!(license_files("MIT"), [("MIT", "LICENSE".to_string())]);
+        assert!(license_files(NO_LICENSE).is_empty());
+        assert_eq!(
+            license_files("MIT OR Apache-2.0"),
+            [
+                ("MIT", "LICENSE-MIT".to_string()),
+                ("Apache-2.0", "LICENSE-APACHE".to_string())
+            ]
+        );
+        assert_eq!(
+            license_files("(GPL-2.0-only OR GPL-3.0-only)"),
+            [
+                ("GPL-2.0-only", "LICENSE-GPL-2.0-ONLY".to_string()),
+                ("GPL-3.0-only", "LICENSE-GPL-3.0-ONLY".to_string())
+            ]
+        );
+
+        assert_eq!(package_json_license("MIT"), "MIT");
+        assert_eq!(
+            package_json_license("MIT OR Apache-2.0"),
+            "(MIT OR Apache-2.0)"
+        );
+        assert_eq!(package_json_license(NO_LICENSE), "UNLICENSED");
+    }
+}
@@ -0,0 +1,265 @@
+use crate::constants::{Runtime, TailwindVersion};
+use crate::status;
+use crate::utils::{js_runtime, tool_failures};
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Vite 配置文件的候选名称
+pub const VITE_CONFIGS: &[&str] = &["vite.config.ts", "vite.config.js", "vite.config.mjs"];
+
+/// Tailwind v3 配置文件的候选名称，`tailwindcss init` 生成 `.js`
+const TAILWIND_CONFIGS: &[&str] = &[
+    "tailwind.config.js",
+    "tailwind.config.ts",
+    "tailwind.config.cjs",
+    "tailwind.config.mjs",
+];
+
+/// 可能导入 Tailwind CSS 的样式入口
+const CSS_ENTRIES: &[&str] = &[
+    "src/assets/main.css",
+    "src/styles/index.css",
+    "src/style.css",
+    "src/index.css",
+];
+
+/// Tailwind v4 中让 `dark:` 跟随 `dark` 类的变体声明
+const DARK_CLASS_VARIANT: &str = "@custom-variant dark (&:where(.dark, .dark *));";
+
+/// 基于 Vite 的前端项目需要的 Tailwind CSS 开发依赖
+pub fn packages(version: TailwindVersion) -> &'static [&'static str] {
+    match version {
+        TailwindVersion::V3 => &[
+            "tailwindcss@^3",
+            "postcss@^8",
+            "autoprefixer@^10",
+            "@tailwindcss/forms@^0.5",
+            "@tailwindcss/typography@^0.5",
+        ],
+        TailwindVersion::V4 => &[
+            "tailwindcss@^4",
+            "@tailwindcss/vite@^4",
+            "@tailwindcss/forms@^0.5",
+            "@tailwindcss/typography@^0.5",
+        ],
+    }
+}
+
+/// 依赖安装完成后为基于 Vite 的前端项目配置 Tailwind CSS
+///
+/// v3 通过 `tailwindcss init -p` 生成 PostCSS 配置，v4 在 Vite 配置中注册 `@tailwindcss/vite`
+pub fn configure_for_vite(
+    output_path: &Path,
+    version: TailwindVersion,
+    runtime: Runtime,
+) -> Result<()> {
+    status!("🎨 Configuring Tailwind CSS v{version}...");
+    match version {
+        TailwindVersion::V3 => init_postcss_config(output_path, runtime),
+        TailwindVersion::V4 => register_vite_plugin(output_path),
+    }
+}
+
+/// 让 `dark:` 样式跟随 `<html>` 上的 `dark` 类，而不是系统配色
+///
+/// v3 在配置文件中设置 `darkMode: 'class'`，v4 在导入 tailwindcss 的样式入口中声明 `dark` 变体
+pub fn use_class_dark_mode(output_path: &Path, version: TailwindVersion) -> Result<()> {
+    let candidates = match version {
+        TailwindVersion::V3 => TAILWIND_CONFIGS,
+        TailwindVersion::V4 => CSS_ENTRIES,
+    };
+    let update = match version {
+        TailwindVersion::V3 => with_dark_mode_config,
+        TailwindVersion::V4 => with_dark_class_variant,
+    };
+
+    let mut unchanged = None;
+    for path in candidates.iter().map(|name| output_path.join(name)) {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        match update(&content) {
+            Some(updated) => {
+                return std::fs::write(&path, updated)
+                    .with_context(|| format!("Failed to write {}", path.display()));
+            }
+            None => unchanged = Some(content),
+        }
+    }
+
+    match (version, unchanged) {
+        (TailwindVersion::V3, None) => {
+            tool_failures::report("No tailwind.config.js found; set darkMode: 'class' manually")
+        }
+        (TailwindVersion::V3, Some(config)) if !config.contains("darkMode") => {
+            tool_failures::report(
+                "Could not find the config object in tailwind.config.js; set darkMode: 'class' manually",
+            )
+        }
+        // 已配置，或 v4 样式入口尚未导入 tailwindcss，此时没有可声明的变体
+        _ => Ok(()),
+    }
+}
+
+/// 在 v3 配置对象开头加入 `darkMode: 'class'`，已配置或找不到配置对象时返回 `None`
+fn with_dark_mode_config(config: &str) -> Option<String> {
+    if config.contains("darkMode") {
+        return None;
+    }
+    let object_start = ["export default {", "module.exports = {"]
+        .iter()
+        .find_map(|prefix| config.find(prefix).map(|at| at + prefix.len()))?;
+
+    let mut updated = config.to_string();
+    updated.insert_str(object_start, "\n  darkMode: 'class',");
+    Some(updated)
+}
+
+/// 在 v4 样式入口的 `@import "tailwindcss"` 之后声明 `dark` 变体，已声明或未导入时返回 `None`
+fn with_dark_class_variant(css: &str) -> Option<String> {
+    if css.contains("@custom-variant dark") {
+        return None;
+    }
+    let import_end = ["@import \"tailwindcss\";", "@import 'tailwindcss';"]
+        .iter()
+        .find_map(|import| css.find(import).map(|at| at + import.len()))?;
+
+    let mut updated = css.to_string();
+    updated.insert_str(import_end, &format!("\n{DARK_CLASS_VARIANT}"));
+    Some(updated)
+}
+
+/// Tailwind v3：生成 tailwind.config.js 和 postcss.config.js
+fn init_postcss_config(output_path: &Path, runtime: Runtime) -> Result<()> {
+    let output = js_runtime::exec(runtime, "tailwindcss")
+        .args(["init", "-p"])
+        .current_dir(output_path)
+        .output()
+        .context("Failed to initialize Tailwind CSS")?;
+
+    if output.status.success() {
+        status!("✅ Tailwind CSS configured successfully");
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        tool_failures::report(format!(
+            "Failed to initialize Tailwind CSS: {}",
+            stderr.trim()
+        ))
+    }
+}
+
+/// Tailwind v4：在 Vite 配置中注册 `@tailwindcss/vite` 插件
+fn register_vite_plugin(output_path: &Path) -> Result<()> {
+    let Some(config_path) = VITE_CONFIGS
+        .iter()
+        .map(|name| output_path.join(name))
+        .find(|path| path.exists())
+    else {
+        return tool_failures::report(
+            "No Vite config found; add the @tailwindcss/vite plugin manually",
+        );
+    };
+
+    let config = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+
+    match with_vite_plugin(&config) {
+        Some(updated) => {
+            std::fs::write(&config_path, updated)
+                .with_context(|| format!("Failed to write {}", config_path.display()))?;
+            status!("✅ Tailwind CSS configured successfully");
+            Ok(())
+        }
+        None if config.contains("@tailwindcss/vite") => {
+            status!("✅ Tailwind CSS configured successfully");
+            Ok(())
+        }
+        None => tool_failures::report(format!(
+            "Could not find the plugins array in {}; add tailwindcss() from @tailwindcss/vite manually",
+            config_path.display()
+        )),
+    }
+}
+
+/// 在 Vite 配置中加入 `@tailwindcss/vite` 的导入和插件调用，已注册或无法定位插件数组时返回 `None`
+fn with_vite_plugin(config: &str) -> Option<String> {
+    if config.contains("@tailwindcss/vite") {
+        return None;
+    }
+
+    let plugins_start = config.find("plugins: [")? + "plugins: [".len();
+    let rest = &config[plugins_start..];
+    let plugin = match rest.strip_prefix('\n') {
+        // 多行数组：沿用下一行的缩进
+        Some(next_lines) => {
+            let indent: String = next_lines
+                .chars()
+                .take_while(|c| c.is_whitespace() && *c != '\n')
+                .collect();
+            format!("\n{indent}tailwindcss(),")
+        }
+        None => "tailwindcss(), ".to_string(),
+    };
+
+    let mut updated = String::with_capacity(config.len() + 64);
+    updated.push_str(&config[..plugins_start]);
+    updated.push_str(&plugin);
+    updated.push_str(rest);
+
+    // 导入语句放在最后一个 import 之后
+    let import = "import tailwindcss from '@tailwindcss/vite'\n";
+    let mut insert_at = 0;
+    let mut offset = 0;
+    for line in updated.split_inclusive('\n') {
+        offset += line.len();
+        if line.starts_with("import ") {
+            insert_at = offset;
+        }
+    }
+    updated.insert_str(insert_at, import);
+
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_vite_plugin() {
+        let inline = "import { defineConfig } from 'vite'\nimport react from '@vitejs/plugin-react'\n\nexport default defineConfig({\n  plugins: [react()],\n})\n";
+        assert_eq!(
+            with_vite_plugin(inline).unwrap(),
+            "import { defineConfig } from 'vite'\nimport react from '@vitejs/plugin-react'\nimport tailwindcss from '@tailwindcss/vite'\n\nexport default defineConfig({\n  plugins: [tailwindcss(), react()],\n})\n"
+        );
+
+        let multiline = "import vue from '@vitejs/plugin-vue'\n\nexport default defineConfig({\n  plugins: [\n    vue(),\n  ],\n})\n";
+        assert_eq!(
+            with_vite_plugin(multiline).unwrap(),
+            "import vue from '@vitejs/plugin-vue'\nimport tailwindcss from '@tailwindcss/vite'\n\nexport default defineConfig({\n  plugins: [\n    tailwindcss(),\n    vue(),\n  ],\n})\n"
+        );
+
+        let registered = with_vite_plugin(inline).unwrap();
+        assert!(with_vite_plugin(&registered).is_none());
+        assert!(with_vite_plugin("export default {}\n").is_none());
+    }
+
+    #[test]
+    fn test_class_dark_mode() {
+        assert_eq!(
+            with_dark_mode_config(
+                "/** @type {import('tailwindcss').Config} */\nexport default {\n  content: [],\n}\n"
+            )
+            .unwrap(),
+            "/** @type {import('tailwindcss').Config} */\nexport default {\n  darkMode: 'class',\n  content: [],\n}\n"
+        );
+        assert!(with_dark_mode_config("module.exports = {\n  darkMode: 'media',\n}\n").is_none());
+
+        assert_eq!(
+            with_dark_class_variant("@import \"tailwindcss\";\n\nbody {}\n").unwrap(),
+            "@import \"tailwindcss\";\n@custom-variant dark (&:where(.dark, .dark *));\n\nbody {}\n"
+        );
+        assert!(with_dark_class_variant("@import './base.css';\n").is_none());
+    }
+}
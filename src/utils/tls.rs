@@ -0,0 +1,144 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::config::UserConfig;
+
+/// 已导出给子进程的 CA 证书包
+static CA_BUNDLE: OnceLock<PathBuf> = OnceLock::new();
+
+/// 各工具读取额外 CA 证书的环境变量：OpenSSL、Go、uv 和 Python 使用 `SSL_CERT_FILE`，
+/// Node.js 系的包管理器使用 `NODE_EXTRA_CA_CERTS`，其余工具各有专门的变量
+const CA_ENV_VARS: &[&str] = &[
+    "SSL_CERT_FILE",
+    "NODE_EXTRA_CA_CERTS",
+    "REQUESTS_CA_BUNDLE",
+    "PIP_CERT",
+    "CARGO_HTTP_CAINFO",
+    "GIT_SSL_CAINFO",
+    "CURL_CA_BUNDLE",
+    "DENO_CERT",
+];
+
+/// 常见的证书校验错误输出及对应工具的处理建议
+const TLS_ERRORS: &[(&str, &str)] = &[
+    (
+        "UNABLE_TO_GET_ISSUER_CERT_LOCALLY",
+        "Node.js tools (npm, pnpm, yarn, bun) read extra CAs from NODE_EXTRA_CA_CERTS",
+    ),
+    (
+        "SELF_SIGNED_CERT_IN_CHAIN",
+        "Node.js tools (npm, pnpm, yarn, bun) read extra CAs from NODE_EXTRA_CA_CERTS",
+    ),
+    (
+        "unable to verify the first certificate",
+        "Node.js tools (npm, pnpm, yarn, bun) read extra CAs from NODE_EXTRA_CA_CERTS",
+    ),
+    (
+        "x509: certificate signed by unknown authority",
+        "Go reads the CA bundle from SSL_CERT_FILE",
+    ),
+    (
+        "invalid peer certificate",
+        "uv reads the CA bundle from SSL_CERT_FILE, or uses the system store with UV_NATIVE_TLS=1",
+    ),
+    (
+        "CERTIFICATE_VERIFY_FAILED",
+        "pip and Python read the CA bundle from PIP_CERT and SSL_CERT_FILE",
+    ),
+    (
+        "SSL certificate problem",
+        "cargo, git and curl read the CA bundle from CARGO_HTTP_CAINFO, GIT_SSL_CAINFO and CURL_CA_BUNDLE",
+    ),
+    (
+        "self signed certificate in certificate chain",
+        "the proxy's root certificate is missing from the trusted CAs",
+    ),
+    (
+        "self-signed certificate in certificate chain",
+        "the proxy's root certificate is missing from the trusted CAs",
+    ),
+    (
+        "unable to get local issuer certificate",
+        "the proxy's root certificate is missing from the trusted CAs",
+    ),
+    (
+        "certificate verify failed",
+        "the proxy's root certificate is missing from the trusted CAs",
+    ),
+];
+
+/// 将配置文件中的 `ca_bundle` 导出为子进程读取 CA 证书的环境变量，用户已设置的变量保持不变
+///
+/// 必须在启动其他线程（包括异步运行时）之前调用
+pub fn export_ca_bundle() {
+    let Some(bundle) = UserConfig::load().ok().and_then(|config| config.ca_bundle) else {
+        return;
+    };
+    if !bundle.is_file() {
+        println!(
+            "⚠️  Warning: CA bundle {} from the config file not found, ignoring it",
+            bundle.display()
+        );
+        return;
+    }
+    for key in CA_ENV_VARS {
+        if std::env::var_os(key).is_none() {
+            // SAFETY: 在 main 中启动异步运行时之前调用，此时没有其他线程读写环境变量
+            unsafe { std::env::set_var(key, &bundle) };
+        }
+    }
+    let _ = CA_BUNDLE.set(bundle);
+}
+
+/// 识别输出中的证书校验错误，返回处理建议；不是证书错误时返回 None
+pub fn diagnose(output: &str) -> Option<String> {
+    let (_, hint) = TLS_ERRORS
+        .iter()
+        .find(|(pattern, _)| output.contains(pattern))?;
+    Some(guidance(hint, CA_BUNDLE.get().map(PathBuf::as_path)))
+}
+
+fn guidance(hint: &str, bundle: Option<&Path>) -> String {
+    let mut lines = vec![
+        "This looks like a TLS certificate error, which usually means a proxy intercepts HTTPS traffic."
+            .to_string(),
+        format!("  Hint: {hint}."),
+    ];
+    match bundle {
+        Some(bundle) => lines.push(format!(
+            "  The CA bundle {} is passed to child processes; check that it contains your proxy's root certificate.",
+            bundle.display()
+        )),
+        None => {
+            let config = UserConfig::path()
+                .map_or("config.json".to_string(), |path| path.display().to_string());
+            lines.push(format!(
+                "  Set \"ca_bundle\" to your organization's CA bundle (PEM) in {config} to pass it to npm, pnpm, uv, pip, cargo, go and git."
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnose() {
+        let output = "ERR_PNPM_META_FETCH_FAIL GET https://registry.npmjs.org/vue: request to https://registry.npmjs.org/vue failed, reason: self-signed certificate in certificate chain";
+        let message = diagnose(output).unwrap();
+        assert!(message.contains("TLS certificate error"));
+        assert!(message.contains("\"ca_bundle\""));
+
+        let go = diagnose(
+            "tls: failed to verify certificate: x509: certificate signed by unknown authority",
+        )
+        .unwrap();
+        assert!(go.contains("Go reads the CA bundle from SSL_CERT_FILE"));
+        assert!(diagnose("go: module github.com/acme/x: not found").is_none());
+
+        let configured = guidance("hint", Some(Path::new("/etc/ssl/corp.pem")));
+        assert!(configured.contains("/etc/ssl/corp.pem is passed to child processes"));
+    }
+}
@@ -0,0 +1,229 @@
+use anyhow::{Context, Result, bail};
+use std::path::Path;
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+/// TOML 文件的结构化修改：设置值、插入整段配置和向数组追加元素
+///
+/// 基于 toml_edit 修改已有的 Cargo.toml、pyproject.toml 等文件，
+/// 保留用户原有的注释、键的顺序和格式；路径为以 `.` 分隔的表名，如 `tool.ruff`
+#[derive(Debug, Default, Clone)]
+pub struct TomlPatch {
+    values: Vec<(String, Value)>,
+    sections: Vec<(String, String)>,
+    array_items: Vec<(String, Vec<String>)>,
+}
+
+impl TomlPatch {
+    /// 创建空的修改
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置 `path` 处的值，如 `project.description`；已有的值会被替换并保留行尾注释
+    pub fn with_value(mut self, path: &str, value: impl Into<Value>) -> Self {
+        self.values.push((path.to_string(), value.into()));
+        self
+    }
+
+    /// 将 TOML 片段插入 `path` 处的表，如向 `dependencies` 插入 `serde = "1"`
+    ///
+    /// 表不存在时创建，已有的键保持用户的值不变；`path` 为空时插入到文档顶层
+    #[allow(dead_code)]
+    pub fn with_section(mut self, path: &str, fragment: &str) -> Self {
+        self.sections.push((path.to_string(), fragment.to_string()));
+        self
+    }
+
+    /// 向 `path` 处的字符串数组追加元素，如 pyproject.toml 的 `project.dependencies`，已有的元素不重复添加
+    #[allow(dead_code)]
+    pub fn with_array_items(mut self, path: &str, items: &[&str]) -> Self {
+        self.array_items.push((
+            path.to_string(),
+            items.iter().map(|item| item.to_string()).collect(),
+        ));
+        self
+    }
+
+    /// 将修改应用到已解析的文档
+    pub fn apply_to(&self, document: &mut DocumentMut) -> Result<()> {
+        for (path, value) in &self.values {
+            let (parent, key) = split_path(path)?;
+            let table = table_at(document.as_table_mut(), parent)?;
+            match table.get_mut(key).and_then(Item::as_value_mut) {
+                Some(existing) => {
+                    let decor = existing.decor().clone();
+                    *existing = value.clone();
+                    *existing.decor_mut() = decor;
+                }
+                None => {
+                    table.insert(key, Item::Value(value.clone()));
+                }
+            }
+        }
+
+        for (path, fragment) in &self.sections {
+            let fragment: DocumentMut = fragment
+                .parse()
+                .with_context(|| format!("Invalid TOML section for `{path}`"))?;
+            let table = table_at(document.as_table_mut(), path)?;
+            insert_missing(table, fragment.as_table());
+        }
+
+        for (path, items) in &self.array_items {
+            let (parent, key) = split_path(path)?;
+            let table = table_at(document.as_table_mut(), parent)?;
+            let array = table
+                .entry(key)
+                .or_insert_with(|| Item::Value(Value::Array(Array::new())))
+                .as_array_mut()
+                .with_context(|| format!("`{path}` is not an array"))?;
+            // 沿用最后一个元素的缩进，多行数组追加后仍是每行一个元素
+            let decor = array.iter().last().map(|item| item.decor().clone());
+            for item in items {
+                if array.iter().any(|existing| existing.as_str() == Some(item)) {
+                    continue;
+                }
+                array.push(item.as_str());
+                if let (Some(decor), Some(pushed)) = (&decor, array.iter_mut().last()) {
+                    *pushed.decor_mut() = decor.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 读取、修改并写回 TOML 文件，文件不存在时不做处理
+    pub fn apply(&self, file_path: &Path) -> Result<()> {
+        if !file_path.exists() {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read {}", file_path.display()))?;
+        let mut document: DocumentMut = content
+            .parse()
+            .with_context(|| format!("Failed to parse {}", file_path.display()))?;
+        self.apply_to(&mut document)?;
+        std::fs::write(file_path, document.to_string())
+            .with_context(|| format!("Failed to write {}", file_path.display()))
+    }
+}
+
+/// 新增的表排在已有表之后，不沿用来源文档中的位置
+pub(crate) fn unpositioned(item: &Item) -> Item {
+    let mut item = item.clone();
+    if let Item::Table(table) = &mut item {
+        clear_positions(table);
+    }
+    item
+}
+
+fn clear_positions(table: &mut Table) {
+    table.set_position(None);
+    for (_, item) in table.iter_mut() {
+        if let Item::Table(table) = item {
+            clear_positions(table);
+        }
+    }
+}
+
+/// 拆分出父表路径和最后一个键，如 `project.urls.Homepage` 得到 `project.urls` 和 `Homepage`
+fn split_path(path: &str) -> Result<(&str, &str)> {
+    let (parent, key) = path.rsplit_once('.').unwrap_or(("", path));
+    if key.is_empty() {
+        bail!("Invalid TOML key path: `{path}`");
+    }
+    Ok((parent, key))
+}
+
+/// 取出 `path` 处的表，不存在的表按隐式表创建，只有子表时不输出空的表头
+fn table_at<'a>(root: &'a mut Table, path: &str) -> Result<&'a mut Table> {
+    let mut table = root;
+    for key in path.split('.').filter(|key| !key.is_empty()) {
+        table = table
+            .entry(key)
+            .or_insert_with(|| {
+                let mut table = Table::new();
+                table.set_implicit(true);
+                Item::Table(table)
+            })
+            .as_table_mut()
+            .with_context(|| format!("`{path}` is not a table"))?;
+    }
+    Ok(table)
+}
+
+/// 逐键插入片段中缺少的键，两边都是表时继续合并
+fn insert_missing(target: &mut Table, fragment: &Table) {
+    for (key, item) in fragment.iter() {
+        match (target.get_mut(key), item) {
+            (Some(Item::Table(existing)), Item::Table(table)) => insert_missing(existing, table),
+            (Some(_), _) => {}
+            (None, _) => {
+                if let Some(key) = fragment.key(key) {
+                    target.insert_formatted(key, unpositioned(item));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_toml_patch() {
+        let mut document: DocumentMut = r#"[project]
+name = "demo"
+description = "Add your description here" # from uv init
+dependencies = [
+    "flask>=3.1.0",
+]
+
+# Lint settings
+[tool.ruff]
+line-length = 100
+"#
+        .parse()
+        .unwrap();
+        TomlPatch::new()
+            .with_value("project.description", "A demo")
+            .with_value("project.urls.Homepage", "https://example.com")
+            .with_array_items("project.dependencies", &["flask>=3.1.0", "redis>=5"])
+            .with_section("tool.ruff", "line-length = 88\ntarget-version = \"py312\"")
+            .with_section("tool.pytest.ini_options", "testpaths = [\"tests\"]")
+            .apply_to(&mut document)
+            .unwrap();
+
+        assert_eq!(
+            document.to_string(),
+            r#"[project]
+name = "demo"
+description = "A demo" # from uv init
+dependencies = [
+    "flask>=3.1.0",
+    "redis>=5",
+]
+
+[project.urls]
+Homepage = "https://example.com"
+
+# Lint settings
+[tool.ruff]
+line-length = 100
+target-version = "py312"
+
+[tool.pytest.ini_options]
+testpaths = ["tests"]
+"#
+        );
+
+        assert!(
+            TomlPatch::new()
+                .with_section("project.name", "x = 1")
+                .apply_to(&mut document)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::Result;
+use std::sync::{Arc, Mutex};
+
+use super::tls;
+use crate::constants::FailurePolicy;
+
+tokio::task_local! {
+    /// 当前生成使用的失败记录，由 `scope` 设置
+    static CURRENT: Arc<ToolFailures>;
+}
+
+/// 一次生成中非关键工具失败的处理策略和记录的警告
+///
+/// 由 `scafgen new` 按 `--failure-policy` 或配置文件创建，并发的生成（如 `scafgen serve` 的请求）各自持有一份
+#[derive(Debug, Default)]
+pub struct ToolFailures {
+    policy: FailurePolicy,
+    warnings: Mutex<Vec<String>>,
+}
+
+impl ToolFailures {
+    pub fn new(policy: FailurePolicy) -> Self {
+        Self {
+            policy,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 报告非关键工具的失败
+    ///
+    /// strict 策略下返回错误中止生成；lenient 策略下输出并记录警告，由调用方继续执行
+    pub fn report(&self, message: impl Into<String>) -> Result<()> {
+        let mut message = message.into();
+        // 代理拦截 HTTPS 导致的失败附上证书配置建议
+        if let Some(guidance) = tls::diagnose(&message) {
+            message = format!("{message}\n  {guidance}");
+        }
+        match self.policy {
+            FailurePolicy::Strict => Err(anyhow::anyhow!(
+                "{message}\n  (aborted by --failure-policy strict; use --failure-policy lenient to continue with a warning)"
+            )),
+            FailurePolicy::Lenient => {
+                println!("⚠️  Warning: {message}");
+                if let Ok(mut warnings) = self.warnings.lock() {
+                    warnings.push(message);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// 取出已记录的警告
+    pub fn take_warnings(&self) -> Vec<String> {
+        self.warnings
+            .lock()
+            .map(|mut warnings| std::mem::take(&mut *warnings))
+            .unwrap_or_default()
+    }
+}
+
+/// 在 `failures` 下执行一次生成，期间的 `report` 都记录到其中
+pub async fn scope<F: Future>(failures: Arc<ToolFailures>, future: F) -> F::Output {
+    CURRENT.scope(failures, future).await
+}
+
+/// 向当前生成报告非关键工具的失败，不在生成中时按 lenient 策略只输出警告
+pub fn report(message: impl Into<String>) -> Result<()> {
+    let message = message.into();
+    CURRENT
+        .try_with(|failures| failures.clone())
+        .unwrap_or_default()
+        .report(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_follows_policy() {
+        let strict = ToolFailures::new(FailurePolicy::Strict);
+        assert!(strict.report("pnpm install failed").is_err());
+        assert!(strict.take_warnings().is_empty());
+
+        let lenient = ToolFailures::new(FailurePolicy::Lenient);
+        assert!(lenient.report("go mod tidy failed").is_ok());
+        assert_eq!(
+            lenient.take_warnings(),
+            vec!["go mod tidy failed".to_string()]
+        );
+        assert!(lenient.take_warnings().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_report_in_scope() {
+        let failures = Arc::new(ToolFailures::new(FailurePolicy::Strict));
+        assert!(
+            scope(failures, async { report("uv sync failed") })
+                .await
+                .is_err()
+        );
+        assert!(report("uv sync failed").is_ok());
+    }
+}
@@ -0,0 +1,93 @@
+use std::process::Command;
+
+use crate::status;
+
+/// 全局安装或升级 Rust 命令行工具到满足 `version` 要求的版本（如 `^4.0.0`）
+///
+/// 有 cargo-binstall 时下载预编译包，否则用 `cargo install` 从源码编译；两者都会替换不满足要求的旧版本
+pub fn cargo_install(crate_name: &str, version: &str) -> Command {
+    cargo_install_with(which::which("cargo-binstall").is_ok(), crate_name, version)
+}
+
+fn cargo_install_with(has_binstall: bool, crate_name: &str, version: &str) -> Command {
+    let mut command = Command::new("cargo");
+    if has_binstall {
+        status!("  Using cargo-binstall to install {crate_name}");
+        command.args(["binstall", "-y", &format!("{crate_name}@{version}")]);
+    } else {
+        command.args(["install", crate_name, "--version", version]);
+    }
+    command
+}
+
+/// 全局安装 Python 命令行工具：优先 `uv tool install`，其次 `pipx install`，都没有时返回 `None`
+pub fn python_tool_install(tool: &str) -> Option<Command> {
+    let installer = ["uv", "pipx"]
+        .into_iter()
+        .find(|installer| which::which(installer).is_ok())?;
+    Some(python_tool_install_with(installer, tool))
+}
+
+/// Python 命令行工具不存在时尝试全局安装，返回之后能否在 PATH 中找到该工具
+pub fn ensure_python_tool(tool: &str) -> bool {
+    if which::which(tool).is_ok() {
+        return true;
+    }
+    let Some(mut command) = python_tool_install(tool) else {
+        return false;
+    };
+    status!("{tool} is not installed, installing it...");
+    let installed = command
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+    installed && which::which(tool).is_ok()
+}
+
+fn python_tool_install_with(installer: &str, tool: &str) -> Command {
+    let mut command = Command::new(installer);
+    if installer == "uv" {
+        command.args(["tool", "install", tool]);
+    } else {
+        command.args(["install", tool]);
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command_line(command: &Command) -> Vec<String> {
+        std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_installers_prefer_prebuilt_tools() {
+        assert_eq!(
+            command_line(&cargo_install_with(true, "create-tauri-app", "^4.0.0")),
+            ["cargo", "binstall", "-y", "create-tauri-app@^4.0.0"]
+        );
+        assert_eq!(
+            command_line(&cargo_install_with(false, "create-tauri-app", "^4.0.0")),
+            [
+                "cargo",
+                "install",
+                "create-tauri-app",
+                "--version",
+                "^4.0.0"
+            ]
+        );
+        assert_eq!(
+            command_line(&python_tool_install_with("uv", "pre-commit")),
+            ["uv", "tool", "install", "pre-commit"]
+        );
+        assert_eq!(
+            command_line(&python_tool_install_with("pipx", "pre-commit")),
+            ["pipx", "install", "pre-commit"]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::constants::{CiProvider, Framework};
+use crate::utils::json_patch::PackageJsonPatch;
+
+/// 覆盖率门槛（百分比），行、函数、分支和语句共用
+pub const COVERAGE_THRESHOLD: u8 = 80;
+
+/// 写入 package.json 的测试脚本，CI 的覆盖率任务运行 `test:coverage`
+const SCRIPTS: &[(&str, &str)] = &[
+    ("test", "vitest"),
+    ("test:coverage", "vitest run --coverage"),
+];
+
+/// Vue3 和 React 共用的测试模板变量，文件位于 `+tests` 目录，覆盖率任务位于 `+ci` 目录
+pub fn template_context(enable_tests: bool) -> HashMap<String, Value> {
+    HashMap::from([
+        ("enable_coverage".to_string(), json!(enable_tests)),
+        ("coverage_threshold".to_string(), json!(COVERAGE_THRESHOLD)),
+    ])
+}
+
+/// Vitest、覆盖率插件和组件测试库，Vitest 4 移除了 `coverage.all`，固定在 3.x
+pub fn packages(framework: Framework) -> Vec<&'static str> {
+    let mut packages = vec!["vitest@^3", "@vitest/coverage-v8@^3", "jsdom@^26"];
+    match framework {
+        Framework::Vue3 => packages.push("@vue/test-utils@^2"),
+        Framework::React => {
+            packages.extend(["@testing-library/react@^16", "@testing-library/dom@^10"])
+        }
+        _ => {}
+    }
+    packages
+}
+
+/// 在 package.json 的修改中加入 Vitest 开发依赖和测试脚本，已有的同名脚本保持不变
+pub fn add_to(patch: PackageJsonPatch, framework: Framework) -> PackageJsonPatch {
+    SCRIPTS.iter().fold(
+        patch.with_dev_dependencies(&packages(framework)),
+        |patch, (name, command)| patch.with_script(name, command),
+    )
+}
+
+/// README 中的覆盖率徽章：GitLab 使用覆盖率任务解析出的数值，其余平台展示 CI 强制的门槛
+pub fn coverage_badge(provider: CiProvider, project_name: &str) -> String {
+    match provider {
+        CiProvider::Gitlab => format!(
+            "[![coverage](https://gitlab.com/<namespace>/{project_name}/badges/main/coverage.svg)](https://gitlab.com/<namespace>/{project_name}/-/pipelines)"
+        ),
+        _ => format!(
+            "![coverage](https://img.shields.io/badge/coverage-%E2%89%A5{COVERAGE_THRESHOLD}%25-brightgreen)"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_to() {
+        let mut package =
+            json!({"name": "demo", "scripts": {"dev": "vite", "test": "vitest --ui"}});
+        add_to(PackageJsonPatch::new(), Framework::Vue3)
+            .apply_to(&mut package)
+            .unwrap();
+        assert_eq!(
+            package["scripts"],
+            json!({
+                "dev": "vite",
+                "test": "vitest --ui",
+                "test:coverage": "vitest run --coverage",
+            })
+        );
+        assert_eq!(package["devDependencies"]["@vue/test-utils"], "^2");
+    }
+}
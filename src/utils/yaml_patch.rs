@@ -0,0 +1,225 @@
+use anyhow::{Context, Result, bail};
+use serde_yaml::{Mapping, Value};
+use std::path::Path;
+
+/// YAML 文件的结构化修改：向已有的映射插入缺少的配置段
+///
+/// serde_yaml 重新序列化会丢失注释，这里只用它解析和校验，按缩进定位块映射后以文本方式插入，
+/// 用户原有的注释和格式保持不变；路径为以 `.` 分隔的键，如 `server.cors`
+#[allow(dead_code)]
+#[derive(Debug, Default, Clone)]
+pub struct YamlPatch {
+    sections: Vec<(String, String)>,
+}
+
+#[allow(dead_code)]
+impl YamlPatch {
+    /// 创建空的修改
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 将 YAML 片段插入 `path` 处的映射，映射不存在时创建，已有的键保持用户的值不变；`path` 为空时插入到文档顶层
+    pub fn with_section(mut self, path: &str, fragment: &str) -> Self {
+        self.sections.push((path.to_string(), fragment.to_string()));
+        self
+    }
+
+    /// 将修改应用到 YAML 文本，返回修改后的文本
+    pub fn apply_to(&self, content: &str) -> Result<String> {
+        let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+        for (path, fragment) in &self.sections {
+            let fragment: Value = serde_yaml::from_str(fragment)
+                .with_context(|| format!("Invalid YAML section for `{path}`"))?;
+            let Value::Mapping(fragment) = fragment else {
+                bail!("YAML section for `{path}` must be a mapping");
+            };
+            let keys: Vec<&str> = path.split('.').filter(|key| !key.is_empty()).collect();
+            insert_missing(&mut lines, &keys, &fragment)?;
+        }
+
+        let mut output = lines.join("\n");
+        output.push('\n');
+        serde_yaml::from_str::<Value>(&output).context("Patched YAML is invalid")?;
+        Ok(output)
+    }
+
+    /// 读取、修改并写回 YAML 文件，文件不存在时不做处理
+    pub fn apply(&self, file_path: &Path) -> Result<()> {
+        if !file_path.exists() {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read {}", file_path.display()))?;
+        let output = self
+            .apply_to(&content)
+            .with_context(|| format!("Failed to patch {}", file_path.display()))?;
+        std::fs::write(file_path, output)
+            .with_context(|| format!("Failed to write {}", file_path.display()))
+    }
+}
+
+/// 块映射在文本中的位置：子键的缩进和最后一个子键所在行之后的插入位置
+struct Block {
+    indent: usize,
+    end: usize,
+}
+
+/// 逐键插入片段中缺少的键，两边都是映射时继续合并
+fn insert_missing(lines: &mut Vec<String>, path: &[&str], fragment: &Mapping) -> Result<()> {
+    let document: Value = serde_yaml::from_str(&lines.join("\n")).context("Invalid YAML")?;
+    let existing = mapping_at(&document, path);
+
+    let mut missing = Mapping::new();
+    for (key, value) in fragment {
+        match (existing.and_then(|mapping| mapping.get(key)), value) {
+            (Some(Value::Mapping(_)), Value::Mapping(value)) => {
+                let Some(key) = key.as_str() else { continue };
+                let path: Vec<&str> = path.iter().copied().chain([key]).collect();
+                insert_missing(lines, &path, value)?;
+            }
+            (Some(_), _) => {}
+            (None, _) => {
+                missing.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    // 缺少的父级映射连同片段一起插入到最近的已有映射中
+    let depth = (1..=path.len())
+        .rev()
+        .find(|&depth| mapping_at(&document, &path[..depth]).is_some())
+        .unwrap_or(0);
+    if depth < path.len()
+        && mapping_at(&document, &path[..depth])
+            .is_some_and(|parent| parent.contains_key(path[depth]))
+    {
+        bail!("`{}` is not a mapping", path[..=depth].join("."));
+    }
+    let Some(block) = find_block(lines, &path[..depth]) else {
+        bail!("`{}` is not a block mapping", path[..depth].join("."));
+    };
+    let value = path[depth..]
+        .iter()
+        .rev()
+        .fold(Value::Mapping(missing), |value, key| {
+            Value::Mapping(Mapping::from_iter([(Value::from(*key), value)]))
+        });
+    let rendered = serde_yaml::to_string(&value)?;
+    let indent = " ".repeat(block.indent);
+    lines.splice(
+        block.end..block.end,
+        rendered.lines().map(|line| format!("{indent}{line}")),
+    );
+    Ok(())
+}
+
+fn mapping_at<'a>(document: &'a Value, path: &[&str]) -> Option<&'a Mapping> {
+    path.iter()
+        .try_fold(document, |value, key| value.get(key))
+        .and_then(Value::as_mapping)
+}
+
+/// 按缩进查找 `path` 对应的块映射，空路径为文档顶层；值写在同一行（如 `{}`）时返回 `None`
+fn find_block(lines: &[String], path: &[&str]) -> Option<Block> {
+    let content_lines = |start: usize, end: usize| {
+        (start..end).filter(|&i| {
+            let trimmed = lines[i].trim_start();
+            !trimmed.is_empty() && !trimmed.starts_with('#') && trimmed != "---"
+        })
+    };
+    let indent_of = |i: usize| lines[i].len() - lines[i].trim_start().len();
+
+    let (mut start, mut end, mut indent) = (0, lines.len(), 0);
+    for key in path {
+        let header = content_lines(start, end).find(|&i| {
+            indent_of(i) == indent && {
+                let rest = lines[i].trim_start();
+                [key.to_string(), format!("\"{key}\""), format!("'{key}'")]
+                    .iter()
+                    .find_map(|name| rest.strip_prefix(name.as_str()))
+                    .and_then(|rest| rest.strip_prefix(':'))
+                    .is_some_and(|value| {
+                        let value = value.trim();
+                        value.is_empty() || value.starts_with('#')
+                    })
+            }
+        })?;
+        let block_end = content_lines(header + 1, end)
+            .find(|&i| indent_of(i) <= indent)
+            .unwrap_or(end);
+        let child_indent = content_lines(header + 1, block_end)
+            .next()
+            .map_or(indent + 2, indent_of);
+        (start, end, indent) = (header + 1, block_end, child_indent);
+    }
+
+    // 插入到最后一个子键之后，块末尾的空行和注释留给后面的内容
+    let end = content_lines(start, end)
+        .next_back()
+        .map_or(start, |i| i + 1);
+    Some(Block { indent, end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_yaml_patch() {
+        let content = "\
+# Server settings
+server:
+    host: 0.0.0.0 # bind address
+    port: 8080
+
+# Logging
+log:
+    level: info
+";
+        let patched = YamlPatch::new()
+            .with_section("server", "port: 9090\ncors:\n  origins: ['*']")
+            .with_section("log", "format: json")
+            .with_section("database.redis", "addr: localhost:6379")
+            .apply_to(content)
+            .unwrap();
+        assert_eq!(
+            patched,
+            "\
+# Server settings
+server:
+    host: 0.0.0.0 # bind address
+    port: 8080
+    cors:
+      origins:
+      - '*'
+
+# Logging
+log:
+    level: info
+    format: json
+database:
+  redis:
+    addr: localhost:6379
+"
+        );
+
+        // 同一行写出的映射无法按块插入，标量值不能作为映射
+        assert!(
+            YamlPatch::new()
+                .with_section("server", "port: 9090\ncors: true")
+                .apply_to("server: { port: 8080 }\n")
+                .is_err()
+        );
+        assert!(
+            YamlPatch::new()
+                .with_section("server.cors", "enabled: true")
+                .apply_to("server: 8080\n")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,18 @@
+{{/*
+Common labels for a workload
+*/}}
+{{- define "chart.labels" -}}
+app.kubernetes.io/name: {{ .workload.name }}
+app.kubernetes.io/part-of: {{ .root.Chart.Name }}
+app.kubernetes.io/instance: {{ .root.Release.Name }}
+app.kubernetes.io/version: {{ .root.Chart.AppVersion | quote }}
+app.kubernetes.io/managed-by: {{ .root.Release.Service }}
+{{- end }}
+
+{{/*
+Selector labels for a workload
+*/}}
+{{- define "chart.selectorLabels" -}}
+app.kubernetes.io/name: {{ .workload.name }}
+app.kubernetes.io/instance: {{ .root.Release.Name }}
+{{- end }}
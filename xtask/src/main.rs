@@ -0,0 +1,113 @@
+//! 开发辅助任务，通过 `cargo xtask <task>` 运行
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+/// 可执行文件名
+const BINARY_NAME: &str = "scafgen";
+
+const USAGE: &str = "Usage: cargo xtask <task>
+
+Tasks:
+  copy-binary [--release]  Build scafgen and copy the binary to the project root";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("copy-binary") => copy_binary(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Some(task) => Err(format!("unknown task: {task}\n\n{USAGE}")),
+        None => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 构建 scafgen 并将二进制复制到项目根目录，复制后运行 `--version` 验证
+fn copy_binary(args: &[String]) -> Result<(), String> {
+    let mut release = false;
+    for arg in args {
+        match arg.as_str() {
+            "--release" => release = true,
+            _ => return Err(format!("unknown argument for copy-binary: {arg}")),
+        }
+    }
+
+    let root = project_root();
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut build = Command::new(cargo);
+    build
+        .current_dir(&root)
+        .args(["build", "--package", "scaffold-gen", "--bin", BINARY_NAME]);
+    if release {
+        build.arg("--release");
+    }
+    let status = build
+        .status()
+        .map_err(|e| format!("failed to run cargo build: {e}"))?;
+    if !status.success() {
+        return Err(format!("cargo build exited with {status}"));
+    }
+
+    let profile = if release { "release" } else { "debug" };
+    let file_name = format!("{BINARY_NAME}{}", env::consts::EXE_SUFFIX);
+    let source = target_dir(&root).join(profile).join(&file_name);
+    let dest = root.join(&file_name);
+
+    fs::copy(&source, &dest).map_err(|e| {
+        format!(
+            "failed to copy {} to {}: {e}",
+            source.display(),
+            dest.display()
+        )
+    })?;
+    println!("Binary copied to project root: {}", dest.display());
+
+    verify_binary(&dest)?;
+    println!("Binary verification successful");
+    Ok(())
+}
+
+/// 运行 `--version` 确认复制的二进制可以执行
+fn verify_binary(binary: &Path) -> Result<(), String> {
+    let output = Command::new(binary)
+        .arg("--version")
+        .output()
+        .map_err(|e| format!("failed to run {}: {e}", binary.display()))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} --version exited with {}",
+            binary.display(),
+            output.status
+        ))
+    }
+}
+
+/// 项目根目录（xtask 的上级目录）
+fn project_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask is inside the project root")
+        .to_path_buf()
+}
+
+/// 构建输出目录，遵循 `CARGO_TARGET_DIR`
+fn target_dir(root: &Path) -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .map(|dir| root.join(dir))
+        .unwrap_or_else(|| root.join("target"))
+}